@@ -0,0 +1,108 @@
+use std::fmt::Display;
+
+// Errors of TS 24.008 structures shared between GTPv1 and GTPv2
+
+#[derive(Debug, PartialEq, Eq)]
+
+pub enum CommonError {
+    // TFT Errors
+    TftInvalidLength,
+    TftIncorrectOperation,
+    TftUnknownComponent(u8),
+    // PCO Errors
+    PcoInvalidLength,
+    // QoS Errors
+    QosInvalidLength,
+}
+
+impl std::error::Error for CommonError {}
+
+impl Display for CommonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            // TFT Errors
+            CommonError::TftInvalidLength => write!(f, "Invalid TFT length"),
+            CommonError::TftIncorrectOperation => {
+                write!(f, "Packet filter list incorrect for TFT operation")
+            }
+            CommonError::TftUnknownComponent(i) => {
+                write!(f, "Unknown packet filter component type {}", i)
+            }
+            // PCO Errors
+            CommonError::PcoInvalidLength => write!(f, "Invalid PCO length"),
+            // QoS Errors
+            CommonError::QosInvalidLength => write!(f, "Invalid QoS profile length"),
+        }
+    }
+}
+
+// Reason of a message decoding failure
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeErrorReason {
+    Truncated,
+    InvalidLength,
+    InvalidSpareBits,
+    InvalidValue,
+    MandatoryIEMissing,
+}
+
+impl Display for DecodeErrorReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeErrorReason::Truncated => write!(f, "truncated"),
+            DecodeErrorReason::InvalidLength => write!(f, "invalid length"),
+            DecodeErrorReason::InvalidSpareBits => write!(f, "invalid spare bits"),
+            DecodeErrorReason::InvalidValue => write!(f, "invalid value"),
+            DecodeErrorReason::MandatoryIEMissing => write!(f, "mandatory IE missing"),
+        }
+    }
+}
+
+// Step of the path to a faulty IE, index counts the preceding IEs with the same type and instance
+// within the same message or grouped IE
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IePathElement {
+    pub t: u8,
+    pub ins: u8,
+    pub index: usize,
+}
+
+impl Display for IePathElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}] ins {}", self.t, self.index, self.ins)
+    }
+}
+
+// Decoding error with its location: message type, byte offset from the start of the message
+// and path through grouped IEs, outermost IE first.
+// The message decoders do not track locations: located errors are only built by parse_detailed(),
+// which walks the message again IE by IE after a failure. The location is the first IE that fails
+// to decode on its own, or the end of the message for message level errors such as a missing mandatory IE
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError<E> {
+    pub msgtype: u8,
+    pub offset: usize,
+    pub path: Vec<IePathElement>,
+    pub reason: DecodeErrorReason,
+    pub error: E,
+}
+
+impl<E: Display> Display for DecodeError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Message type {} decoding failed at offset {}",
+            self.msgtype, self.offset
+        )?;
+        if !self.path.is_empty() {
+            let path: Vec<String> = self.path.iter().map(|x| x.to_string()).collect();
+            write!(f, " in IE {}", path.join(" -> "))?;
+        }
+        write!(f, ": {} ({})", self.reason, self.error)
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for DecodeError<E> {}
@@ -0,0 +1,7 @@
+pub use {errors::*, pco::*, qos::*, tft::*, tftmatch::*, timers::*};
+mod errors;
+mod pco;
+mod qos;
+mod tft;
+mod tftmatch;
+mod timers;
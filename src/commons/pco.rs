@@ -0,0 +1,435 @@
+// Protocol Configuration Options (PCO) - according to 3GPP TS 24.008 V16.0.0 (2019-03), clause 10.5.6.3
+// Extended PCO (ePCO) encoding with 2-octet container length is specified in clause 9.9.4.26 of 3GPP TS 24.301
+
+use crate::commons::errors::*;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+// Configuration protocol
+
+pub const PCO_CONFIG_PROTOCOL_PPP: u8 = 0;
+
+// Protocol identifiers
+
+pub const PCO_LCP: u16 = 0xc021;
+pub const PCO_PAP: u16 = 0xc023;
+pub const PCO_CHAP: u16 = 0xc223;
+pub const PCO_IPCP: u16 = 0x8021;
+
+// Container identifiers
+
+pub const PCO_PCSCF_IPV6: u16 = 0x0001;
+pub const PCO_DNS_IPV6: u16 = 0x0003;
+pub const PCO_BEARER_CONTROL_MODE: u16 = 0x0005;
+pub const PCO_PCSCF_IPV4: u16 = 0x000c;
+pub const PCO_DNS_IPV4: u16 = 0x000d;
+pub const PCO_IPV4_LINK_MTU: u16 = 0x0010;
+
+// IPCP codes and configuration options (RFC 1332 and RFC 1877)
+
+pub const IPCP_CONFIGURE_REQUEST: u8 = 1;
+pub const IPCP_CONFIGURE_ACK: u8 = 2;
+pub const IPCP_CONFIGURE_NAK: u8 = 3;
+pub const IPCP_CONFIGURE_REJECT: u8 = 4;
+
+pub const IPCP_PRIMARY_DNS: u8 = 0x81;
+pub const IPCP_SECONDARY_DNS: u8 = 0x83;
+
+// Selected Bearer Control Mode values
+
+pub const BCM_MS_ONLY: u8 = 1;
+pub const BCM_MS_NW: u8 = 2;
+
+// IPCP configuration option
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IpcpOption {
+    PrimaryDns(Ipv4Addr),
+    SecondaryDns(Ipv4Addr),
+    Unknown { t: u8, value: Vec<u8> },
+}
+
+impl IpcpOption {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        match self {
+            IpcpOption::PrimaryDns(i) => {
+                buffer.extend_from_slice(&[IPCP_PRIMARY_DNS, 6]);
+                buffer.extend_from_slice(&i.octets());
+            }
+            IpcpOption::SecondaryDns(i) => {
+                buffer.extend_from_slice(&[IPCP_SECONDARY_DNS, 6]);
+                buffer.extend_from_slice(&i.octets());
+            }
+            IpcpOption::Unknown { t, value } => {
+                buffer.push(*t);
+                buffer.push((value.len() + 2) as u8);
+                buffer.extend_from_slice(value);
+            }
+        }
+    }
+}
+
+// IPCP packet carried in the PCO (RFC 1332)
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ipcp {
+    pub code: u8,
+    pub identifier: u8,
+    pub options: Vec<IpcpOption>,
+}
+
+impl Default for Ipcp {
+    fn default() -> Self {
+        Ipcp {
+            code: IPCP_CONFIGURE_REQUEST,
+            identifier: 0,
+            options: vec![],
+        }
+    }
+}
+
+impl Ipcp {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_opts: Vec<u8> = vec![];
+        self.options
+            .iter()
+            .for_each(|x| x.marshal(&mut buffer_opts));
+        buffer.push(self.code);
+        buffer.push(self.identifier);
+        buffer.extend_from_slice(&((buffer_opts.len() + 4) as u16).to_be_bytes());
+        buffer.append(&mut buffer_opts);
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, CommonError> {
+        if buffer.len() < 4 {
+            return Err(CommonError::PcoInvalidLength);
+        }
+        let length = u16::from_be_bytes([buffer[2], buffer[3]]) as usize;
+        if length < 4 || buffer.len() < length {
+            return Err(CommonError::PcoInvalidLength);
+        }
+        let mut data = Ipcp {
+            code: buffer[0],
+            identifier: buffer[1],
+            options: vec![],
+        };
+        let mut cursor: usize = 4;
+        while cursor < length {
+            if length < cursor + 2 {
+                return Err(CommonError::PcoInvalidLength);
+            }
+            let end = cursor + buffer[cursor + 1] as usize;
+            if buffer[cursor + 1] < 2 || length < end {
+                return Err(CommonError::PcoInvalidLength);
+            }
+            let value = &buffer[cursor + 2..end];
+            let option = match (buffer[cursor], value.len()) {
+                (IPCP_PRIMARY_DNS, 4) => {
+                    IpcpOption::PrimaryDns(Ipv4Addr::new(value[0], value[1], value[2], value[3]))
+                }
+                (IPCP_SECONDARY_DNS, 4) => {
+                    IpcpOption::SecondaryDns(Ipv4Addr::new(value[0], value[1], value[2], value[3]))
+                }
+                (t, _) => IpcpOption::Unknown {
+                    t,
+                    value: value.to_vec(),
+                },
+            };
+            data.options.push(option);
+            cursor = end;
+        }
+        Ok(data)
+    }
+}
+
+// PCO protocol or container entry
+// Request and response variants share the same identifier and are distinguished by the presence of contents.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PcoContainer {
+    Pap(Vec<u8>),
+    Chap(Vec<u8>),
+    Ipcp(Ipcp),
+    PcscfIpv6AddressRequest,
+    PcscfIpv6Address(Ipv6Addr),
+    DnsServerIpv6AddressRequest,
+    DnsServerIpv6Address(Ipv6Addr),
+    MsSupportOfNetworkRequestedBearerControl,
+    SelectedBearerControlMode(u8),
+    PcscfIpv4AddressRequest,
+    PcscfIpv4Address(Ipv4Addr),
+    DnsServerIpv4AddressRequest,
+    DnsServerIpv4Address(Ipv4Addr),
+    Ipv4LinkMtuRequest,
+    Ipv4LinkMtu(u16),
+    Unknown { id: u16, contents: Vec<u8> },
+}
+
+impl PcoContainer {
+    pub fn id(&self) -> u16 {
+        match self {
+            PcoContainer::Pap(_) => PCO_PAP,
+            PcoContainer::Chap(_) => PCO_CHAP,
+            PcoContainer::Ipcp(_) => PCO_IPCP,
+            PcoContainer::PcscfIpv6AddressRequest | PcoContainer::PcscfIpv6Address(_) => {
+                PCO_PCSCF_IPV6
+            }
+            PcoContainer::DnsServerIpv6AddressRequest | PcoContainer::DnsServerIpv6Address(_) => {
+                PCO_DNS_IPV6
+            }
+            PcoContainer::MsSupportOfNetworkRequestedBearerControl
+            | PcoContainer::SelectedBearerControlMode(_) => PCO_BEARER_CONTROL_MODE,
+            PcoContainer::PcscfIpv4AddressRequest | PcoContainer::PcscfIpv4Address(_) => {
+                PCO_PCSCF_IPV4
+            }
+            PcoContainer::DnsServerIpv4AddressRequest | PcoContainer::DnsServerIpv4Address(_) => {
+                PCO_DNS_IPV4
+            }
+            PcoContainer::Ipv4LinkMtuRequest | PcoContainer::Ipv4LinkMtu(_) => PCO_IPV4_LINK_MTU,
+            PcoContainer::Unknown { id, .. } => *id,
+        }
+    }
+
+    pub fn contents(&self) -> Vec<u8> {
+        match self {
+            PcoContainer::Pap(i) | PcoContainer::Chap(i) => i.clone(),
+            PcoContainer::Ipcp(i) => {
+                let mut buffer: Vec<u8> = vec![];
+                i.marshal(&mut buffer);
+                buffer
+            }
+            PcoContainer::PcscfIpv6Address(i) | PcoContainer::DnsServerIpv6Address(i) => {
+                i.octets().to_vec()
+            }
+            PcoContainer::PcscfIpv4Address(i) | PcoContainer::DnsServerIpv4Address(i) => {
+                i.octets().to_vec()
+            }
+            PcoContainer::SelectedBearerControlMode(i) => vec![*i],
+            PcoContainer::Ipv4LinkMtu(i) => i.to_be_bytes().to_vec(),
+            PcoContainer::Unknown { contents, .. } => contents.clone(),
+            _ => vec![],
+        }
+    }
+
+    pub fn decode(id: u16, contents: &[u8]) -> Result<Self, CommonError> {
+        let container = match (id, contents.len()) {
+            (PCO_PAP, _) => PcoContainer::Pap(contents.to_vec()),
+            (PCO_CHAP, _) => PcoContainer::Chap(contents.to_vec()),
+            (PCO_IPCP, _) => PcoContainer::Ipcp(Ipcp::unmarshal(contents)?),
+            (PCO_PCSCF_IPV6, 0) => PcoContainer::PcscfIpv6AddressRequest,
+            (PCO_PCSCF_IPV6, 16) => PcoContainer::PcscfIpv6Address(ipv6_from_slice(contents)),
+            (PCO_DNS_IPV6, 0) => PcoContainer::DnsServerIpv6AddressRequest,
+            (PCO_DNS_IPV6, 16) => PcoContainer::DnsServerIpv6Address(ipv6_from_slice(contents)),
+            (PCO_BEARER_CONTROL_MODE, 0) => PcoContainer::MsSupportOfNetworkRequestedBearerControl,
+            (PCO_BEARER_CONTROL_MODE, 1) => PcoContainer::SelectedBearerControlMode(contents[0]),
+            (PCO_PCSCF_IPV4, 0) => PcoContainer::PcscfIpv4AddressRequest,
+            (PCO_PCSCF_IPV4, 4) => PcoContainer::PcscfIpv4Address(Ipv4Addr::new(
+                contents[0],
+                contents[1],
+                contents[2],
+                contents[3],
+            )),
+            (PCO_DNS_IPV4, 0) => PcoContainer::DnsServerIpv4AddressRequest,
+            (PCO_DNS_IPV4, 4) => PcoContainer::DnsServerIpv4Address(Ipv4Addr::new(
+                contents[0],
+                contents[1],
+                contents[2],
+                contents[3],
+            )),
+            (PCO_IPV4_LINK_MTU, 0) => PcoContainer::Ipv4LinkMtuRequest,
+            (PCO_IPV4_LINK_MTU, 2) => {
+                PcoContainer::Ipv4LinkMtu(u16::from_be_bytes([contents[0], contents[1]]))
+            }
+            (PCO_PCSCF_IPV6, _)
+            | (PCO_DNS_IPV6, _)
+            | (PCO_BEARER_CONTROL_MODE, _)
+            | (PCO_PCSCF_IPV4, _)
+            | (PCO_DNS_IPV4, _)
+            | (PCO_IPV4_LINK_MTU, _) => return Err(CommonError::PcoInvalidLength),
+            (id, _) => PcoContainer::Unknown {
+                id,
+                contents: contents.to_vec(),
+            },
+        };
+        Ok(container)
+    }
+}
+
+// Protocol Configuration Options
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ProtocolConfigurationOptions {
+    pub configuration_protocol: u8,
+    pub containers: Vec<PcoContainer>,
+}
+
+impl ProtocolConfigurationOptions {
+    // PCO and APCO encoding with 1-octet container length
+
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        self.marshal_with(buffer, false)
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, CommonError> {
+        ProtocolConfigurationOptions::unmarshal_with(buffer, false)
+    }
+
+    // ePCO encoding with 2-octet container length
+
+    pub fn marshal_extended(&self, buffer: &mut Vec<u8>) {
+        self.marshal_with(buffer, true)
+    }
+
+    pub fn unmarshal_extended(buffer: &[u8]) -> Result<Self, CommonError> {
+        ProtocolConfigurationOptions::unmarshal_with(buffer, true)
+    }
+
+    fn marshal_with(&self, buffer: &mut Vec<u8>, extended: bool) {
+        buffer.push(0x80 | (self.configuration_protocol & 0x07));
+        for i in self.containers.iter() {
+            let contents = i.contents();
+            buffer.extend_from_slice(&i.id().to_be_bytes());
+            if extended {
+                buffer.extend_from_slice(&(contents.len() as u16).to_be_bytes());
+            } else {
+                buffer.push(contents.len() as u8);
+            }
+            buffer.extend_from_slice(&contents);
+        }
+    }
+
+    fn unmarshal_with(buffer: &[u8], extended: bool) -> Result<Self, CommonError> {
+        if buffer.is_empty() {
+            return Err(CommonError::PcoInvalidLength);
+        }
+        let mut data = ProtocolConfigurationOptions {
+            configuration_protocol: buffer[0] & 0x07,
+            containers: vec![],
+        };
+        let hdr_len: usize = if extended { 4 } else { 3 };
+        let mut cursor: usize = 1;
+        while cursor < buffer.len() {
+            if buffer.len() < cursor + hdr_len {
+                return Err(CommonError::PcoInvalidLength);
+            }
+            let id = u16::from_be_bytes([buffer[cursor], buffer[cursor + 1]]);
+            let length = if extended {
+                u16::from_be_bytes([buffer[cursor + 2], buffer[cursor + 3]]) as usize
+            } else {
+                buffer[cursor + 2] as usize
+            };
+            let end = cursor + hdr_len + length;
+            if buffer.len() < end {
+                return Err(CommonError::PcoInvalidLength);
+            }
+            data.containers
+                .push(PcoContainer::decode(id, &buffer[cursor + hdr_len..end])?);
+            cursor = end;
+        }
+        Ok(data)
+    }
+}
+
+fn ipv6_from_slice(buffer: &[u8]) -> Ipv6Addr {
+    let mut octets = [0u8; 16];
+    octets.copy_from_slice(&buffer[..16]);
+    Ipv6Addr::from(octets)
+}
+
+#[test]
+fn pco_ipcp_dns_request_unmarshal_test() {
+    let encoded: [u8; 29] = [
+        0x80, 0x80, 0x21, 0x10, 0x01, 0x01, 0x00, 0x10, 0x81, 0x06, 0x00, 0x00, 0x00, 0x00, 0x83,
+        0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x05, 0x00,
+    ];
+    let decoded = ProtocolConfigurationOptions {
+        configuration_protocol: PCO_CONFIG_PROTOCOL_PPP,
+        containers: vec![
+            PcoContainer::Ipcp(Ipcp {
+                code: IPCP_CONFIGURE_REQUEST,
+                identifier: 1,
+                options: vec![
+                    IpcpOption::PrimaryDns(Ipv4Addr::new(0, 0, 0, 0)),
+                    IpcpOption::SecondaryDns(Ipv4Addr::new(0, 0, 0, 0)),
+                ],
+            }),
+            PcoContainer::DnsServerIpv4AddressRequest,
+            PcoContainer::PcscfIpv4AddressRequest,
+            PcoContainer::MsSupportOfNetworkRequestedBearerControl,
+        ],
+    };
+    assert_eq!(
+        ProtocolConfigurationOptions::unmarshal(&encoded).unwrap(),
+        decoded
+    );
+}
+
+#[test]
+fn pco_response_marshal_test() {
+    let encoded: [u8; 55] = [
+        0x80, 0x80, 0x21, 0x10, 0x03, 0x01, 0x00, 0x10, 0x81, 0x06, 0x08, 0x08, 0x08, 0x08, 0x83,
+        0x06, 0x08, 0x08, 0x04, 0x04, 0x00, 0x0d, 0x04, 0x08, 0x08, 0x08, 0x08, 0x00, 0x01, 0x10,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x10, 0x02, 0x05, 0xdc, 0x00, 0x05, 0x01, 0x01,
+    ];
+    let decoded = ProtocolConfigurationOptions {
+        configuration_protocol: PCO_CONFIG_PROTOCOL_PPP,
+        containers: vec![
+            PcoContainer::Ipcp(Ipcp {
+                code: IPCP_CONFIGURE_NAK,
+                identifier: 1,
+                options: vec![
+                    IpcpOption::PrimaryDns(Ipv4Addr::new(8, 8, 8, 8)),
+                    IpcpOption::SecondaryDns(Ipv4Addr::new(8, 8, 4, 4)),
+                ],
+            }),
+            PcoContainer::DnsServerIpv4Address(Ipv4Addr::new(8, 8, 8, 8)),
+            PcoContainer::PcscfIpv6Address(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+            PcoContainer::Ipv4LinkMtu(1500),
+            PcoContainer::SelectedBearerControlMode(BCM_MS_ONLY),
+        ],
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+    assert_eq!(
+        ProtocolConfigurationOptions::unmarshal(&encoded).unwrap(),
+        decoded
+    );
+}
+
+#[test]
+fn pco_extended_round_trip_test() {
+    let encoded: [u8; 13] = [
+        0x80, 0x00, 0x03, 0x00, 0x00, 0xff, 0x00, 0x00, 0x03, 0xaa, 0xbb, 0xcc, 0x80,
+    ];
+    let decoded = ProtocolConfigurationOptions {
+        configuration_protocol: PCO_CONFIG_PROTOCOL_PPP,
+        containers: vec![
+            PcoContainer::DnsServerIpv6AddressRequest,
+            PcoContainer::Unknown {
+                id: 0xff00,
+                contents: vec![0xaa, 0xbb, 0xcc],
+            },
+        ],
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal_extended(&mut buffer);
+    assert_eq!(buffer, encoded[..12]);
+    assert_eq!(
+        ProtocolConfigurationOptions::unmarshal_extended(&encoded[..12]).unwrap(),
+        decoded
+    );
+    assert_eq!(
+        ProtocolConfigurationOptions::unmarshal_extended(&encoded),
+        Err(CommonError::PcoInvalidLength)
+    );
+}
+
+#[test]
+fn pco_invalid_container_length_test() {
+    let encoded: [u8; 6] = [0x80, 0x00, 0x0d, 0x02, 0x08, 0x08];
+    assert_eq!(
+        ProtocolConfigurationOptions::unmarshal(&encoded),
+        Err(CommonError::PcoInvalidLength)
+    );
+}
@@ -0,0 +1,378 @@
+// Quality of Service (QoS) profile - according to 3GPP TS 24.008 V16.0.0 (2019-03), clause 10.5.6.5
+// Octets are numbered as in clause 10.5.6.5, i.e. octet 3 is the first octet of the profile
+
+use crate::commons::errors::*;
+
+// Traffic class values
+
+pub const QOS_TRAFFIC_CLASS_SUBSCRIBED: u8 = 0;
+pub const QOS_TRAFFIC_CLASS_CONVERSATIONAL: u8 = 1;
+pub const QOS_TRAFFIC_CLASS_STREAMING: u8 = 2;
+pub const QOS_TRAFFIC_CLASS_INTERACTIVE: u8 = 3;
+pub const QOS_TRAFFIC_CLASS_BACKGROUND: u8 = 4;
+
+// Source Statistics Descriptor values
+
+pub const QOS_SOURCE_STATISTICS_UNKNOWN: u8 = 0;
+pub const QOS_SOURCE_STATISTICS_SPEECH: u8 = 1;
+
+// Octet counts of the profile versions: pre-R99 (octets 3-5), R99 (octets 3-13),
+// with Signalling Indication and Source Statistics Descriptor (octets 3-14) and
+// with the extended (octets 15-18) and extended-2 (octets 19-22) bitrate octets
+
+pub const QOS_PRE_R99_LENGTH: usize = 3;
+pub const QOS_R99_LENGTH: usize = 11;
+pub const QOS_R5_LENGTH: usize = 12;
+pub const QOS_EXTENDED_DL_LENGTH: usize = 14;
+pub const QOS_EXTENDED_UL_LENGTH: usize = 16;
+pub const QOS_EXTENDED_2_DL_LENGTH: usize = 18;
+pub const QOS_EXTENDED_2_UL_LENGTH: usize = 20;
+
+// Highest bitrates (kbps) encodable in the base, extended and extended-2 octets
+
+const BITRATE_BASE_MAX: u32 = 8640;
+const BITRATE_EXTENDED_MAX: u32 = 256_000;
+const BITRATE_EXTENDED_2_MAX: u32 = 10_000_000;
+
+// Bitrate decoding from the base, extended and extended-2 octets, None stands for "subscribed"
+
+fn decode_bitrate(base: u8, extended: u8, extended_2: u8) -> Option<u32> {
+    if extended_2 != 0 {
+        let i = extended_2.min(0xf6) as u32;
+        return Some(match i {
+            0x01..=0x3d => 256_000 + i * 4_000,
+            0x3e..=0xa1 => 500_000 + (i - 0x3d) * 10_000,
+            _ => 1_500_000 + (i - 0xa1) * 100_000,
+        });
+    }
+    if extended != 0 {
+        let i = extended.min(0xfa) as u32;
+        return Some(match i {
+            0x01..=0x4a => 8_600 + i * 100,
+            0x4b..=0xba => 16_000 + (i - 0x4a) * 1_000,
+            _ => 128_000 + (i - 0xba) * 2_000,
+        });
+    }
+    let i = base as u32;
+    match base {
+        0x00 => None,
+        0x01..=0x3f => Some(i),
+        0x40..=0x7f => Some(64 + (i - 0x40) * 8),
+        0x80..=0xfe => Some(576 + (i - 0x80) * 64),
+        0xff => Some(0),
+    }
+}
+
+// Bitrate encoding into the base, extended and extended-2 octets, rounded down to the closest step
+
+fn encode_bitrate(bitrate: Option<u32>) -> (u8, u8, u8) {
+    let i = match bitrate {
+        None => return (0x00, 0x00, 0x00),
+        Some(0) => return (0xff, 0x00, 0x00),
+        Some(i) => i.min(BITRATE_EXTENDED_2_MAX),
+    };
+    match i {
+        1..=63 => (i as u8, 0x00, 0x00),
+        64..=575 => ((0x40 + (i - 64) / 8) as u8, 0x00, 0x00),
+        576..=8_699 => ((0x80 + (i - 576) / 64) as u8, 0x00, 0x00),
+        8_700..=16_000 => (0xfe, ((i - 8_600) / 100) as u8, 0x00),
+        16_001..=128_000 => (0xfe, (0x4a + (i - 16_000) / 1_000) as u8, 0x00),
+        128_001..=259_999 => (0xfe, (0xba + (i - 128_000) / 2_000).min(0xfa) as u8, 0x00),
+        260_000..=500_000 => (0xfe, 0xfa, ((i - 256_000) / 4_000) as u8),
+        500_001..=1_500_000 => (0xfe, 0xfa, (0x3d + (i - 500_000) / 10_000) as u8),
+        _ => (0xfe, 0xfa, (0xa1 + (i - 1_500_000) / 100_000) as u8),
+    }
+}
+
+// Maximum SDU size in octets, 0 stands for "subscribed"
+
+fn decode_max_sdu_size(i: u8) -> u16 {
+    match i {
+        0x01..=0x96 => i as u16 * 10,
+        0x97 => 1502,
+        0x98 => 1510,
+        0x99 => 1520,
+        _ => 0,
+    }
+}
+
+fn encode_max_sdu_size(i: u16) -> u8 {
+    match i {
+        0 => 0x00,
+        1..=1500 => (i / 10).max(1) as u8,
+        1501..=1502 => 0x97,
+        1503..=1510 => 0x98,
+        _ => 0x99,
+    }
+}
+
+// Transfer delay in milliseconds, 0 stands for "subscribed"
+
+fn decode_transfer_delay(i: u8) -> u16 {
+    let i = i as u16;
+    match i {
+        0x01..=0x0f => i * 10,
+        0x10..=0x1f => 200 + (i - 0x10) * 50,
+        0x20..=0x3e => 1000 + (i - 0x20) * 100,
+        _ => 0,
+    }
+}
+
+fn encode_transfer_delay(i: u16) -> u8 {
+    match i {
+        0 => 0x00,
+        1..=199 => (i / 10).clamp(1, 0x0f) as u8,
+        200..=999 => (0x10 + (i - 200) / 50) as u8,
+        _ => (0x20 + (i - 1000) / 100).min(0x3e) as u8,
+    }
+}
+
+// QoS profile implementation
+// Bitrates are in kbps with None standing for "subscribed" (uplink) or "reserved" (downlink)
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct QosProfile {
+    pub delay_class: u8,
+    pub reliability_class: u8,
+    pub peak_throughput: u8,
+    pub precedence_class: u8,
+    pub mean_throughput: u8,
+    pub traffic_class: u8,
+    pub delivery_order: u8,
+    pub delivery_of_erroneous_sdu: u8,
+    pub max_sdu_size: u16,
+    pub max_bitrate_ul: Option<u32>,
+    pub max_bitrate_dl: Option<u32>,
+    pub residual_ber: u8,
+    pub sdu_error_ratio: u8,
+    pub transfer_delay: u16,
+    pub traffic_handling_priority: u8,
+    pub guaranteed_bitrate_ul: Option<u32>,
+    pub guaranteed_bitrate_dl: Option<u32>,
+    pub signalling_indication: bool,
+    pub source_statistics_descriptor: u8,
+}
+
+impl QosProfile {
+    // Number of octets needed to carry the profile, the R99 attributes are omitted
+    // when all of them are left as "subscribed"
+
+    pub fn encoded_len(&self) -> usize {
+        let pre_r99 = QosProfile {
+            delay_class: self.delay_class,
+            reliability_class: self.reliability_class,
+            peak_throughput: self.peak_throughput,
+            precedence_class: self.precedence_class,
+            mean_throughput: self.mean_throughput,
+            ..Default::default()
+        };
+        let above = |i: Option<u32>, max: u32| i.is_some_and(|x| x > max);
+        if *self == pre_r99 {
+            QOS_PRE_R99_LENGTH
+        } else if above(self.max_bitrate_ul, BITRATE_EXTENDED_MAX)
+            || above(self.guaranteed_bitrate_ul, BITRATE_EXTENDED_MAX)
+        {
+            QOS_EXTENDED_2_UL_LENGTH
+        } else if above(self.max_bitrate_dl, BITRATE_EXTENDED_MAX)
+            || above(self.guaranteed_bitrate_dl, BITRATE_EXTENDED_MAX)
+        {
+            QOS_EXTENDED_2_DL_LENGTH
+        } else if above(self.max_bitrate_ul, BITRATE_BASE_MAX)
+            || above(self.guaranteed_bitrate_ul, BITRATE_BASE_MAX)
+        {
+            QOS_EXTENDED_UL_LENGTH
+        } else if above(self.max_bitrate_dl, BITRATE_BASE_MAX)
+            || above(self.guaranteed_bitrate_dl, BITRATE_BASE_MAX)
+        {
+            QOS_EXTENDED_DL_LENGTH
+        } else if self.signalling_indication || self.source_statistics_descriptor != 0 {
+            QOS_R5_LENGTH
+        } else {
+            QOS_R99_LENGTH
+        }
+    }
+
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        let length = self.encoded_len();
+        let (max_ul, max_ul_ext, max_ul_ext_2) = encode_bitrate(self.max_bitrate_ul);
+        let (max_dl, max_dl_ext, max_dl_ext_2) = encode_bitrate(self.max_bitrate_dl);
+        let (gbr_ul, gbr_ul_ext, gbr_ul_ext_2) = encode_bitrate(self.guaranteed_bitrate_ul);
+        let (gbr_dl, gbr_dl_ext, gbr_dl_ext_2) = encode_bitrate(self.guaranteed_bitrate_dl);
+        let octets: [u8; QOS_EXTENDED_2_UL_LENGTH] = [
+            (self.delay_class & 0x07) << 3 | (self.reliability_class & 0x07),
+            (self.peak_throughput & 0x0f) << 4 | (self.precedence_class & 0x07),
+            self.mean_throughput & 0x1f,
+            (self.traffic_class & 0x07) << 5
+                | (self.delivery_order & 0x03) << 3
+                | (self.delivery_of_erroneous_sdu & 0x07),
+            encode_max_sdu_size(self.max_sdu_size),
+            max_ul,
+            max_dl,
+            (self.residual_ber & 0x0f) << 4 | (self.sdu_error_ratio & 0x0f),
+            encode_transfer_delay(self.transfer_delay) << 2
+                | (self.traffic_handling_priority & 0x03),
+            gbr_ul,
+            gbr_dl,
+            (self.signalling_indication as u8) << 4 | (self.source_statistics_descriptor & 0x0f),
+            max_dl_ext,
+            gbr_dl_ext,
+            max_ul_ext,
+            gbr_ul_ext,
+            max_dl_ext_2,
+            gbr_dl_ext_2,
+            max_ul_ext_2,
+            gbr_ul_ext_2,
+        ];
+        buffer.extend_from_slice(&octets[..length]);
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, CommonError> {
+        if buffer.len() < QOS_PRE_R99_LENGTH {
+            return Err(CommonError::QosInvalidLength);
+        }
+        // Octets not present in the profile are decoded as 0
+        let mut octets = [0; QOS_EXTENDED_2_UL_LENGTH];
+        let length = buffer.len().min(QOS_EXTENDED_2_UL_LENGTH);
+        octets[..length].copy_from_slice(&buffer[..length]);
+        Ok(QosProfile {
+            delay_class: octets[0] >> 3 & 0x07,
+            reliability_class: octets[0] & 0x07,
+            peak_throughput: octets[1] >> 4,
+            precedence_class: octets[1] & 0x07,
+            mean_throughput: octets[2] & 0x1f,
+            traffic_class: octets[3] >> 5,
+            delivery_order: octets[3] >> 3 & 0x03,
+            delivery_of_erroneous_sdu: octets[3] & 0x07,
+            max_sdu_size: decode_max_sdu_size(octets[4]),
+            max_bitrate_ul: decode_bitrate(octets[5], octets[14], octets[18]),
+            max_bitrate_dl: decode_bitrate(octets[6], octets[12], octets[16]),
+            residual_ber: octets[7] >> 4,
+            sdu_error_ratio: octets[7] & 0x0f,
+            transfer_delay: decode_transfer_delay(octets[8] >> 2),
+            traffic_handling_priority: octets[8] & 0x03,
+            guaranteed_bitrate_ul: decode_bitrate(octets[9], octets[15], octets[19]),
+            guaranteed_bitrate_dl: decode_bitrate(octets[10], octets[13], octets[17]),
+            signalling_indication: octets[11] & 0x10 != 0,
+            source_statistics_descriptor: octets[11] & 0x0f,
+        })
+    }
+}
+
+#[test]
+fn qos_profile_r99_unmarshal_test() {
+    let encoded: [u8; 11] = [
+        0x1b, 0x93, 0x1f, 0x73, 0x96, 0x97, 0x97, 0x44, 0xfb, 0x10, 0x40,
+    ];
+    let decoded = QosProfile {
+        delay_class: 3,
+        reliability_class: 3,
+        peak_throughput: 9,
+        precedence_class: 3,
+        mean_throughput: 31,
+        traffic_class: QOS_TRAFFIC_CLASS_INTERACTIVE,
+        delivery_order: 2,
+        delivery_of_erroneous_sdu: 3,
+        max_sdu_size: 1500,
+        max_bitrate_ul: Some(2048),
+        max_bitrate_dl: Some(2048),
+        residual_ber: 4,
+        sdu_error_ratio: 4,
+        transfer_delay: 4000,
+        traffic_handling_priority: 3,
+        guaranteed_bitrate_ul: Some(16),
+        guaranteed_bitrate_dl: Some(64),
+        signalling_indication: false,
+        source_statistics_descriptor: QOS_SOURCE_STATISTICS_UNKNOWN,
+    };
+    assert_eq!(QosProfile::unmarshal(&encoded).unwrap(), decoded);
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn qos_profile_pre_r99_marshal_unmarshal_test() {
+    let encoded: [u8; 3] = [0x23, 0x62, 0x1f];
+    let decoded = QosProfile {
+        delay_class: 4,
+        reliability_class: 3,
+        peak_throughput: 6,
+        precedence_class: 2,
+        mean_throughput: 31,
+        ..Default::default()
+    };
+    assert_eq!(QosProfile::unmarshal(&encoded).unwrap(), decoded);
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn qos_profile_extended_bitrates_marshal_unmarshal_test() {
+    let encoded: [u8; 20] = [
+        0x23, 0x62, 0x1f, 0x93, 0x96, 0xfe, 0xfe, 0x74, 0x13, 0xfe, 0xfe, 0x11, 0xfa, 0x4a, 0xfa,
+        0x01, 0x3d, 0x00, 0x6f, 0x00,
+    ];
+    let decoded = QosProfile {
+        delay_class: 4,
+        reliability_class: 3,
+        peak_throughput: 6,
+        precedence_class: 2,
+        mean_throughput: 31,
+        traffic_class: QOS_TRAFFIC_CLASS_BACKGROUND,
+        delivery_order: 2,
+        delivery_of_erroneous_sdu: 3,
+        max_sdu_size: 1500,
+        max_bitrate_ul: Some(1_000_000),
+        max_bitrate_dl: Some(500_000),
+        residual_ber: 7,
+        sdu_error_ratio: 4,
+        transfer_delay: 40,
+        traffic_handling_priority: 3,
+        guaranteed_bitrate_ul: Some(8_700),
+        guaranteed_bitrate_dl: Some(16_000),
+        signalling_indication: true,
+        source_statistics_descriptor: QOS_SOURCE_STATISTICS_SPEECH,
+    };
+    assert_eq!(QosProfile::unmarshal(&encoded).unwrap(), decoded);
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn qos_profile_bitrate_tables_test() {
+    let steps: [(Option<u32>, (u8, u8, u8)); 12] = [
+        (None, (0x00, 0x00, 0x00)),
+        (Some(0), (0xff, 0x00, 0x00)),
+        (Some(63), (0x3f, 0x00, 0x00)),
+        (Some(568), (0x7f, 0x00, 0x00)),
+        (Some(8_640), (0xfe, 0x00, 0x00)),
+        (Some(8_700), (0xfe, 0x01, 0x00)),
+        (Some(17_000), (0xfe, 0x4b, 0x00)),
+        (Some(256_000), (0xfe, 0xfa, 0x00)),
+        (Some(260_000), (0xfe, 0xfa, 0x01)),
+        (Some(510_000), (0xfe, 0xfa, 0x3e)),
+        (Some(1_600_000), (0xfe, 0xfa, 0xa2)),
+        (Some(10_000_000), (0xfe, 0xfa, 0xf6)),
+    ];
+    for (bitrate, octets) in steps {
+        assert_eq!(encode_bitrate(bitrate), octets);
+        assert_eq!(decode_bitrate(octets.0, octets.1, octets.2), bitrate);
+    }
+    assert_eq!(encode_bitrate(Some(100)), (0x44, 0x00, 0x00));
+    // Bitrates between two steps are rounded down, including across the octet boundaries
+    assert_eq!(encode_bitrate(Some(8_699)), (0xfe, 0x00, 0x00));
+    assert_eq!(encode_bitrate(Some(8_799)), (0xfe, 0x01, 0x00));
+    assert_eq!(encode_bitrate(Some(259_999)), (0xfe, 0xfa, 0x00));
+    assert_eq!(decode_bitrate(0x44, 0x00, 0x00), Some(96));
+}
+
+#[test]
+fn qos_profile_invalid_length_unmarshal_test() {
+    let encoded: [u8; 2] = [0x23, 0x62];
+    assert_eq!(
+        QosProfile::unmarshal(&encoded),
+        Err(CommonError::QosInvalidLength)
+    );
+}
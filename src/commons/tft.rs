@@ -0,0 +1,683 @@
+// Traffic Flow Template (TFT) - according to 3GPP TS 24.008 V16.0.0 (2019-03), clause 10.5.6.12
+
+use crate::commons::errors::*;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+// TFT operation codes
+
+pub const TFT_OP_IGNORE: u8 = 0;
+pub const TFT_OP_CREATE_NEW: u8 = 1;
+pub const TFT_OP_DELETE_EXISTING: u8 = 2;
+pub const TFT_OP_ADD_FILTERS: u8 = 3;
+pub const TFT_OP_REPLACE_FILTERS: u8 = 4;
+pub const TFT_OP_DELETE_FILTERS: u8 = 5;
+pub const TFT_OP_NO_OPERATION: u8 = 6;
+
+// Packet filter component type identifiers
+
+pub const IPV4_REMOTE_ADDR: u8 = 0x10;
+pub const IPV4_LOCAL_ADDR: u8 = 0x11;
+pub const IPV6_REMOTE_ADDR: u8 = 0x20;
+pub const IPV6_REMOTE_ADDR_PREFIX: u8 = 0x21;
+pub const IPV6_LOCAL_ADDR_PREFIX: u8 = 0x23;
+pub const PROTOCOL_ID: u8 = 0x30;
+pub const SINGLE_LOCAL_PORT: u8 = 0x40;
+pub const LOCAL_PORT_RANGE: u8 = 0x41;
+pub const SINGLE_REMOTE_PORT: u8 = 0x50;
+pub const REMOTE_PORT_RANGE: u8 = 0x51;
+pub const SPI: u8 = 0x60;
+pub const TOS: u8 = 0x70;
+pub const FLOW_LABEL: u8 = 0x80;
+pub const DST_MAC_ADDR: u8 = 0x81;
+pub const SRC_MAC_ADDR: u8 = 0x82;
+pub const CTAG_VID: u8 = 0x83;
+pub const STAG_VID: u8 = 0x84;
+pub const CTAG_PCP_DEI: u8 = 0x85;
+pub const STAG_PCP_DEI: u8 = 0x86;
+pub const ETHERTYPE: u8 = 0x87;
+
+// TFT parameter identifiers
+
+pub const AUTHORIZATION_TOKEN: u8 = 1;
+pub const FLOW_ID: u8 = 2;
+pub const PACKET_FILTER_ID: u8 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TftOperationCode {
+    Ignore,
+    #[default]
+    CreateNew,
+    DeleteExisting,
+    AddPacketFilters,
+    ReplacePacketFilters,
+    DeletePacketFilters,
+    NoOperation,
+    Reserved,
+}
+
+impl From<&TftOperationCode> for u8 {
+    fn from(i: &TftOperationCode) -> u8 {
+        match i {
+            TftOperationCode::Ignore => TFT_OP_IGNORE,
+            TftOperationCode::CreateNew => TFT_OP_CREATE_NEW,
+            TftOperationCode::DeleteExisting => TFT_OP_DELETE_EXISTING,
+            TftOperationCode::AddPacketFilters => TFT_OP_ADD_FILTERS,
+            TftOperationCode::ReplacePacketFilters => TFT_OP_REPLACE_FILTERS,
+            TftOperationCode::DeletePacketFilters => TFT_OP_DELETE_FILTERS,
+            TftOperationCode::NoOperation => TFT_OP_NO_OPERATION,
+            TftOperationCode::Reserved => 7,
+        }
+    }
+}
+
+impl From<u8> for TftOperationCode {
+    fn from(i: u8) -> TftOperationCode {
+        match i {
+            TFT_OP_IGNORE => TftOperationCode::Ignore,
+            TFT_OP_CREATE_NEW => TftOperationCode::CreateNew,
+            TFT_OP_DELETE_EXISTING => TftOperationCode::DeleteExisting,
+            TFT_OP_ADD_FILTERS => TftOperationCode::AddPacketFilters,
+            TFT_OP_REPLACE_FILTERS => TftOperationCode::ReplacePacketFilters,
+            TFT_OP_DELETE_FILTERS => TftOperationCode::DeletePacketFilters,
+            TFT_OP_NO_OPERATION => TftOperationCode::NoOperation,
+            _ => TftOperationCode::Reserved,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PacketFilterDirection {
+    PreRel7,
+    Downlink,
+    Uplink,
+    #[default]
+    Bidirectional,
+}
+
+impl From<&PacketFilterDirection> for u8 {
+    fn from(i: &PacketFilterDirection) -> u8 {
+        match i {
+            PacketFilterDirection::PreRel7 => 0,
+            PacketFilterDirection::Downlink => 1,
+            PacketFilterDirection::Uplink => 2,
+            PacketFilterDirection::Bidirectional => 3,
+        }
+    }
+}
+
+impl From<u8> for PacketFilterDirection {
+    fn from(i: u8) -> PacketFilterDirection {
+        match i & 0x03 {
+            0 => PacketFilterDirection::PreRel7,
+            1 => PacketFilterDirection::Downlink,
+            2 => PacketFilterDirection::Uplink,
+            _ => PacketFilterDirection::Bidirectional,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketFilterComponent {
+    Ipv4RemoteAddress { addr: Ipv4Addr, mask: Ipv4Addr },
+    Ipv4LocalAddress { addr: Ipv4Addr, mask: Ipv4Addr },
+    Ipv6RemoteAddress { addr: Ipv6Addr, mask: Ipv6Addr },
+    Ipv6RemoteAddressPrefix { addr: Ipv6Addr, prefix_length: u8 },
+    Ipv6LocalAddressPrefix { addr: Ipv6Addr, prefix_length: u8 },
+    ProtocolIdentifier(u8),
+    SingleLocalPort(u16),
+    LocalPortRange { low: u16, high: u16 },
+    SingleRemotePort(u16),
+    RemotePortRange { low: u16, high: u16 },
+    SecurityParameterIndex(u32),
+    TypeOfService { value: u8, mask: u8 },
+    FlowLabel(u32),
+    DestinationMacAddress([u8; 6]),
+    SourceMacAddress([u8; 6]),
+    CTagVid(u16),
+    STagVid(u16),
+    CTagPcpDei(u8),
+    STagPcpDei(u8),
+    Ethertype(u16),
+}
+
+impl PacketFilterComponent {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        match self {
+            PacketFilterComponent::Ipv4RemoteAddress { addr, mask } => {
+                buffer.push(IPV4_REMOTE_ADDR);
+                buffer.extend_from_slice(&addr.octets());
+                buffer.extend_from_slice(&mask.octets());
+            }
+            PacketFilterComponent::Ipv4LocalAddress { addr, mask } => {
+                buffer.push(IPV4_LOCAL_ADDR);
+                buffer.extend_from_slice(&addr.octets());
+                buffer.extend_from_slice(&mask.octets());
+            }
+            PacketFilterComponent::Ipv6RemoteAddress { addr, mask } => {
+                buffer.push(IPV6_REMOTE_ADDR);
+                buffer.extend_from_slice(&addr.octets());
+                buffer.extend_from_slice(&mask.octets());
+            }
+            PacketFilterComponent::Ipv6RemoteAddressPrefix {
+                addr,
+                prefix_length,
+            } => {
+                buffer.push(IPV6_REMOTE_ADDR_PREFIX);
+                buffer.extend_from_slice(&addr.octets());
+                buffer.push(*prefix_length);
+            }
+            PacketFilterComponent::Ipv6LocalAddressPrefix {
+                addr,
+                prefix_length,
+            } => {
+                buffer.push(IPV6_LOCAL_ADDR_PREFIX);
+                buffer.extend_from_slice(&addr.octets());
+                buffer.push(*prefix_length);
+            }
+            PacketFilterComponent::ProtocolIdentifier(i) => {
+                buffer.push(PROTOCOL_ID);
+                buffer.push(*i);
+            }
+            PacketFilterComponent::SingleLocalPort(i) => {
+                buffer.push(SINGLE_LOCAL_PORT);
+                buffer.extend_from_slice(&i.to_be_bytes());
+            }
+            PacketFilterComponent::LocalPortRange { low, high } => {
+                buffer.push(LOCAL_PORT_RANGE);
+                buffer.extend_from_slice(&low.to_be_bytes());
+                buffer.extend_from_slice(&high.to_be_bytes());
+            }
+            PacketFilterComponent::SingleRemotePort(i) => {
+                buffer.push(SINGLE_REMOTE_PORT);
+                buffer.extend_from_slice(&i.to_be_bytes());
+            }
+            PacketFilterComponent::RemotePortRange { low, high } => {
+                buffer.push(REMOTE_PORT_RANGE);
+                buffer.extend_from_slice(&low.to_be_bytes());
+                buffer.extend_from_slice(&high.to_be_bytes());
+            }
+            PacketFilterComponent::SecurityParameterIndex(i) => {
+                buffer.push(SPI);
+                buffer.extend_from_slice(&i.to_be_bytes());
+            }
+            PacketFilterComponent::TypeOfService { value, mask } => {
+                buffer.push(TOS);
+                buffer.push(*value);
+                buffer.push(*mask);
+            }
+            PacketFilterComponent::FlowLabel(i) => {
+                buffer.push(FLOW_LABEL);
+                buffer.extend_from_slice(&(i & 0x000fffff).to_be_bytes()[1..]);
+            }
+            PacketFilterComponent::DestinationMacAddress(i) => {
+                buffer.push(DST_MAC_ADDR);
+                buffer.extend_from_slice(i);
+            }
+            PacketFilterComponent::SourceMacAddress(i) => {
+                buffer.push(SRC_MAC_ADDR);
+                buffer.extend_from_slice(i);
+            }
+            PacketFilterComponent::CTagVid(i) => {
+                buffer.push(CTAG_VID);
+                buffer.extend_from_slice(&(i & 0x0fff).to_be_bytes());
+            }
+            PacketFilterComponent::STagVid(i) => {
+                buffer.push(STAG_VID);
+                buffer.extend_from_slice(&(i & 0x0fff).to_be_bytes());
+            }
+            PacketFilterComponent::CTagPcpDei(i) => {
+                buffer.push(CTAG_PCP_DEI);
+                buffer.push(*i);
+            }
+            PacketFilterComponent::STagPcpDei(i) => {
+                buffer.push(STAG_PCP_DEI);
+                buffer.push(*i);
+            }
+            PacketFilterComponent::Ethertype(i) => {
+                buffer.push(ETHERTYPE);
+                buffer.extend_from_slice(&i.to_be_bytes());
+            }
+        }
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, CommonError> {
+        if buffer.is_empty() {
+            return Err(CommonError::TftInvalidLength);
+        }
+        let value_len = match buffer[0] {
+            IPV4_REMOTE_ADDR | IPV4_LOCAL_ADDR => 8,
+            IPV6_REMOTE_ADDR => 32,
+            IPV6_REMOTE_ADDR_PREFIX | IPV6_LOCAL_ADDR_PREFIX => 17,
+            PROTOCOL_ID | CTAG_PCP_DEI | STAG_PCP_DEI => 1,
+            SINGLE_LOCAL_PORT | SINGLE_REMOTE_PORT | TOS | CTAG_VID | STAG_VID | ETHERTYPE => 2,
+            FLOW_LABEL => 3,
+            LOCAL_PORT_RANGE | REMOTE_PORT_RANGE | SPI => 4,
+            DST_MAC_ADDR | SRC_MAC_ADDR => 6,
+            i => return Err(CommonError::TftUnknownComponent(i)),
+        };
+        if buffer.len() <= value_len {
+            return Err(CommonError::TftInvalidLength);
+        }
+        let v = &buffer[1..=value_len];
+        let component = match buffer[0] {
+            IPV4_REMOTE_ADDR => PacketFilterComponent::Ipv4RemoteAddress {
+                addr: Ipv4Addr::new(v[0], v[1], v[2], v[3]),
+                mask: Ipv4Addr::new(v[4], v[5], v[6], v[7]),
+            },
+            IPV4_LOCAL_ADDR => PacketFilterComponent::Ipv4LocalAddress {
+                addr: Ipv4Addr::new(v[0], v[1], v[2], v[3]),
+                mask: Ipv4Addr::new(v[4], v[5], v[6], v[7]),
+            },
+            IPV6_REMOTE_ADDR => PacketFilterComponent::Ipv6RemoteAddress {
+                addr: ipv6_from_slice(&v[..16]),
+                mask: ipv6_from_slice(&v[16..]),
+            },
+            IPV6_REMOTE_ADDR_PREFIX => PacketFilterComponent::Ipv6RemoteAddressPrefix {
+                addr: ipv6_from_slice(&v[..16]),
+                prefix_length: v[16],
+            },
+            IPV6_LOCAL_ADDR_PREFIX => PacketFilterComponent::Ipv6LocalAddressPrefix {
+                addr: ipv6_from_slice(&v[..16]),
+                prefix_length: v[16],
+            },
+            PROTOCOL_ID => PacketFilterComponent::ProtocolIdentifier(v[0]),
+            SINGLE_LOCAL_PORT => {
+                PacketFilterComponent::SingleLocalPort(u16::from_be_bytes([v[0], v[1]]))
+            }
+            LOCAL_PORT_RANGE => PacketFilterComponent::LocalPortRange {
+                low: u16::from_be_bytes([v[0], v[1]]),
+                high: u16::from_be_bytes([v[2], v[3]]),
+            },
+            SINGLE_REMOTE_PORT => {
+                PacketFilterComponent::SingleRemotePort(u16::from_be_bytes([v[0], v[1]]))
+            }
+            REMOTE_PORT_RANGE => PacketFilterComponent::RemotePortRange {
+                low: u16::from_be_bytes([v[0], v[1]]),
+                high: u16::from_be_bytes([v[2], v[3]]),
+            },
+            SPI => PacketFilterComponent::SecurityParameterIndex(u32::from_be_bytes([
+                v[0], v[1], v[2], v[3],
+            ])),
+            TOS => PacketFilterComponent::TypeOfService {
+                value: v[0],
+                mask: v[1],
+            },
+            FLOW_LABEL => PacketFilterComponent::FlowLabel(u32::from_be_bytes([
+                0x00,
+                v[0] & 0x0f,
+                v[1],
+                v[2],
+            ])),
+            DST_MAC_ADDR => {
+                PacketFilterComponent::DestinationMacAddress([v[0], v[1], v[2], v[3], v[4], v[5]])
+            }
+            SRC_MAC_ADDR => {
+                PacketFilterComponent::SourceMacAddress([v[0], v[1], v[2], v[3], v[4], v[5]])
+            }
+            CTAG_VID => PacketFilterComponent::CTagVid(u16::from_be_bytes([v[0] & 0x0f, v[1]])),
+            STAG_VID => PacketFilterComponent::STagVid(u16::from_be_bytes([v[0] & 0x0f, v[1]])),
+            CTAG_PCP_DEI => PacketFilterComponent::CTagPcpDei(v[0]),
+            STAG_PCP_DEI => PacketFilterComponent::STagPcpDei(v[0]),
+            _ => PacketFilterComponent::Ethertype(u16::from_be_bytes([v[0], v[1]])),
+        };
+        Ok(component)
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        match self {
+            PacketFilterComponent::Ipv4RemoteAddress { .. }
+            | PacketFilterComponent::Ipv4LocalAddress { .. } => 9,
+            PacketFilterComponent::Ipv6RemoteAddress { .. } => 33,
+            PacketFilterComponent::Ipv6RemoteAddressPrefix { .. }
+            | PacketFilterComponent::Ipv6LocalAddressPrefix { .. } => 18,
+            PacketFilterComponent::ProtocolIdentifier(_)
+            | PacketFilterComponent::CTagPcpDei(_)
+            | PacketFilterComponent::STagPcpDei(_) => 2,
+            PacketFilterComponent::SingleLocalPort(_)
+            | PacketFilterComponent::SingleRemotePort(_)
+            | PacketFilterComponent::TypeOfService { .. }
+            | PacketFilterComponent::CTagVid(_)
+            | PacketFilterComponent::STagVid(_)
+            | PacketFilterComponent::Ethertype(_) => 3,
+            PacketFilterComponent::FlowLabel(_) => 4,
+            PacketFilterComponent::LocalPortRange { .. }
+            | PacketFilterComponent::RemotePortRange { .. }
+            | PacketFilterComponent::SecurityParameterIndex(_) => 5,
+            PacketFilterComponent::DestinationMacAddress(_)
+            | PacketFilterComponent::SourceMacAddress(_) => 7,
+        }
+    }
+}
+
+// Packet filter with direction, identifier, evaluation precedence and components
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PacketFilter {
+    pub direction: PacketFilterDirection,
+    pub id: u8,
+    pub precedence: u8,
+    pub components: Vec<PacketFilterComponent>,
+}
+
+impl PacketFilter {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push((u8::from(&self.direction) << 4) | (self.id & 0x0f));
+        buffer.push(self.precedence);
+        let mut buffer_pf: Vec<u8> = vec![];
+        self.components
+            .iter()
+            .for_each(|x| x.marshal(&mut buffer_pf));
+        buffer.push(buffer_pf.len() as u8);
+        buffer.append(&mut buffer_pf);
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, CommonError> {
+        if buffer.len() < 3 {
+            return Err(CommonError::TftInvalidLength);
+        }
+        let mut data = PacketFilter {
+            direction: PacketFilterDirection::from(buffer[0] >> 4),
+            id: buffer[0] & 0x0f,
+            precedence: buffer[1],
+            components: vec![],
+        };
+        let end = 3 + buffer[2] as usize;
+        if buffer.len() < end {
+            return Err(CommonError::TftInvalidLength);
+        }
+        let mut cursor: usize = 3;
+        while cursor < end {
+            match PacketFilterComponent::unmarshal(&buffer[cursor..end]) {
+                Ok(i) => {
+                    cursor += i.encoded_len();
+                    data.components.push(i);
+                }
+                Err(j) => return Err(j),
+            }
+        }
+        Ok(data)
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        3 + self
+            .components
+            .iter()
+            .map(|x| x.encoded_len())
+            .sum::<usize>()
+    }
+}
+
+// TFT parameter (Authorization Token, Flow Identifier, Packet Filter Identifier)
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TftParameter {
+    pub id: u8,
+    pub contents: Vec<u8>,
+}
+
+// Traffic Flow Template
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TrafficFlowTemplate {
+    pub operation: TftOperationCode,
+    pub packet_filters: Vec<PacketFilter>, // Create new TFT, Add or Replace packet filters
+    pub packet_filter_ids: Vec<u8>,        // Delete packet filters
+    pub parameters: Vec<TftParameter>,     // E bit
+}
+
+impl TrafficFlowTemplate {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        let count = match self.operation {
+            TftOperationCode::DeletePacketFilters => self.packet_filter_ids.len(),
+            _ => self.packet_filters.len(),
+        };
+        buffer.push(
+            (u8::from(&self.operation) << 5)
+                | ((!self.parameters.is_empty() as u8) << 4)
+                | (count as u8 & 0x0f),
+        );
+        match self.operation {
+            TftOperationCode::DeletePacketFilters => self
+                .packet_filter_ids
+                .iter()
+                .for_each(|x| buffer.push(x & 0x0f)),
+            _ => self.packet_filters.iter().for_each(|x| x.marshal(buffer)),
+        }
+        for i in self.parameters.iter() {
+            buffer.push(i.id);
+            buffer.push(i.contents.len() as u8);
+            buffer.extend_from_slice(&i.contents);
+        }
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, CommonError> {
+        if buffer.is_empty() {
+            return Err(CommonError::TftInvalidLength);
+        }
+        let mut data = TrafficFlowTemplate {
+            operation: TftOperationCode::from(buffer[0] >> 5),
+            ..TrafficFlowTemplate::default()
+        };
+        let count = (buffer[0] & 0x0f) as usize;
+        let mut cursor: usize = 1;
+        match data.operation {
+            TftOperationCode::DeleteExisting | TftOperationCode::NoOperation => {
+                if count != 0 {
+                    return Err(CommonError::TftIncorrectOperation);
+                }
+            }
+            TftOperationCode::DeletePacketFilters => {
+                if buffer.len() < cursor + count {
+                    return Err(CommonError::TftInvalidLength);
+                }
+                data.packet_filter_ids = buffer[cursor..cursor + count]
+                    .iter()
+                    .map(|x| x & 0x0f)
+                    .collect();
+                cursor += count;
+            }
+            _ => {
+                for _ in 0..count {
+                    match PacketFilter::unmarshal(&buffer[cursor..]) {
+                        Ok(i) => {
+                            cursor += 3 + buffer[cursor + 2] as usize;
+                            data.packet_filters.push(i);
+                        }
+                        Err(j) => return Err(j),
+                    }
+                }
+            }
+        }
+        if (buffer[0] >> 4) & 0x01 == 1 {
+            while cursor < buffer.len() {
+                if buffer.len() < cursor + 2 {
+                    return Err(CommonError::TftInvalidLength);
+                }
+                let end = cursor + 2 + buffer[cursor + 1] as usize;
+                if buffer.len() < end {
+                    return Err(CommonError::TftInvalidLength);
+                }
+                data.parameters.push(TftParameter {
+                    id: buffer[cursor],
+                    contents: buffer[cursor + 2..end].to_vec(),
+                });
+                cursor = end;
+            }
+        }
+        Ok(data)
+    }
+}
+
+fn ipv6_from_slice(buffer: &[u8]) -> Ipv6Addr {
+    let mut octets = [0u8; 16];
+    octets.copy_from_slice(&buffer[..16]);
+    Ipv6Addr::from(octets)
+}
+
+#[test]
+fn tft_create_new_unmarshal_test() {
+    let encoded: [u8; 28] = [
+        0x22, 0x31, 0x10, 0x10, 0x10, 0x0a, 0x00, 0x00, 0x01, 0xff, 0xff, 0xff, 0xff, 0x30, 0x06,
+        0x51, 0x00, 0x50, 0x00, 0x51, 0x12, 0xf0, 0x05, 0x30, 0x11, 0x50, 0x13, 0xc4,
+    ];
+    let decoded = TrafficFlowTemplate {
+        operation: TftOperationCode::CreateNew,
+        packet_filters: vec![
+            PacketFilter {
+                direction: PacketFilterDirection::Bidirectional,
+                id: 1,
+                precedence: 0x10,
+                components: vec![
+                    PacketFilterComponent::Ipv4RemoteAddress {
+                        addr: Ipv4Addr::new(10, 0, 0, 1),
+                        mask: Ipv4Addr::new(255, 255, 255, 255),
+                    },
+                    PacketFilterComponent::ProtocolIdentifier(6),
+                    PacketFilterComponent::RemotePortRange { low: 80, high: 81 },
+                ],
+            },
+            PacketFilter {
+                direction: PacketFilterDirection::Downlink,
+                id: 2,
+                precedence: 0xf0,
+                components: vec![
+                    PacketFilterComponent::ProtocolIdentifier(17),
+                    PacketFilterComponent::SingleRemotePort(5060),
+                ],
+            },
+        ],
+        packet_filter_ids: vec![],
+        parameters: vec![],
+    };
+    assert_eq!(TrafficFlowTemplate::unmarshal(&encoded).unwrap(), decoded);
+}
+
+#[test]
+fn tft_create_new_marshal_test() {
+    let encoded: [u8; 28] = [
+        0x22, 0x31, 0x10, 0x10, 0x10, 0x0a, 0x00, 0x00, 0x01, 0xff, 0xff, 0xff, 0xff, 0x30, 0x06,
+        0x51, 0x00, 0x50, 0x00, 0x51, 0x12, 0xf0, 0x05, 0x30, 0x11, 0x50, 0x13, 0xc4,
+    ];
+    let decoded = TrafficFlowTemplate {
+        operation: TftOperationCode::CreateNew,
+        packet_filters: vec![
+            PacketFilter {
+                direction: PacketFilterDirection::Bidirectional,
+                id: 1,
+                precedence: 0x10,
+                components: vec![
+                    PacketFilterComponent::Ipv4RemoteAddress {
+                        addr: Ipv4Addr::new(10, 0, 0, 1),
+                        mask: Ipv4Addr::new(255, 255, 255, 255),
+                    },
+                    PacketFilterComponent::ProtocolIdentifier(6),
+                    PacketFilterComponent::RemotePortRange { low: 80, high: 81 },
+                ],
+            },
+            PacketFilter {
+                direction: PacketFilterDirection::Downlink,
+                id: 2,
+                precedence: 0xf0,
+                components: vec![
+                    PacketFilterComponent::ProtocolIdentifier(17),
+                    PacketFilterComponent::SingleRemotePort(5060),
+                ],
+            },
+        ],
+        packet_filter_ids: vec![],
+        parameters: vec![],
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn tft_delete_filters_with_parameters_round_trip_test() {
+    let encoded: [u8; 7] = [0xb2, 0x01, 0x02, 0x03, 0x02, 0x01, 0x02];
+    let decoded = TrafficFlowTemplate {
+        operation: TftOperationCode::DeletePacketFilters,
+        packet_filters: vec![],
+        packet_filter_ids: vec![1, 2],
+        parameters: vec![TftParameter {
+            id: PACKET_FILTER_ID,
+            contents: vec![0x01, 0x02],
+        }],
+    };
+    assert_eq!(TrafficFlowTemplate::unmarshal(&encoded).unwrap(), decoded);
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn tft_all_components_round_trip_test() {
+    let decoded = TrafficFlowTemplate {
+        operation: TftOperationCode::AddPacketFilters,
+        packet_filters: vec![PacketFilter {
+            direction: PacketFilterDirection::Uplink,
+            id: 7,
+            precedence: 1,
+            components: vec![
+                PacketFilterComponent::Ipv4LocalAddress {
+                    addr: Ipv4Addr::new(192, 168, 0, 0),
+                    mask: Ipv4Addr::new(255, 255, 0, 0),
+                },
+                PacketFilterComponent::Ipv6RemoteAddress {
+                    addr: Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+                    mask: Ipv6Addr::new(0xffff, 0xffff, 0, 0, 0, 0, 0, 0),
+                },
+                PacketFilterComponent::Ipv6RemoteAddressPrefix {
+                    addr: Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0),
+                    prefix_length: 32,
+                },
+                PacketFilterComponent::Ipv6LocalAddressPrefix {
+                    addr: Ipv6Addr::new(0x2001, 0xdb9, 0, 0, 0, 0, 0, 0),
+                    prefix_length: 64,
+                },
+                PacketFilterComponent::SingleLocalPort(2152),
+                PacketFilterComponent::LocalPortRange {
+                    low: 1000,
+                    high: 2000,
+                },
+                PacketFilterComponent::SecurityParameterIndex(0xdeadbeef),
+                PacketFilterComponent::TypeOfService {
+                    value: 0xb8,
+                    mask: 0xfc,
+                },
+                PacketFilterComponent::FlowLabel(0xfffff),
+                PacketFilterComponent::DestinationMacAddress([1, 2, 3, 4, 5, 6]),
+                PacketFilterComponent::SourceMacAddress([6, 5, 4, 3, 2, 1]),
+                PacketFilterComponent::CTagVid(100),
+                PacketFilterComponent::STagVid(200),
+                PacketFilterComponent::CTagPcpDei(0x0a),
+                PacketFilterComponent::STagPcpDei(0x05),
+                PacketFilterComponent::Ethertype(0x0800),
+            ],
+        }],
+        packet_filter_ids: vec![],
+        parameters: vec![],
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer.len(), 1 + decoded.packet_filters[0].encoded_len());
+    assert_eq!(TrafficFlowTemplate::unmarshal(&buffer).unwrap(), decoded);
+}
+
+#[test]
+fn tft_unknown_component_unmarshal_test() {
+    let encoded: [u8; 6] = [0x21, 0x31, 0x10, 0x02, 0x99, 0x00];
+    assert_eq!(
+        TrafficFlowTemplate::unmarshal(&encoded),
+        Err(CommonError::TftUnknownComponent(0x99))
+    );
+}
+
+#[test]
+fn tft_invalid_length_unmarshal_test() {
+    let encoded: [u8; 6] = [0x21, 0x31, 0x10, 0x05, 0x30, 0x06];
+    assert_eq!(
+        TrafficFlowTemplate::unmarshal(&encoded),
+        Err(CommonError::TftInvalidLength)
+    );
+}
@@ -0,0 +1,458 @@
+// Packet filter matching for bearer binding - according to 3GPP TS 23.060 V17.0.0 (2022-03), clause 15.3
+// and 3GPP TS 23.401 V17.0.0 (2021-03), clause 4.7.2.2
+
+use crate::{
+    commons::{errors::*, tft::*},
+    gtpv2::messages::BearerContext,
+};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+// IP protocol numbers relevant for packet filter evaluation
+
+const IPPROTO_HOPOPTS: u8 = 0;
+const IPPROTO_TCP: u8 = 6;
+const IPPROTO_UDP: u8 = 17;
+const IPPROTO_ROUTING: u8 = 43;
+const IPPROTO_FRAGMENT: u8 = 44;
+const IPPROTO_ESP: u8 = 50;
+const IPPROTO_AH: u8 = 51;
+const IPPROTO_DSTOPTS: u8 = 60;
+const IPPROTO_SCTP: u8 = 132;
+
+// Direction of the packet being bound to a bearer
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PacketDirection {
+    Uplink,
+    Downlink,
+}
+
+// Fields of an IPv4/IPv6 packet used for packet filter evaluation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpPacketInfo {
+    pub source: IpAddr,
+    pub destination: IpAddr,
+    pub protocol: u8, // Protocol (IPv4) or last Next Header (IPv6)
+    pub source_port: Option<u16>,
+    pub destination_port: Option<u16>,
+    pub spi: Option<u32>,
+    pub tos: u8, // Type of Service (IPv4) or Traffic Class (IPv6)
+    pub flow_label: Option<u32>,
+}
+
+impl IpPacketInfo {
+    pub fn parse(packet: &[u8]) -> Option<Self> {
+        match packet.first()? >> 4 {
+            4 => IpPacketInfo::parse_ipv4(packet),
+            6 => IpPacketInfo::parse_ipv6(packet),
+            _ => None,
+        }
+    }
+
+    fn parse_ipv4(packet: &[u8]) -> Option<Self> {
+        let ihl = ((packet[0] & 0x0f) as usize) * 4;
+        if ihl < 20 || packet.len() < ihl {
+            return None;
+        }
+        let mut data = IpPacketInfo {
+            source: IpAddr::V4(Ipv4Addr::new(
+                packet[12], packet[13], packet[14], packet[15],
+            )),
+            destination: IpAddr::V4(Ipv4Addr::new(
+                packet[16], packet[17], packet[18], packet[19],
+            )),
+            protocol: packet[9],
+            source_port: None,
+            destination_port: None,
+            spi: None,
+            tos: packet[1],
+            flow_label: None,
+        };
+        // Only the first fragment carries the transport header
+        if u16::from_be_bytes([packet[6], packet[7]]) & 0x1fff == 0 {
+            data.set_transport(&packet[ihl..]);
+        }
+        Some(data)
+    }
+
+    fn parse_ipv6(packet: &[u8]) -> Option<Self> {
+        if packet.len() < 40 {
+            return None;
+        }
+        let mut src = [0u8; 16];
+        let mut dst = [0u8; 16];
+        src.copy_from_slice(&packet[8..24]);
+        dst.copy_from_slice(&packet[24..40]);
+        let mut data = IpPacketInfo {
+            source: IpAddr::V6(Ipv6Addr::from(src)),
+            destination: IpAddr::V6(Ipv6Addr::from(dst)),
+            protocol: packet[6],
+            source_port: None,
+            destination_port: None,
+            spi: None,
+            tos: (packet[0] << 4) | (packet[1] >> 4),
+            flow_label: Some(u32::from_be_bytes([
+                0x00,
+                packet[1] & 0x0f,
+                packet[2],
+                packet[3],
+            ])),
+        };
+        let mut cursor: usize = 40;
+        loop {
+            match data.protocol {
+                IPPROTO_HOPOPTS | IPPROTO_ROUTING | IPPROTO_DSTOPTS => {
+                    if packet.len() < cursor + 2 {
+                        return Some(data);
+                    }
+                    data.protocol = packet[cursor];
+                    cursor += (packet[cursor + 1] as usize + 1) * 8;
+                }
+                IPPROTO_FRAGMENT => {
+                    if packet.len() < cursor + 8 {
+                        return Some(data);
+                    }
+                    data.protocol = packet[cursor];
+                    let offset = u16::from_be_bytes([packet[cursor + 2], packet[cursor + 3]]) >> 3;
+                    cursor += 8;
+                    if offset != 0 {
+                        return Some(data);
+                    }
+                }
+                _ => break,
+            }
+        }
+        if cursor <= packet.len() {
+            data.set_transport(&packet[cursor..]);
+        }
+        Some(data)
+    }
+
+    fn set_transport(&mut self, buffer: &[u8]) {
+        match self.protocol {
+            IPPROTO_TCP | IPPROTO_UDP | IPPROTO_SCTP if buffer.len() >= 4 => {
+                self.source_port = Some(u16::from_be_bytes([buffer[0], buffer[1]]));
+                self.destination_port = Some(u16::from_be_bytes([buffer[2], buffer[3]]));
+            }
+            IPPROTO_ESP if buffer.len() >= 4 => {
+                self.spi = Some(u32::from_be_bytes([
+                    buffer[0], buffer[1], buffer[2], buffer[3],
+                ]));
+            }
+            IPPROTO_AH if buffer.len() >= 8 => {
+                self.spi = Some(u32::from_be_bytes([
+                    buffer[4], buffer[5], buffer[6], buffer[7],
+                ]));
+            }
+            _ => (),
+        }
+    }
+}
+
+impl PacketFilter {
+    // Returns true if the packet matches all components of the packet filter.
+    // Remote refers to the peer of the UE, i.e. the destination of uplink and the source of downlink packets.
+    // Pre-Rel-7 packet filters are applied to downlink packets only.
+
+    pub fn matches(&self, packet: &IpPacketInfo, direction: PacketDirection) -> bool {
+        let applicable = matches!(
+            (self.direction, direction),
+            (PacketFilterDirection::Bidirectional, _)
+                | (PacketFilterDirection::Uplink, PacketDirection::Uplink)
+                | (PacketFilterDirection::Downlink, PacketDirection::Downlink)
+                | (PacketFilterDirection::PreRel7, PacketDirection::Downlink)
+        );
+        applicable && self.components.iter().all(|x| x.matches(packet, direction))
+    }
+}
+
+impl PacketFilterComponent {
+    pub fn matches(&self, packet: &IpPacketInfo, direction: PacketDirection) -> bool {
+        let (local, remote, local_port, remote_port) = match direction {
+            PacketDirection::Uplink => (
+                packet.source,
+                packet.destination,
+                packet.source_port,
+                packet.destination_port,
+            ),
+            PacketDirection::Downlink => (
+                packet.destination,
+                packet.source,
+                packet.destination_port,
+                packet.source_port,
+            ),
+        };
+        match self {
+            PacketFilterComponent::Ipv4RemoteAddress { addr, mask } => {
+                matches!(remote, IpAddr::V4(i) if ipv4_masked_eq(&i, addr, mask))
+            }
+            PacketFilterComponent::Ipv4LocalAddress { addr, mask } => {
+                matches!(local, IpAddr::V4(i) if ipv4_masked_eq(&i, addr, mask))
+            }
+            PacketFilterComponent::Ipv6RemoteAddress { addr, mask } => {
+                matches!(remote, IpAddr::V6(i) if ipv6_masked_eq(&i, addr, &u128::from(*mask)))
+            }
+            PacketFilterComponent::Ipv6RemoteAddressPrefix {
+                addr,
+                prefix_length,
+            } => {
+                matches!(remote, IpAddr::V6(i) if ipv6_masked_eq(&i, addr, &prefix_mask(*prefix_length)))
+            }
+            PacketFilterComponent::Ipv6LocalAddressPrefix {
+                addr,
+                prefix_length,
+            } => {
+                matches!(local, IpAddr::V6(i) if ipv6_masked_eq(&i, addr, &prefix_mask(*prefix_length)))
+            }
+            PacketFilterComponent::ProtocolIdentifier(i) => packet.protocol == *i,
+            PacketFilterComponent::SingleLocalPort(i) => local_port == Some(*i),
+            PacketFilterComponent::LocalPortRange { low, high } => {
+                matches!(local_port, Some(i) if (*low..=*high).contains(&i))
+            }
+            PacketFilterComponent::SingleRemotePort(i) => remote_port == Some(*i),
+            PacketFilterComponent::RemotePortRange { low, high } => {
+                matches!(remote_port, Some(i) if (*low..=*high).contains(&i))
+            }
+            PacketFilterComponent::SecurityParameterIndex(i) => packet.spi == Some(*i),
+            PacketFilterComponent::TypeOfService { value, mask } => {
+                packet.tos & mask == value & mask
+            }
+            PacketFilterComponent::FlowLabel(i) => packet.flow_label == Some(*i & 0x000fffff),
+            // Ethernet components never match an IP packet
+            _ => false,
+        }
+    }
+}
+
+// Packet filter bound to the EPS bearer whose TFT it belongs to
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundPacketFilter {
+    pub ebi: u8,
+    pub filter: PacketFilter,
+}
+
+// Bearer binding for a PDN connection: packet filters of all bearers, sorted by evaluation precedence
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BearerBinding {
+    pub default_ebi: u8,
+    pub filters: Vec<BoundPacketFilter>,
+}
+
+impl BearerBinding {
+    pub fn new(default_ebi: u8, bearers: &[BearerContext]) -> Result<Self, CommonError> {
+        let mut data = BearerBinding {
+            default_ebi,
+            filters: vec![],
+        };
+        for bearer in bearers.iter() {
+            if let Some(i) = &bearer.tft {
+                let tft = TrafficFlowTemplate::unmarshal(&i.tft)?;
+                data.filters
+                    .extend(
+                        tft.packet_filters
+                            .into_iter()
+                            .map(|filter| BoundPacketFilter {
+                                ebi: bearer.ebi.value,
+                                filter,
+                            }),
+                    );
+            }
+        }
+        data.filters.sort_by_key(|x| x.filter.precedence);
+        Ok(data)
+    }
+
+    // Returns the EBI of the bearer the packet is bound to, or the default bearer if no packet filter matches
+
+    pub fn bind(&self, packet: &[u8], direction: PacketDirection) -> u8 {
+        match IpPacketInfo::parse(packet) {
+            Some(info) => self
+                .filters
+                .iter()
+                .find(|x| x.filter.matches(&info, direction))
+                .map_or(self.default_ebi, |x| x.ebi),
+            None => self.default_ebi,
+        }
+    }
+}
+
+fn ipv4_masked_eq(a: &Ipv4Addr, b: &Ipv4Addr, mask: &Ipv4Addr) -> bool {
+    u32::from(*a) & u32::from(*mask) == u32::from(*b) & u32::from(*mask)
+}
+
+fn ipv6_masked_eq(a: &Ipv6Addr, b: &Ipv6Addr, mask: &u128) -> bool {
+    u128::from(*a) & mask == u128::from(*b) & mask
+}
+
+fn prefix_mask(prefix_length: u8) -> u128 {
+    match prefix_length {
+        0 => 0,
+        i if i >= 128 => u128::MAX,
+        i => u128::MAX << (128 - i as u32),
+    }
+}
+
+#[cfg(test)]
+fn udp_ipv4_packet(src: [u8; 4], dst: [u8; 4], sport: u16, dport: u16) -> Vec<u8> {
+    let mut packet: Vec<u8> = vec![
+        0x45, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0x00, 0x00,
+    ];
+    packet.extend_from_slice(&src);
+    packet.extend_from_slice(&dst);
+    packet.extend_from_slice(&sport.to_be_bytes());
+    packet.extend_from_slice(&dport.to_be_bytes());
+    packet.extend_from_slice(&[0x00, 0x08, 0x00, 0x00]);
+    packet
+}
+
+#[cfg(test)]
+fn bearer_with_tft(ebi: u8, tft: TrafficFlowTemplate) -> BearerContext {
+    use crate::gtpv2::messages::{BearerTft, Ebi};
+    BearerContext {
+        ebi: Ebi {
+            value: ebi,
+            ..Ebi::default()
+        },
+        tft: Some(BearerTft::from(tft)),
+        ..BearerContext::default()
+    }
+}
+
+#[test]
+fn ip_packet_info_ipv4_parse_test() {
+    let packet = udp_ipv4_packet([10, 0, 0, 1], [8, 8, 8, 8], 40000, 53);
+    let info = IpPacketInfo {
+        source: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+        destination: IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8)),
+        protocol: IPPROTO_UDP,
+        source_port: Some(40000),
+        destination_port: Some(53),
+        spi: None,
+        tos: 0,
+        flow_label: None,
+    };
+    assert_eq!(IpPacketInfo::parse(&packet).unwrap(), info);
+}
+
+#[test]
+fn ip_packet_info_ipv6_parse_test() {
+    let mut packet: Vec<u8> = vec![
+        0x6b, 0x81, 0x23, 0x45, 0x00, 0x10, 0x00, 0x40, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    ];
+    // Hop-by-hop options header followed by TCP
+    packet.extend_from_slice(&[0x06, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x00]);
+    packet.extend_from_slice(&[0x01, 0xbb, 0xc3, 0x50]);
+    let info = IpPacketInfo::parse(&packet).unwrap();
+    assert_eq!(info.protocol, IPPROTO_TCP);
+    assert_eq!(info.tos, 0xb8);
+    assert_eq!(info.flow_label, Some(0x12345));
+    assert_eq!(info.source_port, Some(443));
+    assert_eq!(info.destination_port, Some(50000));
+}
+
+#[test]
+fn bearer_binding_precedence_and_direction_test() {
+    let voice = TrafficFlowTemplate {
+        operation: TftOperationCode::CreateNew,
+        packet_filters: vec![PacketFilter {
+            direction: PacketFilterDirection::Bidirectional,
+            id: 1,
+            precedence: 10,
+            components: vec![
+                PacketFilterComponent::Ipv4RemoteAddress {
+                    addr: Ipv4Addr::new(192, 168, 1, 0),
+                    mask: Ipv4Addr::new(255, 255, 255, 0),
+                },
+                PacketFilterComponent::ProtocolIdentifier(IPPROTO_UDP),
+                PacketFilterComponent::RemotePortRange {
+                    low: 5060,
+                    high: 5061,
+                },
+            ],
+        }],
+        ..TrafficFlowTemplate::default()
+    };
+    let video = TrafficFlowTemplate {
+        operation: TftOperationCode::CreateNew,
+        packet_filters: vec![
+            PacketFilter {
+                direction: PacketFilterDirection::Uplink,
+                id: 1,
+                precedence: 20,
+                components: vec![PacketFilterComponent::ProtocolIdentifier(IPPROTO_UDP)],
+            },
+            PacketFilter {
+                direction: PacketFilterDirection::Downlink,
+                id: 2,
+                precedence: 5,
+                components: vec![PacketFilterComponent::SingleLocalPort(5000)],
+            },
+        ],
+        ..TrafficFlowTemplate::default()
+    };
+    let bearers = vec![
+        BearerContext {
+            ebi: crate::gtpv2::messages::Ebi {
+                value: 5,
+                ..Default::default()
+            },
+            ..BearerContext::default()
+        },
+        bearer_with_tft(6, voice),
+        bearer_with_tft(7, video),
+    ];
+    let binding = BearerBinding::new(5, &bearers).unwrap();
+    assert_eq!(
+        binding
+            .filters
+            .iter()
+            .map(|x| x.filter.precedence)
+            .collect::<Vec<u8>>(),
+        vec![5, 10, 20]
+    );
+    // Uplink SIP matches voice (precedence 10) before the generic uplink UDP filter (precedence 20)
+    let ul_sip = udp_ipv4_packet([10, 0, 0, 1], [192, 168, 1, 10], 40000, 5060);
+    assert_eq!(binding.bind(&ul_sip, PacketDirection::Uplink), 6);
+    // Downlink SIP: remote is the source
+    let dl_sip = udp_ipv4_packet([192, 168, 1, 10], [10, 0, 0, 1], 5061, 40000);
+    assert_eq!(binding.bind(&dl_sip, PacketDirection::Downlink), 6);
+    // Uplink UDP elsewhere matches video uplink filter
+    let ul_other = udp_ipv4_packet([10, 0, 0, 1], [1, 1, 1, 1], 40000, 443);
+    assert_eq!(binding.bind(&ul_other, PacketDirection::Uplink), 7);
+    // Downlink to local port 5000 matches video downlink filter
+    let dl_video = udp_ipv4_packet([1, 1, 1, 1], [10, 0, 0, 1], 443, 5000);
+    assert_eq!(binding.bind(&dl_video, PacketDirection::Downlink), 7);
+    // Downlink UDP elsewhere falls back to default bearer
+    let dl_other = udp_ipv4_packet([1, 1, 1, 1], [10, 0, 0, 1], 443, 40000);
+    assert_eq!(binding.bind(&dl_other, PacketDirection::Downlink), 5);
+    // Non-IP payload falls back to default bearer
+    assert_eq!(binding.bind(&[0x00, 0x01], PacketDirection::Uplink), 5);
+}
+
+#[test]
+fn bearer_binding_ipv6_prefix_test() {
+    let tft = TrafficFlowTemplate {
+        operation: TftOperationCode::CreateNew,
+        packet_filters: vec![PacketFilter {
+            direction: PacketFilterDirection::Bidirectional,
+            id: 1,
+            precedence: 1,
+            components: vec![PacketFilterComponent::Ipv6RemoteAddressPrefix {
+                addr: Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0),
+                prefix_length: 32,
+            }],
+        }],
+        ..TrafficFlowTemplate::default()
+    };
+    let binding = BearerBinding::new(5, &[bearer_with_tft(6, tft)]).unwrap();
+    let mut packet: Vec<u8> = vec![0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3b, 0x40];
+    packet.extend_from_slice(&Ipv6Addr::new(0x2001, 0xdb9, 0, 0, 0, 0, 0, 1).octets());
+    packet.extend_from_slice(&Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1).octets());
+    assert_eq!(binding.bind(&packet, PacketDirection::Uplink), 6);
+    assert_eq!(binding.bind(&packet, PacketDirection::Downlink), 5);
+}
@@ -0,0 +1,8 @@
+use std::time::Duration;
+
+// Default path management and retransmission timers shared by the GTPv1-U and GTPv2-C path supervision.
+// Echo Requests are not to be sent more often than every 60 seconds on a path (3GPP TS 29.281 and TS 29.274)
+
+pub const DEFAULT_ECHO_INTERVAL: Duration = Duration::from_secs(60);
+pub const DEFAULT_T3_RESPONSE: Duration = Duration::from_secs(3);
+pub const DEFAULT_N3_REQUESTS: u8 = 3;
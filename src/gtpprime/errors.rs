@@ -0,0 +1,39 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq)]
+
+pub enum GTPPrimeError {
+    // GTP' Header Errors
+    HeaderInvalidLength,
+    HeaderVersionNotSupported,
+    // GTP' IE Errors
+    IEInvalidLength,
+    IEIncorrect,
+    // GTP' Message Errors
+    MessageLengthError,
+    MessageMandatoryIEMissing,
+    MessageOptionalIEIncorrect,
+    MessageInvalidMessageFormat,
+    MessageIncorrectMessageType,
+}
+
+impl std::error::Error for GTPPrimeError {}
+
+impl Display for GTPPrimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            // GTP' Header Errors
+            GTPPrimeError::HeaderInvalidLength => write!(f, "Invalid Header length"),
+            GTPPrimeError::HeaderVersionNotSupported => write!(f, "GTP' Version not supported"),
+            // GTP' IE Errors
+            GTPPrimeError::IEInvalidLength => write!(f, "Invalid IE length"),
+            GTPPrimeError::IEIncorrect => write!(f, "Incorrect IE"),
+            // GTP' Message Errors
+            GTPPrimeError::MessageLengthError => write!(f, "Message length error"),
+            GTPPrimeError::MessageMandatoryIEMissing => write!(f, "Mandatory IE missing"),
+            GTPPrimeError::MessageOptionalIEIncorrect => write!(f, "Optional IE incorrect"),
+            GTPPrimeError::MessageInvalidMessageFormat => write!(f, "Invalid Message format"),
+            GTPPrimeError::MessageIncorrectMessageType => write!(f, "Incorrect Message type"),
+        }
+    }
+}
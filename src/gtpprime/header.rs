@@ -0,0 +1,145 @@
+use crate::gtpprime::errors::*;
+
+// According to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+// Definition of GTP' Header
+// GTP' uses a 6-octet header, only GTP' version 0 may signal the legacy 20-octet header
+// whose trailing 14 octets are unused: they are sent as 0xff and ignored on reception
+
+pub const SHORT_HEADER_LENGTH: usize = 6;
+pub const LONG_HEADER_LENGTH: usize = 20;
+pub const GTP_PRIME_VERSION: u8 = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GtpPrimeHeader {
+    pub version: u8,
+    pub long_header: bool, // Only applicable to GTP' version 0
+    pub msgtype: u8,
+    pub length: u16,
+    pub sequence_number: u16,
+}
+
+impl Default for GtpPrimeHeader {
+    fn default() -> Self {
+        GtpPrimeHeader {
+            version: GTP_PRIME_VERSION,
+            long_header: false,
+            msgtype: 0,
+            length: 0,
+            sequence_number: 0,
+        }
+    }
+}
+
+impl GtpPrimeHeader {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        // Version, PT = 0 (GTP'), spare bits set to 1 and the header length flag
+        let short_header = self.version == 0 && !self.long_header;
+        buffer.push((self.version << 5) | 0x0e | short_header as u8);
+        buffer.push(self.msgtype);
+        buffer.extend_from_slice(&self.length.to_be_bytes());
+        buffer.extend_from_slice(&self.sequence_number.to_be_bytes());
+        if self.len() == LONG_HEADER_LENGTH {
+            buffer.extend_from_slice(&[0xff; LONG_HEADER_LENGTH - SHORT_HEADER_LENGTH]);
+        }
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        if buffer.len() < SHORT_HEADER_LENGTH {
+            return Err(GTPPrimeError::HeaderInvalidLength);
+        }
+        let version = buffer[0] >> 5;
+        if version > GTP_PRIME_VERSION || buffer[0] & 0x10 != 0 {
+            return Err(GTPPrimeError::HeaderVersionNotSupported);
+        }
+        let data = GtpPrimeHeader {
+            version,
+            long_header: version == 0 && buffer[0] & 0x01 == 0,
+            msgtype: buffer[1],
+            length: u16::from_be_bytes([buffer[2], buffer[3]]),
+            sequence_number: u16::from_be_bytes([buffer[4], buffer[5]]),
+        };
+        if buffer.len() < data.len() {
+            return Err(GTPPrimeError::HeaderInvalidLength);
+        }
+        Ok(data)
+    }
+
+    pub fn len(&self) -> usize {
+        if self.version == 0 && self.long_header {
+            LONG_HEADER_LENGTH
+        } else {
+            SHORT_HEADER_LENGTH
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn gtp_prime_header_marshal_test() {
+    let encoded: [u8; 6] = [0x4e, 0xf0, 0x00, 0x00, 0x01, 0x02];
+    let decoded = GtpPrimeHeader {
+        msgtype: 0xf0,
+        sequence_number: 0x0102,
+        ..Default::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn gtp_prime_header_unmarshal_test() {
+    let encoded: [u8; 6] = [0x4e, 0xf0, 0x00, 0x00, 0x01, 0x02];
+    let decoded = GtpPrimeHeader {
+        msgtype: 0xf0,
+        sequence_number: 0x0102,
+        ..Default::default()
+    };
+    assert_eq!(GtpPrimeHeader::unmarshal(&encoded).unwrap(), decoded);
+}
+
+#[test]
+fn gtp_prime_header_v0_long_header_test() {
+    let mut encoded: Vec<u8> = vec![0x0e, 0x01, 0x00, 0x00, 0x00, 0x05];
+    encoded.extend_from_slice(&[0xff; 14]);
+    let decoded = GtpPrimeHeader {
+        version: 0,
+        long_header: true,
+        msgtype: 1,
+        length: 0,
+        sequence_number: 5,
+    };
+    assert_eq!(GtpPrimeHeader::unmarshal(&encoded).unwrap(), decoded);
+    assert_eq!(decoded.len(), LONG_HEADER_LENGTH);
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+    assert_eq!(
+        GtpPrimeHeader::unmarshal(&encoded[..10]),
+        Err(GTPPrimeError::HeaderInvalidLength)
+    );
+}
+
+#[test]
+fn gtp_prime_header_v0_short_header_test() {
+    let encoded: [u8; 6] = [0x0f, 0x01, 0x00, 0x00, 0x00, 0x05];
+    let decoded = GtpPrimeHeader::unmarshal(&encoded).unwrap();
+    assert!(!decoded.long_header);
+    assert_eq!(decoded.len(), SHORT_HEADER_LENGTH);
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn gtp_prime_header_gtp_protocol_type_test() {
+    let encoded: [u8; 6] = [0x5e, 0x01, 0x00, 0x00, 0x00, 0x05];
+    assert_eq!(
+        GtpPrimeHeader::unmarshal(&encoded),
+        Err(GTPPrimeError::HeaderVersionNotSupported)
+    );
+}
@@ -0,0 +1,45 @@
+use crate::gtpprime::errors::*;
+use crate::gtpprime::header::*;
+use crate::gtpprime::messages::ies::*;
+
+// Common traits of GTP' Messages
+
+pub trait Messages {
+    fn marshal(self, buffer: &mut Vec<u8>);
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError>
+    where
+        Self: Sized;
+}
+
+// Decode the IEs of a message body with the generic IE decoder.
+// IEs shall be sent in increasing order of their type, unknown IEs are kept for the caller to ignore.
+// The decoding error of a mandatory or conditional IE of the message (listed in mandatory) is returned as is,
+// i.e. as a mandatory IE incorrect, any other IE which does not decode is reported as an optional IE incorrect.
+
+pub fn decode_message_body(
+    header: &GtpPrimeHeader,
+    buffer: &[u8],
+    mandatory: &[u8],
+) -> Result<Vec<InformationElement>, GTPPrimeError> {
+    let end = header.length as usize + header.len();
+    if end > buffer.len() {
+        return Err(GTPPrimeError::MessageLengthError);
+    }
+    let mut ies: Vec<InformationElement> = vec![];
+    let mut cursor = header.len();
+    let mut increment: u8 = 0;
+    while cursor < end {
+        if buffer[cursor] < increment {
+            return Err(GTPPrimeError::MessageInvalidMessageFormat);
+        }
+        increment = buffer[cursor];
+        let ie = match InformationElement::decode(&buffer[cursor..end]) {
+            Ok(i) => i,
+            Err(j) if mandatory.contains(&buffer[cursor]) => return Err(j),
+            Err(_) => return Err(GTPPrimeError::MessageOptionalIEIncorrect),
+        };
+        cursor += ie.len();
+        ies.push(ie);
+    }
+    Ok(ies)
+}
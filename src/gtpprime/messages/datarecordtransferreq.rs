@@ -0,0 +1,246 @@
+use crate::gtpprime::errors::*;
+use crate::gtpprime::header::*;
+use crate::gtpprime::messages::commons::*;
+use crate::gtpprime::messages::ies::*;
+use crate::gtpprime::utils::*;
+
+// According to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+pub const DATA_RECORD_TRANSFER_REQUEST: u8 = 240;
+
+// Definition of GTP' Data Record Transfer Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataRecordTransferRequest {
+    pub header: GtpPrimeHeader,
+    pub packet_transfer_cmd: PacketTransferCommand,
+    pub data_record_packet: Option<DataRecordPacket>,
+    pub seq_nums_released: Option<SequenceNumbers>,
+    pub seq_nums_cancelled: Option<SequenceNumbers>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for DataRecordTransferRequest {
+    fn default() -> DataRecordTransferRequest {
+        let hdr = GtpPrimeHeader {
+            msgtype: DATA_RECORD_TRANSFER_REQUEST,
+            ..Default::default()
+        };
+        DataRecordTransferRequest {
+            header: hdr,
+            packet_transfer_cmd: PacketTransferCommand::default(),
+            data_record_packet: None,
+            seq_nums_released: None,
+            seq_nums_cancelled: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for DataRecordTransferRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.packet_transfer_cmd.into()];
+        if let Some(i) = self.data_record_packet {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.seq_nums_released {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.seq_nums_cancelled {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer, self.header.len());
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        let mut message = DataRecordTransferRequest {
+            header: GtpPrimeHeader::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != DATA_RECORD_TRANSFER_REQUEST {
+            return Err(GTPPrimeError::MessageIncorrectMessageType);
+        }
+
+        let mut packet_transfer_cmd = None;
+        let mandatory = [
+            PACKET_TRANSFER_COMMAND,
+            DATA_RECORD_PACKET,
+            SEQUENCE_NUMBERS_RELEASED,
+            SEQUENCE_NUMBERS_CANCELLED,
+        ];
+        for ie in decode_message_body(&message.header, buffer, &mandatory)? {
+            match ie {
+                InformationElement::PacketTransferCommand(i) if packet_transfer_cmd.is_none() => {
+                    packet_transfer_cmd = Some(i)
+                }
+                InformationElement::DataRecordPacket(i) if message.data_record_packet.is_none() => {
+                    message.data_record_packet = Some(i)
+                }
+                InformationElement::SequenceNumbers(i)
+                    if i.t == SEQUENCE_NUMBERS_RELEASED && message.seq_nums_released.is_none() =>
+                {
+                    message.seq_nums_released = Some(i)
+                }
+                InformationElement::SequenceNumbers(i)
+                    if i.t == SEQUENCE_NUMBERS_CANCELLED
+                        && message.seq_nums_cancelled.is_none() =>
+                {
+                    message.seq_nums_cancelled = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match packet_transfer_cmd {
+            Some(i) => {
+                message.packet_transfer_cmd = i;
+                // Data Record Packet, Sequence Numbers of Cancelled or Released Packets IEs
+                // are conditional on the Packet Transfer Command
+                let conditional_ie_present = match message.packet_transfer_cmd.value {
+                    SEND_DATA_RECORD_PACKET | SEND_POSSIBLY_DUPLICATED_DATA_RECORD_PACKET => {
+                        message.data_record_packet.is_some()
+                    }
+                    CANCEL_DATA_RECORD_PACKET => message.seq_nums_cancelled.is_some(),
+                    _ => message.seq_nums_released.is_some(),
+                };
+                if !conditional_ie_present {
+                    return Err(GTPPrimeError::MessageMandatoryIEMissing);
+                }
+                Ok(message)
+            }
+            None => Err(GTPPrimeError::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+const DATA_RECORD_TRANSFER_REQUEST_TEST: [u8; 24] = [
+    0x4e, 0xf0, 0x00, 0x12, 0x00, 0x10, 0x7e, 0x01, 0xfc, 0x00, 0x0d, 0x02, 0x01, 0x13, 0x11, 0x00,
+    0x03, 0xbf, 0x4e, 0x00, 0x00, 0x02, 0xbf, 0x4f,
+];
+
+#[cfg(test)]
+fn data_record_transfer_req_test() -> DataRecordTransferRequest {
+    DataRecordTransferRequest {
+        header: GtpPrimeHeader {
+            msgtype: DATA_RECORD_TRANSFER_REQUEST,
+            length: 18,
+            sequence_number: 16,
+            ..Default::default()
+        },
+        packet_transfer_cmd: PacketTransferCommand {
+            t: PACKET_TRANSFER_COMMAND,
+            value: SEND_DATA_RECORD_PACKET,
+        },
+        data_record_packet: Some(DataRecordPacket {
+            t: DATA_RECORD_PACKET,
+            length: 13,
+            format: DATA_RECORD_FORMAT_BER,
+            format_version: 0x1311,
+            records: vec![vec![0xbf, 0x4e, 0x00], vec![0xbf, 0x4f]],
+        }),
+        seq_nums_released: None,
+        seq_nums_cancelled: None,
+        private_extension: None,
+    }
+}
+
+#[test]
+fn data_record_transfer_req_unmarshal_test() {
+    assert_eq!(
+        DataRecordTransferRequest::unmarshal(&DATA_RECORD_TRANSFER_REQUEST_TEST).unwrap(),
+        data_record_transfer_req_test()
+    );
+}
+
+#[test]
+fn data_record_transfer_req_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    data_record_transfer_req_test().marshal(&mut buffer);
+    assert_eq!(buffer, DATA_RECORD_TRANSFER_REQUEST_TEST);
+}
+
+#[test]
+fn data_record_transfer_req_cancel_marshal_unmarshal_test() {
+    let mut message = DataRecordTransferRequest {
+        header: GtpPrimeHeader {
+            msgtype: DATA_RECORD_TRANSFER_REQUEST,
+            sequence_number: 0x11,
+            ..Default::default()
+        },
+        packet_transfer_cmd: PacketTransferCommand {
+            t: PACKET_TRANSFER_COMMAND,
+            value: CANCEL_DATA_RECORD_PACKET,
+        },
+        seq_nums_cancelled: Some(SequenceNumbers {
+            t: SEQUENCE_NUMBERS_CANCELLED,
+            length: 4,
+            sequence_numbers: vec![0x0e, 0x0f],
+        }),
+        ..Default::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - SHORT_HEADER_LENGTH) as u16;
+    assert_eq!(&buffer[6..10], &[0x7e, 0x03, 0xfa, 0x00]);
+    assert_eq!(
+        DataRecordTransferRequest::unmarshal(&buffer).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn data_record_transfer_req_missing_conditional_ie_unmarshal_test() {
+    let encoded: [u8; 15] = [
+        0x4e, 0xf0, 0x00, 0x09, 0x00, 0x12, 0x7e, 0x04, 0xfa, 0x00, 0x04, 0x00, 0x0e, 0x00, 0x0f,
+    ];
+    assert_eq!(
+        DataRecordTransferRequest::unmarshal(&encoded),
+        Err(GTPPrimeError::MessageMandatoryIEMissing)
+    );
+}
+
+#[test]
+fn data_record_transfer_req_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 11] = [
+        0x4e, 0xf0, 0x00, 0x05, 0x00, 0x12, 0xf9, 0x00, 0x02, 0x00, 0x0e,
+    ];
+    assert_eq!(
+        DataRecordTransferRequest::unmarshal(&encoded),
+        Err(GTPPrimeError::MessageMandatoryIEMissing)
+    );
+}
+
+#[test]
+fn data_record_transfer_req_incorrect_ie_unmarshal_test() {
+    // Data Record Packet, conditional on the Packet Transfer Command, longer than the message
+    let mut encoded = DATA_RECORD_TRANSFER_REQUEST_TEST;
+    encoded[10] = 0x0e;
+    assert_eq!(
+        DataRecordTransferRequest::unmarshal(&encoded),
+        Err(GTPPrimeError::IEInvalidLength)
+    );
+    // Truncated Private Extension
+    let mut encoded = DATA_RECORD_TRANSFER_REQUEST_TEST.to_vec();
+    encoded.extend_from_slice(&[0xff, 0x00, 0x05, 0x00]);
+    encoded[3] = 0x16;
+    assert_eq!(
+        DataRecordTransferRequest::unmarshal(&encoded),
+        Err(GTPPrimeError::MessageOptionalIEIncorrect)
+    );
+}
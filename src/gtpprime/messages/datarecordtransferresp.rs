@@ -0,0 +1,143 @@
+use crate::gtpprime::errors::*;
+use crate::gtpprime::header::*;
+use crate::gtpprime::messages::commons::*;
+use crate::gtpprime::messages::ies::*;
+use crate::gtpprime::utils::*;
+
+// According to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+pub const DATA_RECORD_TRANSFER_RESPONSE: u8 = 241;
+
+// Definition of GTP' Data Record Transfer Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataRecordTransferResponse {
+    pub header: GtpPrimeHeader,
+    pub cause: Cause,
+    pub requests_responded: SequenceNumbers,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for DataRecordTransferResponse {
+    fn default() -> DataRecordTransferResponse {
+        let hdr = GtpPrimeHeader {
+            msgtype: DATA_RECORD_TRANSFER_RESPONSE,
+            ..Default::default()
+        };
+        DataRecordTransferResponse {
+            header: hdr,
+            cause: Cause::default(),
+            requests_responded: SequenceNumbers {
+                t: REQUESTS_RESPONDED,
+                ..Default::default()
+            },
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for DataRecordTransferResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        elements.push(self.requests_responded.into());
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer, self.header.len());
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        let mut message = DataRecordTransferResponse {
+            header: GtpPrimeHeader::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != DATA_RECORD_TRANSFER_RESPONSE {
+            return Err(GTPPrimeError::MessageIncorrectMessageType);
+        }
+
+        let (mut cause, mut requests_responded) = (None, None);
+        for ie in decode_message_body(&message.header, buffer, &[CAUSE, REQUESTS_RESPONDED])? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::SequenceNumbers(i)
+                    if i.t == REQUESTS_RESPONDED && requests_responded.is_none() =>
+                {
+                    requests_responded = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match (cause, requests_responded) {
+            (Some(i), Some(j)) => {
+                message.cause = i;
+                message.requests_responded = j;
+                Ok(message)
+            }
+            _ => Err(GTPPrimeError::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+const DATA_RECORD_TRANSFER_RESPONSE_TEST: [u8; 15] = [
+    0x4e, 0xf1, 0x00, 0x09, 0x00, 0x10, 0x01, 0x80, 0xfd, 0x00, 0x04, 0x00, 0x10, 0x00, 0x11,
+];
+
+#[cfg(test)]
+fn data_record_transfer_resp_test() -> DataRecordTransferResponse {
+    DataRecordTransferResponse {
+        header: GtpPrimeHeader {
+            msgtype: DATA_RECORD_TRANSFER_RESPONSE,
+            length: 9,
+            sequence_number: 16,
+            ..Default::default()
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: REQUEST_ACCEPTED,
+        },
+        requests_responded: SequenceNumbers {
+            t: REQUESTS_RESPONDED,
+            length: 4,
+            sequence_numbers: vec![0x10, 0x11],
+        },
+        private_extension: None,
+    }
+}
+
+#[test]
+fn data_record_transfer_resp_unmarshal_test() {
+    assert_eq!(
+        DataRecordTransferResponse::unmarshal(&DATA_RECORD_TRANSFER_RESPONSE_TEST).unwrap(),
+        data_record_transfer_resp_test()
+    );
+}
+
+#[test]
+fn data_record_transfer_resp_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    data_record_transfer_resp_test().marshal(&mut buffer);
+    assert_eq!(buffer, DATA_RECORD_TRANSFER_RESPONSE_TEST);
+}
+
+#[test]
+fn data_record_transfer_resp_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 8] = [0x4e, 0xf1, 0x00, 0x02, 0x00, 0x10, 0x01, 0x80];
+    assert_eq!(
+        DataRecordTransferResponse::unmarshal(&encoded),
+        Err(GTPPrimeError::MessageMandatoryIEMissing)
+    );
+}
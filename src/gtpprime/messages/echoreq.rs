@@ -0,0 +1,101 @@
+use crate::gtpprime::errors::*;
+use crate::gtpprime::header::*;
+use crate::gtpprime::messages::commons::*;
+use crate::gtpprime::messages::ies::*;
+use crate::gtpprime::utils::*;
+
+// According to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+pub const ECHO_REQUEST: u8 = 1;
+
+// Definition of GTP' Echo Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EchoRequest {
+    pub header: GtpPrimeHeader,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for EchoRequest {
+    fn default() -> EchoRequest {
+        let hdr = GtpPrimeHeader {
+            msgtype: ECHO_REQUEST,
+            ..Default::default()
+        };
+        EchoRequest {
+            header: hdr,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for EchoRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![];
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer, self.header.len());
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        let mut message = EchoRequest {
+            header: GtpPrimeHeader::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != ECHO_REQUEST {
+            return Err(GTPPrimeError::MessageIncorrectMessageType);
+        }
+
+        for ie in decode_message_body(&message.header, buffer, &[])? {
+            match ie {
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+const ECHO_REQUEST_TEST: [u8; 6] = [0x4e, 0x01, 0x00, 0x00, 0x00, 0x01];
+
+#[cfg(test)]
+fn echo_req_test() -> EchoRequest {
+    EchoRequest {
+        header: GtpPrimeHeader {
+            msgtype: ECHO_REQUEST,
+            length: 0,
+            sequence_number: 1,
+            ..Default::default()
+        },
+        private_extension: None,
+    }
+}
+
+#[test]
+fn echo_req_unmarshal_test() {
+    assert_eq!(
+        EchoRequest::unmarshal(&ECHO_REQUEST_TEST).unwrap(),
+        echo_req_test()
+    );
+}
+
+#[test]
+fn echo_req_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    echo_req_test().marshal(&mut buffer);
+    assert_eq!(buffer, ECHO_REQUEST_TEST);
+}
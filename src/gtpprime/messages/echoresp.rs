@@ -0,0 +1,124 @@
+use crate::gtpprime::errors::*;
+use crate::gtpprime::header::*;
+use crate::gtpprime::messages::commons::*;
+use crate::gtpprime::messages::ies::*;
+use crate::gtpprime::utils::*;
+
+// According to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+pub const ECHO_RESPONSE: u8 = 2;
+
+// Definition of GTP' Echo Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EchoResponse {
+    pub header: GtpPrimeHeader,
+    pub recovery: Recovery,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for EchoResponse {
+    fn default() -> EchoResponse {
+        let hdr = GtpPrimeHeader {
+            msgtype: ECHO_RESPONSE,
+            ..Default::default()
+        };
+        EchoResponse {
+            header: hdr,
+            recovery: Recovery::default(),
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for EchoResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.recovery.into()];
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer, self.header.len());
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        let mut message = EchoResponse {
+            header: GtpPrimeHeader::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != ECHO_RESPONSE {
+            return Err(GTPPrimeError::MessageIncorrectMessageType);
+        }
+
+        let mut recovery = None;
+        for ie in decode_message_body(&message.header, buffer, &[RECOVERY])? {
+            match ie {
+                InformationElement::Recovery(i) if recovery.is_none() => recovery = Some(i),
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match recovery {
+            Some(i) => {
+                message.recovery = i;
+                Ok(message)
+            }
+            None => Err(GTPPrimeError::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+const ECHO_RESPONSE_TEST: [u8; 8] = [0x4e, 0x02, 0x00, 0x02, 0x00, 0x01, 0x0e, 0x05];
+
+#[cfg(test)]
+fn echo_resp_test() -> EchoResponse {
+    EchoResponse {
+        header: GtpPrimeHeader {
+            msgtype: ECHO_RESPONSE,
+            length: 2,
+            sequence_number: 1,
+            ..Default::default()
+        },
+        recovery: Recovery {
+            t: RECOVERY,
+            value: 5,
+        },
+        private_extension: None,
+    }
+}
+
+#[test]
+fn echo_resp_unmarshal_test() {
+    assert_eq!(
+        EchoResponse::unmarshal(&ECHO_RESPONSE_TEST).unwrap(),
+        echo_resp_test()
+    );
+}
+
+#[test]
+fn echo_resp_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    echo_resp_test().marshal(&mut buffer);
+    assert_eq!(buffer, ECHO_RESPONSE_TEST);
+}
+
+#[test]
+fn echo_resp_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 6] = [0x4e, 0x02, 0x00, 0x00, 0x00, 0x01];
+    assert_eq!(
+        EchoResponse::unmarshal(&encoded),
+        Err(GTPPrimeError::MessageMandatoryIEMissing)
+    );
+}
@@ -0,0 +1,100 @@
+// Cause IE - according to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+use crate::gtpprime::{errors::GTPPrimeError, messages::ies::commons::*};
+
+// Cause IE TV
+
+pub const CAUSE: u8 = 1;
+pub const CAUSE_LENGTH: usize = 1;
+
+// Cause values used in Redirection Requests
+
+pub const SYSTEM_FAILURE: u8 = 59;
+pub const TRANSMIT_BUFFERS_BECOMING_FULL: u8 = 60;
+pub const RECEIVE_BUFFERS_BECOMING_FULL: u8 = 61;
+pub const ANOTHER_NODE_ABOUT_TO_GO_DOWN: u8 = 62;
+pub const THIS_NODE_ABOUT_TO_GO_DOWN: u8 = 63;
+
+// Cause values used in responses
+
+pub const REQUEST_ACCEPTED: u8 = 128;
+pub const CDR_DECODING_ERROR: u8 = 177;
+pub const INVALID_MESSAGE_FORMAT: u8 = 193;
+pub const VERSION_NOT_SUPPORTED_CAUSE: u8 = 198;
+pub const NO_RESOURCES_AVAILABLE: u8 = 199;
+pub const SERVICE_NOT_SUPPORTED: u8 = 200;
+pub const MANDATORY_IE_INCORRECT: u8 = 201;
+pub const MANDATORY_IE_MISSING: u8 = 202;
+pub const OPTIONAL_IE_INCORRECT: u8 = 203;
+pub const SYSTEM_FAILURE_RESPONSE: u8 = 204;
+pub const REQUEST_DUPLICATE_ALREADY_FULFILLED: u8 = 252;
+pub const REQUEST_ALREADY_FULFILLED: u8 = 253;
+pub const SEQUENCE_NUMBERS_INCORRECT: u8 = 254;
+pub const REQUEST_NOT_FULFILLED: u8 = 255;
+
+// Cause IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cause {
+    pub t: u8,
+    pub value: u8,
+}
+
+impl Default for Cause {
+    fn default() -> Self {
+        Cause {
+            t: CAUSE,
+            value: REQUEST_ACCEPTED,
+        }
+    }
+}
+
+impl IEs for Cause {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.push(self.value);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        if buffer.len() > CAUSE_LENGTH {
+            Ok(Cause {
+                value: buffer[1],
+                ..Default::default()
+            })
+        } else {
+            Err(GTPPrimeError::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        CAUSE_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn cause_ie_marshal_test() {
+    let ie_to_marshal = Cause {
+        t: CAUSE,
+        value: CDR_DECODING_ERROR,
+    };
+    let ie_marshalled: [u8; 2] = [0x01, 0xb1];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn cause_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 2] = [0x01, 0x80];
+    assert_eq!(
+        Cause::unmarshal(&ie_to_unmarshal).unwrap(),
+        Cause::default()
+    );
+    assert_eq!(
+        Cause::unmarshal(&ie_to_unmarshal[..1]),
+        Err(GTPPrimeError::IEInvalidLength)
+    );
+}
@@ -0,0 +1,12 @@
+// Commons for GTP' IEs
+
+use crate::gtpprime::errors::GTPPrimeError;
+
+pub trait IEs {
+    fn marshal(&self, buffer: &mut Vec<u8>);
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError>
+    where
+        Self: Sized;
+    fn len(&self) -> usize; // Total IE length including Type+Value for TV messages, Type+Length+Value for TLV messages
+    fn is_empty(&self) -> bool; // is_empty() method implementation
+}
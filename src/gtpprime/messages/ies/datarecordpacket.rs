@@ -0,0 +1,155 @@
+// Data Record Packet IE - according to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+use crate::gtpprime::{errors::GTPPrimeError, messages::ies::commons::*, utils::*};
+
+// Data Record Packet IE type
+
+pub const DATA_RECORD_PACKET: u8 = 252;
+
+// Data Record Format values, 1 is ASN.1 BER as specified in 3GPP TS 32.298
+
+pub const DATA_RECORD_FORMAT_BER: u8 = 1;
+
+// Data Record Packet IE implementation
+// The Number of Data Records octet is derived from the records when marshalling.
+// The Data Record Format Version holds the Application Identifier and the Release Identifier octets
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataRecordPacket {
+    pub t: u8,
+    pub length: u16,
+    pub format: u8,
+    pub format_version: u16,
+    pub records: Vec<Vec<u8>>,
+}
+
+impl Default for DataRecordPacket {
+    fn default() -> DataRecordPacket {
+        DataRecordPacket {
+            t: DATA_RECORD_PACKET,
+            length: 0,
+            format: DATA_RECORD_FORMAT_BER,
+            format_version: 0,
+            records: vec![],
+        }
+    }
+}
+
+impl IEs for DataRecordPacket {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.push(self.records.len() as u8);
+        buffer_ie.push(self.format);
+        buffer_ie.extend_from_slice(&self.format_version.to_be_bytes());
+        for i in self.records.iter() {
+            buffer_ie.extend_from_slice(&(i.len() as u16).to_be_bytes());
+            buffer_ie.extend_from_slice(i);
+        }
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<DataRecordPacket, GTPPrimeError> {
+        if buffer.len() >= 7 {
+            let mut data = DataRecordPacket {
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                format: buffer[4],
+                format_version: u16::from_be_bytes([buffer[5], buffer[6]]),
+                ..Default::default()
+            };
+            if !check_tlv_ie_buffer(data.length, buffer) || data.length < 4 {
+                return Err(GTPPrimeError::IEInvalidLength);
+            }
+            let end = data.length as usize + 3;
+            let mut cursor = 7;
+            for _ in 0..buffer[3] {
+                if cursor + 2 > end {
+                    return Err(GTPPrimeError::IEInvalidLength);
+                }
+                let len = u16::from_be_bytes([buffer[cursor], buffer[cursor + 1]]) as usize;
+                cursor += 2;
+                if cursor + len > end {
+                    return Err(GTPPrimeError::IEInvalidLength);
+                }
+                data.records.push(buffer[cursor..cursor + len].to_vec());
+                cursor += len;
+            }
+            if cursor != end {
+                return Err(GTPPrimeError::IEIncorrect);
+            }
+            Ok(data)
+        } else {
+            Err(GTPPrimeError::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn data_record_packet_ie_marshal_test() {
+    let ie_to_marshal = DataRecordPacket {
+        t: DATA_RECORD_PACKET,
+        length: 13,
+        format: DATA_RECORD_FORMAT_BER,
+        format_version: 0x1311,
+        records: vec![vec![0xbf, 0x4e, 0x00], vec![0xbf, 0x4f]],
+    };
+    let ie_marshalled: [u8; 16] = [
+        0xfc, 0x00, 0x0d, 0x02, 0x01, 0x13, 0x11, 0x00, 0x03, 0xbf, 0x4e, 0x00, 0x00, 0x02, 0xbf,
+        0x4f,
+    ];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn data_record_packet_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 16] = [
+        0xfc, 0x00, 0x0d, 0x02, 0x01, 0x13, 0x11, 0x00, 0x03, 0xbf, 0x4e, 0x00, 0x00, 0x02, 0xbf,
+        0x4f,
+    ];
+    let ie_unmarshalled = DataRecordPacket {
+        t: DATA_RECORD_PACKET,
+        length: 13,
+        format: DATA_RECORD_FORMAT_BER,
+        format_version: 0x1311,
+        records: vec![vec![0xbf, 0x4e, 0x00], vec![0xbf, 0x4f]],
+    };
+    assert_eq!(
+        DataRecordPacket::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
+
+#[test]
+fn data_record_packet_ie_record_overflow_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 16] = [
+        0xfc, 0x00, 0x0d, 0x02, 0x01, 0x13, 0x11, 0x00, 0x03, 0xbf, 0x4e, 0x00, 0x00, 0x03, 0xbf,
+        0x4f,
+    ];
+    assert_eq!(
+        DataRecordPacket::unmarshal(&ie_to_unmarshal),
+        Err(GTPPrimeError::IEInvalidLength)
+    );
+}
+
+#[test]
+fn data_record_packet_ie_record_count_mismatch_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 16] = [
+        0xfc, 0x00, 0x0d, 0x01, 0x01, 0x13, 0x11, 0x00, 0x03, 0xbf, 0x4e, 0x00, 0x00, 0x02, 0xbf,
+        0x4f,
+    ];
+    assert_eq!(
+        DataRecordPacket::unmarshal(&ie_to_unmarshal),
+        Err(GTPPrimeError::IEIncorrect)
+    );
+}
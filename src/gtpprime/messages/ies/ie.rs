@@ -0,0 +1,187 @@
+use crate::gtpprime::errors::GTPPrimeError;
+use crate::gtpprime::messages::ies::*;
+
+// IEs with a type value of 128 and above are TLV encoded, the ones below are TV encoded
+
+pub const TLV_IE_TYPE_MIN: u8 = 128;
+
+// Definition of GTP' Information Elements
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InformationElement {
+    Cause(Cause),
+    Recovery(Recovery),
+    PacketTransferCommand(PacketTransferCommand),
+    SequenceNumbers(SequenceNumbers),
+    NodeAddress(NodeAddress),
+    DataRecordPacket(DataRecordPacket),
+    PrivateExtension(PrivateExtension),
+    Unknown(UnknownIe),
+}
+
+impl From<Cause> for InformationElement {
+    fn from(i: Cause) -> Self {
+        InformationElement::Cause(i)
+    }
+}
+
+impl From<Recovery> for InformationElement {
+    fn from(i: Recovery) -> Self {
+        InformationElement::Recovery(i)
+    }
+}
+
+impl From<PacketTransferCommand> for InformationElement {
+    fn from(i: PacketTransferCommand) -> Self {
+        InformationElement::PacketTransferCommand(i)
+    }
+}
+
+impl From<SequenceNumbers> for InformationElement {
+    fn from(i: SequenceNumbers) -> Self {
+        InformationElement::SequenceNumbers(i)
+    }
+}
+
+impl From<NodeAddress> for InformationElement {
+    fn from(i: NodeAddress) -> Self {
+        InformationElement::NodeAddress(i)
+    }
+}
+
+impl From<DataRecordPacket> for InformationElement {
+    fn from(i: DataRecordPacket) -> Self {
+        InformationElement::DataRecordPacket(i)
+    }
+}
+
+impl From<PrivateExtension> for InformationElement {
+    fn from(i: PrivateExtension) -> Self {
+        InformationElement::PrivateExtension(i)
+    }
+}
+
+impl From<UnknownIe> for InformationElement {
+    fn from(i: UnknownIe) -> Self {
+        InformationElement::Unknown(i)
+    }
+}
+
+impl InformationElement {
+    pub fn marshal(self, buffer: &mut Vec<u8>) {
+        match self {
+            InformationElement::Cause(i) => i.marshal(buffer),
+            InformationElement::Recovery(i) => i.marshal(buffer),
+            InformationElement::PacketTransferCommand(i) => i.marshal(buffer),
+            InformationElement::SequenceNumbers(i) => i.marshal(buffer),
+            InformationElement::NodeAddress(i) => i.marshal(buffer),
+            InformationElement::DataRecordPacket(i) => i.marshal(buffer),
+            InformationElement::PrivateExtension(i) => i.marshal(buffer),
+            InformationElement::Unknown(i) => i.marshal(buffer),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            InformationElement::Cause(i) => i.len(),
+            InformationElement::Recovery(i) => i.len(),
+            InformationElement::PacketTransferCommand(i) => i.len(),
+            InformationElement::SequenceNumbers(i) => i.len(),
+            InformationElement::NodeAddress(i) => i.len(),
+            InformationElement::DataRecordPacket(i) => i.len(),
+            InformationElement::PrivateExtension(i) => i.len(),
+            InformationElement::Unknown(i) => i.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn encoder(message: Vec<InformationElement>, buffer: &mut Vec<u8>) {
+        for i in message.into_iter() {
+            i.marshal(buffer);
+        }
+    }
+
+    // Decode the IE at the start of the buffer
+
+    pub fn decode(buffer: &[u8]) -> Result<InformationElement, GTPPrimeError> {
+        let ie = match buffer[0] {
+            CAUSE => InformationElement::Cause(Cause::unmarshal(buffer)?),
+            RECOVERY => InformationElement::Recovery(Recovery::unmarshal(buffer)?),
+            PACKET_TRANSFER_COMMAND => {
+                InformationElement::PacketTransferCommand(PacketTransferCommand::unmarshal(buffer)?)
+            }
+            SEQUENCE_NUMBERS_RELEASED | SEQUENCE_NUMBERS_CANCELLED | REQUESTS_RESPONDED => {
+                InformationElement::SequenceNumbers(SequenceNumbers::unmarshal(buffer)?)
+            }
+            CHARGING_GATEWAY_ADDRESS | RECOMMENDED_NODE_ADDRESS => {
+                InformationElement::NodeAddress(NodeAddress::unmarshal(buffer)?)
+            }
+            DATA_RECORD_PACKET => {
+                InformationElement::DataRecordPacket(DataRecordPacket::unmarshal(buffer)?)
+            }
+            PRIVATE_EXTENSION => {
+                InformationElement::PrivateExtension(PrivateExtension::unmarshal(buffer)?)
+            }
+            _ => InformationElement::Unknown(UnknownIe::unmarshal(buffer)?),
+        };
+        Ok(ie)
+    }
+
+    pub fn decoder(buffer: &[u8]) -> Result<Vec<InformationElement>, GTPPrimeError> {
+        let mut ies: Vec<InformationElement> = vec![];
+        let mut cursor: usize = 0;
+        loop {
+            if cursor >= buffer.len() {
+                break;
+            }
+            let ie = InformationElement::decode(&buffer[cursor..])?;
+            cursor += ie.len();
+            ies.push(ie);
+        }
+        Ok(ies)
+    }
+}
+
+#[test]
+fn ie_decoder_encoder_test() {
+    let encoded: [u8; 30] = [
+        0x01, 0x80, 0x7e, 0x01, 0xf9, 0x00, 0x02, 0x00, 0x01, 0xfb, 0x00, 0x04, 0x0a, 0x00, 0x00,
+        0x01, 0xfc, 0x00, 0x07, 0x01, 0x01, 0x13, 0x11, 0x00, 0x01, 0xbf, 0xc8, 0x00, 0x01, 0xaa,
+    ];
+    let ies = InformationElement::decoder(&encoded).unwrap();
+    assert_eq!(ies.len(), 6);
+    assert_eq!(ies[0], Cause::default().into());
+    assert_eq!(
+        ies[2],
+        SequenceNumbers {
+            t: SEQUENCE_NUMBERS_RELEASED,
+            length: 2,
+            sequence_numbers: vec![1],
+        }
+        .into()
+    );
+    assert_eq!(
+        ies[5],
+        UnknownIe {
+            t: 0xc8,
+            length: 1,
+            value: vec![0xaa],
+        }
+        .into()
+    );
+    let mut buffer: Vec<u8> = vec![];
+    InformationElement::encoder(ies, &mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn ie_decoder_truncated_ie_test() {
+    let encoded: [u8; 6] = [0x01, 0x80, 0xfb, 0x00, 0x04, 0x0a];
+    assert_eq!(
+        InformationElement::decoder(&encoded),
+        Err(GTPPrimeError::IEInvalidLength)
+    );
+}
@@ -0,0 +1,15 @@
+pub use {
+    cause::*, commons::*, datarecordpacket::*, ie::*, nodeaddress::*, packettransfercmd::*,
+    privateextension::*, recovery::*, seqnumbers::*, unknown::*,
+};
+
+mod cause;
+mod commons;
+mod datarecordpacket;
+mod ie;
+mod nodeaddress;
+mod packettransfercmd;
+mod privateextension;
+mod recovery;
+mod seqnumbers;
+mod unknown;
@@ -0,0 +1,115 @@
+// Node Address IE - according to 3GPP TS 32.295 V17.0.0 (2022-04)
+// Covers the Charging Gateway Address and Address of Recommended Node IEs
+
+use crate::gtpprime::{errors::GTPPrimeError, messages::ies::commons::*, utils::*};
+use std::net::{IpAddr, Ipv4Addr};
+
+// Node Address IE types
+
+pub const CHARGING_GATEWAY_ADDRESS: u8 = 251;
+pub const RECOMMENDED_NODE_ADDRESS: u8 = 254;
+
+// Node Address IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeAddress {
+    pub t: u8,
+    pub length: u16,
+    pub ip: IpAddr,
+}
+
+impl Default for NodeAddress {
+    fn default() -> NodeAddress {
+        NodeAddress {
+            t: CHARGING_GATEWAY_ADDRESS,
+            length: 4,
+            ip: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+        }
+    }
+}
+
+impl IEs for NodeAddress {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        match self.ip {
+            IpAddr::V4(i) => buffer_ie.extend_from_slice(&i.octets()),
+            IpAddr::V6(i) => buffer_ie.extend_from_slice(&i.octets()),
+        }
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<NodeAddress, GTPPrimeError> {
+        if buffer.len() >= 3 {
+            let mut data = NodeAddress {
+                t: buffer[0],
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if check_tlv_ie_buffer(data.length, buffer) {
+                match data.length {
+                    0x04 => data.ip = IpAddr::from([buffer[3], buffer[4], buffer[5], buffer[6]]),
+                    0x10 => {
+                        let mut dst = [0; 16];
+                        dst.copy_from_slice(&buffer[3..19]);
+                        data.ip = IpAddr::from(dst);
+                    }
+                    _ => return Err(GTPPrimeError::IEIncorrect),
+                }
+                Ok(data)
+            } else {
+                Err(GTPPrimeError::IEInvalidLength)
+            }
+        } else {
+            Err(GTPPrimeError::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn node_address_ie_ipv4_marshal_test() {
+    let ie_to_marshal = NodeAddress {
+        t: CHARGING_GATEWAY_ADDRESS,
+        length: 4,
+        ip: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+    };
+    let ie_marshalled: [u8; 7] = [0xfb, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x01];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn node_address_ie_ipv6_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 19] = [
+        0xfe, 0x00, 0x10, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01,
+    ];
+    let ie_unmarshalled = NodeAddress {
+        t: RECOMMENDED_NODE_ADDRESS,
+        length: 16,
+        ip: "2001:db8::1".parse().unwrap(),
+    };
+    assert_eq!(
+        NodeAddress::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
+
+#[test]
+fn node_address_ie_wrong_length_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 5] = [0xfb, 0x00, 0x02, 0x0a, 0x00];
+    assert_eq!(
+        NodeAddress::unmarshal(&ie_to_unmarshal),
+        Err(GTPPrimeError::IEIncorrect)
+    );
+}
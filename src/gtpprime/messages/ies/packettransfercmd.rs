@@ -0,0 +1,94 @@
+// Packet Transfer Command IE - according to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+use crate::gtpprime::{errors::GTPPrimeError, messages::ies::commons::*};
+
+// Packet Transfer Command IE TV
+
+pub const PACKET_TRANSFER_COMMAND: u8 = 126;
+pub const PACKET_TRANSFER_COMMAND_LENGTH: usize = 1;
+
+// Packet Transfer Command values
+
+pub const SEND_DATA_RECORD_PACKET: u8 = 1;
+pub const SEND_POSSIBLY_DUPLICATED_DATA_RECORD_PACKET: u8 = 2;
+pub const CANCEL_DATA_RECORD_PACKET: u8 = 3;
+pub const RELEASE_DATA_RECORD_PACKET: u8 = 4;
+
+// Packet Transfer Command IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PacketTransferCommand {
+    pub t: u8,
+    pub value: u8,
+}
+
+impl Default for PacketTransferCommand {
+    fn default() -> Self {
+        PacketTransferCommand {
+            t: PACKET_TRANSFER_COMMAND,
+            value: SEND_DATA_RECORD_PACKET,
+        }
+    }
+}
+
+impl IEs for PacketTransferCommand {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.push(self.value);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        if buffer.len() > PACKET_TRANSFER_COMMAND_LENGTH {
+            match buffer[1] {
+                SEND_DATA_RECORD_PACKET..=RELEASE_DATA_RECORD_PACKET => Ok(PacketTransferCommand {
+                    value: buffer[1],
+                    ..Default::default()
+                }),
+                _ => Err(GTPPrimeError::IEIncorrect),
+            }
+        } else {
+            Err(GTPPrimeError::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        PACKET_TRANSFER_COMMAND_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn packet_transfer_cmd_ie_marshal_test() {
+    let ie_to_marshal = PacketTransferCommand {
+        t: PACKET_TRANSFER_COMMAND,
+        value: CANCEL_DATA_RECORD_PACKET,
+    };
+    let ie_marshalled: [u8; 2] = [0x7e, 0x03];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn packet_transfer_cmd_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 2] = [0x7e, 0x04];
+    let ie_unmarshalled = PacketTransferCommand {
+        t: PACKET_TRANSFER_COMMAND,
+        value: RELEASE_DATA_RECORD_PACKET,
+    };
+    assert_eq!(
+        PacketTransferCommand::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
+
+#[test]
+fn packet_transfer_cmd_ie_wrong_value_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 2] = [0x7e, 0x05];
+    assert_eq!(
+        PacketTransferCommand::unmarshal(&ie_to_unmarshal),
+        Err(GTPPrimeError::IEIncorrect)
+    );
+}
@@ -0,0 +1,75 @@
+// Recovery IE - according to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+use crate::gtpprime::{errors::GTPPrimeError, messages::ies::commons::*};
+
+// Recovery IE TV
+
+pub const RECOVERY: u8 = 14;
+pub const RECOVERY_LENGTH: usize = 1;
+
+// Recovery IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recovery {
+    pub t: u8,
+    pub value: u8,
+}
+
+impl Default for Recovery {
+    fn default() -> Recovery {
+        Recovery {
+            t: RECOVERY,
+            value: 0,
+        }
+    }
+}
+
+impl IEs for Recovery {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.push(self.value);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Recovery, GTPPrimeError> {
+        if buffer.len() > RECOVERY_LENGTH {
+            Ok(Recovery {
+                value: buffer[1],
+                ..Default::default()
+            })
+        } else {
+            Err(GTPPrimeError::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        RECOVERY_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn recovery_ie_marshal_test() {
+    let ie_marshalled: [u8; 2] = [0x0e, 0x63];
+    let ie_to_marshal = Recovery {
+        t: RECOVERY,
+        value: 0x63,
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn recovery_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 2] = [0x0e, 0x63];
+    let ie_unmarshalled = Recovery {
+        t: RECOVERY,
+        value: 0x63,
+    };
+    assert_eq!(
+        Recovery::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
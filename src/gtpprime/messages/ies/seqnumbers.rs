@@ -0,0 +1,107 @@
+// Sequence Numbers IE - according to 3GPP TS 32.295 V17.0.0 (2022-04)
+// Covers the Sequence Numbers of Released Packets, Sequence Numbers of Cancelled Packets
+// and Requests Responded IEs, which all carry a list of GTP' sequence numbers
+
+use crate::gtpprime::{errors::GTPPrimeError, messages::ies::commons::*, utils::*};
+
+// Sequence Numbers IE types
+
+pub const SEQUENCE_NUMBERS_RELEASED: u8 = 249;
+pub const SEQUENCE_NUMBERS_CANCELLED: u8 = 250;
+pub const REQUESTS_RESPONDED: u8 = 253;
+
+// Sequence Numbers IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SequenceNumbers {
+    pub t: u8,
+    pub length: u16,
+    pub sequence_numbers: Vec<u16>,
+}
+
+impl Default for SequenceNumbers {
+    fn default() -> SequenceNumbers {
+        SequenceNumbers {
+            t: REQUESTS_RESPONDED,
+            length: 0,
+            sequence_numbers: vec![],
+        }
+    }
+}
+
+impl IEs for SequenceNumbers {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        for i in self.sequence_numbers.iter() {
+            buffer_ie.extend_from_slice(&i.to_be_bytes());
+        }
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<SequenceNumbers, GTPPrimeError> {
+        if buffer.len() >= 3 {
+            let mut data = SequenceNumbers {
+                t: buffer[0],
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if check_tlv_ie_buffer(data.length, buffer) && data.length.is_multiple_of(2) {
+                data.sequence_numbers = buffer[3..(data.length as usize + 3)]
+                    .chunks_exact(2)
+                    .map(|x| u16::from_be_bytes([x[0], x[1]]))
+                    .collect();
+                Ok(data)
+            } else {
+                Err(GTPPrimeError::IEInvalidLength)
+            }
+        } else {
+            Err(GTPPrimeError::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn sequence_numbers_ie_marshal_test() {
+    let ie_to_marshal = SequenceNumbers {
+        t: SEQUENCE_NUMBERS_RELEASED,
+        length: 4,
+        sequence_numbers: vec![0x0102, 0x0103],
+    };
+    let ie_marshalled: [u8; 7] = [0xf9, 0x00, 0x04, 0x01, 0x02, 0x01, 0x03];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn sequence_numbers_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 7] = [0xfa, 0x00, 0x04, 0x01, 0x02, 0x01, 0x03];
+    let ie_unmarshalled = SequenceNumbers {
+        t: SEQUENCE_NUMBERS_CANCELLED,
+        length: 4,
+        sequence_numbers: vec![0x0102, 0x0103],
+    };
+    assert_eq!(
+        SequenceNumbers::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
+
+#[test]
+fn sequence_numbers_ie_odd_length_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 6] = [0xfd, 0x00, 0x03, 0x01, 0x02, 0x01];
+    assert_eq!(
+        SequenceNumbers::unmarshal(&ie_to_unmarshal),
+        Err(GTPPrimeError::IEInvalidLength)
+    );
+}
@@ -0,0 +1,80 @@
+// Unknown IE - for internal message handling purposes
+
+use crate::gtpprime::{
+    errors::GTPPrimeError,
+    messages::ies::{commons::*, ie::*},
+};
+
+// Unknown IE implementation
+// GTP' only defines TV IEs of known length, so unknown TV IEs are not decodable
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UnknownIe {
+    pub t: u8,
+    pub length: u16,
+    pub value: Vec<u8>,
+}
+
+impl IEs for UnknownIe {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.extend_from_slice(&(self.value.len() as u16).to_be_bytes());
+        buffer.extend_from_slice(&self.value);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        if buffer.is_empty() {
+            return Err(GTPPrimeError::IEInvalidLength);
+        }
+        if buffer[0] < TLV_IE_TYPE_MIN {
+            return Err(GTPPrimeError::IEIncorrect);
+        }
+        if buffer.len() >= 3 {
+            let mut data = UnknownIe {
+                t: buffer[0],
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if (data.length as usize) + 3 <= buffer.len() {
+                data.value
+                    .extend_from_slice(&buffer[3..(data.length + 3) as usize]);
+                Ok(data)
+            } else {
+                Err(GTPPrimeError::IEInvalidLength)
+            }
+        } else {
+            Err(GTPPrimeError::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.length as usize + 3
+    }
+
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn unknown_ie_tlv_unmarshal_test() {
+    let encoded: [u8; 5] = [0xc8, 0x00, 0x02, 0xaa, 0xbb];
+    let decoded = UnknownIe {
+        t: 0xc8,
+        length: 2,
+        value: vec![0xaa, 0xbb],
+    };
+    assert_eq!(UnknownIe::unmarshal(&encoded).unwrap(), decoded);
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn unknown_ie_tv_unmarshal_test() {
+    let encoded: [u8; 2] = [0x10, 0x01];
+    assert_eq!(
+        UnknownIe::unmarshal(&encoded),
+        Err(GTPPrimeError::IEIncorrect)
+    );
+}
@@ -0,0 +1,133 @@
+use crate::gtpprime::errors::*;
+use crate::gtpprime::header::*;
+use crate::gtpprime::messages::*;
+use crate::gtpprime::utils::*;
+
+// According to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+// Definition of GTP' Message enum covering all implemented GTP' messages
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GtpPrimeMessage {
+    EchoRequest(EchoRequest),
+    EchoResponse(EchoResponse),
+    VersionNotSupported(VersionNotSupported),
+    NodeAliveRequest(NodeAliveRequest),
+    NodeAliveResponse(NodeAliveResponse),
+    RedirectionRequest(RedirectionRequest),
+    RedirectionResponse(RedirectionResponse),
+    DataRecordTransferRequest(DataRecordTransferRequest),
+    DataRecordTransferResponse(DataRecordTransferResponse),
+    Unknown {
+        header: GtpPrimeHeader,
+        body: Vec<u8>,
+    },
+}
+
+impl GtpPrimeMessage {
+    // Decode any GTP' message based on the message type in the header
+
+    pub fn parse(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        let header = GtpPrimeHeader::unmarshal(buffer)?;
+        match header.msgtype {
+            ECHO_REQUEST => EchoRequest::unmarshal(buffer).map(GtpPrimeMessage::EchoRequest),
+            ECHO_RESPONSE => EchoResponse::unmarshal(buffer).map(GtpPrimeMessage::EchoResponse),
+            VERSION_NOT_SUPPORTED => {
+                VersionNotSupported::unmarshal(buffer).map(GtpPrimeMessage::VersionNotSupported)
+            }
+            NODE_ALIVE_REQUEST => {
+                NodeAliveRequest::unmarshal(buffer).map(GtpPrimeMessage::NodeAliveRequest)
+            }
+            NODE_ALIVE_RESPONSE => {
+                NodeAliveResponse::unmarshal(buffer).map(GtpPrimeMessage::NodeAliveResponse)
+            }
+            REDIRECTION_REQUEST => {
+                RedirectionRequest::unmarshal(buffer).map(GtpPrimeMessage::RedirectionRequest)
+            }
+            REDIRECTION_RESPONSE => {
+                RedirectionResponse::unmarshal(buffer).map(GtpPrimeMessage::RedirectionResponse)
+            }
+            DATA_RECORD_TRANSFER_REQUEST => DataRecordTransferRequest::unmarshal(buffer)
+                .map(GtpPrimeMessage::DataRecordTransferRequest),
+            DATA_RECORD_TRANSFER_RESPONSE => DataRecordTransferResponse::unmarshal(buffer)
+                .map(GtpPrimeMessage::DataRecordTransferResponse),
+            _ => {
+                let offset = header.length as usize + header.len();
+                if offset <= buffer.len() {
+                    let body = buffer[header.len()..offset].to_vec();
+                    Ok(GtpPrimeMessage::Unknown { header, body })
+                } else {
+                    Err(GTPPrimeError::MessageLengthError)
+                }
+            }
+        }
+    }
+
+    pub fn marshal(self, buffer: &mut Vec<u8>) {
+        match self {
+            GtpPrimeMessage::EchoRequest(i) => i.marshal(buffer),
+            GtpPrimeMessage::EchoResponse(i) => i.marshal(buffer),
+            GtpPrimeMessage::VersionNotSupported(i) => i.marshal(buffer),
+            GtpPrimeMessage::NodeAliveRequest(i) => i.marshal(buffer),
+            GtpPrimeMessage::NodeAliveResponse(i) => i.marshal(buffer),
+            GtpPrimeMessage::RedirectionRequest(i) => i.marshal(buffer),
+            GtpPrimeMessage::RedirectionResponse(i) => i.marshal(buffer),
+            GtpPrimeMessage::DataRecordTransferRequest(i) => i.marshal(buffer),
+            GtpPrimeMessage::DataRecordTransferResponse(i) => i.marshal(buffer),
+            GtpPrimeMessage::Unknown { header, body } => {
+                header.marshal(buffer);
+                buffer.extend_from_slice(&body);
+                set_length(buffer, header.len());
+            }
+        }
+    }
+
+    pub fn header(&self) -> &GtpPrimeHeader {
+        match self {
+            GtpPrimeMessage::EchoRequest(i) => &i.header,
+            GtpPrimeMessage::EchoResponse(i) => &i.header,
+            GtpPrimeMessage::VersionNotSupported(i) => &i.header,
+            GtpPrimeMessage::NodeAliveRequest(i) => &i.header,
+            GtpPrimeMessage::NodeAliveResponse(i) => &i.header,
+            GtpPrimeMessage::RedirectionRequest(i) => &i.header,
+            GtpPrimeMessage::RedirectionResponse(i) => &i.header,
+            GtpPrimeMessage::DataRecordTransferRequest(i) => &i.header,
+            GtpPrimeMessage::DataRecordTransferResponse(i) => &i.header,
+            GtpPrimeMessage::Unknown { header, .. } => header,
+        }
+    }
+
+    pub fn msg_type(&self) -> u8 {
+        self.header().msgtype
+    }
+}
+
+#[test]
+fn test_gtp_prime_message_data_record_transfer_resp_parse() {
+    let encoded: [u8; 15] = [
+        0x4e, 0xf1, 0x00, 0x09, 0x00, 0x10, 0x01, 0x80, 0xfd, 0x00, 0x04, 0x00, 0x10, 0x00, 0x11,
+    ];
+    let message = GtpPrimeMessage::parse(&encoded).unwrap();
+    assert_eq!(message.msg_type(), DATA_RECORD_TRANSFER_RESPONSE);
+    let mut buffer: Vec<u8> = vec![];
+    message.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn test_gtp_prime_message_unknown_parse_marshal() {
+    let encoded: [u8; 8] = [0x4e, 0x7f, 0x00, 0x02, 0x00, 0x01, 0x0e, 0x05];
+    let decoded = GtpPrimeMessage::Unknown {
+        header: GtpPrimeHeader {
+            msgtype: 0x7f,
+            length: 2,
+            sequence_number: 1,
+            ..Default::default()
+        },
+        body: vec![0x0e, 0x05],
+    };
+    assert_eq!(GtpPrimeMessage::parse(&encoded).unwrap(), decoded);
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
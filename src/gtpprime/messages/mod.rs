@@ -0,0 +1,17 @@
+pub use {
+    commons::*, datarecordtransferreq::*, datarecordtransferresp::*, echoreq::*, echoresp::*,
+    ies::*, message::*, nodealivereq::*, nodealiveresp::*, redirectionreq::*, redirectionresp::*,
+    versionnotsupported::*,
+};
+mod commons;
+mod datarecordtransferreq;
+mod datarecordtransferresp;
+mod echoreq;
+mod echoresp;
+mod ies;
+mod message;
+mod nodealivereq;
+mod nodealiveresp;
+mod redirectionreq;
+mod redirectionresp;
+mod versionnotsupported;
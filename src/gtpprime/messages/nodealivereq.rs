@@ -0,0 +1,152 @@
+use crate::gtpprime::errors::*;
+use crate::gtpprime::header::*;
+use crate::gtpprime::messages::commons::*;
+use crate::gtpprime::messages::ies::*;
+use crate::gtpprime::utils::*;
+
+// According to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+pub const NODE_ALIVE_REQUEST: u8 = 4;
+
+// Definition of GTP' Node Alive Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeAliveRequest {
+    pub header: GtpPrimeHeader,
+    pub node_address: NodeAddress,
+    pub alt_node_address: Option<NodeAddress>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for NodeAliveRequest {
+    fn default() -> NodeAliveRequest {
+        let hdr = GtpPrimeHeader {
+            msgtype: NODE_ALIVE_REQUEST,
+            ..Default::default()
+        };
+        NodeAliveRequest {
+            header: hdr,
+            node_address: NodeAddress {
+                t: CHARGING_GATEWAY_ADDRESS,
+                ..Default::default()
+            },
+            alt_node_address: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for NodeAliveRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.node_address.into()];
+        if let Some(i) = self.alt_node_address {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer, self.header.len());
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        let mut message = NodeAliveRequest {
+            header: GtpPrimeHeader::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != NODE_ALIVE_REQUEST {
+            return Err(GTPPrimeError::MessageIncorrectMessageType);
+        }
+
+        let mut node_address = None;
+        for ie in decode_message_body(&message.header, buffer, &[CHARGING_GATEWAY_ADDRESS])? {
+            match ie {
+                InformationElement::NodeAddress(i)
+                    if i.t == CHARGING_GATEWAY_ADDRESS && node_address.is_none() =>
+                {
+                    node_address = Some(i)
+                }
+                InformationElement::NodeAddress(i)
+                    if i.t == CHARGING_GATEWAY_ADDRESS && message.alt_node_address.is_none() =>
+                {
+                    message.alt_node_address = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match node_address {
+            Some(i) => {
+                message.node_address = i;
+                Ok(message)
+            }
+            None => Err(GTPPrimeError::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+const NODE_ALIVE_REQUEST_TEST: [u8; 20] = [
+    0x4e, 0x04, 0x00, 0x0e, 0x00, 0x02, 0xfb, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x01, 0xfb, 0x00, 0x04,
+    0x0a, 0x00, 0x00, 0x02,
+];
+
+#[cfg(test)]
+fn node_alive_req_test() -> NodeAliveRequest {
+    NodeAliveRequest {
+        header: GtpPrimeHeader {
+            msgtype: NODE_ALIVE_REQUEST,
+            length: 14,
+            sequence_number: 2,
+            ..Default::default()
+        },
+        node_address: NodeAddress {
+            t: CHARGING_GATEWAY_ADDRESS,
+            length: 4,
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 1)),
+        },
+        alt_node_address: Some(NodeAddress {
+            t: CHARGING_GATEWAY_ADDRESS,
+            length: 4,
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 2)),
+        }),
+        private_extension: None,
+    }
+}
+
+#[test]
+fn node_alive_req_unmarshal_test() {
+    assert_eq!(
+        NodeAliveRequest::unmarshal(&NODE_ALIVE_REQUEST_TEST).unwrap(),
+        node_alive_req_test()
+    );
+}
+
+#[test]
+fn node_alive_req_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    node_alive_req_test().marshal(&mut buffer);
+    assert_eq!(buffer, NODE_ALIVE_REQUEST_TEST);
+}
+
+#[test]
+fn node_alive_req_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 13] = [
+        0x4e, 0x04, 0x00, 0x07, 0x00, 0x02, 0xfe, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x01,
+    ];
+    assert_eq!(
+        NodeAliveRequest::unmarshal(&encoded),
+        Err(GTPPrimeError::MessageMandatoryIEMissing)
+    );
+}
@@ -0,0 +1,101 @@
+use crate::gtpprime::errors::*;
+use crate::gtpprime::header::*;
+use crate::gtpprime::messages::commons::*;
+use crate::gtpprime::messages::ies::*;
+use crate::gtpprime::utils::*;
+
+// According to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+pub const NODE_ALIVE_RESPONSE: u8 = 5;
+
+// Definition of GTP' Node Alive Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeAliveResponse {
+    pub header: GtpPrimeHeader,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for NodeAliveResponse {
+    fn default() -> NodeAliveResponse {
+        let hdr = GtpPrimeHeader {
+            msgtype: NODE_ALIVE_RESPONSE,
+            ..Default::default()
+        };
+        NodeAliveResponse {
+            header: hdr,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for NodeAliveResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![];
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer, self.header.len());
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        let mut message = NodeAliveResponse {
+            header: GtpPrimeHeader::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != NODE_ALIVE_RESPONSE {
+            return Err(GTPPrimeError::MessageIncorrectMessageType);
+        }
+
+        for ie in decode_message_body(&message.header, buffer, &[])? {
+            match ie {
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+const NODE_ALIVE_RESPONSE_TEST: [u8; 6] = [0x4e, 0x05, 0x00, 0x00, 0x00, 0x02];
+
+#[cfg(test)]
+fn node_alive_resp_test() -> NodeAliveResponse {
+    NodeAliveResponse {
+        header: GtpPrimeHeader {
+            msgtype: NODE_ALIVE_RESPONSE,
+            length: 0,
+            sequence_number: 2,
+            ..Default::default()
+        },
+        private_extension: None,
+    }
+}
+
+#[test]
+fn node_alive_resp_unmarshal_test() {
+    assert_eq!(
+        NodeAliveResponse::unmarshal(&NODE_ALIVE_RESPONSE_TEST).unwrap(),
+        node_alive_resp_test()
+    );
+}
+
+#[test]
+fn node_alive_resp_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    node_alive_resp_test().marshal(&mut buffer);
+    assert_eq!(buffer, NODE_ALIVE_RESPONSE_TEST);
+}
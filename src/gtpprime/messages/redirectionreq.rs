@@ -0,0 +1,155 @@
+use crate::gtpprime::errors::*;
+use crate::gtpprime::header::*;
+use crate::gtpprime::messages::commons::*;
+use crate::gtpprime::messages::ies::*;
+use crate::gtpprime::utils::*;
+
+// According to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+pub const REDIRECTION_REQUEST: u8 = 6;
+
+// Definition of GTP' Redirection Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedirectionRequest {
+    pub header: GtpPrimeHeader,
+    pub cause: Cause,
+    pub recommended_node: Option<NodeAddress>,
+    pub alt_recommended_node: Option<NodeAddress>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for RedirectionRequest {
+    fn default() -> RedirectionRequest {
+        let hdr = GtpPrimeHeader {
+            msgtype: REDIRECTION_REQUEST,
+            ..Default::default()
+        };
+        RedirectionRequest {
+            header: hdr,
+            cause: Cause::default(),
+            recommended_node: None,
+            alt_recommended_node: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for RedirectionRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        if let Some(i) = self.recommended_node {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.alt_recommended_node {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer, self.header.len());
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        let mut message = RedirectionRequest {
+            header: GtpPrimeHeader::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != REDIRECTION_REQUEST {
+            return Err(GTPPrimeError::MessageIncorrectMessageType);
+        }
+
+        let mut cause = None;
+        for ie in decode_message_body(&message.header, buffer, &[CAUSE])? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::NodeAddress(i)
+                    if i.t == RECOMMENDED_NODE_ADDRESS && message.recommended_node.is_none() =>
+                {
+                    message.recommended_node = Some(i)
+                }
+                InformationElement::NodeAddress(i)
+                    if i.t == RECOMMENDED_NODE_ADDRESS
+                        && message.alt_recommended_node.is_none() =>
+                {
+                    message.alt_recommended_node = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match cause {
+            Some(i) => {
+                message.cause = i;
+                Ok(message)
+            }
+            None => Err(GTPPrimeError::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+const REDIRECTION_REQUEST_TEST: [u8; 15] = [
+    0x4e, 0x06, 0x00, 0x09, 0x00, 0x03, 0x01, 0x3f, 0xfe, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x03,
+];
+
+#[cfg(test)]
+fn redirection_req_test() -> RedirectionRequest {
+    RedirectionRequest {
+        header: GtpPrimeHeader {
+            msgtype: REDIRECTION_REQUEST,
+            length: 9,
+            sequence_number: 3,
+            ..Default::default()
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: THIS_NODE_ABOUT_TO_GO_DOWN,
+        },
+        recommended_node: Some(NodeAddress {
+            t: RECOMMENDED_NODE_ADDRESS,
+            length: 4,
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 3)),
+        }),
+        alt_recommended_node: None,
+        private_extension: None,
+    }
+}
+
+#[test]
+fn redirection_req_unmarshal_test() {
+    assert_eq!(
+        RedirectionRequest::unmarshal(&REDIRECTION_REQUEST_TEST).unwrap(),
+        redirection_req_test()
+    );
+}
+
+#[test]
+fn redirection_req_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    redirection_req_test().marshal(&mut buffer);
+    assert_eq!(buffer, REDIRECTION_REQUEST_TEST);
+}
+
+#[test]
+fn redirection_req_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 13] = [
+        0x4e, 0x06, 0x00, 0x07, 0x00, 0x03, 0xfe, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x03,
+    ];
+    assert_eq!(
+        RedirectionRequest::unmarshal(&encoded),
+        Err(GTPPrimeError::MessageMandatoryIEMissing)
+    );
+}
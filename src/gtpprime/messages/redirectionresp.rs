@@ -0,0 +1,124 @@
+use crate::gtpprime::errors::*;
+use crate::gtpprime::header::*;
+use crate::gtpprime::messages::commons::*;
+use crate::gtpprime::messages::ies::*;
+use crate::gtpprime::utils::*;
+
+// According to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+pub const REDIRECTION_RESPONSE: u8 = 7;
+
+// Definition of GTP' Redirection Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedirectionResponse {
+    pub header: GtpPrimeHeader,
+    pub cause: Cause,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for RedirectionResponse {
+    fn default() -> RedirectionResponse {
+        let hdr = GtpPrimeHeader {
+            msgtype: REDIRECTION_RESPONSE,
+            ..Default::default()
+        };
+        RedirectionResponse {
+            header: hdr,
+            cause: Cause::default(),
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for RedirectionResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer, self.header.len());
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        let mut message = RedirectionResponse {
+            header: GtpPrimeHeader::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != REDIRECTION_RESPONSE {
+            return Err(GTPPrimeError::MessageIncorrectMessageType);
+        }
+
+        let mut cause = None;
+        for ie in decode_message_body(&message.header, buffer, &[CAUSE])? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match cause {
+            Some(i) => {
+                message.cause = i;
+                Ok(message)
+            }
+            None => Err(GTPPrimeError::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+const REDIRECTION_RESPONSE_TEST: [u8; 8] = [0x4e, 0x07, 0x00, 0x02, 0x00, 0x03, 0x01, 0x80];
+
+#[cfg(test)]
+fn redirection_resp_test() -> RedirectionResponse {
+    RedirectionResponse {
+        header: GtpPrimeHeader {
+            msgtype: REDIRECTION_RESPONSE,
+            length: 2,
+            sequence_number: 3,
+            ..Default::default()
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: REQUEST_ACCEPTED,
+        },
+        private_extension: None,
+    }
+}
+
+#[test]
+fn redirection_resp_unmarshal_test() {
+    assert_eq!(
+        RedirectionResponse::unmarshal(&REDIRECTION_RESPONSE_TEST).unwrap(),
+        redirection_resp_test()
+    );
+}
+
+#[test]
+fn redirection_resp_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    redirection_resp_test().marshal(&mut buffer);
+    assert_eq!(buffer, REDIRECTION_RESPONSE_TEST);
+}
+
+#[test]
+fn redirection_resp_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 6] = [0x4e, 0x07, 0x00, 0x00, 0x00, 0x03];
+    assert_eq!(
+        RedirectionResponse::unmarshal(&encoded),
+        Err(GTPPrimeError::MessageMandatoryIEMissing)
+    );
+}
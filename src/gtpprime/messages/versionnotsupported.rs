@@ -0,0 +1,91 @@
+use crate::gtpprime::errors::*;
+use crate::gtpprime::header::*;
+use crate::gtpprime::messages::commons::*;
+use crate::gtpprime::utils::*;
+
+// According to 3GPP TS 32.295 V17.0.0 (2022-04)
+
+pub const VERSION_NOT_SUPPORTED: u8 = 3;
+
+// Definition of GTP' Version Not Supported
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionNotSupported {
+    pub header: GtpPrimeHeader,
+}
+
+impl Default for VersionNotSupported {
+    fn default() -> VersionNotSupported {
+        let hdr = GtpPrimeHeader {
+            msgtype: VERSION_NOT_SUPPORTED,
+            ..Default::default()
+        };
+        VersionNotSupported { header: hdr }
+    }
+}
+
+impl Messages for VersionNotSupported {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        set_length(buffer, self.header.len());
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPPrimeError> {
+        let message = VersionNotSupported {
+            header: GtpPrimeHeader::unmarshal(buffer)?,
+        };
+
+        if message.header.msgtype != VERSION_NOT_SUPPORTED {
+            return Err(GTPPrimeError::MessageIncorrectMessageType);
+        }
+
+        decode_message_body(&message.header, buffer, &[])?;
+
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+const VERSION_NOT_SUPPORTED_TEST: [u8; 6] = [0x4e, 0x03, 0x00, 0x00, 0x00, 0x01];
+
+#[cfg(test)]
+fn version_not_supported_test() -> VersionNotSupported {
+    VersionNotSupported {
+        header: GtpPrimeHeader {
+            msgtype: VERSION_NOT_SUPPORTED,
+            length: 0,
+            sequence_number: 1,
+            ..Default::default()
+        },
+    }
+}
+
+#[test]
+fn version_not_supported_unmarshal_test() {
+    assert_eq!(
+        VersionNotSupported::unmarshal(&VERSION_NOT_SUPPORTED_TEST).unwrap(),
+        version_not_supported_test()
+    );
+}
+
+#[test]
+fn version_not_supported_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    version_not_supported_test().marshal(&mut buffer);
+    assert_eq!(buffer, VERSION_NOT_SUPPORTED_TEST);
+}
+
+#[test]
+fn version_not_supported_v0_long_header_test() {
+    let mut encoded: Vec<u8> = vec![0x0e, 0x03, 0x00, 0x00, 0x00, 0x01];
+    encoded.extend_from_slice(&[0xff; 14]);
+    let decoded = VersionNotSupported::unmarshal(&encoded).unwrap();
+    assert_eq!(decoded.header.version, 0);
+    assert!(decoded.header.long_header);
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
@@ -0,0 +1,4 @@
+pub mod errors;
+pub mod header;
+pub mod messages;
+pub mod utils;
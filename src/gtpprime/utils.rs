@@ -0,0 +1,21 @@
+// Set the right size of GTP' message based on buffer size, the length excludes the header
+
+pub fn set_length(buffer: &mut [u8], header_length: usize) {
+    let size = ((buffer.len() - header_length) as u16).to_be_bytes();
+    buffer[2] = size[0];
+    buffer[3] = size[1];
+}
+
+// Set the right size of IE based on buffer size
+
+pub fn set_tlv_ie_length(buffer: &mut [u8]) {
+    let size = ((buffer.len() - 3) as u16).to_be_bytes();
+    buffer[1] = size[0];
+    buffer[2] = size[1];
+}
+
+// Check TLV IE length vs buffer size
+
+pub fn check_tlv_ie_buffer(length: u16, buffer: &[u8]) -> bool {
+    (length as usize + 3) <= buffer.len()
+}
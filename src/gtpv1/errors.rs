@@ -1,6 +1,7 @@
+use crate::commons::{DecodeError, DecodeErrorReason};
 use std::fmt::Display;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 
 pub enum GTPV1Error {
     // GTPv1 Header Errors
@@ -27,6 +28,23 @@ pub enum GTPV1Error {
 
 impl std::error::Error for GTPV1Error {}
 
+// GTPv1 decoding error with the location of the faulty IE
+
+pub type GTPV1DecodeError = DecodeError<GTPV1Error>;
+
+impl GTPV1Error {
+    pub fn reason(&self) -> DecodeErrorReason {
+        match self {
+            GTPV1Error::HeaderInvalidLength
+            | GTPV1Error::ExtHeaderInvalidLength
+            | GTPV1Error::MessageLengthError => DecodeErrorReason::Truncated,
+            GTPV1Error::IEInvalidLength => DecodeErrorReason::InvalidLength,
+            GTPV1Error::MessageMandatoryIEMissing => DecodeErrorReason::MandatoryIEMissing,
+            _ => DecodeErrorReason::InvalidValue,
+        }
+    }
+}
+
 impl Display for GTPV1Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1,4 +1,7 @@
+use crate::commons::{DecodeErrorReason, IePathElement};
 use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::ies::*;
 
 // Common traits of GTPv1 Messages
 
@@ -9,3 +12,112 @@ pub trait Messages {
         Self: Sized;
     //fn len (&self) -> usize;
 }
+
+// Decode the IEs of a message body with the generic IE decoder.
+// IEs shall be sent in increasing order of their type, unknown IEs are kept for the caller to ignore.
+// An IE failing to decode aborts the decoding with the error of the IE
+
+pub fn decode_message_body(
+    header: &Gtpv1Header,
+    buffer: &[u8],
+) -> Result<Vec<InformationElement>, GTPV1Error> {
+    let end = header.length as usize + MIN_HEADER_LENGTH;
+    if end > buffer.len() || header.len() > end {
+        return Err(GTPV1Error::MessageLengthError);
+    }
+    let ies = InformationElement::decoder(&buffer[header.len()..end])?;
+    let mut cursor = header.len();
+    let mut increment: u8 = 0;
+    for ie in ies.iter() {
+        if buffer[cursor] < increment {
+            return Err(GTPV1Error::MessageInvalidMessageFormat);
+        }
+        increment = buffer[cursor];
+        cursor += ie.len();
+    }
+    Ok(ies)
+}
+
+// List the types of the top level IEs of a message, in the order they are sent.
+// The walk stops at the first IE which cannot be framed, decoding of the IE values is left to the message
+
+pub fn message_ie_types(buffer: &[u8]) -> Vec<u8> {
+    let mut types: Vec<u8> = vec![];
+    let header = match Gtpv1Header::unmarshal(buffer) {
+        Ok(i) => i,
+        Err(_) => return types,
+    };
+    let end = (header.length as usize + MIN_HEADER_LENGTH).min(buffer.len());
+    let mut cursor = header.len();
+    while cursor < end {
+        let t = buffer[cursor];
+        cursor = match tv_ie_value_length(t) {
+            Some(i) => cursor + 1 + i,
+            None if t >= TLV_IE_TYPE_MIN && cursor + 3 <= end => {
+                cursor + 3 + u16::from_be_bytes([buffer[cursor + 1], buffer[cursor + 2]]) as usize
+            }
+            None => break,
+        };
+        types.push(t);
+    }
+    types
+}
+
+// Locate the faulty IE of a message which failed to decode with the given error.
+// The message is walked IE by IE, so the error is reported with the byte offset and type
+// of the first IE which does not decode
+
+pub fn locate_decode_error(buffer: &[u8], error: GTPV1Error) -> GTPV1DecodeError {
+    let mut data = GTPV1DecodeError {
+        msgtype: buffer.get(1).copied().unwrap_or_default(),
+        offset: 0,
+        path: vec![],
+        reason: error.reason(),
+        error,
+    };
+    let header = match Gtpv1Header::unmarshal(buffer) {
+        Ok(i) => i,
+        Err(_) => return data,
+    };
+    let end = header.length as usize + MIN_HEADER_LENGTH;
+    if end > buffer.len() || header.len() > end {
+        data.offset = buffer.len().min(end);
+        data.reason = DecodeErrorReason::Truncated;
+        return data;
+    }
+    let mut seen: Vec<u8> = vec![];
+    let mut cursor = header.len();
+    while cursor < end {
+        let t = buffer[cursor];
+        let index = seen.iter().filter(|x| **x == t).count();
+        seen.push(t);
+        data.path = vec![IePathElement { t, ins: 0, index }];
+        data.offset = cursor;
+        let ie_end = match tv_ie_value_length(t) {
+            Some(i) => cursor + 1 + i,
+            None if t >= 128 && cursor + 3 <= end => {
+                cursor + 3 + u16::from_be_bytes([buffer[cursor + 1], buffer[cursor + 2]]) as usize
+            }
+            None if t >= 128 => end + 1,
+            None => {
+                data.reason = DecodeErrorReason::InvalidValue;
+                data.error = GTPV1Error::IEIncorrect;
+                return data;
+            }
+        };
+        if ie_end > end {
+            data.reason = DecodeErrorReason::Truncated;
+            data.error = GTPV1Error::IEInvalidLength;
+            return data;
+        }
+        if let Err(i) = InformationElement::decoder(&buffer[cursor..ie_end]) {
+            data.reason = i.reason();
+            data.error = i;
+            return data;
+        }
+        cursor = ie_end;
+    }
+    data.path = vec![];
+    data.offset = end;
+    data
+}
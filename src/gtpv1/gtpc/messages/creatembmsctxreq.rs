@@ -0,0 +1,321 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const CREATE_MBMS_CONTEXT_REQUEST: u8 = 100;
+
+// Definition of GTPv1-C Create MBMS Context Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateMBMSContextRequest {
+    pub header: Gtpv1Header,
+    pub imsi: Option<Imsi>,
+    pub rai: Rai,
+    pub recovery: Option<Recovery>,
+    pub selection_mode: Option<SelectionMode>,
+    pub teid_control: Option<Teid>,
+    pub trace_ref: Option<TraceReference>,
+    pub trace_type: Option<TraceType>,
+    pub end_user_address: EndUserAddress,
+    pub apn: Apn,
+    pub sgsn_ip_control: GsnAddress,
+    pub msisdn: Option<Msisdn>,
+    pub trigger_id: Option<TriggerId>,
+    pub omc_id: Option<OmcId>,
+    pub rat_type: Option<RatType>,
+    pub uli: Option<Uli>,
+    pub ms_timezone: Option<MsTimeZone>,
+    pub imei: Option<Imei>,
+    pub mbms_pco: Option<MbmsPco>,
+    pub additional_trace_info: Option<AdditionalTraceInfo>,
+    pub enhanced_nsapi: EnhancedNsapi,
+    pub additional_mbms_trace_info: Option<AdditionalMbmsTraceInfo>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for CreateMBMSContextRequest {
+    fn default() -> CreateMBMSContextRequest {
+        let hdr = Gtpv1Header {
+            msgtype: CREATE_MBMS_CONTEXT_REQUEST,
+            ..Default::default()
+        };
+        CreateMBMSContextRequest {
+            header: hdr,
+            imsi: None,
+            rai: Rai::default(),
+            recovery: None,
+            selection_mode: None,
+            teid_control: None,
+            trace_ref: None,
+            trace_type: None,
+            end_user_address: EndUserAddress::default(),
+            apn: Apn::default(),
+            sgsn_ip_control: GsnAddress::default(),
+            msisdn: None,
+            trigger_id: None,
+            omc_id: None,
+            rat_type: None,
+            uli: None,
+            ms_timezone: None,
+            imei: None,
+            mbms_pco: None,
+            additional_trace_info: None,
+            enhanced_nsapi: EnhancedNsapi::default(),
+            additional_mbms_trace_info: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for CreateMBMSContextRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![];
+        if let Some(i) = self.imsi {
+            elements.push(i.into());
+        }
+        elements.push(self.rai.into());
+        if let Some(i) = self.recovery {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.selection_mode {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.teid_control {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.trace_ref {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.trace_type {
+            elements.push(i.into());
+        }
+        elements.push(self.end_user_address.into());
+        elements.push(self.apn.into());
+        elements.push(self.sgsn_ip_control.into());
+        if let Some(i) = self.msisdn {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.trigger_id {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.omc_id {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.rat_type {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.uli {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.ms_timezone {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.imei {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.mbms_pco {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.additional_trace_info {
+            elements.push(i.into());
+        }
+        elements.push(self.enhanced_nsapi.into());
+        if let Some(i) = self.additional_mbms_trace_info {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = CreateMBMSContextRequest {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != CREATE_MBMS_CONTEXT_REQUEST {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let (mut rai, mut end_user_address, mut apn, mut sgsn_ip_control, mut enhanced_nsapi) =
+            (None, None, None, None, None);
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Imsi(i) if message.imsi.is_none() => message.imsi = Some(i),
+                InformationElement::Rai(i) if rai.is_none() => rai = Some(i),
+                InformationElement::Recovery(i) if message.recovery.is_none() => {
+                    message.recovery = Some(i)
+                }
+                InformationElement::SelectionMode(i) if message.selection_mode.is_none() => {
+                    message.selection_mode = Some(i)
+                }
+                InformationElement::Teid(i)
+                    if i.t == TEID_CONTROL && message.teid_control.is_none() =>
+                {
+                    message.teid_control = Some(i)
+                }
+                InformationElement::TraceReference(i) if message.trace_ref.is_none() => {
+                    message.trace_ref = Some(i)
+                }
+                InformationElement::TraceType(i) if message.trace_type.is_none() => {
+                    message.trace_type = Some(i)
+                }
+                InformationElement::EndUserAddress(i) if end_user_address.is_none() => {
+                    end_user_address = Some(i)
+                }
+                InformationElement::Apn(i) if apn.is_none() => apn = Some(i),
+                InformationElement::GsnAddress(i) if sgsn_ip_control.is_none() => {
+                    sgsn_ip_control = Some(i)
+                }
+                InformationElement::Msisdn(i) if message.msisdn.is_none() => {
+                    message.msisdn = Some(i)
+                }
+                InformationElement::TriggerId(i) if message.trigger_id.is_none() => {
+                    message.trigger_id = Some(i)
+                }
+                InformationElement::OmcId(i) if message.omc_id.is_none() => {
+                    message.omc_id = Some(i)
+                }
+                InformationElement::RatType(i) if message.rat_type.is_none() => {
+                    message.rat_type = Some(i)
+                }
+                InformationElement::Uli(i) if message.uli.is_none() => message.uli = Some(i),
+                InformationElement::MsTimeZone(i) if message.ms_timezone.is_none() => {
+                    message.ms_timezone = Some(i)
+                }
+                InformationElement::Imei(i) if message.imei.is_none() => message.imei = Some(i),
+                InformationElement::MbmsPco(i) if message.mbms_pco.is_none() => {
+                    message.mbms_pco = Some(i)
+                }
+                InformationElement::AdditionalTraceInfo(i)
+                    if message.additional_trace_info.is_none() =>
+                {
+                    message.additional_trace_info = Some(i)
+                }
+                InformationElement::EnhancedNsapi(i) if enhanced_nsapi.is_none() => {
+                    enhanced_nsapi = Some(i)
+                }
+                InformationElement::AdditionalMbmsTraceInfo(i)
+                    if message.additional_mbms_trace_info.is_none() =>
+                {
+                    message.additional_mbms_trace_info = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match (rai, end_user_address, apn, sgsn_ip_control, enhanced_nsapi) {
+            (
+                Some(rai),
+                Some(end_user_address),
+                Some(apn),
+                Some(sgsn_ip_control),
+                Some(enhanced_nsapi),
+            ) => {
+                message.rai = rai;
+                message.end_user_address = end_user_address;
+                message.apn = apn;
+                message.sgsn_ip_control = sgsn_ip_control;
+                message.enhanced_nsapi = enhanced_nsapi;
+                Ok(message)
+            }
+            _ => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+fn create_mbms_ctx_req_test() -> CreateMBMSContextRequest {
+    CreateMBMSContextRequest {
+        header: Gtpv1Header {
+            msgtype: CREATE_MBMS_CONTEXT_REQUEST,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            ..Default::default()
+        },
+        imsi: Some(Imsi {
+            imsi: "262010123456789".to_string(),
+            ..Default::default()
+        }),
+        rai: Rai {
+            mcc: 262,
+            mnc: 1,
+            lac: 1,
+            rac: 5,
+            ..Default::default()
+        },
+        recovery: Some(Recovery {
+            t: RECOVERY,
+            value: 7,
+        }),
+        teid_control: Some(Teid {
+            t: TEID_CONTROL,
+            teid: 0x2002,
+        }),
+        end_user_address: EndUserAddress {
+            length: 6,
+            ipv4: Some(std::net::Ipv4Addr::new(239, 0, 0, 1)),
+            ..Default::default()
+        },
+        apn: Apn {
+            length: 17,
+            name: "mbms.example.com".to_string(),
+            ..Default::default()
+        },
+        sgsn_ip_control: GsnAddress {
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 2)),
+            ..Default::default()
+        },
+        mbms_pco: Some(MbmsPco {
+            length: 3,
+            pco: vec![0x80, 0x00, 0x00],
+            ..Default::default()
+        }),
+        enhanced_nsapi: EnhancedNsapi {
+            value: 0x80,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn create_mbms_ctx_req_marshal_unmarshal_test() {
+    let mut message = create_mbms_ctx_req_test();
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - 8) as u16;
+    assert_eq!(&buffer[..2], &[0x32, 0x64]);
+    assert_eq!(
+        CreateMBMSContextRequest::unmarshal(&buffer).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn create_mbms_ctx_req_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x64, 0x00, 0x04, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        CreateMBMSContextRequest::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
@@ -0,0 +1,202 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const CREATE_MBMS_CONTEXT_RESPONSE: u8 = 101;
+
+// Definition of GTPv1-C Create MBMS Context Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateMBMSContextResponse {
+    pub header: Gtpv1Header,
+    pub cause: Cause,
+    pub recovery: Option<Recovery>,
+    pub teid_control: Option<Teid>,
+    pub ggsn_ip_control: Option<GsnAddress>,
+    pub alt_ggsn_ip_control: Option<GsnAddress>,
+    pub mbms_pco: Option<MbmsPco>,
+    pub charging_gw_addr: Option<ChargingGWAddress>,
+    pub alt_charging_gw_addr: Option<ChargingGWAddress>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for CreateMBMSContextResponse {
+    fn default() -> CreateMBMSContextResponse {
+        let hdr = Gtpv1Header {
+            msgtype: CREATE_MBMS_CONTEXT_RESPONSE,
+            ..Default::default()
+        };
+        CreateMBMSContextResponse {
+            header: hdr,
+            cause: Cause::default(),
+            recovery: None,
+            teid_control: None,
+            ggsn_ip_control: None,
+            alt_ggsn_ip_control: None,
+            mbms_pco: None,
+            charging_gw_addr: None,
+            alt_charging_gw_addr: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for CreateMBMSContextResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        if let Some(i) = self.recovery {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.teid_control {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.ggsn_ip_control {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.alt_ggsn_ip_control {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.mbms_pco {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.charging_gw_addr {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.alt_charging_gw_addr {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = CreateMBMSContextResponse {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != CREATE_MBMS_CONTEXT_RESPONSE {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let mut cause = None;
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::Recovery(i) if message.recovery.is_none() => {
+                    message.recovery = Some(i)
+                }
+                InformationElement::Teid(i)
+                    if i.t == TEID_CONTROL && message.teid_control.is_none() =>
+                {
+                    message.teid_control = Some(i)
+                }
+                InformationElement::GsnAddress(i) if message.ggsn_ip_control.is_none() => {
+                    message.ggsn_ip_control = Some(i)
+                }
+                InformationElement::GsnAddress(i) if message.alt_ggsn_ip_control.is_none() => {
+                    message.alt_ggsn_ip_control = Some(i)
+                }
+                InformationElement::MbmsPco(i) if message.mbms_pco.is_none() => {
+                    message.mbms_pco = Some(i)
+                }
+                InformationElement::ChargingGWAddress(i) if message.charging_gw_addr.is_none() => {
+                    message.charging_gw_addr = Some(i)
+                }
+                InformationElement::ChargingGWAddress(i)
+                    if message.alt_charging_gw_addr.is_none() =>
+                {
+                    message.alt_charging_gw_addr = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match cause {
+            Some(i) => {
+                message.cause = i;
+                Ok(message)
+            }
+            None => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+fn create_mbms_ctx_resp_test() -> CreateMBMSContextResponse {
+    CreateMBMSContextResponse {
+        header: Gtpv1Header {
+            msgtype: CREATE_MBMS_CONTEXT_RESPONSE,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            ..Default::default()
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        recovery: Some(Recovery {
+            t: RECOVERY,
+            value: 7,
+        }),
+        teid_control: Some(Teid {
+            t: TEID_CONTROL,
+            teid: 0x2002,
+        }),
+        ggsn_ip_control: Some(GsnAddress {
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 1)),
+            ..Default::default()
+        }),
+        alt_ggsn_ip_control: Some(GsnAddress {
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 1, 1)),
+            ..Default::default()
+        }),
+        mbms_pco: Some(MbmsPco {
+            length: 3,
+            pco: vec![0x80, 0x00, 0x00],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn create_mbms_ctx_resp_marshal_unmarshal_test() {
+    let mut message = create_mbms_ctx_resp_test();
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - 8) as u16;
+    assert_eq!(&buffer[..2], &[0x32, 0x65]);
+    assert_eq!(
+        CreateMBMSContextResponse::unmarshal(&buffer).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn create_mbms_ctx_resp_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x65, 0x00, 0x04, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        CreateMBMSContextResponse::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
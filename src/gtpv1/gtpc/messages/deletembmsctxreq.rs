@@ -0,0 +1,151 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const DELETE_MBMS_CONTEXT_REQUEST: u8 = 104;
+
+// Definition of GTPv1-C Delete MBMS Context Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteMBMSContextRequest {
+    pub header: Gtpv1Header,
+    pub imsi: Option<Imsi>,
+    pub teid_control: Option<Teid>,
+    pub end_user_address: Option<EndUserAddress>,
+    pub apn: Option<Apn>,
+    pub mbms_pco: Option<MbmsPco>,
+    pub enhanced_nsapi: Option<EnhancedNsapi>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for DeleteMBMSContextRequest {
+    fn default() -> DeleteMBMSContextRequest {
+        let hdr = Gtpv1Header {
+            msgtype: DELETE_MBMS_CONTEXT_REQUEST,
+            ..Default::default()
+        };
+        DeleteMBMSContextRequest {
+            header: hdr,
+            imsi: None,
+            teid_control: None,
+            end_user_address: None,
+            apn: None,
+            mbms_pco: None,
+            enhanced_nsapi: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for DeleteMBMSContextRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![];
+        if let Some(i) = self.imsi {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.teid_control {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.end_user_address {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.apn {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.mbms_pco {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.enhanced_nsapi {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = DeleteMBMSContextRequest {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != DELETE_MBMS_CONTEXT_REQUEST {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Imsi(i) if message.imsi.is_none() => message.imsi = Some(i),
+                InformationElement::Teid(i)
+                    if i.t == TEID_CONTROL && message.teid_control.is_none() =>
+                {
+                    message.teid_control = Some(i)
+                }
+                InformationElement::EndUserAddress(i) if message.end_user_address.is_none() => {
+                    message.end_user_address = Some(i)
+                }
+                InformationElement::Apn(i) if message.apn.is_none() => message.apn = Some(i),
+                InformationElement::MbmsPco(i) if message.mbms_pco.is_none() => {
+                    message.mbms_pco = Some(i)
+                }
+                InformationElement::EnhancedNsapi(i) if message.enhanced_nsapi.is_none() => {
+                    message.enhanced_nsapi = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+fn delete_mbms_ctx_req_test() -> DeleteMBMSContextRequest {
+    DeleteMBMSContextRequest {
+        header: Gtpv1Header {
+            msgtype: DELETE_MBMS_CONTEXT_REQUEST,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            ..Default::default()
+        },
+        teid_control: Some(Teid {
+            t: TEID_CONTROL,
+            teid: 0x2002,
+        }),
+        mbms_pco: Some(MbmsPco {
+            length: 3,
+            pco: vec![0x80, 0x00, 0x00],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn delete_mbms_ctx_req_marshal_unmarshal_test() {
+    let mut message = delete_mbms_ctx_req_test();
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - 8) as u16;
+    assert_eq!(&buffer[..2], &[0x32, 0x68]);
+    assert_eq!(
+        DeleteMBMSContextRequest::unmarshal(&buffer).unwrap(),
+        message
+    );
+}
@@ -0,0 +1,134 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const DELETE_MBMS_CONTEXT_RESPONSE: u8 = 105;
+
+// Definition of GTPv1-C Delete MBMS Context Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteMBMSContextResponse {
+    pub header: Gtpv1Header,
+    pub cause: Cause,
+    pub mbms_pco: Option<MbmsPco>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for DeleteMBMSContextResponse {
+    fn default() -> DeleteMBMSContextResponse {
+        let hdr = Gtpv1Header {
+            msgtype: DELETE_MBMS_CONTEXT_RESPONSE,
+            ..Default::default()
+        };
+        DeleteMBMSContextResponse {
+            header: hdr,
+            cause: Cause::default(),
+            mbms_pco: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for DeleteMBMSContextResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        if let Some(i) = self.mbms_pco {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = DeleteMBMSContextResponse {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != DELETE_MBMS_CONTEXT_RESPONSE {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let mut cause = None;
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::MbmsPco(i) if message.mbms_pco.is_none() => {
+                    message.mbms_pco = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match cause {
+            Some(i) => {
+                message.cause = i;
+                Ok(message)
+            }
+            None => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+fn delete_mbms_ctx_resp_test() -> DeleteMBMSContextResponse {
+    DeleteMBMSContextResponse {
+        header: Gtpv1Header {
+            msgtype: DELETE_MBMS_CONTEXT_RESPONSE,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            ..Default::default()
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        mbms_pco: Some(MbmsPco {
+            length: 3,
+            pco: vec![0x80, 0x00, 0x00],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn delete_mbms_ctx_resp_marshal_unmarshal_test() {
+    let mut message = delete_mbms_ctx_resp_test();
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - 8) as u16;
+    assert_eq!(&buffer[..2], &[0x32, 0x69]);
+    assert_eq!(
+        DeleteMBMSContextResponse::unmarshal(&buffer).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn delete_mbms_ctx_resp_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x69, 0x00, 0x04, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        DeleteMBMSContextResponse::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
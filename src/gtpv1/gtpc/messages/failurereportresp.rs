@@ -0,0 +1,157 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const FAILURE_REPORT_RESPONSE: u8 = 35;
+
+// Definition of GTPv1-C Failure Report Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailureReportResponse {
+    pub header: Gtpv1Header,
+    pub cause: Cause,
+    pub map_cause: Option<MapCause>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for FailureReportResponse {
+    fn default() -> FailureReportResponse {
+        let hdr = Gtpv1Header {
+            msgtype: FAILURE_REPORT_RESPONSE,
+            ..Default::default()
+        };
+        FailureReportResponse {
+            header: hdr,
+            cause: Cause::default(),
+            map_cause: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for FailureReportResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        if let Some(i) = self.map_cause {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = FailureReportResponse {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != FAILURE_REPORT_RESPONSE {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let mut cause = None;
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::MapCause(i) if message.map_cause.is_none() => {
+                    message.map_cause = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match cause {
+            Some(i) => {
+                message.cause = i;
+                Ok(message)
+            }
+            None => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[test]
+fn failure_report_resp_unmarshal_test() {
+    let encoded: [u8; 16] = [
+        0x32, 0x23, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x01, 0x80, 0x0b,
+        0x22,
+    ];
+    let decoded = FailureReportResponse {
+        header: Gtpv1Header {
+            msgtype: FAILURE_REPORT_RESPONSE,
+            length: 8,
+            teid: 0,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        map_cause: Some(MapCause {
+            t: MAP_CAUSE,
+            value: 34,
+        }),
+        private_extension: None,
+    };
+    assert_eq!(FailureReportResponse::unmarshal(&encoded).unwrap(), decoded);
+}
+
+#[test]
+fn failure_report_resp_marshal_test() {
+    let encoded: [u8; 16] = [
+        0x32, 0x23, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x01, 0x80, 0x0b,
+        0x22,
+    ];
+    let decoded = FailureReportResponse {
+        header: Gtpv1Header {
+            msgtype: FAILURE_REPORT_RESPONSE,
+            length: 8,
+            teid: 0,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        map_cause: Some(MapCause {
+            t: MAP_CAUSE,
+            value: 34,
+        }),
+        private_extension: None,
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn failure_report_resp_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 14] = [
+        0x32, 0x23, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x0b, 0x22,
+    ];
+    assert_eq!(
+        FailureReportResponse::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
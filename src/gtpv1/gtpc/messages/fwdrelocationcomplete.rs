@@ -0,0 +1,97 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const FORWARD_RELOCATION_COMPLETE: u8 = 55;
+
+// Definition of GTPv1-C Forward Relocation Complete
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwardRelocationComplete {
+    pub header: Gtpv1Header,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for ForwardRelocationComplete {
+    fn default() -> ForwardRelocationComplete {
+        let hdr = Gtpv1Header {
+            msgtype: FORWARD_RELOCATION_COMPLETE,
+            ..Default::default()
+        };
+        ForwardRelocationComplete {
+            header: hdr,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for ForwardRelocationComplete {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal Private Extension IE
+
+        if let Some(i) = self.private_extension {
+            i.marshal(buffer)
+        };
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = ForwardRelocationComplete {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != FORWARD_RELOCATION_COMPLETE {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        for ie in decode_message_body(&message.header, buffer)? {
+            if let InformationElement::PrivateExtension(i) = ie {
+                if message.private_extension.is_none() {
+                    message.private_extension = Some(i)
+                }
+            }
+        }
+        Ok(message)
+    }
+}
+
+#[test]
+fn forward_relocation_complete_unmarshal_test() {
+    let encoded: [u8; 18] = [
+        0x32, 0x37, 0x00, 0x0a, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00, 0xff, 0x00, 0x03,
+        0x00, 0x0a, 0xff,
+    ];
+    let decoded = ForwardRelocationComplete {
+        header: Gtpv1Header {
+            msgtype: FORWARD_RELOCATION_COMPLETE,
+            length: 10,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        private_extension: Some(PrivateExtension {
+            t: PRIVATE_EXTENSION,
+            length: 3,
+            extension_id: 10,
+            extension_value: vec![0xff],
+        }),
+    };
+    assert_eq!(
+        ForwardRelocationComplete::unmarshal(&encoded).unwrap(),
+        decoded
+    );
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
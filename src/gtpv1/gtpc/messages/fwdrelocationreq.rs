@@ -0,0 +1,271 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const FORWARD_RELOCATION_REQUEST: u8 = 53;
+
+// Definition of GTPv1-C Forward Relocation Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwardRelocationRequest {
+    pub header: Gtpv1Header,
+    pub imsi: Option<Imsi>,
+    pub teid_control: Teid,
+    pub ranap_cause: RanapCause,
+    pub packet_flow_ids: Vec<PacketFlowId>,
+    pub charging_char: Option<ChargingCharacteristics>,
+    pub mm_context: MmContext,
+    pub pdp_contexts: Vec<PdpContext>,
+    pub sgsn_ip_control: GsnAddress,
+    pub target_id: Option<TargetIdentification>,
+    pub utran_container: Option<UtranTransparentContainer>,
+    pub mbms_ue_contexts: Vec<MbmsUeContext>,
+    pub bss_container: Option<BssContainer>,
+    pub direct_tunnel_flags: Option<DirectTunnelFlags>,
+    pub ext_common_flags: Option<ExtendedCommonFlags>,
+    pub ext_common_flags_ii: Option<ExtendedCommonFlagsII>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for ForwardRelocationRequest {
+    fn default() -> ForwardRelocationRequest {
+        let hdr = Gtpv1Header {
+            msgtype: FORWARD_RELOCATION_REQUEST,
+            ..Default::default()
+        };
+        ForwardRelocationRequest {
+            header: hdr,
+            imsi: None,
+            teid_control: Teid {
+                t: TEID_CONTROL,
+                ..Default::default()
+            },
+            ranap_cause: RanapCause::default(),
+            packet_flow_ids: vec![],
+            charging_char: None,
+            mm_context: MmContext::default(),
+            pdp_contexts: vec![],
+            sgsn_ip_control: GsnAddress::default(),
+            target_id: None,
+            utran_container: None,
+            mbms_ue_contexts: vec![],
+            bss_container: None,
+            direct_tunnel_flags: None,
+            ext_common_flags: None,
+            ext_common_flags_ii: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for ForwardRelocationRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![];
+        if let Some(i) = self.imsi {
+            elements.push(i.into());
+        }
+        elements.push(self.teid_control.into());
+        elements.push(self.ranap_cause.into());
+        elements.extend(self.packet_flow_ids.into_iter().map(|x| x.into()));
+        if let Some(i) = self.charging_char {
+            elements.push(i.into());
+        }
+        elements.push(self.mm_context.into());
+        elements.extend(self.pdp_contexts.into_iter().map(|x| x.into()));
+        elements.push(self.sgsn_ip_control.into());
+        if let Some(i) = self.target_id {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.utran_container {
+            elements.push(i.into());
+        }
+        elements.extend(self.mbms_ue_contexts.into_iter().map(|x| x.into()));
+        if let Some(i) = self.bss_container {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.direct_tunnel_flags {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.ext_common_flags {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.ext_common_flags_ii {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = ForwardRelocationRequest {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != FORWARD_RELOCATION_REQUEST {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let (mut teid_control, mut ranap_cause, mut mm_context, mut sgsn_ip_control) =
+            (None, None, None, None);
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Imsi(i) if message.imsi.is_none() => message.imsi = Some(i),
+                InformationElement::Teid(i) if i.t == TEID_CONTROL && teid_control.is_none() => {
+                    teid_control = Some(i)
+                }
+                InformationElement::RanapCause(i) if ranap_cause.is_none() => ranap_cause = Some(i),
+                InformationElement::PacketFlowId(i) => message.packet_flow_ids.push(i),
+                InformationElement::ChargingCharacteristics(i)
+                    if message.charging_char.is_none() =>
+                {
+                    message.charging_char = Some(i)
+                }
+                InformationElement::MmContext(i) if mm_context.is_none() => mm_context = Some(i),
+                InformationElement::PdpContext(i) => message.pdp_contexts.push(i),
+                InformationElement::GsnAddress(i) if sgsn_ip_control.is_none() => {
+                    sgsn_ip_control = Some(i)
+                }
+                InformationElement::TargetIdentification(i) if message.target_id.is_none() => {
+                    message.target_id = Some(i)
+                }
+                InformationElement::UtranTransparentContainer(i)
+                    if message.utran_container.is_none() =>
+                {
+                    message.utran_container = Some(i)
+                }
+                InformationElement::MbmsUeContext(i) => message.mbms_ue_contexts.push(i),
+                InformationElement::BssContainer(i) if message.bss_container.is_none() => {
+                    message.bss_container = Some(i)
+                }
+                InformationElement::DirectTunnelFlags(i)
+                    if message.direct_tunnel_flags.is_none() =>
+                {
+                    message.direct_tunnel_flags = Some(i)
+                }
+                InformationElement::ExtendedCommonFlags(i)
+                    if message.ext_common_flags.is_none() =>
+                {
+                    message.ext_common_flags = Some(i)
+                }
+                InformationElement::ExtendedCommonFlagsII(i)
+                    if message.ext_common_flags_ii.is_none() =>
+                {
+                    message.ext_common_flags_ii = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match (teid_control, ranap_cause, mm_context, sgsn_ip_control) {
+            (Some(i), Some(j), Some(k), Some(l)) => {
+                message.teid_control = i;
+                message.ranap_cause = j;
+                message.mm_context = k;
+                message.sgsn_ip_control = l;
+                Ok(message)
+            }
+            _ => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+fn forward_relocation_request_test() -> ForwardRelocationRequest {
+    ForwardRelocationRequest {
+        header: Gtpv1Header {
+            msgtype: FORWARD_RELOCATION_REQUEST,
+            sequence_number: Some(0x2345),
+            ..Default::default()
+        },
+        imsi: Some(Imsi {
+            imsi: "262010123456789".to_string(),
+            ..Default::default()
+        }),
+        teid_control: Teid {
+            t: TEID_CONTROL,
+            teid: 0x1001,
+        },
+        ranap_cause: RanapCause {
+            t: RANAP_CAUSE,
+            value: 43,
+        },
+        mm_context: MmContext {
+            length: 0x2c,
+            cksn: 1,
+            used_cipher: 2,
+            security: SecurityContext::GsmKeyTriplets {
+                kc: [0xaa; 8],
+                triplets: vec![Triplet {
+                    rand: [0x01; 16],
+                    sres: [0x02; 4],
+                    kc: [0x03; 8],
+                }],
+            },
+            drx_param: [0x0a, 0x00],
+            ms_network_capability: vec![0xe5],
+            ..Default::default()
+        },
+        sgsn_ip_control: GsnAddress {
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 1)),
+            ..Default::default()
+        },
+        target_id: Some(TargetIdentification {
+            mcc: 262,
+            mnc: 1,
+            lac: 1,
+            rac: 5,
+            rnc_id: 0x123,
+            ..Default::default()
+        }),
+        utran_container: Some(UtranTransparentContainer {
+            length: 4,
+            container: vec![0x01, 0x02, 0x03, 0x04],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn forward_relocation_req_marshal_unmarshal_test() {
+    let mut message = forward_relocation_request_test();
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - 8) as u16;
+    assert_eq!(&buffer[..2], &[0x32, 0x35]);
+    assert_eq!(&buffer[21..28], &[0x11, 0x00, 0x00, 0x10, 0x01, 0x15, 0x2b]);
+    assert_eq!(
+        ForwardRelocationRequest::unmarshal(&buffer).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn forward_relocation_req_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 19] = [
+        0x32, 0x35, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x11, 0x00, 0x00,
+        0x10, 0x01, 0x15, 0x2b,
+    ];
+    assert_eq!(
+        ForwardRelocationRequest::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
@@ -0,0 +1,221 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const FORWARD_RELOCATION_RESPONSE: u8 = 54;
+
+// Definition of GTPv1-C Forward Relocation Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwardRelocationResponse {
+    pub header: Gtpv1Header,
+    pub cause: Cause,
+    pub teid_control: Option<Teid>,
+    pub teid_data_ii: Option<TeidDataII>,
+    pub ranap_cause: Option<RanapCause>,
+    pub sgsn_ip_control: Option<GsnAddress>,
+    pub sgsn_ip_user: Option<GsnAddress>,
+    pub utran_container: Option<UtranTransparentContainer>,
+    pub rab_setup_info: Vec<RabSetupInfo>,
+    pub bss_container: Option<BssContainer>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for ForwardRelocationResponse {
+    fn default() -> ForwardRelocationResponse {
+        let hdr = Gtpv1Header {
+            msgtype: FORWARD_RELOCATION_RESPONSE,
+            ..Default::default()
+        };
+        ForwardRelocationResponse {
+            header: hdr,
+            cause: Cause::default(),
+            teid_control: None,
+            teid_data_ii: None,
+            ranap_cause: None,
+            sgsn_ip_control: None,
+            sgsn_ip_user: None,
+            utran_container: None,
+            rab_setup_info: vec![],
+            bss_container: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for ForwardRelocationResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        if let Some(i) = self.teid_control {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.teid_data_ii {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.ranap_cause {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.sgsn_ip_control {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.sgsn_ip_user {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.utran_container {
+            elements.push(i.into());
+        }
+        elements.extend(self.rab_setup_info.into_iter().map(|x| x.into()));
+        if let Some(i) = self.bss_container {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = ForwardRelocationResponse {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != FORWARD_RELOCATION_RESPONSE {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let mut cause = None;
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::Teid(i)
+                    if i.t == TEID_CONTROL && message.teid_control.is_none() =>
+                {
+                    message.teid_control = Some(i)
+                }
+                InformationElement::TeidDataII(i) if message.teid_data_ii.is_none() => {
+                    message.teid_data_ii = Some(i)
+                }
+                InformationElement::RanapCause(i) if message.ranap_cause.is_none() => {
+                    message.ranap_cause = Some(i)
+                }
+                InformationElement::GsnAddress(i) if message.sgsn_ip_control.is_none() => {
+                    message.sgsn_ip_control = Some(i)
+                }
+                InformationElement::GsnAddress(i) if message.sgsn_ip_user.is_none() => {
+                    message.sgsn_ip_user = Some(i)
+                }
+                InformationElement::UtranTransparentContainer(i)
+                    if message.utran_container.is_none() =>
+                {
+                    message.utran_container = Some(i)
+                }
+                InformationElement::RabSetupInfo(i) => message.rab_setup_info.push(i),
+                InformationElement::BssContainer(i) if message.bss_container.is_none() => {
+                    message.bss_container = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match cause {
+            Some(i) => {
+                message.cause = i;
+                Ok(message)
+            }
+            None => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+const FORWARD_RELOCATION_RESPONSE_TEST: [u8; 56] = [
+    0x32, 0x36, 0x00, 0x30, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00, 0x01, 0x80, 0x11, 0x00,
+    0x00, 0x20, 0x02, 0x15, 0x01, 0x85, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x02, 0x85, 0x00, 0x04, 0x0a,
+    0x00, 0x00, 0x03, 0x8b, 0x00, 0x02, 0xaa, 0xbb, 0x92, 0x00, 0x09, 0x05, 0x00, 0x00, 0x40, 0x01,
+    0x0a, 0x00, 0x00, 0x03, 0x92, 0x00, 0x01, 0x06,
+];
+
+#[cfg(test)]
+fn forward_relocation_response_test() -> ForwardRelocationResponse {
+    ForwardRelocationResponse {
+        header: Gtpv1Header {
+            msgtype: FORWARD_RELOCATION_RESPONSE,
+            length: 0x30,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        teid_control: Some(Teid {
+            t: TEID_CONTROL,
+            teid: 0x2002,
+        }),
+        teid_data_ii: None,
+        ranap_cause: Some(RanapCause {
+            t: RANAP_CAUSE,
+            value: 1,
+        }),
+        sgsn_ip_control: Some(GsnAddress {
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 2)),
+            ..Default::default()
+        }),
+        sgsn_ip_user: Some(GsnAddress {
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 3)),
+            ..Default::default()
+        }),
+        utran_container: Some(UtranTransparentContainer {
+            length: 2,
+            container: vec![0xaa, 0xbb],
+            ..Default::default()
+        }),
+        rab_setup_info: vec![
+            RabSetupInfo {
+                length: 9,
+                nsapi: 5,
+                forwarding: Some((0x4001, std::net::IpAddr::from([10, 0, 0, 3]))),
+                ..Default::default()
+            },
+            RabSetupInfo {
+                nsapi: 6,
+                ..Default::default()
+            },
+        ],
+        bss_container: None,
+        private_extension: None,
+    }
+}
+
+#[test]
+fn forward_relocation_resp_unmarshal_test() {
+    assert_eq!(
+        ForwardRelocationResponse::unmarshal(&FORWARD_RELOCATION_RESPONSE_TEST).unwrap(),
+        forward_relocation_response_test()
+    );
+}
+
+#[test]
+fn forward_relocation_resp_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    forward_relocation_response_test().marshal(&mut buffer);
+    assert_eq!(buffer, FORWARD_RELOCATION_RESPONSE_TEST);
+}
@@ -0,0 +1,159 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const FORWARD_SRNS_CONTEXT: u8 = 58;
+
+// Definition of GTPv1-C Forward SRNS Context
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwardSRNSContext {
+    pub header: Gtpv1Header,
+    pub rab_contexts: Vec<RabContext>,
+    pub source_rnc_pdcp_ctx_info: Option<SourceRncPdcpContextInfo>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for ForwardSRNSContext {
+    fn default() -> ForwardSRNSContext {
+        let hdr = Gtpv1Header {
+            msgtype: FORWARD_SRNS_CONTEXT,
+            ..Default::default()
+        };
+        ForwardSRNSContext {
+            header: hdr,
+            rab_contexts: vec![],
+            source_rnc_pdcp_ctx_info: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for ForwardSRNSContext {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> =
+            self.rab_contexts.into_iter().map(|x| x.into()).collect();
+        if let Some(i) = self.source_rnc_pdcp_ctx_info {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = ForwardSRNSContext {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != FORWARD_SRNS_CONTEXT {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::RabContext(i) => message.rab_contexts.push(i),
+                InformationElement::SourceRncPdcpContextInfo(i)
+                    if message.source_rnc_pdcp_ctx_info.is_none() =>
+                {
+                    message.source_rnc_pdcp_ctx_info = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        if message.rab_contexts.is_empty() {
+            Err(GTPV1Error::MessageMandatoryIEMissing)
+        } else {
+            Ok(message)
+        }
+    }
+}
+
+#[cfg(test)]
+const FORWARD_SRNS_CONTEXT_TEST: [u8; 39] = [
+    0x32, 0x3a, 0x00, 0x1f, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00, 0x16, 0x05, 0x00, 0x01,
+    0x00, 0x02, 0x00, 0x03, 0x00, 0x04, 0x16, 0x06, 0x00, 0x05, 0x00, 0x06, 0x00, 0x07, 0x00, 0x08,
+    0xa1, 0x00, 0x04, 0x01, 0x02, 0x03, 0x04,
+];
+
+#[cfg(test)]
+fn forward_srns_context_test() -> ForwardSRNSContext {
+    ForwardSRNSContext {
+        header: Gtpv1Header {
+            msgtype: FORWARD_SRNS_CONTEXT,
+            length: 0x1f,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        rab_contexts: vec![
+            RabContext {
+                nsapi: 5,
+                dl_gtpu_sqn: 1,
+                ul_gtpu_sqn: 2,
+                dl_pdcp_sqn: 3,
+                ul_pdcp_sqn: 4,
+                ..Default::default()
+            },
+            RabContext {
+                nsapi: 6,
+                dl_gtpu_sqn: 5,
+                ul_gtpu_sqn: 6,
+                dl_pdcp_sqn: 7,
+                ul_pdcp_sqn: 8,
+                ..Default::default()
+            },
+        ],
+        source_rnc_pdcp_ctx_info: Some(SourceRncPdcpContextInfo {
+            length: 4,
+            rrc_container: vec![0x01, 0x02, 0x03, 0x04],
+            ..Default::default()
+        }),
+        private_extension: None,
+    }
+}
+
+#[test]
+fn forward_srns_ctx_unmarshal_test() {
+    assert_eq!(
+        ForwardSRNSContext::unmarshal(&FORWARD_SRNS_CONTEXT_TEST).unwrap(),
+        forward_srns_context_test()
+    );
+}
+
+#[test]
+fn forward_srns_ctx_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    forward_srns_context_test().marshal(&mut buffer);
+    assert_eq!(buffer, FORWARD_SRNS_CONTEXT_TEST);
+}
+
+#[test]
+fn forward_srns_ctx_missing_mandatory_ie_unmarshal_test() {
+    let mut encoded = FORWARD_SRNS_CONTEXT_TEST[..12].to_vec();
+    encoded.extend_from_slice(&FORWARD_SRNS_CONTEXT_TEST[32..]);
+    encoded[3] = 11;
+    assert_eq!(
+        ForwardSRNSContext::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
@@ -0,0 +1,92 @@
+// BSS Container IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+
+// BSS Container IE Type
+
+pub const BSS_CONTAINER: u8 = 173;
+
+// BSS Container IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BssContainer {
+    pub t: u8,
+    pub length: u16,
+    pub container: Vec<u8>,
+}
+
+impl Default for BssContainer {
+    fn default() -> Self {
+        BssContainer {
+            t: BSS_CONTAINER,
+            length: 0,
+            container: vec![],
+        }
+    }
+}
+
+impl IEs for BssContainer {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.extend_from_slice(&self.container);
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() >= 3 {
+            let mut data = BssContainer {
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if check_tlv_ie_buffer(data.length, buffer) {
+                data.container
+                    .extend_from_slice(&buffer[3..(3 + data.length as usize)]);
+                Ok(data)
+            } else {
+                Err(GTPV1Error::IEInvalidLength)
+            }
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn bss_container_ie_marshal_test() {
+    let ie_marshalled: [u8; 7] = [0xad, 0x00, 0x04, 0x01, 0x02, 0x03, 0x04];
+    let ie_to_marshal = BssContainer {
+        t: BSS_CONTAINER,
+        length: 4,
+        container: vec![0x01, 0x02, 0x03, 0x04],
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn bss_container_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 7] = [0xad, 0x00, 0x04, 0x01, 0x02, 0x03, 0x04];
+    let ie_unmarshalled = BssContainer {
+        t: BSS_CONTAINER,
+        length: 4,
+        container: vec![0x01, 0x02, 0x03, 0x04],
+    };
+    assert_eq!(
+        BssContainer::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
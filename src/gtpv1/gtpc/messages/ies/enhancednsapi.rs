@@ -0,0 +1,94 @@
+// Enhanced NSAPI IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+
+// Enhanced NSAPI IE Type
+
+pub const ENHANCED_NSAPI: u8 = 167;
+pub const ENHANCED_NSAPI_LENGTH: u16 = 1;
+
+// Enhanced NSAPI IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnhancedNsapi {
+    pub t: u8,
+    pub length: u16,
+    pub value: u8,
+}
+
+impl Default for EnhancedNsapi {
+    fn default() -> Self {
+        EnhancedNsapi {
+            t: ENHANCED_NSAPI,
+            length: ENHANCED_NSAPI_LENGTH,
+            value: 0,
+        }
+    }
+}
+
+impl IEs for EnhancedNsapi {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.push(self.value);
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() >= 3 {
+            let data = EnhancedNsapi {
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if check_tlv_ie_buffer(data.length, buffer) && data.length >= ENHANCED_NSAPI_LENGTH {
+                Ok(EnhancedNsapi {
+                    value: buffer[3],
+                    ..data
+                })
+            } else {
+                Err(GTPV1Error::IEInvalidLength)
+            }
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn enhanced_nsapi_ie_marshal_test() {
+    let ie_marshalled: [u8; 4] = [0xa7, 0x00, 0x01, 0x80];
+    let ie_to_marshal = EnhancedNsapi {
+        t: ENHANCED_NSAPI,
+        length: ENHANCED_NSAPI_LENGTH,
+        value: 128,
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn enhanced_nsapi_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 4] = [0xa7, 0x00, 0x01, 0x80];
+    let ie_unmarshalled = EnhancedNsapi {
+        t: ENHANCED_NSAPI,
+        length: ENHANCED_NSAPI_LENGTH,
+        value: 128,
+    };
+    assert_eq!(
+        EnhancedNsapi::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
@@ -36,12 +36,16 @@ impl IEs for ExtensionHeaderTypeList {
     }
 
     fn unmarshal(buffer: &[u8]) -> Result<ExtensionHeaderTypeList, GTPV1Error> {
+        if buffer.len() < 2 {
+            return Err(GTPV1Error::IEInvalidLength);
+        }
         let mut data = ExtensionHeaderTypeList {
             length: buffer[1],
             ..Default::default()
         };
-        if (data.length + 2) as usize <= buffer.len() {
-            data.list.extend_from_slice(&buffer[2..]);
+        if data.length as usize + 2 <= buffer.len() {
+            data.list
+                .extend_from_slice(&buffer[2..data.length as usize + 2]);
             Ok(data)
         } else {
             Err(GTPV1Error::IEInvalidLength)
@@ -49,7 +53,7 @@ impl IEs for ExtensionHeaderTypeList {
     }
 
     fn len(&self) -> usize {
-        (self.length + 2) as usize
+        self.length as usize + 2
     }
     fn is_empty(&self) -> bool {
         self.length == 0
@@ -82,3 +86,16 @@ fn extension_header_type_list_ie_unmarshal_test() {
         ie_unmarshalled
     );
 }
+
+#[test]
+fn extension_header_type_list_ie_unmarshal_trailing_test() {
+    let ie_to_unmarshal: [u8; 6] = [0x8d, 0x02, 0x00, 0x01, 0x01, 0x07];
+    let ie = ExtensionHeaderTypeList::unmarshal(&ie_to_unmarshal).unwrap();
+    assert_eq!(ie.list, vec![0x00, 0x01]);
+    assert_eq!(ie.len(), 4);
+    let ie = ExtensionHeaderTypeList {
+        length: 0xfe,
+        ..ExtensionHeaderTypeList::default()
+    };
+    assert_eq!(ie.len(), 0x100);
+}
@@ -0,0 +1,1171 @@
+// GTPv1-C Information Element enum and generic TV/TLV IE decoder - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::*};
+
+// IE types below 128 are TV encoded with a fixed length, IE types from 128 are TLV encoded
+
+pub const TLV_IE_TYPE_MIN: u8 = 128;
+
+// Length of the value of TV IEs according to 3GPP TS 29.060 Table 37
+
+pub fn tv_ie_value_length(t: u8) -> Option<usize> {
+    match t {
+        1 => Some(1),   // Cause
+        2 => Some(8),   // IMSI
+        3 => Some(6),   // RAI
+        4 => Some(4),   // TLLI
+        5 => Some(4),   // P-TMSI
+        8 => Some(1),   // Reordering Required
+        9 => Some(28),  // Authentication Triplet
+        11 => Some(1),  // MAP Cause
+        12 => Some(3),  // P-TMSI Signature
+        13 => Some(1),  // MS Validated
+        14 => Some(1),  // Recovery
+        15 => Some(1),  // Selection Mode
+        16 => Some(4),  // TEID Data I
+        17 => Some(4),  // TEID Control Plane
+        18 => Some(5),  // TEID Data II
+        19 => Some(1),  // Teardown Ind
+        20 => Some(1),  // NSAPI
+        21 => Some(1),  // RANAP Cause
+        22 => Some(9),  // RAB Context
+        23 => Some(1),  // Radio Priority SMS
+        24 => Some(1),  // Radio Priority
+        25 => Some(2),  // Packet Flow Id
+        26 => Some(2),  // Charging Characteristics
+        27 => Some(2),  // Trace Reference
+        28 => Some(2),  // Trace Type
+        29 => Some(1),  // MS Not Reachable Reason
+        127 => Some(4), // Charging ID
+        _ => None,
+    }
+}
+
+// GTPv1-C Information Element enum
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InformationElement {
+    Cause(Cause),
+    Imsi(Imsi),
+    Rai(Rai),
+    ReorderingRequired(ReorderingRequired),
+    Recovery(Recovery),
+    SelectionMode(SelectionMode),
+    Teid(Teid),
+    TeardownInd(TeardownInd),
+    Nsapi(Nsapi),
+    ChargingCharacteristics(ChargingCharacteristics),
+    TraceReference(TraceReference),
+    TraceType(TraceType),
+    ChargingID(ChargingID),
+    EndUserAddress(EndUserAddress),
+    Apn(Apn),
+    Pco(Pco),
+    GsnAddress(GsnAddress),
+    Msisdn(Msisdn),
+    Qos(Qos),
+    Tft(Tft),
+    ExtensionHeaderTypeList(ExtensionHeaderTypeList),
+    TriggerId(TriggerId),
+    OmcId(OmcId),
+    CommonFlags(CommonFlags),
+    ApnRestriction(ApnRestriction),
+    RatType(RatType),
+    Uli(Uli),
+    MsTimeZone(MsTimeZone),
+    Imei(Imei),
+    CamelChargingInfoContainer(CamelChargingInfoContainer),
+    AdditionalTraceInfo(AdditionalTraceInfo),
+    MSInfoChangeReportingAction(MSInfoChangeReportingAction),
+    DirectTunnelFlags(DirectTunnelFlags),
+    CorrelationId(CorrelationId),
+    BearerControlMode(BearerControlMode),
+    EvolvedAllocationRetentionI(EvolvedAllocationRetentionI),
+    ExtendedCommonFlags(ExtendedCommonFlags),
+    Uci(Uci),
+    CSGInformationReportingAction(CSGInformationReportingAction),
+    ApnAmbr(ApnAmbr),
+    GGSNBackOffTime(GGSNBackOffTime),
+    Spi(Spi),
+    UliTimestamp(UliTimestamp),
+    CnOperatorSelectionEntity(CnOperatorSelectionEntity),
+    ExtendedCommonFlagsII(ExtendedCommonFlagsII),
+    MappedUeUsageType(MappedUeUsageType),
+    UpFunctionSelectionIndicationFlags(UpFunctionSelectionIndicationFlags),
+    ChargingGWAddress(ChargingGWAddress),
+    Tlli(Tlli),
+    Ptmsi(Ptmsi),
+    PtmsiSignature(PtmsiSignature),
+    MsValidated(MsValidated),
+    TeidDataII(TeidDataII),
+    RabContext(RabContext),
+    RadioPrioritySms(RadioPrioritySms),
+    RadioPriority(RadioPriority),
+    PacketFlowId(PacketFlowId),
+    MmContext(MmContext),
+    PdpContext(PdpContext),
+    RadioPriorityLcs(RadioPriorityLcs),
+    HopCounter(HopCounter),
+    RanapCause(RanapCause),
+    TargetIdentification(TargetIdentification),
+    UtranTransparentContainer(UtranTransparentContainer),
+    RabSetupInfo(RabSetupInfo),
+    SourceRncPdcpContextInfo(SourceRncPdcpContextInfo),
+    BssContainer(BssContainer),
+    MapCause(MapCause),
+    MsNotReachableReason(MsNotReachableReason),
+    Tmgi(Tmgi),
+    MbmsUeContext(MbmsUeContext),
+    RimRoutingAddress(RimRoutingAddress),
+    MbmsPco(MbmsPco),
+    MbmsServiceArea(MbmsServiceArea),
+    MbmsSessionIdentifier(MbmsSessionIdentifier),
+    Mbms2g3gIndicator(Mbms2g3gIndicator),
+    EnhancedNsapi(EnhancedNsapi),
+    MbmsSessionDuration(MbmsSessionDuration),
+    AdditionalMbmsTraceInfo(AdditionalMbmsTraceInfo),
+    MbmsSessionRepetitionNumber(MbmsSessionRepetitionNumber),
+    MbmsTimeToDataTransfer(MbmsTimeToDataTransfer),
+    RequiredMbmsBearerCapabilities(RequiredMbmsBearerCapabilities),
+    MbmsFlowIdentifier(MbmsFlowIdentifier),
+    MbmsIpMulticastDistribution(MbmsIpMulticastDistribution),
+    MbmsDistributionAck(MbmsDistributionAck),
+    PrivateExtension(PrivateExtension),
+    Unknown(UnknownIe),
+}
+
+impl From<Cause> for InformationElement {
+    fn from(i: Cause) -> Self {
+        InformationElement::Cause(i)
+    }
+}
+
+impl From<Imsi> for InformationElement {
+    fn from(i: Imsi) -> Self {
+        InformationElement::Imsi(i)
+    }
+}
+
+impl From<Rai> for InformationElement {
+    fn from(i: Rai) -> Self {
+        InformationElement::Rai(i)
+    }
+}
+
+impl From<ReorderingRequired> for InformationElement {
+    fn from(i: ReorderingRequired) -> Self {
+        InformationElement::ReorderingRequired(i)
+    }
+}
+
+impl From<Recovery> for InformationElement {
+    fn from(i: Recovery) -> Self {
+        InformationElement::Recovery(i)
+    }
+}
+
+impl From<SelectionMode> for InformationElement {
+    fn from(i: SelectionMode) -> Self {
+        InformationElement::SelectionMode(i)
+    }
+}
+
+impl From<Teid> for InformationElement {
+    fn from(i: Teid) -> Self {
+        InformationElement::Teid(i)
+    }
+}
+
+impl From<TeardownInd> for InformationElement {
+    fn from(i: TeardownInd) -> Self {
+        InformationElement::TeardownInd(i)
+    }
+}
+
+impl From<Nsapi> for InformationElement {
+    fn from(i: Nsapi) -> Self {
+        InformationElement::Nsapi(i)
+    }
+}
+
+impl From<ChargingCharacteristics> for InformationElement {
+    fn from(i: ChargingCharacteristics) -> Self {
+        InformationElement::ChargingCharacteristics(i)
+    }
+}
+
+impl From<TraceReference> for InformationElement {
+    fn from(i: TraceReference) -> Self {
+        InformationElement::TraceReference(i)
+    }
+}
+
+impl From<TraceType> for InformationElement {
+    fn from(i: TraceType) -> Self {
+        InformationElement::TraceType(i)
+    }
+}
+
+impl From<ChargingID> for InformationElement {
+    fn from(i: ChargingID) -> Self {
+        InformationElement::ChargingID(i)
+    }
+}
+
+impl From<EndUserAddress> for InformationElement {
+    fn from(i: EndUserAddress) -> Self {
+        InformationElement::EndUserAddress(i)
+    }
+}
+
+impl From<Apn> for InformationElement {
+    fn from(i: Apn) -> Self {
+        InformationElement::Apn(i)
+    }
+}
+
+impl From<Pco> for InformationElement {
+    fn from(i: Pco) -> Self {
+        InformationElement::Pco(i)
+    }
+}
+
+impl From<GsnAddress> for InformationElement {
+    fn from(i: GsnAddress) -> Self {
+        InformationElement::GsnAddress(i)
+    }
+}
+
+impl From<Msisdn> for InformationElement {
+    fn from(i: Msisdn) -> Self {
+        InformationElement::Msisdn(i)
+    }
+}
+
+impl From<Qos> for InformationElement {
+    fn from(i: Qos) -> Self {
+        InformationElement::Qos(i)
+    }
+}
+
+impl From<Tft> for InformationElement {
+    fn from(i: Tft) -> Self {
+        InformationElement::Tft(i)
+    }
+}
+
+impl From<ExtensionHeaderTypeList> for InformationElement {
+    fn from(i: ExtensionHeaderTypeList) -> Self {
+        InformationElement::ExtensionHeaderTypeList(i)
+    }
+}
+
+impl From<TriggerId> for InformationElement {
+    fn from(i: TriggerId) -> Self {
+        InformationElement::TriggerId(i)
+    }
+}
+
+impl From<OmcId> for InformationElement {
+    fn from(i: OmcId) -> Self {
+        InformationElement::OmcId(i)
+    }
+}
+
+impl From<CommonFlags> for InformationElement {
+    fn from(i: CommonFlags) -> Self {
+        InformationElement::CommonFlags(i)
+    }
+}
+
+impl From<ApnRestriction> for InformationElement {
+    fn from(i: ApnRestriction) -> Self {
+        InformationElement::ApnRestriction(i)
+    }
+}
+
+impl From<RatType> for InformationElement {
+    fn from(i: RatType) -> Self {
+        InformationElement::RatType(i)
+    }
+}
+
+impl From<Uli> for InformationElement {
+    fn from(i: Uli) -> Self {
+        InformationElement::Uli(i)
+    }
+}
+
+impl From<MsTimeZone> for InformationElement {
+    fn from(i: MsTimeZone) -> Self {
+        InformationElement::MsTimeZone(i)
+    }
+}
+
+impl From<Imei> for InformationElement {
+    fn from(i: Imei) -> Self {
+        InformationElement::Imei(i)
+    }
+}
+
+impl From<CamelChargingInfoContainer> for InformationElement {
+    fn from(i: CamelChargingInfoContainer) -> Self {
+        InformationElement::CamelChargingInfoContainer(i)
+    }
+}
+
+impl From<AdditionalTraceInfo> for InformationElement {
+    fn from(i: AdditionalTraceInfo) -> Self {
+        InformationElement::AdditionalTraceInfo(i)
+    }
+}
+
+impl From<MSInfoChangeReportingAction> for InformationElement {
+    fn from(i: MSInfoChangeReportingAction) -> Self {
+        InformationElement::MSInfoChangeReportingAction(i)
+    }
+}
+
+impl From<DirectTunnelFlags> for InformationElement {
+    fn from(i: DirectTunnelFlags) -> Self {
+        InformationElement::DirectTunnelFlags(i)
+    }
+}
+
+impl From<CorrelationId> for InformationElement {
+    fn from(i: CorrelationId) -> Self {
+        InformationElement::CorrelationId(i)
+    }
+}
+
+impl From<BearerControlMode> for InformationElement {
+    fn from(i: BearerControlMode) -> Self {
+        InformationElement::BearerControlMode(i)
+    }
+}
+
+impl From<EvolvedAllocationRetentionI> for InformationElement {
+    fn from(i: EvolvedAllocationRetentionI) -> Self {
+        InformationElement::EvolvedAllocationRetentionI(i)
+    }
+}
+
+impl From<ExtendedCommonFlags> for InformationElement {
+    fn from(i: ExtendedCommonFlags) -> Self {
+        InformationElement::ExtendedCommonFlags(i)
+    }
+}
+
+impl From<Uci> for InformationElement {
+    fn from(i: Uci) -> Self {
+        InformationElement::Uci(i)
+    }
+}
+
+impl From<CSGInformationReportingAction> for InformationElement {
+    fn from(i: CSGInformationReportingAction) -> Self {
+        InformationElement::CSGInformationReportingAction(i)
+    }
+}
+
+impl From<ApnAmbr> for InformationElement {
+    fn from(i: ApnAmbr) -> Self {
+        InformationElement::ApnAmbr(i)
+    }
+}
+
+impl From<GGSNBackOffTime> for InformationElement {
+    fn from(i: GGSNBackOffTime) -> Self {
+        InformationElement::GGSNBackOffTime(i)
+    }
+}
+
+impl From<Spi> for InformationElement {
+    fn from(i: Spi) -> Self {
+        InformationElement::Spi(i)
+    }
+}
+
+impl From<UliTimestamp> for InformationElement {
+    fn from(i: UliTimestamp) -> Self {
+        InformationElement::UliTimestamp(i)
+    }
+}
+
+impl From<CnOperatorSelectionEntity> for InformationElement {
+    fn from(i: CnOperatorSelectionEntity) -> Self {
+        InformationElement::CnOperatorSelectionEntity(i)
+    }
+}
+
+impl From<ExtendedCommonFlagsII> for InformationElement {
+    fn from(i: ExtendedCommonFlagsII) -> Self {
+        InformationElement::ExtendedCommonFlagsII(i)
+    }
+}
+
+impl From<MappedUeUsageType> for InformationElement {
+    fn from(i: MappedUeUsageType) -> Self {
+        InformationElement::MappedUeUsageType(i)
+    }
+}
+
+impl From<UpFunctionSelectionIndicationFlags> for InformationElement {
+    fn from(i: UpFunctionSelectionIndicationFlags) -> Self {
+        InformationElement::UpFunctionSelectionIndicationFlags(i)
+    }
+}
+
+impl From<ChargingGWAddress> for InformationElement {
+    fn from(i: ChargingGWAddress) -> Self {
+        InformationElement::ChargingGWAddress(i)
+    }
+}
+
+impl From<Tlli> for InformationElement {
+    fn from(i: Tlli) -> Self {
+        InformationElement::Tlli(i)
+    }
+}
+
+impl From<Ptmsi> for InformationElement {
+    fn from(i: Ptmsi) -> Self {
+        InformationElement::Ptmsi(i)
+    }
+}
+
+impl From<PtmsiSignature> for InformationElement {
+    fn from(i: PtmsiSignature) -> Self {
+        InformationElement::PtmsiSignature(i)
+    }
+}
+
+impl From<MsValidated> for InformationElement {
+    fn from(i: MsValidated) -> Self {
+        InformationElement::MsValidated(i)
+    }
+}
+
+impl From<TeidDataII> for InformationElement {
+    fn from(i: TeidDataII) -> Self {
+        InformationElement::TeidDataII(i)
+    }
+}
+
+impl From<RabContext> for InformationElement {
+    fn from(i: RabContext) -> Self {
+        InformationElement::RabContext(i)
+    }
+}
+
+impl From<RadioPrioritySms> for InformationElement {
+    fn from(i: RadioPrioritySms) -> Self {
+        InformationElement::RadioPrioritySms(i)
+    }
+}
+
+impl From<RadioPriority> for InformationElement {
+    fn from(i: RadioPriority) -> Self {
+        InformationElement::RadioPriority(i)
+    }
+}
+
+impl From<PacketFlowId> for InformationElement {
+    fn from(i: PacketFlowId) -> Self {
+        InformationElement::PacketFlowId(i)
+    }
+}
+
+impl From<MmContext> for InformationElement {
+    fn from(i: MmContext) -> Self {
+        InformationElement::MmContext(i)
+    }
+}
+
+impl From<PdpContext> for InformationElement {
+    fn from(i: PdpContext) -> Self {
+        InformationElement::PdpContext(i)
+    }
+}
+
+impl From<RadioPriorityLcs> for InformationElement {
+    fn from(i: RadioPriorityLcs) -> Self {
+        InformationElement::RadioPriorityLcs(i)
+    }
+}
+
+impl From<HopCounter> for InformationElement {
+    fn from(i: HopCounter) -> Self {
+        InformationElement::HopCounter(i)
+    }
+}
+
+impl From<RanapCause> for InformationElement {
+    fn from(i: RanapCause) -> Self {
+        InformationElement::RanapCause(i)
+    }
+}
+
+impl From<TargetIdentification> for InformationElement {
+    fn from(i: TargetIdentification) -> Self {
+        InformationElement::TargetIdentification(i)
+    }
+}
+
+impl From<UtranTransparentContainer> for InformationElement {
+    fn from(i: UtranTransparentContainer) -> Self {
+        InformationElement::UtranTransparentContainer(i)
+    }
+}
+
+impl From<RabSetupInfo> for InformationElement {
+    fn from(i: RabSetupInfo) -> Self {
+        InformationElement::RabSetupInfo(i)
+    }
+}
+
+impl From<SourceRncPdcpContextInfo> for InformationElement {
+    fn from(i: SourceRncPdcpContextInfo) -> Self {
+        InformationElement::SourceRncPdcpContextInfo(i)
+    }
+}
+
+impl From<BssContainer> for InformationElement {
+    fn from(i: BssContainer) -> Self {
+        InformationElement::BssContainer(i)
+    }
+}
+
+impl From<MapCause> for InformationElement {
+    fn from(i: MapCause) -> Self {
+        InformationElement::MapCause(i)
+    }
+}
+
+impl From<MsNotReachableReason> for InformationElement {
+    fn from(i: MsNotReachableReason) -> Self {
+        InformationElement::MsNotReachableReason(i)
+    }
+}
+
+impl From<Tmgi> for InformationElement {
+    fn from(i: Tmgi) -> Self {
+        InformationElement::Tmgi(i)
+    }
+}
+
+impl From<MbmsUeContext> for InformationElement {
+    fn from(i: MbmsUeContext) -> Self {
+        InformationElement::MbmsUeContext(i)
+    }
+}
+
+impl From<RimRoutingAddress> for InformationElement {
+    fn from(i: RimRoutingAddress) -> Self {
+        InformationElement::RimRoutingAddress(i)
+    }
+}
+
+impl From<MbmsPco> for InformationElement {
+    fn from(i: MbmsPco) -> Self {
+        InformationElement::MbmsPco(i)
+    }
+}
+
+impl From<MbmsServiceArea> for InformationElement {
+    fn from(i: MbmsServiceArea) -> Self {
+        InformationElement::MbmsServiceArea(i)
+    }
+}
+
+impl From<MbmsSessionIdentifier> for InformationElement {
+    fn from(i: MbmsSessionIdentifier) -> Self {
+        InformationElement::MbmsSessionIdentifier(i)
+    }
+}
+
+impl From<Mbms2g3gIndicator> for InformationElement {
+    fn from(i: Mbms2g3gIndicator) -> Self {
+        InformationElement::Mbms2g3gIndicator(i)
+    }
+}
+
+impl From<EnhancedNsapi> for InformationElement {
+    fn from(i: EnhancedNsapi) -> Self {
+        InformationElement::EnhancedNsapi(i)
+    }
+}
+
+impl From<MbmsSessionDuration> for InformationElement {
+    fn from(i: MbmsSessionDuration) -> Self {
+        InformationElement::MbmsSessionDuration(i)
+    }
+}
+
+impl From<AdditionalMbmsTraceInfo> for InformationElement {
+    fn from(i: AdditionalMbmsTraceInfo) -> Self {
+        InformationElement::AdditionalMbmsTraceInfo(i)
+    }
+}
+
+impl From<MbmsSessionRepetitionNumber> for InformationElement {
+    fn from(i: MbmsSessionRepetitionNumber) -> Self {
+        InformationElement::MbmsSessionRepetitionNumber(i)
+    }
+}
+
+impl From<MbmsTimeToDataTransfer> for InformationElement {
+    fn from(i: MbmsTimeToDataTransfer) -> Self {
+        InformationElement::MbmsTimeToDataTransfer(i)
+    }
+}
+
+impl From<RequiredMbmsBearerCapabilities> for InformationElement {
+    fn from(i: RequiredMbmsBearerCapabilities) -> Self {
+        InformationElement::RequiredMbmsBearerCapabilities(i)
+    }
+}
+
+impl From<MbmsFlowIdentifier> for InformationElement {
+    fn from(i: MbmsFlowIdentifier) -> Self {
+        InformationElement::MbmsFlowIdentifier(i)
+    }
+}
+
+impl From<MbmsIpMulticastDistribution> for InformationElement {
+    fn from(i: MbmsIpMulticastDistribution) -> Self {
+        InformationElement::MbmsIpMulticastDistribution(i)
+    }
+}
+
+impl From<MbmsDistributionAck> for InformationElement {
+    fn from(i: MbmsDistributionAck) -> Self {
+        InformationElement::MbmsDistributionAck(i)
+    }
+}
+
+impl From<PrivateExtension> for InformationElement {
+    fn from(i: PrivateExtension) -> Self {
+        InformationElement::PrivateExtension(i)
+    }
+}
+
+impl From<UnknownIe> for InformationElement {
+    fn from(i: UnknownIe) -> Self {
+        InformationElement::Unknown(i)
+    }
+}
+
+impl InformationElement {
+    pub fn marshal(self, buffer: &mut Vec<u8>) {
+        match self {
+            InformationElement::Cause(i) => i.marshal(buffer),
+            InformationElement::Imsi(i) => i.marshal(buffer),
+            InformationElement::Rai(i) => i.marshal(buffer),
+            InformationElement::ReorderingRequired(i) => i.marshal(buffer),
+            InformationElement::Recovery(i) => i.marshal(buffer),
+            InformationElement::SelectionMode(i) => i.marshal(buffer),
+            InformationElement::Teid(i) => i.marshal(buffer),
+            InformationElement::TeardownInd(i) => i.marshal(buffer),
+            InformationElement::Nsapi(i) => i.marshal(buffer),
+            InformationElement::ChargingCharacteristics(i) => i.marshal(buffer),
+            InformationElement::TraceReference(i) => i.marshal(buffer),
+            InformationElement::TraceType(i) => i.marshal(buffer),
+            InformationElement::ChargingID(i) => i.marshal(buffer),
+            InformationElement::EndUserAddress(i) => i.marshal(buffer),
+            InformationElement::Apn(i) => i.marshal(buffer),
+            InformationElement::Pco(i) => i.marshal(buffer),
+            InformationElement::GsnAddress(i) => i.marshal(buffer),
+            InformationElement::Msisdn(i) => i.marshal(buffer),
+            InformationElement::Qos(i) => i.marshal(buffer),
+            InformationElement::Tft(i) => i.marshal(buffer),
+            InformationElement::ExtensionHeaderTypeList(i) => i.marshal(buffer),
+            InformationElement::TriggerId(i) => i.marshal(buffer),
+            InformationElement::OmcId(i) => i.marshal(buffer),
+            InformationElement::CommonFlags(i) => i.marshal(buffer),
+            InformationElement::ApnRestriction(i) => i.marshal(buffer),
+            InformationElement::RatType(i) => i.marshal(buffer),
+            InformationElement::Uli(i) => i.marshal(buffer),
+            InformationElement::MsTimeZone(i) => i.marshal(buffer),
+            InformationElement::Imei(i) => i.marshal(buffer),
+            InformationElement::CamelChargingInfoContainer(i) => i.marshal(buffer),
+            InformationElement::AdditionalTraceInfo(i) => i.marshal(buffer),
+            InformationElement::MSInfoChangeReportingAction(i) => i.marshal(buffer),
+            InformationElement::DirectTunnelFlags(i) => i.marshal(buffer),
+            InformationElement::CorrelationId(i) => i.marshal(buffer),
+            InformationElement::BearerControlMode(i) => i.marshal(buffer),
+            InformationElement::EvolvedAllocationRetentionI(i) => i.marshal(buffer),
+            InformationElement::ExtendedCommonFlags(i) => i.marshal(buffer),
+            InformationElement::Uci(i) => i.marshal(buffer),
+            InformationElement::CSGInformationReportingAction(i) => i.marshal(buffer),
+            InformationElement::ApnAmbr(i) => i.marshal(buffer),
+            InformationElement::GGSNBackOffTime(i) => i.marshal(buffer),
+            InformationElement::Spi(i) => i.marshal(buffer),
+            InformationElement::UliTimestamp(i) => i.marshal(buffer),
+            InformationElement::CnOperatorSelectionEntity(i) => i.marshal(buffer),
+            InformationElement::ExtendedCommonFlagsII(i) => i.marshal(buffer),
+            InformationElement::MappedUeUsageType(i) => i.marshal(buffer),
+            InformationElement::UpFunctionSelectionIndicationFlags(i) => i.marshal(buffer),
+            InformationElement::ChargingGWAddress(i) => i.marshal(buffer),
+            InformationElement::Tlli(i) => i.marshal(buffer),
+            InformationElement::Ptmsi(i) => i.marshal(buffer),
+            InformationElement::PtmsiSignature(i) => i.marshal(buffer),
+            InformationElement::MsValidated(i) => i.marshal(buffer),
+            InformationElement::TeidDataII(i) => i.marshal(buffer),
+            InformationElement::RabContext(i) => i.marshal(buffer),
+            InformationElement::RadioPrioritySms(i) => i.marshal(buffer),
+            InformationElement::RadioPriority(i) => i.marshal(buffer),
+            InformationElement::PacketFlowId(i) => i.marshal(buffer),
+            InformationElement::MmContext(i) => i.marshal(buffer),
+            InformationElement::PdpContext(i) => i.marshal(buffer),
+            InformationElement::RadioPriorityLcs(i) => i.marshal(buffer),
+            InformationElement::HopCounter(i) => i.marshal(buffer),
+            InformationElement::RanapCause(i) => i.marshal(buffer),
+            InformationElement::TargetIdentification(i) => i.marshal(buffer),
+            InformationElement::UtranTransparentContainer(i) => i.marshal(buffer),
+            InformationElement::RabSetupInfo(i) => i.marshal(buffer),
+            InformationElement::SourceRncPdcpContextInfo(i) => i.marshal(buffer),
+            InformationElement::BssContainer(i) => i.marshal(buffer),
+            InformationElement::MapCause(i) => i.marshal(buffer),
+            InformationElement::MsNotReachableReason(i) => i.marshal(buffer),
+            InformationElement::Tmgi(i) => i.marshal(buffer),
+            InformationElement::MbmsUeContext(i) => i.marshal(buffer),
+            InformationElement::RimRoutingAddress(i) => i.marshal(buffer),
+            InformationElement::MbmsPco(i) => i.marshal(buffer),
+            InformationElement::MbmsServiceArea(i) => i.marshal(buffer),
+            InformationElement::MbmsSessionIdentifier(i) => i.marshal(buffer),
+            InformationElement::Mbms2g3gIndicator(i) => i.marshal(buffer),
+            InformationElement::EnhancedNsapi(i) => i.marshal(buffer),
+            InformationElement::MbmsSessionDuration(i) => i.marshal(buffer),
+            InformationElement::AdditionalMbmsTraceInfo(i) => i.marshal(buffer),
+            InformationElement::MbmsSessionRepetitionNumber(i) => i.marshal(buffer),
+            InformationElement::MbmsTimeToDataTransfer(i) => i.marshal(buffer),
+            InformationElement::RequiredMbmsBearerCapabilities(i) => i.marshal(buffer),
+            InformationElement::MbmsFlowIdentifier(i) => i.marshal(buffer),
+            InformationElement::MbmsIpMulticastDistribution(i) => i.marshal(buffer),
+            InformationElement::MbmsDistributionAck(i) => i.marshal(buffer),
+            InformationElement::PrivateExtension(i) => i.marshal(buffer),
+            InformationElement::Unknown(i) => i.marshal(buffer),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            InformationElement::Cause(i) => i.len(),
+            InformationElement::Imsi(i) => i.len(),
+            InformationElement::Rai(i) => i.len(),
+            InformationElement::ReorderingRequired(i) => i.len(),
+            InformationElement::Recovery(i) => i.len(),
+            InformationElement::SelectionMode(i) => i.len(),
+            InformationElement::Teid(i) => i.len(),
+            InformationElement::TeardownInd(i) => i.len(),
+            InformationElement::Nsapi(i) => i.len(),
+            InformationElement::ChargingCharacteristics(i) => i.len(),
+            InformationElement::TraceReference(i) => i.len(),
+            InformationElement::TraceType(i) => i.len(),
+            InformationElement::ChargingID(i) => i.len(),
+            InformationElement::EndUserAddress(i) => i.len(),
+            InformationElement::Apn(i) => i.len(),
+            InformationElement::Pco(i) => i.len(),
+            InformationElement::GsnAddress(i) => i.len(),
+            InformationElement::Msisdn(i) => i.len(),
+            InformationElement::Qos(i) => i.len(),
+            InformationElement::Tft(i) => i.len(),
+            InformationElement::ExtensionHeaderTypeList(i) => i.len(),
+            InformationElement::TriggerId(i) => i.len(),
+            InformationElement::OmcId(i) => i.len(),
+            InformationElement::CommonFlags(i) => i.len(),
+            InformationElement::ApnRestriction(i) => i.len(),
+            InformationElement::RatType(i) => i.len(),
+            InformationElement::Uli(i) => i.len(),
+            InformationElement::MsTimeZone(i) => i.len(),
+            InformationElement::Imei(i) => i.len(),
+            InformationElement::CamelChargingInfoContainer(i) => i.len(),
+            InformationElement::AdditionalTraceInfo(i) => i.len(),
+            InformationElement::MSInfoChangeReportingAction(i) => i.len(),
+            InformationElement::DirectTunnelFlags(i) => i.len(),
+            InformationElement::CorrelationId(i) => i.len(),
+            InformationElement::BearerControlMode(i) => i.len(),
+            InformationElement::EvolvedAllocationRetentionI(i) => i.len(),
+            InformationElement::ExtendedCommonFlags(i) => i.len(),
+            InformationElement::Uci(i) => i.len(),
+            InformationElement::CSGInformationReportingAction(i) => i.len(),
+            InformationElement::ApnAmbr(i) => i.len(),
+            InformationElement::GGSNBackOffTime(i) => i.len(),
+            InformationElement::Spi(i) => i.len(),
+            InformationElement::UliTimestamp(i) => i.len(),
+            InformationElement::CnOperatorSelectionEntity(i) => i.len(),
+            InformationElement::ExtendedCommonFlagsII(i) => i.len(),
+            InformationElement::MappedUeUsageType(i) => i.len(),
+            InformationElement::UpFunctionSelectionIndicationFlags(i) => i.len(),
+            InformationElement::ChargingGWAddress(i) => i.len(),
+            InformationElement::Tlli(i) => i.len(),
+            InformationElement::Ptmsi(i) => i.len(),
+            InformationElement::PtmsiSignature(i) => i.len(),
+            InformationElement::MsValidated(i) => i.len(),
+            InformationElement::TeidDataII(i) => i.len(),
+            InformationElement::RabContext(i) => i.len(),
+            InformationElement::RadioPrioritySms(i) => i.len(),
+            InformationElement::RadioPriority(i) => i.len(),
+            InformationElement::PacketFlowId(i) => i.len(),
+            InformationElement::MmContext(i) => i.len(),
+            InformationElement::PdpContext(i) => i.len(),
+            InformationElement::RadioPriorityLcs(i) => i.len(),
+            InformationElement::HopCounter(i) => i.len(),
+            InformationElement::RanapCause(i) => i.len(),
+            InformationElement::TargetIdentification(i) => i.len(),
+            InformationElement::UtranTransparentContainer(i) => i.len(),
+            InformationElement::RabSetupInfo(i) => i.len(),
+            InformationElement::SourceRncPdcpContextInfo(i) => i.len(),
+            InformationElement::BssContainer(i) => i.len(),
+            InformationElement::MapCause(i) => i.len(),
+            InformationElement::MsNotReachableReason(i) => i.len(),
+            InformationElement::Tmgi(i) => i.len(),
+            InformationElement::MbmsUeContext(i) => i.len(),
+            InformationElement::RimRoutingAddress(i) => i.len(),
+            InformationElement::MbmsPco(i) => i.len(),
+            InformationElement::MbmsServiceArea(i) => i.len(),
+            InformationElement::MbmsSessionIdentifier(i) => i.len(),
+            InformationElement::Mbms2g3gIndicator(i) => i.len(),
+            InformationElement::EnhancedNsapi(i) => i.len(),
+            InformationElement::MbmsSessionDuration(i) => i.len(),
+            InformationElement::AdditionalMbmsTraceInfo(i) => i.len(),
+            InformationElement::MbmsSessionRepetitionNumber(i) => i.len(),
+            InformationElement::MbmsTimeToDataTransfer(i) => i.len(),
+            InformationElement::RequiredMbmsBearerCapabilities(i) => i.len(),
+            InformationElement::MbmsFlowIdentifier(i) => i.len(),
+            InformationElement::MbmsIpMulticastDistribution(i) => i.len(),
+            InformationElement::MbmsDistributionAck(i) => i.len(),
+            InformationElement::PrivateExtension(i) => i.len(),
+            InformationElement::Unknown(i) => i.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn encoder(message: Vec<InformationElement>, buffer: &mut Vec<u8>) {
+        for i in message.into_iter() {
+            i.marshal(buffer);
+        }
+    }
+
+    pub fn decoder(buffer: &[u8]) -> Result<Vec<InformationElement>, GTPV1Error> {
+        let mut ies: Vec<InformationElement> = vec![];
+        let mut cursor: usize = 0;
+        loop {
+            if cursor >= buffer.len() {
+                break;
+            }
+            let ie = match buffer[cursor] {
+                CAUSE => InformationElement::Cause(Cause::unmarshal(&buffer[cursor..])?),
+                IMSI => InformationElement::Imsi(Imsi::unmarshal(&buffer[cursor..])?),
+                RAI => InformationElement::Rai(Rai::unmarshal(&buffer[cursor..])?),
+                REORDERING_REQUIRED => InformationElement::ReorderingRequired(
+                    ReorderingRequired::unmarshal(&buffer[cursor..])?,
+                ),
+                RECOVERY => InformationElement::Recovery(Recovery::unmarshal(&buffer[cursor..])?),
+                SELECTION_MODE => {
+                    InformationElement::SelectionMode(SelectionMode::unmarshal(&buffer[cursor..])?)
+                }
+                TEID_DATA | TEID_CONTROL => {
+                    InformationElement::Teid(Teid::unmarshal(&buffer[cursor..])?)
+                }
+                TEARDOWN_IND => {
+                    InformationElement::TeardownInd(TeardownInd::unmarshal(&buffer[cursor..])?)
+                }
+                NSAPI => InformationElement::Nsapi(Nsapi::unmarshal(&buffer[cursor..])?),
+                CHARGING_CHARACTERISTICS => InformationElement::ChargingCharacteristics(
+                    ChargingCharacteristics::unmarshal(&buffer[cursor..])?,
+                ),
+                TRACE_REFERENCE => InformationElement::TraceReference(TraceReference::unmarshal(
+                    &buffer[cursor..],
+                )?),
+                TRACE_TYPE => {
+                    InformationElement::TraceType(TraceType::unmarshal(&buffer[cursor..])?)
+                }
+                CHARGING_ID => {
+                    InformationElement::ChargingID(ChargingID::unmarshal(&buffer[cursor..])?)
+                }
+                END_USER_ADDRESS => InformationElement::EndUserAddress(EndUserAddress::unmarshal(
+                    &buffer[cursor..],
+                )?),
+                APN => InformationElement::Apn(Apn::unmarshal(&buffer[cursor..])?),
+                PCO => InformationElement::Pco(Pco::unmarshal(&buffer[cursor..])?),
+                GSN_ADDRESS => {
+                    InformationElement::GsnAddress(GsnAddress::unmarshal(&buffer[cursor..])?)
+                }
+                MSISDN => InformationElement::Msisdn(Msisdn::unmarshal(&buffer[cursor..])?),
+                QOS => InformationElement::Qos(Qos::unmarshal(&buffer[cursor..])?),
+                TFT => InformationElement::Tft(Tft::unmarshal(&buffer[cursor..])?),
+                EXTENSION_HEADER_TYPE_LIST => InformationElement::ExtensionHeaderTypeList(
+                    ExtensionHeaderTypeList::unmarshal(&buffer[cursor..])?,
+                ),
+                TRIGGERID => {
+                    InformationElement::TriggerId(TriggerId::unmarshal(&buffer[cursor..])?)
+                }
+                OMCID => InformationElement::OmcId(OmcId::unmarshal(&buffer[cursor..])?),
+                COMMONFLAGS => {
+                    InformationElement::CommonFlags(CommonFlags::unmarshal(&buffer[cursor..])?)
+                }
+                APNRESTRICTION => InformationElement::ApnRestriction(ApnRestriction::unmarshal(
+                    &buffer[cursor..],
+                )?),
+                RATTYPE => InformationElement::RatType(RatType::unmarshal(&buffer[cursor..])?),
+                ULI => InformationElement::Uli(Uli::unmarshal(&buffer[cursor..])?),
+                MSTIMEZONETYPE => {
+                    InformationElement::MsTimeZone(MsTimeZone::unmarshal(&buffer[cursor..])?)
+                }
+                IMEI => InformationElement::Imei(Imei::unmarshal(&buffer[cursor..])?),
+                CAMELCIC => InformationElement::CamelChargingInfoContainer(
+                    CamelChargingInfoContainer::unmarshal(&buffer[cursor..])?,
+                ),
+                ADDITIONALTRACEINFO => InformationElement::AdditionalTraceInfo(
+                    AdditionalTraceInfo::unmarshal(&buffer[cursor..])?,
+                ),
+                MSINFO_CHANGE => InformationElement::MSInfoChangeReportingAction(
+                    MSInfoChangeReportingAction::unmarshal(&buffer[cursor..])?,
+                ),
+                DTF => InformationElement::DirectTunnelFlags(DirectTunnelFlags::unmarshal(
+                    &buffer[cursor..],
+                )?),
+                CORRELATIONID => {
+                    InformationElement::CorrelationId(CorrelationId::unmarshal(&buffer[cursor..])?)
+                }
+                BEARER_CONTROL_MODE => InformationElement::BearerControlMode(
+                    BearerControlMode::unmarshal(&buffer[cursor..])?,
+                ),
+                EVOLVEDALLOCRETENTIONI => InformationElement::EvolvedAllocationRetentionI(
+                    EvolvedAllocationRetentionI::unmarshal(&buffer[cursor..])?,
+                ),
+                EXTCOMMONFLAGS => InformationElement::ExtendedCommonFlags(
+                    ExtendedCommonFlags::unmarshal(&buffer[cursor..])?,
+                ),
+                UCI => InformationElement::Uci(Uci::unmarshal(&buffer[cursor..])?),
+                CSG_INFO_REPORT => InformationElement::CSGInformationReportingAction(
+                    CSGInformationReportingAction::unmarshal(&buffer[cursor..])?,
+                ),
+                APNAMBR => InformationElement::ApnAmbr(ApnAmbr::unmarshal(&buffer[cursor..])?),
+                GGSN_BACKOFF => InformationElement::GGSNBackOffTime(GGSNBackOffTime::unmarshal(
+                    &buffer[cursor..],
+                )?),
+                SPI => InformationElement::Spi(Spi::unmarshal(&buffer[cursor..])?),
+                ULI_TIMESTAMP => {
+                    InformationElement::UliTimestamp(UliTimestamp::unmarshal(&buffer[cursor..])?)
+                }
+                CNOSE => InformationElement::CnOperatorSelectionEntity(
+                    CnOperatorSelectionEntity::unmarshal(&buffer[cursor..])?,
+                ),
+                EXTCOMMONFLAGS_II => InformationElement::ExtendedCommonFlagsII(
+                    ExtendedCommonFlagsII::unmarshal(&buffer[cursor..])?,
+                ),
+                MUEUT => InformationElement::MappedUeUsageType(MappedUeUsageType::unmarshal(
+                    &buffer[cursor..],
+                )?),
+                UPFSIF => InformationElement::UpFunctionSelectionIndicationFlags(
+                    UpFunctionSelectionIndicationFlags::unmarshal(&buffer[cursor..])?,
+                ),
+                CHARGING_GW_ADDRESS => InformationElement::ChargingGWAddress(
+                    ChargingGWAddress::unmarshal(&buffer[cursor..])?,
+                ),
+                TLLI => InformationElement::Tlli(Tlli::unmarshal(&buffer[cursor..])?),
+                PTMSI => InformationElement::Ptmsi(Ptmsi::unmarshal(&buffer[cursor..])?),
+                PTMSI_SIGNATURE => InformationElement::PtmsiSignature(PtmsiSignature::unmarshal(
+                    &buffer[cursor..],
+                )?),
+                MS_VALIDATED => {
+                    InformationElement::MsValidated(MsValidated::unmarshal(&buffer[cursor..])?)
+                }
+                TEID_DATA_II => {
+                    InformationElement::TeidDataII(TeidDataII::unmarshal(&buffer[cursor..])?)
+                }
+                RAB_CONTEXT => {
+                    InformationElement::RabContext(RabContext::unmarshal(&buffer[cursor..])?)
+                }
+                RADIO_PRIORITY_SMS => InformationElement::RadioPrioritySms(
+                    RadioPrioritySms::unmarshal(&buffer[cursor..])?,
+                ),
+                RADIO_PRIORITY => {
+                    InformationElement::RadioPriority(RadioPriority::unmarshal(&buffer[cursor..])?)
+                }
+                PACKET_FLOW_ID => {
+                    InformationElement::PacketFlowId(PacketFlowId::unmarshal(&buffer[cursor..])?)
+                }
+                MM_CONTEXT => {
+                    InformationElement::MmContext(MmContext::unmarshal(&buffer[cursor..])?)
+                }
+                PDP_CONTEXT => {
+                    InformationElement::PdpContext(PdpContext::unmarshal(&buffer[cursor..])?)
+                }
+                RADIO_PRIORITY_LCS => InformationElement::RadioPriorityLcs(
+                    RadioPriorityLcs::unmarshal(&buffer[cursor..])?,
+                ),
+                HOP_COUNTER => {
+                    InformationElement::HopCounter(HopCounter::unmarshal(&buffer[cursor..])?)
+                }
+                RANAP_CAUSE => {
+                    InformationElement::RanapCause(RanapCause::unmarshal(&buffer[cursor..])?)
+                }
+                TARGET_ID => InformationElement::TargetIdentification(
+                    TargetIdentification::unmarshal(&buffer[cursor..])?,
+                ),
+                UTRAN_CONTAINER => InformationElement::UtranTransparentContainer(
+                    UtranTransparentContainer::unmarshal(&buffer[cursor..])?,
+                ),
+                RAB_SETUP_INFO => {
+                    InformationElement::RabSetupInfo(RabSetupInfo::unmarshal(&buffer[cursor..])?)
+                }
+                SOURCE_RNC_PDCP_CTX_INFO => InformationElement::SourceRncPdcpContextInfo(
+                    SourceRncPdcpContextInfo::unmarshal(&buffer[cursor..])?,
+                ),
+                BSS_CONTAINER => {
+                    InformationElement::BssContainer(BssContainer::unmarshal(&buffer[cursor..])?)
+                }
+                MAP_CAUSE => InformationElement::MapCause(MapCause::unmarshal(&buffer[cursor..])?),
+                MS_NOT_REACHABLE_REASON => InformationElement::MsNotReachableReason(
+                    MsNotReachableReason::unmarshal(&buffer[cursor..])?,
+                ),
+                TMGI => InformationElement::Tmgi(Tmgi::unmarshal(&buffer[cursor..])?),
+                MBMS_UE_CONTEXT => {
+                    InformationElement::MbmsUeContext(MbmsUeContext::unmarshal(&buffer[cursor..])?)
+                }
+                RIM_ROUTING_ADDRESS => InformationElement::RimRoutingAddress(
+                    RimRoutingAddress::unmarshal(&buffer[cursor..])?,
+                ),
+                MBMS_PCO => InformationElement::MbmsPco(MbmsPco::unmarshal(&buffer[cursor..])?),
+                MBMS_SERVICE_AREA => InformationElement::MbmsServiceArea(
+                    MbmsServiceArea::unmarshal(&buffer[cursor..])?,
+                ),
+                MBMS_SESSION_ID => InformationElement::MbmsSessionIdentifier(
+                    MbmsSessionIdentifier::unmarshal(&buffer[cursor..])?,
+                ),
+                MBMS_2G3G_INDICATOR => InformationElement::Mbms2g3gIndicator(
+                    Mbms2g3gIndicator::unmarshal(&buffer[cursor..])?,
+                ),
+                ENHANCED_NSAPI => {
+                    InformationElement::EnhancedNsapi(EnhancedNsapi::unmarshal(&buffer[cursor..])?)
+                }
+                MBMS_SESSION_DURATION => InformationElement::MbmsSessionDuration(
+                    MbmsSessionDuration::unmarshal(&buffer[cursor..])?,
+                ),
+                ADDITIONAL_MBMS_TRACE_INFO => InformationElement::AdditionalMbmsTraceInfo(
+                    AdditionalMbmsTraceInfo::unmarshal(&buffer[cursor..])?,
+                ),
+                MBMS_SESSION_REPETITION_NUMBER => InformationElement::MbmsSessionRepetitionNumber(
+                    MbmsSessionRepetitionNumber::unmarshal(&buffer[cursor..])?,
+                ),
+                MBMS_TIME_TO_DATA_TRANSFER => InformationElement::MbmsTimeToDataTransfer(
+                    MbmsTimeToDataTransfer::unmarshal(&buffer[cursor..])?,
+                ),
+                REQUIRED_MBMS_BEARER_CAPS => InformationElement::RequiredMbmsBearerCapabilities(
+                    RequiredMbmsBearerCapabilities::unmarshal(&buffer[cursor..])?,
+                ),
+                MBMS_FLOW_ID => InformationElement::MbmsFlowIdentifier(
+                    MbmsFlowIdentifier::unmarshal(&buffer[cursor..])?,
+                ),
+                MBMS_IP_MULTICAST => InformationElement::MbmsIpMulticastDistribution(
+                    MbmsIpMulticastDistribution::unmarshal(&buffer[cursor..])?,
+                ),
+                MBMS_DISTRIBUTION_ACK => InformationElement::MbmsDistributionAck(
+                    MbmsDistributionAck::unmarshal(&buffer[cursor..])?,
+                ),
+                PRIVATE_EXTENSION => InformationElement::PrivateExtension(
+                    PrivateExtension::unmarshal(&buffer[cursor..])?,
+                ),
+                _ => InformationElement::Unknown(UnknownIe::unmarshal(&buffer[cursor..])?),
+            };
+            if ie.is_empty() {
+                return Err(GTPV1Error::IEInvalidLength);
+            }
+            cursor += ie.len();
+            ies.push(ie);
+        }
+        Ok(ies)
+    }
+}
+
+#[test]
+fn ie_decoder_encoder_test() {
+    let encoded: [u8; 163] = [
+        0x02, 0x09, 0x41, 0x50, 0x01, 0x71, 0x44, 0x45, 0xf6, 0x03, 0x13, 0x00, 0x62, 0xff, 0xfe,
+        0xff, 0x0e, 0x0e, 0x0f, 0xfc, 0x10, 0x00, 0x04, 0x72, 0xd5, 0x11, 0xd7, 0x08, 0x61, 0x02,
+        0x14, 0x05, 0x80, 0x00, 0x02, 0xf1, 0x21, 0x83, 0x00, 0x0d, 0x03, 0x69, 0x6f, 0x74, 0x04,
+        0x31, 0x6e, 0x63, 0x65, 0x03, 0x6e, 0x65, 0x74, 0x84, 0x00, 0x20, 0x80, 0x80, 0x21, 0x10,
+        0x01, 0x00, 0x00, 0x10, 0x81, 0x06, 0x00, 0x00, 0x00, 0x00, 0x83, 0x06, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x05, 0x00, 0x00, 0x11, 0x00, 0x85, 0x00,
+        0x04, 0xac, 0x39, 0x2b, 0xcc, 0x85, 0x00, 0x04, 0xac, 0x39, 0x2b, 0xcc, 0x86, 0x00, 0x09,
+        0x91, 0x88, 0x22, 0x58, 0x01, 0x71, 0x44, 0x45, 0xf6, 0x87, 0x00, 0x0f, 0x03, 0x1b, 0x63,
+        0x1f, 0x73, 0x96, 0x73, 0x73, 0x74, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x97, 0x00, 0x01,
+        0x02, 0x98, 0x00, 0x08, 0x00, 0x13, 0x00, 0x62, 0x53, 0x17, 0x04, 0x27, 0x99, 0x00, 0x02,
+        0x00, 0x00, 0x9a, 0x00, 0x08, 0x68, 0x99, 0x15, 0x30, 0x91, 0x64, 0x10, 0x10,
+    ];
+    let ies = InformationElement::decoder(&encoded).unwrap();
+    assert_eq!(ies.len(), 18);
+    assert!(matches!(ies[0], InformationElement::Imsi(_)));
+    assert!(matches!(
+        ies[4],
+        InformationElement::Teid(Teid { t: TEID_DATA, .. })
+    ));
+    assert!(matches!(
+        ies[5],
+        InformationElement::Teid(Teid {
+            t: TEID_CONTROL,
+            ..
+        })
+    ));
+    assert!(matches!(ies[17], InformationElement::Imei(_)));
+    let mut buffer: Vec<u8> = vec![];
+    InformationElement::encoder(ies, &mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn ie_decoder_unknown_test() {
+    let mut encoded: Vec<u8> = vec![0x0e, 0x05, 0x09];
+    encoded.extend_from_slice(&[0xc0; 28]);
+    encoded.extend_from_slice(&[0xfe, 0x00, 0x02, 0xaa, 0xbb, 0x14, 0x05]);
+    let ies = InformationElement::decoder(&encoded).unwrap();
+    assert_eq!(
+        ies,
+        vec![
+            InformationElement::Recovery(Recovery {
+                t: RECOVERY,
+                value: 5
+            }),
+            InformationElement::Unknown(UnknownIe {
+                t: 0x09,
+                length: 28,
+                value: vec![0xc0; 28],
+            }),
+            InformationElement::Unknown(UnknownIe {
+                t: 0xfe,
+                length: 2,
+                value: vec![0xaa, 0xbb],
+            }),
+            InformationElement::Nsapi(Nsapi { t: NSAPI, value: 5 }),
+        ]
+    );
+    let mut buffer: Vec<u8> = vec![];
+    InformationElement::encoder(ies, &mut buffer);
+    assert_eq!(buffer, encoded);
+    assert_eq!(
+        InformationElement::decoder(&[0x06, 0x00, 0x00]),
+        Err(GTPV1Error::IEIncorrect)
+    );
+}
+
+#[test]
+fn ie_decoder_extension_header_type_list_length_test() {
+    assert_eq!(
+        InformationElement::decoder(&[0x8d, 0xfe, 0x00]),
+        Err(GTPV1Error::IEInvalidLength)
+    );
+    assert_eq!(
+        InformationElement::decoder(&[0x8d, 0x00, 0x01, 0x07]).unwrap(),
+        vec![
+            InformationElement::ExtensionHeaderTypeList(ExtensionHeaderTypeList::default()),
+            InformationElement::Cause(Cause { t: CAUSE, value: 7 }),
+        ]
+    );
+}
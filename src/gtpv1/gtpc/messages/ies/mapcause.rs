@@ -0,0 +1,75 @@
+// MAP Cause IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*};
+
+// MAP Cause IE TL
+
+pub const MAP_CAUSE: u8 = 11;
+pub const MAP_CAUSE_LENGTH: usize = 1;
+
+// MAP Cause IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapCause {
+    pub t: u8,
+    pub value: u8,
+}
+
+impl Default for MapCause {
+    fn default() -> Self {
+        MapCause {
+            t: MAP_CAUSE,
+            value: 0,
+        }
+    }
+}
+
+impl IEs for MapCause {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.push(self.value);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() > MAP_CAUSE_LENGTH {
+            Ok(MapCause {
+                value: buffer[1],
+                ..Default::default()
+            })
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        MAP_CAUSE_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn map_cause_ie_marshal_test() {
+    let ie_to_marshal = MapCause {
+        t: MAP_CAUSE,
+        value: 34,
+    };
+    let ie_marshalled: [u8; 2] = [0x0b, 0x22];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn map_cause_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 2] = [0x0b, 0x22];
+    let ie_unmarshalled = MapCause {
+        t: MAP_CAUSE,
+        value: 34,
+    };
+    assert_eq!(
+        MapCause::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
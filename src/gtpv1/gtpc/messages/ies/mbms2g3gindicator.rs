@@ -0,0 +1,95 @@
+// MBMS 2G/3G Indicator IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+
+// MBMS 2G/3G Indicator IE Type
+
+pub const MBMS_2G3G_INDICATOR: u8 = 166;
+pub const MBMS_2G3G_INDICATOR_LENGTH: u16 = 1;
+
+// MBMS 2G/3G Indicator IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mbms2g3gIndicator {
+    pub t: u8,
+    pub length: u16,
+    pub value: u8, // 0 = 2G only, 1 = 3G only, 2 = both 2G and 3G
+}
+
+impl Default for Mbms2g3gIndicator {
+    fn default() -> Self {
+        Mbms2g3gIndicator {
+            t: MBMS_2G3G_INDICATOR,
+            length: MBMS_2G3G_INDICATOR_LENGTH,
+            value: 0,
+        }
+    }
+}
+
+impl IEs for Mbms2g3gIndicator {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.push(self.value);
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() >= 3 {
+            let data = Mbms2g3gIndicator {
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if check_tlv_ie_buffer(data.length, buffer) && data.length >= MBMS_2G3G_INDICATOR_LENGTH
+            {
+                Ok(Mbms2g3gIndicator {
+                    value: buffer[3],
+                    ..data
+                })
+            } else {
+                Err(GTPV1Error::IEInvalidLength)
+            }
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn mbms_2g3g_indicator_ie_marshal_test() {
+    let ie_marshalled: [u8; 4] = [0xa6, 0x00, 0x01, 0x02];
+    let ie_to_marshal = Mbms2g3gIndicator {
+        t: MBMS_2G3G_INDICATOR,
+        length: MBMS_2G3G_INDICATOR_LENGTH,
+        value: 2,
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn mbms_2g3g_indicator_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 4] = [0xa6, 0x00, 0x01, 0x02];
+    let ie_unmarshalled = Mbms2g3gIndicator {
+        t: MBMS_2G3G_INDICATOR,
+        length: MBMS_2G3G_INDICATOR_LENGTH,
+        value: 2,
+    };
+    assert_eq!(
+        Mbms2g3gIndicator::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
@@ -0,0 +1,96 @@
+// MBMS Distribution Acknowledgement IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+
+// MBMS Distribution Acknowledgement IE Type
+
+pub const MBMS_DISTRIBUTION_ACK: u8 = 187;
+pub const MBMS_DISTRIBUTION_ACK_LENGTH: u16 = 1;
+
+// MBMS Distribution Acknowledgement IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MbmsDistributionAck {
+    pub t: u8,
+    pub length: u16,
+    pub distribution_ind: u8, // 2 bits, 0 = no RNC, 1 = all RNCs, 2 = some RNCs accepted IP multicast
+}
+
+impl Default for MbmsDistributionAck {
+    fn default() -> Self {
+        MbmsDistributionAck {
+            t: MBMS_DISTRIBUTION_ACK,
+            length: MBMS_DISTRIBUTION_ACK_LENGTH,
+            distribution_ind: 0,
+        }
+    }
+}
+
+impl IEs for MbmsDistributionAck {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.push(self.distribution_ind & 0x03);
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() >= 3 {
+            let data = MbmsDistributionAck {
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if check_tlv_ie_buffer(data.length, buffer)
+                && data.length >= MBMS_DISTRIBUTION_ACK_LENGTH
+            {
+                Ok(MbmsDistributionAck {
+                    distribution_ind: buffer[3] & 0x03,
+                    ..data
+                })
+            } else {
+                Err(GTPV1Error::IEInvalidLength)
+            }
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn mbms_distribution_ack_ie_marshal_test() {
+    let ie_marshalled: [u8; 4] = [0xbb, 0x00, 0x01, 0x01];
+    let ie_to_marshal = MbmsDistributionAck {
+        t: MBMS_DISTRIBUTION_ACK,
+        length: MBMS_DISTRIBUTION_ACK_LENGTH,
+        distribution_ind: 1,
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn mbms_distribution_ack_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 4] = [0xbb, 0x00, 0x01, 0x01];
+    let ie_unmarshalled = MbmsDistributionAck {
+        t: MBMS_DISTRIBUTION_ACK,
+        length: MBMS_DISTRIBUTION_ACK_LENGTH,
+        distribution_ind: 1,
+    };
+    assert_eq!(
+        MbmsDistributionAck::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
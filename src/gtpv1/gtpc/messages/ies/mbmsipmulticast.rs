@@ -0,0 +1,156 @@
+// MBMS IP Multicast Distribution IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{
+    errors::GTPV1Error,
+    gtpc::messages::ies::{commons::*, pdpcontext::unmarshal_address},
+    utils::*,
+};
+use std::net::{IpAddr, Ipv4Addr};
+
+// MBMS IP Multicast Distribution IE Type
+
+pub const MBMS_IP_MULTICAST: u8 = 186;
+
+// MBMS IP Multicast Distribution IE implementation
+// Addresses are preceded by an octet carrying the address type (bits 8-7) and the address length (bits 6-1)
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MbmsIpMulticastDistribution {
+    pub t: u8,
+    pub length: u16,
+    pub common_teid: u32,
+    pub distribution_address: IpAddr,
+    pub source_address: IpAddr,
+    pub hc_indicator: u8,
+}
+
+impl Default for MbmsIpMulticastDistribution {
+    fn default() -> Self {
+        MbmsIpMulticastDistribution {
+            t: MBMS_IP_MULTICAST,
+            length: 0,
+            common_teid: 0,
+            distribution_address: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+            source_address: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+            hc_indicator: 0,
+        }
+    }
+}
+
+fn marshal_typed_address(address: &IpAddr, buffer: &mut Vec<u8>) {
+    match address {
+        IpAddr::V4(i) => {
+            buffer.push(4);
+            buffer.extend_from_slice(&i.octets());
+        }
+        IpAddr::V6(i) => {
+            buffer.push(0x40 | 16);
+            buffer.extend_from_slice(&i.octets());
+        }
+    }
+}
+
+fn unmarshal_typed_address(buffer: &[u8], cursor: &mut usize) -> Result<IpAddr, GTPV1Error> {
+    let octet = read_slice(buffer, cursor, 1)?[0];
+    let address = unmarshal_address(read_slice(buffer, cursor, (octet & 0x3f) as usize)?)?;
+    match (octet >> 6, address) {
+        (0, IpAddr::V4(_)) | (1, IpAddr::V6(_)) => Ok(address),
+        _ => Err(GTPV1Error::IEIncorrect),
+    }
+}
+
+impl IEs for MbmsIpMulticastDistribution {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.extend_from_slice(&self.common_teid.to_be_bytes());
+        marshal_typed_address(&self.distribution_address, &mut buffer_ie);
+        marshal_typed_address(&self.source_address, &mut buffer_ie);
+        buffer_ie.push(self.hc_indicator);
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() < 3 {
+            return Err(GTPV1Error::IEInvalidLength);
+        }
+        let mut data = MbmsIpMulticastDistribution {
+            length: u16::from_be_bytes([buffer[1], buffer[2]]),
+            ..Default::default()
+        };
+        if !check_tlv_ie_buffer(data.length, buffer) {
+            return Err(GTPV1Error::IEInvalidLength);
+        }
+        let value = &buffer[3..(data.length + 3) as usize];
+        let mut cursor = 0;
+        let octets = read_slice(value, &mut cursor, 4)?;
+        data.common_teid = u32::from_be_bytes([octets[0], octets[1], octets[2], octets[3]]);
+        data.distribution_address = unmarshal_typed_address(value, &mut cursor)?;
+        data.source_address = unmarshal_typed_address(value, &mut cursor)?;
+        data.hc_indicator = read_slice(value, &mut cursor, 1)?[0];
+        Ok(data)
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn mbms_ip_multicast_ie_marshal_test() {
+    let ie_marshalled: [u8; 18] = [
+        0xba, 0x00, 0x0f, 0x00, 0x00, 0x10, 0x01, 0x04, 0xef, 0x00, 0x00, 0x01, 0x04, 0x0a, 0x00,
+        0x00, 0x01, 0x00,
+    ];
+    let ie_to_marshal = MbmsIpMulticastDistribution {
+        t: MBMS_IP_MULTICAST,
+        length: 15,
+        common_teid: 0x1001,
+        distribution_address: IpAddr::V4(Ipv4Addr::new(239, 0, 0, 1)),
+        source_address: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+        hc_indicator: 0,
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn mbms_ip_multicast_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 18] = [
+        0xba, 0x00, 0x0f, 0x00, 0x00, 0x10, 0x01, 0x04, 0xef, 0x00, 0x00, 0x01, 0x04, 0x0a, 0x00,
+        0x00, 0x01, 0x00,
+    ];
+    let ie_unmarshalled = MbmsIpMulticastDistribution {
+        t: MBMS_IP_MULTICAST,
+        length: 15,
+        common_teid: 0x1001,
+        distribution_address: IpAddr::V4(Ipv4Addr::new(239, 0, 0, 1)),
+        source_address: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+        hc_indicator: 0,
+    };
+    assert_eq!(
+        MbmsIpMulticastDistribution::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
+
+#[test]
+fn mbms_ip_multicast_ie_wrong_address_type_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 18] = [
+        0xba, 0x00, 0x0f, 0x00, 0x00, 0x10, 0x01, 0x44, 0xef, 0x00, 0x00, 0x01, 0x04, 0x0a, 0x00,
+        0x00, 0x01, 0x00,
+    ];
+    assert_eq!(
+        MbmsIpMulticastDistribution::unmarshal(&ie_to_unmarshal),
+        Err(GTPV1Error::IEIncorrect)
+    );
+}
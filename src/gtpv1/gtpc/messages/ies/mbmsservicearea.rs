@@ -0,0 +1,111 @@
+// MBMS Service Area IE - according to 3GPP TS 29.060 V15.5.0 (2019-06) and 3GPP TS 29.061
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+
+// MBMS Service Area IE Type
+
+pub const MBMS_SERVICE_AREA: u8 = 160;
+
+// MBMS Service Area IE implementation
+// The first octet holds the number of MBMS Service Area Codes minus one, so between 1 and 256 codes are carried
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MbmsServiceArea {
+    pub t: u8,
+    pub length: u16,
+    pub service_areas: Vec<u16>,
+}
+
+impl Default for MbmsServiceArea {
+    fn default() -> Self {
+        MbmsServiceArea {
+            t: MBMS_SERVICE_AREA,
+            length: 3,
+            service_areas: vec![0],
+        }
+    }
+}
+
+impl IEs for MbmsServiceArea {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.push((self.service_areas.len().clamp(1, 256) - 1) as u8);
+        for i in self.service_areas.iter().take(256) {
+            buffer_ie.extend_from_slice(&i.to_be_bytes());
+        }
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() >= 3 {
+            let mut data = MbmsServiceArea {
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                service_areas: vec![],
+                ..Default::default()
+            };
+            if !check_tlv_ie_buffer(data.length, buffer) || data.length == 0 {
+                return Err(GTPV1Error::IEInvalidLength);
+            }
+            let count = buffer[3] as usize + 1;
+            if data.length as usize != 1 + count * 2 {
+                return Err(GTPV1Error::IEIncorrect);
+            }
+            data.service_areas = buffer[4..4 + count * 2]
+                .chunks_exact(2)
+                .map(|x| u16::from_be_bytes([x[0], x[1]]))
+                .collect();
+            Ok(data)
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn mbms_service_area_ie_marshal_test() {
+    let ie_marshalled: [u8; 8] = [0xa0, 0x00, 0x05, 0x01, 0x00, 0x01, 0x00, 0x02];
+    let ie_to_marshal = MbmsServiceArea {
+        t: MBMS_SERVICE_AREA,
+        length: 5,
+        service_areas: vec![1, 2],
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn mbms_service_area_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 8] = [0xa0, 0x00, 0x05, 0x01, 0x00, 0x01, 0x00, 0x02];
+    let ie_unmarshalled = MbmsServiceArea {
+        t: MBMS_SERVICE_AREA,
+        length: 5,
+        service_areas: vec![1, 2],
+    };
+    assert_eq!(
+        MbmsServiceArea::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
+
+#[test]
+fn mbms_service_area_ie_wrong_count_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 8] = [0xa0, 0x00, 0x05, 0x02, 0x00, 0x01, 0x00, 0x02];
+    assert_eq!(
+        MbmsServiceArea::unmarshal(&ie_to_unmarshal),
+        Err(GTPV1Error::IEIncorrect)
+    );
+}
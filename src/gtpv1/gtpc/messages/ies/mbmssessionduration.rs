@@ -0,0 +1,100 @@
+// MBMS Session Duration IE - according to 3GPP TS 29.060 V15.5.0 (2019-06) and 3GPP TS 29.061
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+
+// MBMS Session Duration IE Type
+
+pub const MBMS_SESSION_DURATION: u8 = 168;
+pub const MBMS_SESSION_DURATION_LENGTH: u16 = 3;
+
+// MBMS Session Duration IE implementation
+// The duration is coded as 17 bits of seconds followed by 7 bits of days
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MbmsSessionDuration {
+    pub t: u8,
+    pub length: u16,
+    pub seconds: u32, // 17 bits
+    pub days: u8,     // 7 bits
+}
+
+impl Default for MbmsSessionDuration {
+    fn default() -> Self {
+        MbmsSessionDuration {
+            t: MBMS_SESSION_DURATION,
+            length: MBMS_SESSION_DURATION_LENGTH,
+            seconds: 0,
+            days: 0,
+        }
+    }
+}
+
+impl IEs for MbmsSessionDuration {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        let value = ((self.seconds & 0x1ffff) << 7) | (self.days & 0x7f) as u32;
+        buffer_ie.extend_from_slice(&value.to_be_bytes()[1..]);
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() >= 3 {
+            let mut data = MbmsSessionDuration {
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if check_tlv_ie_buffer(data.length, buffer)
+                && data.length >= MBMS_SESSION_DURATION_LENGTH
+            {
+                let value = u32::from_be_bytes([0x00, buffer[3], buffer[4], buffer[5]]);
+                data.seconds = value >> 7;
+                data.days = (value & 0x7f) as u8;
+                Ok(data)
+            } else {
+                Err(GTPV1Error::IEInvalidLength)
+            }
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn mbms_session_duration_ie_marshal_test() {
+    let ie_marshalled: [u8; 6] = [0xa8, 0x00, 0x03, 0x01, 0xc2, 0x01];
+    let ie_to_marshal = MbmsSessionDuration {
+        seconds: 900,
+        days: 1,
+        ..Default::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn mbms_session_duration_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 6] = [0xa8, 0x00, 0x03, 0x01, 0xc2, 0x01];
+    let ie_unmarshalled = MbmsSessionDuration {
+        seconds: 900,
+        days: 1,
+        ..Default::default()
+    };
+    assert_eq!(
+        MbmsSessionDuration::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
@@ -0,0 +1,168 @@
+// MBMS UE Context IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{
+    errors::GTPV1Error,
+    gtpc::messages::ies::{commons::*, pdpcontext::*},
+    utils::*,
+};
+use std::net::{IpAddr, Ipv4Addr};
+
+// MBMS UE Context IE Type
+
+pub const MBMS_UE_CONTEXT: u8 = 156;
+
+// MBMS UE Context IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MbmsUeContext {
+    pub t: u8,
+    pub length: u16,
+    pub linked_nsapi: u8,
+    pub uplink_teid_control: u32,
+    pub enhanced_nsapi: u8,
+    pub pdp_type_org: u8,
+    pub pdp_type_number: u8,
+    pub pdp_address: Vec<u8>,
+    pub ggsn_address_control: IpAddr,
+    pub apn: String,
+    pub transaction_id: u16, // 12 bits
+    pub extensions: Vec<u8>, // Any later fields are kept as received
+}
+
+impl Default for MbmsUeContext {
+    fn default() -> Self {
+        MbmsUeContext {
+            t: MBMS_UE_CONTEXT,
+            length: 0,
+            linked_nsapi: 0,
+            uplink_teid_control: 0,
+            enhanced_nsapi: 0,
+            pdp_type_org: 1,
+            pdp_type_number: IPV4,
+            pdp_address: vec![],
+            ggsn_address_control: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+            apn: "".to_string(),
+            transaction_id: 0,
+            extensions: vec![],
+        }
+    }
+}
+
+impl IEs for MbmsUeContext {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.push(self.linked_nsapi << 4);
+        buffer_ie.extend_from_slice(&self.uplink_teid_control.to_be_bytes());
+        buffer_ie.push(self.enhanced_nsapi);
+        buffer_ie.push(0xf0 | (self.pdp_type_org & 0x0f));
+        buffer_ie.push(self.pdp_type_number);
+        buffer_ie.push(self.pdp_address.len() as u8);
+        buffer_ie.extend_from_slice(&self.pdp_address);
+        match self.ggsn_address_control {
+            IpAddr::V4(i) => {
+                buffer_ie.push(4);
+                buffer_ie.extend_from_slice(&i.octets());
+            }
+            IpAddr::V6(i) => {
+                buffer_ie.push(16);
+                buffer_ie.extend_from_slice(&i.octets());
+            }
+        }
+        let mut apn = marshal_apn(&self.apn);
+        buffer_ie.push(apn.len() as u8);
+        buffer_ie.append(&mut apn);
+        buffer_ie.extend_from_slice(&(0xf000 | (self.transaction_id & 0x0fff)).to_be_bytes());
+        buffer_ie.extend_from_slice(&self.extensions);
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() < 3 {
+            return Err(GTPV1Error::IEInvalidLength);
+        }
+        let mut data = MbmsUeContext {
+            length: u16::from_be_bytes([buffer[1], buffer[2]]),
+            ..Default::default()
+        };
+        if !check_tlv_ie_buffer(data.length, buffer) {
+            return Err(GTPV1Error::IEInvalidLength);
+        }
+        let value = &buffer[3..(data.length + 3) as usize];
+        let mut cursor = 0;
+        let octets = read_slice(value, &mut cursor, 9)?;
+        data.linked_nsapi = octets[0] >> 4;
+        data.uplink_teid_control = u32::from_be_bytes([octets[1], octets[2], octets[3], octets[4]]);
+        data.enhanced_nsapi = octets[5];
+        data.pdp_type_org = octets[6] & 0x0f;
+        data.pdp_type_number = octets[7];
+        data.pdp_address = read_slice(value, &mut cursor, octets[8] as usize)?.to_vec();
+        let len = read_slice(value, &mut cursor, 1)?[0] as usize;
+        data.ggsn_address_control = unmarshal_address(read_slice(value, &mut cursor, len)?)?;
+        let len = read_slice(value, &mut cursor, 1)?[0] as usize;
+        data.apn = unmarshal_apn(read_slice(value, &mut cursor, len)?)?;
+        let octets = read_slice(value, &mut cursor, 2)?;
+        data.transaction_id = u16::from_be_bytes([octets[0], octets[1]]) & 0x0fff;
+        data.extensions = value[cursor..].to_vec();
+        Ok(data)
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[cfg(test)]
+const MBMS_UE_CONTEXT_TEST_IE: [u8; 37] = [
+    0x9c, 0x00, 0x22, 0x50, 0x00, 0x00, 0x10, 0x01, 0x80, 0xf1, 0x21, 0x04, 0xef, 0x00, 0x00, 0x01,
+    0x04, 0xc0, 0xa8, 0x00, 0x01, 0x0d, 0x04, 0x74, 0x65, 0x73, 0x74, 0x03, 0x6e, 0x65, 0x74, 0x03,
+    0x63, 0x6f, 0x6d, 0xf0, 0x02,
+];
+
+#[cfg(test)]
+fn mbms_ue_context_test_ie() -> MbmsUeContext {
+    MbmsUeContext {
+        length: 0x22,
+        linked_nsapi: 5,
+        uplink_teid_control: 0x1001,
+        enhanced_nsapi: 0x80,
+        pdp_address: vec![0xef, 0x00, 0x00, 0x01],
+        ggsn_address_control: IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)),
+        apn: "test.net.com".to_string(),
+        transaction_id: 2,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn mbms_ue_context_ie_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    mbms_ue_context_test_ie().marshal(&mut buffer);
+    assert_eq!(buffer, MBMS_UE_CONTEXT_TEST_IE);
+}
+
+#[test]
+fn mbms_ue_context_ie_unmarshal_test() {
+    assert_eq!(
+        MbmsUeContext::unmarshal(&MBMS_UE_CONTEXT_TEST_IE).unwrap(),
+        mbms_ue_context_test_ie()
+    );
+}
+
+#[test]
+fn mbms_ue_context_ie_truncated_unmarshal_test() {
+    let mut encoded = MBMS_UE_CONTEXT_TEST_IE[..30].to_vec();
+    encoded[2] = 27;
+    assert_eq!(
+        MbmsUeContext::unmarshal(&encoded),
+        Err(GTPV1Error::IEInvalidLength)
+    );
+}
@@ -0,0 +1,504 @@
+// MM Context IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+
+// MM Context IE Type
+
+pub const MM_CONTEXT: u8 = 129;
+
+// Security Mode values
+
+pub const SECURITY_MODE_USED_CIPHER_UMTS_KEY_QUINTUPLETS: u8 = 0;
+pub const SECURITY_MODE_GSM_KEY_TRIPLETS: u8 = 1;
+pub const SECURITY_MODE_UMTS_KEY_QUINTUPLETS: u8 = 2;
+pub const SECURITY_MODE_GSM_KEY_QUINTUPLETS: u8 = 3;
+
+pub const TRIPLET_LENGTH: usize = 28;
+
+// Authentication Triplet - RAND, SRES and Kc
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Triplet {
+    pub rand: [u8; 16],
+    pub sres: [u8; 4],
+    pub kc: [u8; 8],
+}
+
+impl Triplet {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.rand);
+        buffer.extend_from_slice(&self.sres);
+        buffer.extend_from_slice(&self.kc);
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() >= TRIPLET_LENGTH {
+            let mut data = Triplet::default();
+            data.rand.copy_from_slice(&buffer[..16]);
+            data.sres.copy_from_slice(&buffer[16..20]);
+            data.kc.copy_from_slice(&buffer[20..28]);
+            Ok(data)
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+}
+
+// Authentication Quintuplet - RAND, XRES, CK, IK and AUTN
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Quintuplet {
+    pub rand: [u8; 16],
+    pub xres: Vec<u8>,
+    pub ck: [u8; 16],
+    pub ik: [u8; 16],
+    pub autn: Vec<u8>,
+}
+
+impl Quintuplet {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.rand);
+        buffer.push(self.xres.len() as u8);
+        buffer.extend_from_slice(&self.xres);
+        buffer.extend_from_slice(&self.ck);
+        buffer.extend_from_slice(&self.ik);
+        buffer.push(self.autn.len() as u8);
+        buffer.extend_from_slice(&self.autn);
+    }
+
+    // Returns the decoded Quintuplet and the number of octets consumed
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<(Self, usize), GTPV1Error> {
+        let mut data = Quintuplet::default();
+        let mut cursor = 0;
+        data.rand
+            .copy_from_slice(read_slice(buffer, &mut cursor, 16)?);
+        let len = read_slice(buffer, &mut cursor, 1)?[0] as usize;
+        data.xres = read_slice(buffer, &mut cursor, len)?.to_vec();
+        data.ck
+            .copy_from_slice(read_slice(buffer, &mut cursor, 16)?);
+        data.ik
+            .copy_from_slice(read_slice(buffer, &mut cursor, 16)?);
+        let len = read_slice(buffer, &mut cursor, 1)?[0] as usize;
+        data.autn = read_slice(buffer, &mut cursor, len)?.to_vec();
+        Ok((data, cursor))
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        50 + self.xres.len() + self.autn.len()
+    }
+}
+
+// Security parameters of the MM Context, one variant per Security Mode.
+// The used cipher of the GSM key and Used cipher value modes is carried in octet 5 (MmContext::used_cipher).
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecurityContext {
+    GsmKeyTriplets {
+        kc: [u8; 8],
+        triplets: Vec<Triplet>,
+    },
+    GsmKeyQuintuplets {
+        kc: [u8; 8],
+        quintuplets: Vec<Quintuplet>,
+    },
+    UmtsKeyQuintuplets {
+        ck: [u8; 16],
+        ik: [u8; 16],
+        quintuplets: Vec<Quintuplet>,
+    },
+    UsedCipherUmtsKeyQuintuplets {
+        ck: [u8; 16],
+        ik: [u8; 16],
+        quintuplets: Vec<Quintuplet>,
+    },
+}
+
+impl SecurityContext {
+    pub fn security_mode(&self) -> u8 {
+        match self {
+            SecurityContext::GsmKeyQuintuplets { .. } => SECURITY_MODE_GSM_KEY_QUINTUPLETS,
+            SecurityContext::GsmKeyTriplets { .. } => SECURITY_MODE_GSM_KEY_TRIPLETS,
+            SecurityContext::UmtsKeyQuintuplets { .. } => SECURITY_MODE_UMTS_KEY_QUINTUPLETS,
+            SecurityContext::UsedCipherUmtsKeyQuintuplets { .. } => {
+                SECURITY_MODE_USED_CIPHER_UMTS_KEY_QUINTUPLETS
+            }
+        }
+    }
+
+    pub fn vectors(&self) -> usize {
+        match self {
+            SecurityContext::GsmKeyTriplets { triplets, .. } => triplets.len(),
+            SecurityContext::GsmKeyQuintuplets { quintuplets, .. }
+            | SecurityContext::UmtsKeyQuintuplets { quintuplets, .. }
+            | SecurityContext::UsedCipherUmtsKeyQuintuplets { quintuplets, .. } => {
+                quintuplets.len()
+            }
+        }
+    }
+}
+
+impl Default for SecurityContext {
+    fn default() -> Self {
+        SecurityContext::UmtsKeyQuintuplets {
+            ck: [0; 16],
+            ik: [0; 16],
+            quintuplets: vec![],
+        }
+    }
+}
+
+// MM Context IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MmContext {
+    pub t: u8,
+    pub length: u16,
+    pub cksn: u8,        // CKSN for GSM keys, KSI for UMTS keys
+    pub used_cipher: u8, // Octet 5 bits 3-1, spare (0x07) when UMTS key and quintuplets are used
+    pub security: SecurityContext,
+    pub drx_param: [u8; 2],
+    pub ms_network_capability: Vec<u8>,
+    pub container: Vec<u8>,
+    pub extensions: Vec<u8>, // Access Restriction Data and any later fields are kept as received
+}
+
+impl Default for MmContext {
+    fn default() -> Self {
+        MmContext {
+            t: MM_CONTEXT,
+            length: 0,
+            cksn: 0,
+            used_cipher: 0x07,
+            security: SecurityContext::default(),
+            drx_param: [0; 2],
+            ms_network_capability: vec![],
+            container: vec![],
+            extensions: vec![],
+        }
+    }
+}
+
+impl IEs for MmContext {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.push(0xf8 | (self.cksn & 0x07));
+        buffer_ie.push(
+            (self.security.security_mode() << 6)
+                | ((self.security.vectors().min(7) as u8) << 3)
+                | (self.used_cipher & 0x07),
+        );
+        let mut quintuplets_buffer: Vec<u8> = vec![];
+        match &self.security {
+            SecurityContext::GsmKeyTriplets { kc, triplets } => {
+                buffer_ie.extend_from_slice(kc);
+                triplets.iter().for_each(|x| x.marshal(&mut buffer_ie));
+            }
+            SecurityContext::GsmKeyQuintuplets { kc, quintuplets } => {
+                buffer_ie.extend_from_slice(kc);
+                quintuplets
+                    .iter()
+                    .for_each(|x| x.marshal(&mut quintuplets_buffer));
+            }
+            SecurityContext::UmtsKeyQuintuplets {
+                ck,
+                ik,
+                quintuplets,
+            } => {
+                buffer_ie.extend_from_slice(ck);
+                buffer_ie.extend_from_slice(ik);
+                quintuplets
+                    .iter()
+                    .for_each(|x| x.marshal(&mut quintuplets_buffer));
+            }
+            SecurityContext::UsedCipherUmtsKeyQuintuplets {
+                ck,
+                ik,
+                quintuplets,
+            } => {
+                buffer_ie.extend_from_slice(ck);
+                buffer_ie.extend_from_slice(ik);
+                quintuplets
+                    .iter()
+                    .for_each(|x| x.marshal(&mut quintuplets_buffer));
+            }
+        }
+        if !matches!(self.security, SecurityContext::GsmKeyTriplets { .. }) {
+            buffer_ie.extend_from_slice(&(quintuplets_buffer.len() as u16).to_be_bytes());
+            buffer_ie.append(&mut quintuplets_buffer);
+        }
+        buffer_ie.extend_from_slice(&self.drx_param);
+        buffer_ie.push(self.ms_network_capability.len() as u8);
+        buffer_ie.extend_from_slice(&self.ms_network_capability);
+        buffer_ie.extend_from_slice(&(self.container.len() as u16).to_be_bytes());
+        buffer_ie.extend_from_slice(&self.container);
+        buffer_ie.extend_from_slice(&self.extensions);
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() < 3 {
+            return Err(GTPV1Error::IEInvalidLength);
+        }
+        let mut data = MmContext {
+            length: u16::from_be_bytes([buffer[1], buffer[2]]),
+            ..Default::default()
+        };
+        if !check_tlv_ie_buffer(data.length, buffer) {
+            return Err(GTPV1Error::IEInvalidLength);
+        }
+        let value = &buffer[3..(data.length + 3) as usize];
+        let mut cursor = 0;
+        let octets = read_slice(value, &mut cursor, 2)?;
+        data.cksn = octets[0] & 0x07;
+        data.used_cipher = octets[1] & 0x07;
+        let security_mode = octets[1] >> 6;
+        let vectors = ((octets[1] >> 3) & 0x07) as usize;
+        data.security = match security_mode {
+            SECURITY_MODE_GSM_KEY_TRIPLETS => {
+                let mut kc = [0; 8];
+                kc.copy_from_slice(read_slice(value, &mut cursor, 8)?);
+                let mut triplets = vec![];
+                for _ in 0..vectors {
+                    triplets.push(Triplet::unmarshal(read_slice(
+                        value,
+                        &mut cursor,
+                        TRIPLET_LENGTH,
+                    )?)?);
+                }
+                SecurityContext::GsmKeyTriplets { kc, triplets }
+            }
+            SECURITY_MODE_GSM_KEY_QUINTUPLETS => {
+                let mut kc = [0; 8];
+                kc.copy_from_slice(read_slice(value, &mut cursor, 8)?);
+                let quintuplets = unmarshal_quintuplets(value, &mut cursor)?;
+                SecurityContext::GsmKeyQuintuplets { kc, quintuplets }
+            }
+            SECURITY_MODE_UMTS_KEY_QUINTUPLETS => {
+                let (ck, ik) = unmarshal_umts_keys(value, &mut cursor)?;
+                let quintuplets = unmarshal_quintuplets(value, &mut cursor)?;
+                SecurityContext::UmtsKeyQuintuplets {
+                    ck,
+                    ik,
+                    quintuplets,
+                }
+            }
+            _ => {
+                let (ck, ik) = unmarshal_umts_keys(value, &mut cursor)?;
+                let quintuplets = unmarshal_quintuplets(value, &mut cursor)?;
+                SecurityContext::UsedCipherUmtsKeyQuintuplets {
+                    ck,
+                    ik,
+                    quintuplets,
+                }
+            }
+        };
+        data.drx_param
+            .copy_from_slice(read_slice(value, &mut cursor, 2)?);
+        let len = read_slice(value, &mut cursor, 1)?[0] as usize;
+        data.ms_network_capability = read_slice(value, &mut cursor, len)?.to_vec();
+        let len = read_slice(value, &mut cursor, 2)?;
+        let len = u16::from_be_bytes([len[0], len[1]]) as usize;
+        data.container = read_slice(value, &mut cursor, len)?.to_vec();
+        data.extensions = value[cursor..].to_vec();
+        Ok(data)
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+fn unmarshal_umts_keys(
+    buffer: &[u8],
+    cursor: &mut usize,
+) -> Result<([u8; 16], [u8; 16]), GTPV1Error> {
+    let mut ck = [0; 16];
+    let mut ik = [0; 16];
+    ck.copy_from_slice(read_slice(buffer, cursor, 16)?);
+    ik.copy_from_slice(read_slice(buffer, cursor, 16)?);
+    Ok((ck, ik))
+}
+
+fn unmarshal_quintuplets(buffer: &[u8], cursor: &mut usize) -> Result<Vec<Quintuplet>, GTPV1Error> {
+    let len = read_slice(buffer, cursor, 2)?;
+    let len = u16::from_be_bytes([len[0], len[1]]) as usize;
+    let mut donor = read_slice(buffer, cursor, len)?;
+    let mut quintuplets = vec![];
+    while !donor.is_empty() {
+        let (quintuplet, consumed) = Quintuplet::unmarshal(donor)?;
+        quintuplets.push(quintuplet);
+        donor = &donor[consumed..];
+    }
+    Ok(quintuplets)
+}
+
+#[cfg(test)]
+fn test_quintuplet() -> Quintuplet {
+    Quintuplet {
+        rand: [0x11; 16],
+        xres: vec![0x22; 8],
+        ck: [0x33; 16],
+        ik: [0x44; 16],
+        autn: vec![0x55; 16],
+    }
+}
+
+#[cfg(test)]
+fn test_quintuplet_bytes() -> Vec<u8> {
+    let mut buffer = vec![0x11; 16];
+    buffer.push(0x08);
+    buffer.extend_from_slice(&[0x22; 8]);
+    buffer.extend_from_slice(&[0x33; 16]);
+    buffer.extend_from_slice(&[0x44; 16]);
+    buffer.push(0x10);
+    buffer.extend_from_slice(&[0x55; 16]);
+    buffer
+}
+
+#[test]
+fn quintuplet_marshal_unmarshal_test() {
+    let quintuplet = test_quintuplet();
+    let mut buffer: Vec<u8> = vec![];
+    quintuplet.marshal(&mut buffer);
+    assert_eq!(buffer.len(), quintuplet.encoded_len());
+    assert_eq!(buffer[16], 8);
+    assert_eq!(buffer[57], 16);
+    assert_eq!(
+        Quintuplet::unmarshal(&buffer).unwrap(),
+        (quintuplet, buffer.len())
+    );
+    assert_eq!(
+        Quintuplet::unmarshal(&buffer[..40]),
+        Err(GTPV1Error::IEInvalidLength)
+    );
+}
+
+#[test]
+fn mm_context_ie_gsm_triplets_marshal_test() {
+    let mut ie_marshalled: Vec<u8> = vec![0x81, 0x00, 0x2c, 0xf9, 0x4a];
+    ie_marshalled.extend_from_slice(&[0xaa; 8]);
+    ie_marshalled.extend_from_slice(&[0x01; 16]);
+    ie_marshalled.extend_from_slice(&[0x02; 4]);
+    ie_marshalled.extend_from_slice(&[0x03; 8]);
+    ie_marshalled.extend_from_slice(&[0x0a, 0x00, 0x01, 0xe5, 0x00, 0x00]);
+    let ie_to_marshal = MmContext {
+        length: 0x2c,
+        cksn: 1,
+        used_cipher: 2,
+        security: SecurityContext::GsmKeyTriplets {
+            kc: [0xaa; 8],
+            triplets: vec![Triplet {
+                rand: [0x01; 16],
+                sres: [0x02; 4],
+                kc: [0x03; 8],
+            }],
+        },
+        drx_param: [0x0a, 0x00],
+        ms_network_capability: vec![0xe5],
+        ..Default::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+    assert_eq!(MmContext::unmarshal(&buffer).unwrap(), ie_to_marshal);
+}
+
+#[test]
+fn mm_context_ie_umts_quintuplets_marshal_unmarshal_test() {
+    let ie_to_marshal = MmContext {
+        length: 0x76,
+        cksn: 3,
+        security: SecurityContext::UmtsKeyQuintuplets {
+            ck: [0x66; 16],
+            ik: [0x77; 16],
+            quintuplets: vec![test_quintuplet()],
+        },
+        drx_param: [0x0a, 0x00],
+        ms_network_capability: vec![0xe5, 0xe0],
+        container: vec![],
+        extensions: vec![0x00],
+        ..Default::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer.len(), ie_to_marshal.len());
+    assert_eq!(&buffer[..5], &[0x81, 0x00, 0x76, 0xfb, 0x8f]);
+    assert_eq!(&buffer[37..39], &[0x00, 0x4a]);
+    assert_eq!(MmContext::unmarshal(&buffer).unwrap(), ie_to_marshal);
+}
+
+#[test]
+fn mm_context_ie_gsm_key_quintuplets_marshal_unmarshal_test() {
+    // Security Mode 11, two vectors, used cipher GEA/1
+    let mut ie_marshalled: Vec<u8> = vec![0x81, 0x00, 0xa8, 0xfa, 0xd1];
+    ie_marshalled.extend_from_slice(&[0xaa; 8]);
+    ie_marshalled.extend_from_slice(&[0x00, 0x94]);
+    ie_marshalled.append(&mut test_quintuplet_bytes());
+    ie_marshalled.append(&mut test_quintuplet_bytes());
+    ie_marshalled.extend_from_slice(&[0x0a, 0x00, 0x01, 0xe5, 0x00, 0x02, 0x01, 0x02]);
+    let ie_to_marshal = MmContext {
+        length: 0xa8,
+        cksn: 2,
+        used_cipher: 1,
+        security: SecurityContext::GsmKeyQuintuplets {
+            kc: [0xaa; 8],
+            quintuplets: vec![test_quintuplet(), test_quintuplet()],
+        },
+        drx_param: [0x0a, 0x00],
+        ms_network_capability: vec![0xe5],
+        container: vec![0x01, 0x02],
+        ..Default::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+    assert_eq!(MmContext::unmarshal(&ie_marshalled).unwrap(), ie_to_marshal);
+}
+
+#[test]
+fn mm_context_ie_used_cipher_umts_key_quintuplets_marshal_unmarshal_test() {
+    // Security Mode 00, one vector, used cipher GEA/1 in octet 5 and CK from octet 6
+    let mut ie_marshalled: Vec<u8> = vec![0x81, 0x00, 0x76, 0xfa, 0x09];
+    ie_marshalled.extend_from_slice(&[0x66; 16]);
+    ie_marshalled.extend_from_slice(&[0x77; 16]);
+    ie_marshalled.extend_from_slice(&[0x00, 0x4a]);
+    ie_marshalled.append(&mut test_quintuplet_bytes());
+    ie_marshalled.extend_from_slice(&[0x0a, 0x00, 0x01, 0xe5, 0x00, 0x02, 0x01, 0x02]);
+    let ie_to_marshal = MmContext {
+        length: 0x76,
+        cksn: 2,
+        used_cipher: 1,
+        security: SecurityContext::UsedCipherUmtsKeyQuintuplets {
+            ck: [0x66; 16],
+            ik: [0x77; 16],
+            quintuplets: vec![test_quintuplet()],
+        },
+        drx_param: [0x0a, 0x00],
+        ms_network_capability: vec![0xe5],
+        container: vec![0x01, 0x02],
+        ..Default::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+    assert_eq!(MmContext::unmarshal(&ie_marshalled).unwrap(), ie_to_marshal);
+}
+
+#[test]
+fn mm_context_ie_truncated_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 10] = [0x81, 0x00, 0x07, 0xf9, 0x4a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa];
+    assert_eq!(
+        MmContext::unmarshal(&ie_to_unmarshal),
+        Err(GTPV1Error::IEInvalidLength)
+    );
+}
@@ -1,20 +1,30 @@
 pub use {
-    additionaltraceinfo::*, apn::*, apnambr::*, apnrestriction::*, bearercontrolmode::*,
-    camelcic::*, cause::*, chargingcharateristics::*, charginggwaddress::*, chargingid::*,
-    cnose::*, commonflags::*, commons::*, correlationid::*, csginforeporting::*,
-    directtunnelflags::*, enduseraddress::*, evolvedallocretentioni::*, extendedcommonflags::*,
-    extendedcommonflagsii::*, extensionheadertypelist::*, ggsnbackofftime::*, gsnaddress::*,
-    imei::*, imsi::*, msinfochange::*, msisdn::*, mstimezone::*, mueut::*, nsapi::*, omcid::*,
-    pco::*, privateextension::*, qos::*, rai::*, rattype::*, recovery::*, reorderingreq::*,
-    selectionmode::*, spi::*, teardownind::*, teid::*, tft::*, tracereference::*, tracetype::*,
-    triggerid::*, uci::*, uli::*, ulitimestamp::*, upfsif::*,
+    additionalmbmstraceinfo::*, additionaltraceinfo::*, apn::*, apnambr::*, apnrestriction::*,
+    bearercontrolmode::*, bsscontainer::*, camelcic::*, cause::*, chargingcharateristics::*,
+    charginggwaddress::*, chargingid::*, cnose::*, commonflags::*, commons::*, correlationid::*,
+    csginforeporting::*, directtunnelflags::*, enduseraddress::*, enhancednsapi::*,
+    evolvedallocretentioni::*, extendedcommonflags::*, extendedcommonflagsii::*,
+    extensionheadertypelist::*, ggsnbackofftime::*, gsnaddress::*, hopcounter::*, ie::*, imei::*,
+    imsi::*, mapcause::*, mbms2g3gindicator::*, mbmsdistributionack::*, mbmsflowid::*,
+    mbmsipmulticast::*, mbmspco::*, mbmsservicearea::*, mbmssessionduration::*, mbmssessionid::*,
+    mbmssessionrepetition::*, mbmstimetodatatransfer::*, mbmsuecontext::*, mmcontext::*,
+    msinfochange::*, msisdn::*, msnotreachablereason::*, mstimezone::*, msvalidated::*, mueut::*,
+    nsapi::*, omcid::*, packetflowid::*, pco::*, pdpcontext::*, privateextension::*, ptmsi::*,
+    ptmsisignature::*, qos::*, rabcontext::*, rabsetupinfo::*, radiopriority::*,
+    radioprioritylcs::*, radioprioritysms::*, rai::*, ranapcause::*, rattype::*, recovery::*,
+    reorderingreq::*, reqmbmsbearercaps::*, rimroutingaddress::*, selectionmode::*, spi::*,
+    srcrncpdcpctxinfo::*, targetid::*, teardownind::*, teid::*, teiddataii::*, tft::*, tlli::*,
+    tmgi::*, tracereference::*, tracetype::*, triggerid::*, uci::*, uli::*, ulitimestamp::*,
+    unknown::*, upfsif::*, utrancontainer::*,
 };
 
+mod additionalmbmstraceinfo;
 mod additionaltraceinfo;
 mod apn;
 mod apnambr;
 mod apnrestriction;
 mod bearercontrolmode;
+mod bsscontainer;
 mod camelcic;
 mod cause;
 mod chargingcharateristics;
@@ -27,36 +37,73 @@ mod correlationid;
 mod csginforeporting;
 mod directtunnelflags;
 mod enduseraddress;
+mod enhancednsapi;
 mod evolvedallocretentioni;
 mod extendedcommonflags;
 mod extendedcommonflagsii;
 mod extensionheadertypelist;
 mod ggsnbackofftime;
 mod gsnaddress;
+mod hopcounter;
+mod ie;
 mod imei;
 mod imsi;
+mod mapcause;
+mod mbms2g3gindicator;
+mod mbmsdistributionack;
+mod mbmsflowid;
+mod mbmsipmulticast;
+mod mbmspco;
+mod mbmsservicearea;
+mod mbmssessionduration;
+mod mbmssessionid;
+mod mbmssessionrepetition;
+mod mbmstimetodatatransfer;
+mod mbmsuecontext;
+mod mmcontext;
 mod msinfochange;
 mod msisdn;
+mod msnotreachablereason;
 mod mstimezone;
+mod msvalidated;
 mod mueut;
 mod nsapi;
 mod omcid;
+mod packetflowid;
 mod pco;
+mod pdpcontext;
 mod privateextension;
+mod ptmsi;
+mod ptmsisignature;
 mod qos;
+mod rabcontext;
+mod rabsetupinfo;
+mod radiopriority;
+mod radioprioritylcs;
+mod radioprioritysms;
 mod rai;
+mod ranapcause;
 mod rattype;
 mod recovery;
 mod reorderingreq;
+mod reqmbmsbearercaps;
+mod rimroutingaddress;
 mod selectionmode;
 mod spi;
+mod srcrncpdcpctxinfo;
+mod targetid;
 mod teardownind;
 mod teid;
+mod teiddataii;
 mod tft;
+mod tlli;
+mod tmgi;
 mod tracereference;
 mod tracetype;
 mod triggerid;
 mod uci;
 mod uli;
 mod ulitimestamp;
+mod unknown;
 mod upfsif;
+mod utrancontainer;
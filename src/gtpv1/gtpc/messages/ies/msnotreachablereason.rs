@@ -0,0 +1,75 @@
+// MS Not Reachable Reason IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*};
+
+// MS Not Reachable Reason IE TL
+
+pub const MS_NOT_REACHABLE_REASON: u8 = 29;
+pub const MS_NOT_REACHABLE_REASON_LENGTH: usize = 1;
+
+// MS Not Reachable Reason IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MsNotReachableReason {
+    pub t: u8,
+    pub value: u8,
+}
+
+impl Default for MsNotReachableReason {
+    fn default() -> Self {
+        MsNotReachableReason {
+            t: MS_NOT_REACHABLE_REASON,
+            value: 0,
+        }
+    }
+}
+
+impl IEs for MsNotReachableReason {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.push(self.value);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() > MS_NOT_REACHABLE_REASON_LENGTH {
+            Ok(MsNotReachableReason {
+                value: buffer[1],
+                ..Default::default()
+            })
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        MS_NOT_REACHABLE_REASON_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn ms_not_reachable_reason_ie_marshal_test() {
+    let ie_to_marshal = MsNotReachableReason {
+        t: MS_NOT_REACHABLE_REASON,
+        value: 1,
+    };
+    let ie_marshalled: [u8; 2] = [0x1d, 0x01];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn ms_not_reachable_reason_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 2] = [0x1d, 0x01];
+    let ie_unmarshalled = MsNotReachableReason {
+        t: MS_NOT_REACHABLE_REASON,
+        value: 1,
+    };
+    assert_eq!(
+        MsNotReachableReason::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
@@ -0,0 +1,75 @@
+// MS Validated IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*};
+
+// MS Validated IE TL
+
+pub const MS_VALIDATED: u8 = 13;
+pub const MS_VALIDATED_LENGTH: usize = 1;
+
+// MS Validated IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MsValidated {
+    pub t: u8,
+    pub validated: bool,
+}
+
+impl Default for MsValidated {
+    fn default() -> Self {
+        MsValidated {
+            t: MS_VALIDATED,
+            validated: false,
+        }
+    }
+}
+
+impl IEs for MsValidated {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.push(0xfe | self.validated as u8);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() > MS_VALIDATED_LENGTH {
+            Ok(MsValidated {
+                validated: buffer[1] & 0x01 == 1,
+                ..Default::default()
+            })
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        MS_VALIDATED_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn ms_validated_ie_marshal_test() {
+    let ie_to_marshal = MsValidated {
+        t: MS_VALIDATED,
+        validated: true,
+    };
+    let ie_marshalled: [u8; 2] = [0x0d, 0xff];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn ms_validated_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 2] = [0x0d, 0xfe];
+    let ie_unmarshalled = MsValidated {
+        t: MS_VALIDATED,
+        validated: false,
+    };
+    assert_eq!(
+        MsValidated::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
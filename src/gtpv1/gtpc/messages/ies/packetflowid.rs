@@ -0,0 +1,81 @@
+// Packet Flow Id IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*};
+
+// Packet Flow Id IE TL
+
+pub const PACKET_FLOW_ID: u8 = 25;
+pub const PACKET_FLOW_ID_LENGTH: usize = 2;
+
+// Packet Flow Id IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PacketFlowId {
+    pub t: u8,
+    pub nsapi: u8,
+    pub value: u8,
+}
+
+impl Default for PacketFlowId {
+    fn default() -> Self {
+        PacketFlowId {
+            t: PACKET_FLOW_ID,
+            nsapi: 0,
+            value: 0,
+        }
+    }
+}
+
+impl IEs for PacketFlowId {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.push(self.nsapi & 0x0f);
+        buffer.push(self.value);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() > PACKET_FLOW_ID_LENGTH {
+            Ok(PacketFlowId {
+                nsapi: buffer[1] & 0x0f,
+                value: buffer[2],
+                ..Default::default()
+            })
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        PACKET_FLOW_ID_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn packet_flow_id_ie_marshal_test() {
+    let ie_to_marshal = PacketFlowId {
+        t: PACKET_FLOW_ID,
+        nsapi: 5,
+        value: 0x10,
+    };
+    let ie_marshalled: [u8; 3] = [0x19, 0x05, 0x10];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn packet_flow_id_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 3] = [0x19, 0x05, 0x10];
+    let ie_unmarshalled = PacketFlowId {
+        t: PACKET_FLOW_ID,
+        nsapi: 5,
+        value: 0x10,
+    };
+    assert_eq!(
+        PacketFlowId::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
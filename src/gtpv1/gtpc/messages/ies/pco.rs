@@ -1,6 +1,9 @@
 // PCO IE - according to 3GPP TS 29.060 V15.5.0 (2019-06) and 3GPP TS 24.008 V16.0.0 (2019-03)
 
-use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+use crate::{
+    commons::ProtocolConfigurationOptions,
+    gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*},
+};
 
 // PCO IE Type
 
@@ -25,6 +28,31 @@ impl Default for Pco {
     }
 }
 
+impl Pco {
+    // Decode the PCO octets into typed Protocol Configuration Options
+
+    pub fn protocol_configuration_options(
+        &self,
+    ) -> Result<ProtocolConfigurationOptions, GTPV1Error> {
+        ProtocolConfigurationOptions::unmarshal(&self.pco).map_err(|_| GTPV1Error::IEIncorrect)
+    }
+
+    pub fn set_protocol_configuration_options(&mut self, pco: &ProtocolConfigurationOptions) {
+        let mut buffer: Vec<u8> = vec![];
+        pco.marshal(&mut buffer);
+        self.length = buffer.len() as u16;
+        self.pco = buffer;
+    }
+}
+
+impl From<ProtocolConfigurationOptions> for Pco {
+    fn from(i: ProtocolConfigurationOptions) -> Self {
+        let mut data = Pco::default();
+        data.set_protocol_configuration_options(&i);
+        data
+    }
+}
+
 impl IEs for Pco {
     fn marshal(&self, buffer: &mut Vec<u8>) {
         let mut buffer_ie: Vec<u8> = vec![];
@@ -99,3 +127,33 @@ fn pco_ie_unmarshal_test() {
     };
     assert_eq!(Pco::unmarshal(&ie_to_unmarshal).unwrap(), ie_unmarshalled);
 }
+
+#[test]
+fn pco_ie_protocol_configuration_options_test() {
+    use crate::commons::*;
+    let ie_to_unmarshal: [u8; 23] = [
+        0x84, 0x00, 0x14, 0x80, 0x80, 0x21, 0x10, 0x01, 0x01, 0x00, 0x10, 0x81, 0x06, 0x00, 0x00,
+        0x00, 0x00, 0x83, 0x06, 0x00, 0x00, 0x00, 0x00,
+    ];
+    let pco = ProtocolConfigurationOptions {
+        configuration_protocol: PCO_CONFIG_PROTOCOL_PPP,
+        containers: vec![PcoContainer::Ipcp(Ipcp {
+            code: IPCP_CONFIGURE_REQUEST,
+            identifier: 1,
+            options: vec![
+                IpcpOption::PrimaryDns(std::net::Ipv4Addr::UNSPECIFIED),
+                IpcpOption::SecondaryDns(std::net::Ipv4Addr::UNSPECIFIED),
+            ],
+        })],
+    };
+    assert_eq!(
+        Pco::unmarshal(&ie_to_unmarshal)
+            .unwrap()
+            .protocol_configuration_options()
+            .unwrap(),
+        pco
+    );
+    let mut buffer: Vec<u8> = vec![];
+    Pco::from(pco).marshal(&mut buffer);
+    assert_eq!(buffer, ie_to_unmarshal);
+}
@@ -0,0 +1,320 @@
+// PDP Context IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+use std::net::{IpAddr, Ipv4Addr};
+
+// PDP Context IE Type
+
+pub const PDP_CONTEXT: u8 = 130;
+
+// PDP Context IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdpContext {
+    pub t: u8,
+    pub length: u16,
+    pub ea: bool,    // Extended PDP Type / second PDP address present
+    pub vaa: bool,   // Valid MS Access
+    pub asi: bool,   // Activity Status Indicator
+    pub order: bool, // Reordering Required
+    pub nsapi: u8,
+    pub sapi: u8,
+    pub qos_subscribed: Vec<u8>,
+    pub qos_requested: Vec<u8>,
+    pub qos_negotiated: Vec<u8>,
+    pub sqn_down: u16,
+    pub sqn_up: u16,
+    pub send_npdu_number: u8,
+    pub receive_npdu_number: u8,
+    pub uplink_teid_control: u32,
+    pub uplink_teid_data: u32,
+    pub pdp_context_id: u8,
+    pub pdp_type_org: u8,
+    pub pdp_type_number: u8,
+    pub pdp_address: Vec<u8>,
+    pub ggsn_address_control: IpAddr,
+    pub ggsn_address_user: IpAddr,
+    pub apn: String,
+    pub transaction_id: u16, // 12 bits
+    pub pdp_type_number_ext: Option<u8>,
+    pub pdp_address_ext: Option<Vec<u8>>,
+    pub extensions: Vec<u8>, // Any later fields are kept as received
+}
+
+impl Default for PdpContext {
+    fn default() -> Self {
+        PdpContext {
+            t: PDP_CONTEXT,
+            length: 0,
+            ea: false,
+            vaa: false,
+            asi: false,
+            order: false,
+            nsapi: 0,
+            sapi: 0,
+            qos_subscribed: vec![],
+            qos_requested: vec![],
+            qos_negotiated: vec![],
+            sqn_down: 0,
+            sqn_up: 0,
+            send_npdu_number: 0,
+            receive_npdu_number: 0,
+            uplink_teid_control: 0,
+            uplink_teid_data: 0,
+            pdp_context_id: 0,
+            pdp_type_org: 1,
+            pdp_type_number: IPV4,
+            pdp_address: vec![],
+            ggsn_address_control: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+            ggsn_address_user: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+            apn: "".to_string(),
+            transaction_id: 0,
+            pdp_type_number_ext: None,
+            pdp_address_ext: None,
+            extensions: vec![],
+        }
+    }
+}
+
+impl IEs for PdpContext {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.push(
+            (self.ea as u8) << 7
+                | (self.vaa as u8) << 6
+                | (self.asi as u8) << 5
+                | (self.order as u8) << 4
+                | (self.nsapi & 0x0f),
+        );
+        buffer_ie.push(self.sapi & 0x0f);
+        for i in [
+            &self.qos_subscribed,
+            &self.qos_requested,
+            &self.qos_negotiated,
+        ] {
+            buffer_ie.push(i.len() as u8);
+            buffer_ie.extend_from_slice(i);
+        }
+        buffer_ie.extend_from_slice(&self.sqn_down.to_be_bytes());
+        buffer_ie.extend_from_slice(&self.sqn_up.to_be_bytes());
+        buffer_ie.push(self.send_npdu_number);
+        buffer_ie.push(self.receive_npdu_number);
+        buffer_ie.extend_from_slice(&self.uplink_teid_control.to_be_bytes());
+        buffer_ie.extend_from_slice(&self.uplink_teid_data.to_be_bytes());
+        buffer_ie.push(self.pdp_context_id);
+        buffer_ie.push(0xf0 | (self.pdp_type_org & 0x0f));
+        buffer_ie.push(self.pdp_type_number);
+        buffer_ie.push(self.pdp_address.len() as u8);
+        buffer_ie.extend_from_slice(&self.pdp_address);
+        for i in [self.ggsn_address_control, self.ggsn_address_user] {
+            match i {
+                IpAddr::V4(j) => {
+                    buffer_ie.push(4);
+                    buffer_ie.extend_from_slice(&j.octets());
+                }
+                IpAddr::V6(j) => {
+                    buffer_ie.push(16);
+                    buffer_ie.extend_from_slice(&j.octets());
+                }
+            }
+        }
+        let mut apn = marshal_apn(&self.apn);
+        buffer_ie.push(apn.len() as u8);
+        buffer_ie.append(&mut apn);
+        buffer_ie.extend_from_slice(&(0xf000 | (self.transaction_id & 0x0fff)).to_be_bytes());
+        if self.ea {
+            buffer_ie.push(self.pdp_type_number_ext.unwrap_or_default());
+            let address = self.pdp_address_ext.clone().unwrap_or_default();
+            buffer_ie.push(address.len() as u8);
+            buffer_ie.extend_from_slice(&address);
+        }
+        buffer_ie.extend_from_slice(&self.extensions);
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() < 3 {
+            return Err(GTPV1Error::IEInvalidLength);
+        }
+        let mut data = PdpContext {
+            length: u16::from_be_bytes([buffer[1], buffer[2]]),
+            ..Default::default()
+        };
+        if !check_tlv_ie_buffer(data.length, buffer) {
+            return Err(GTPV1Error::IEInvalidLength);
+        }
+        let value = &buffer[3..(data.length + 3) as usize];
+        let mut cursor = 0;
+        let octets = read_slice(value, &mut cursor, 2)?;
+        data.ea = octets[0] & 0x80 == 0x80;
+        data.vaa = octets[0] & 0x40 == 0x40;
+        data.asi = octets[0] & 0x20 == 0x20;
+        data.order = octets[0] & 0x10 == 0x10;
+        data.nsapi = octets[0] & 0x0f;
+        data.sapi = octets[1] & 0x0f;
+        let len = read_slice(value, &mut cursor, 1)?[0] as usize;
+        data.qos_subscribed = read_slice(value, &mut cursor, len)?.to_vec();
+        let len = read_slice(value, &mut cursor, 1)?[0] as usize;
+        data.qos_requested = read_slice(value, &mut cursor, len)?.to_vec();
+        let len = read_slice(value, &mut cursor, 1)?[0] as usize;
+        data.qos_negotiated = read_slice(value, &mut cursor, len)?.to_vec();
+        let octets = read_slice(value, &mut cursor, 18)?;
+        data.sqn_down = u16::from_be_bytes([octets[0], octets[1]]);
+        data.sqn_up = u16::from_be_bytes([octets[2], octets[3]]);
+        data.send_npdu_number = octets[4];
+        data.receive_npdu_number = octets[5];
+        data.uplink_teid_control = u32::from_be_bytes([octets[6], octets[7], octets[8], octets[9]]);
+        data.uplink_teid_data =
+            u32::from_be_bytes([octets[10], octets[11], octets[12], octets[13]]);
+        data.pdp_context_id = octets[14];
+        data.pdp_type_org = octets[15] & 0x0f;
+        data.pdp_type_number = octets[16];
+        data.pdp_address = read_slice(value, &mut cursor, octets[17] as usize)?.to_vec();
+        let len = read_slice(value, &mut cursor, 1)?[0] as usize;
+        data.ggsn_address_control = unmarshal_address(read_slice(value, &mut cursor, len)?)?;
+        let len = read_slice(value, &mut cursor, 1)?[0] as usize;
+        data.ggsn_address_user = unmarshal_address(read_slice(value, &mut cursor, len)?)?;
+        let len = read_slice(value, &mut cursor, 1)?[0] as usize;
+        data.apn = unmarshal_apn(read_slice(value, &mut cursor, len)?)?;
+        let octets = read_slice(value, &mut cursor, 2)?;
+        data.transaction_id = u16::from_be_bytes([octets[0], octets[1]]) & 0x0fff;
+        if data.ea {
+            data.pdp_type_number_ext = Some(read_slice(value, &mut cursor, 1)?[0]);
+            let len = read_slice(value, &mut cursor, 1)?[0] as usize;
+            data.pdp_address_ext = Some(read_slice(value, &mut cursor, len)?.to_vec());
+        }
+        data.extensions = value[cursor..].to_vec();
+        Ok(data)
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+pub(crate) fn marshal_apn(apn: &str) -> Vec<u8> {
+    let mut buffer: Vec<u8> = vec![];
+    if !apn.is_empty() {
+        for i in apn.split('.') {
+            buffer.push(i.len() as u8);
+            buffer.extend_from_slice(i.as_bytes());
+        }
+    }
+    buffer
+}
+
+pub(crate) fn unmarshal_apn(buffer: &[u8]) -> Result<String, GTPV1Error> {
+    let mut donor = buffer;
+    let mut labels: Vec<String> = vec![];
+    while !donor.is_empty() {
+        let len = donor[0] as usize;
+        match donor.get(1..len + 1) {
+            Some(i) => labels.push(i.iter().map(|x| *x as char).collect()),
+            None => return Err(GTPV1Error::IEIncorrect),
+        }
+        donor = &donor[len + 1..];
+    }
+    Ok(labels.join("."))
+}
+
+pub(crate) fn unmarshal_address(buffer: &[u8]) -> Result<IpAddr, GTPV1Error> {
+    match buffer.len() {
+        4 => Ok(IpAddr::from([buffer[0], buffer[1], buffer[2], buffer[3]])),
+        16 => {
+            let mut dst = [0; 16];
+            dst.copy_from_slice(buffer);
+            Ok(IpAddr::from(dst))
+        }
+        _ => Err(GTPV1Error::IEIncorrect),
+    }
+}
+
+#[cfg(test)]
+const PDP_CONTEXT_TEST_IE: [u8; 72] = [
+    0x82, 0x00, 0x45, 0x25, 0x03, 0x04, 0x08, 0x1b, 0x93, 0x1f, 0x04, 0x08, 0x1b, 0x93, 0x1f, 0x04,
+    0x08, 0x1b, 0x93, 0x1f, 0x00, 0x01, 0x00, 0x02, 0x03, 0x04, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00,
+    0x00, 0x0b, 0x01, 0xf1, 0x21, 0x04, 0x0a, 0x00, 0x00, 0x01, 0x04, 0xc0, 0xa8, 0x00, 0x01, 0x04,
+    0xc0, 0xa8, 0x00, 0x02, 0x0d, 0x04, 0x74, 0x65, 0x73, 0x74, 0x03, 0x6e, 0x65, 0x74, 0x03, 0x63,
+    0x6f, 0x6d, 0xf0, 0x01, 0x00, 0x00, 0x00, 0x00,
+];
+
+#[cfg(test)]
+fn pdp_context_test_ie() -> PdpContext {
+    PdpContext {
+        length: 0x45,
+        asi: true,
+        nsapi: 5,
+        sapi: 3,
+        qos_subscribed: vec![0x08, 0x1b, 0x93, 0x1f],
+        qos_requested: vec![0x08, 0x1b, 0x93, 0x1f],
+        qos_negotiated: vec![0x08, 0x1b, 0x93, 0x1f],
+        sqn_down: 1,
+        sqn_up: 2,
+        send_npdu_number: 3,
+        receive_npdu_number: 4,
+        uplink_teid_control: 10,
+        uplink_teid_data: 11,
+        pdp_context_id: 1,
+        pdp_type_org: 1,
+        pdp_type_number: IPV4,
+        pdp_address: vec![0x0a, 0x00, 0x00, 0x01],
+        ggsn_address_control: IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)),
+        ggsn_address_user: IpAddr::V4(Ipv4Addr::new(192, 168, 0, 2)),
+        apn: "test.net.com".to_string(),
+        transaction_id: 1,
+        extensions: vec![0x00, 0x00, 0x00, 0x00],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn pdp_context_ie_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    pdp_context_test_ie().marshal(&mut buffer);
+    assert_eq!(buffer, PDP_CONTEXT_TEST_IE);
+}
+
+#[test]
+fn pdp_context_ie_unmarshal_test() {
+    assert_eq!(
+        PdpContext::unmarshal(&PDP_CONTEXT_TEST_IE).unwrap(),
+        pdp_context_test_ie()
+    );
+}
+
+#[test]
+fn pdp_context_ie_second_pdp_address_round_trip_test() {
+    let mut ie = PdpContext {
+        ea: true,
+        pdp_type_number_ext: Some(IPV6),
+        pdp_address_ext: Some(vec![
+            0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        ]),
+        extensions: vec![],
+        ..pdp_context_test_ie()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie.marshal(&mut buffer);
+    ie.length = (buffer.len() - 3) as u16;
+    assert_eq!(buffer[3], 0xa5);
+    assert_eq!(PdpContext::unmarshal(&buffer).unwrap(), ie);
+}
+
+#[test]
+fn pdp_context_ie_truncated_unmarshal_test() {
+    let mut buffer = PDP_CONTEXT_TEST_IE[..40].to_vec();
+    buffer[2] = 37;
+    assert_eq!(
+        PdpContext::unmarshal(&buffer),
+        Err(GTPV1Error::IEInvalidLength)
+    );
+}
@@ -0,0 +1,69 @@
+// P-TMSI IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*};
+
+// P-TMSI IE TL
+
+pub const PTMSI: u8 = 5;
+pub const PTMSI_LENGTH: usize = 4;
+
+// Packet TMSI IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ptmsi {
+    pub t: u8,
+    pub value: u32,
+}
+
+impl Default for Ptmsi {
+    fn default() -> Self {
+        Ptmsi { t: PTMSI, value: 0 }
+    }
+}
+
+impl IEs for Ptmsi {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.extend_from_slice(&self.value.to_be_bytes());
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() > PTMSI_LENGTH {
+            Ok(Ptmsi {
+                value: u32::from_be_bytes([buffer[1], buffer[2], buffer[3], buffer[4]]),
+                ..Default::default()
+            })
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        PTMSI_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn ptmsi_ie_marshal_test() {
+    let ie_to_marshal = Ptmsi {
+        t: PTMSI,
+        value: 0xd1a2b3c4,
+    };
+    let ie_marshalled: [u8; 5] = [0x05, 0xd1, 0xa2, 0xb3, 0xc4];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn ptmsi_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 5] = [0x05, 0xd1, 0xa2, 0xb3, 0xc4];
+    let ie_unmarshalled = Ptmsi {
+        t: PTMSI,
+        value: 0xd1a2b3c4,
+    };
+    assert_eq!(Ptmsi::unmarshal(&ie_to_unmarshal).unwrap(), ie_unmarshalled);
+}
@@ -0,0 +1,75 @@
+// P-TMSI Signature IE - according to 3GPP TS 29.060 V15.5.0 (2019-06) and 3GPP TS 24.008 V16.0.0 (2019-03)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*};
+
+// P-TMSI Signature IE TL
+
+pub const PTMSI_SIGNATURE: u8 = 12;
+pub const PTMSI_SIGNATURE_LENGTH: usize = 3;
+
+// P-TMSI Signature IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PtmsiSignature {
+    pub t: u8,
+    pub value: u32, // 24 bits
+}
+
+impl Default for PtmsiSignature {
+    fn default() -> Self {
+        PtmsiSignature {
+            t: PTMSI_SIGNATURE,
+            value: 0,
+        }
+    }
+}
+
+impl IEs for PtmsiSignature {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.extend_from_slice(&self.value.to_be_bytes()[1..]);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() > PTMSI_SIGNATURE_LENGTH {
+            Ok(PtmsiSignature {
+                value: u32::from_be_bytes([0x00, buffer[1], buffer[2], buffer[3]]),
+                ..Default::default()
+            })
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        PTMSI_SIGNATURE_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn ptmsi_signature_ie_marshal_test() {
+    let ie_to_marshal = PtmsiSignature {
+        t: PTMSI_SIGNATURE,
+        value: 0xaabbcc,
+    };
+    let ie_marshalled: [u8; 4] = [0x0c, 0xaa, 0xbb, 0xcc];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn ptmsi_signature_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 4] = [0x0c, 0xaa, 0xbb, 0xcc];
+    let ie_unmarshalled = PtmsiSignature {
+        t: PTMSI_SIGNATURE,
+        value: 0xaabbcc,
+    };
+    assert_eq!(
+        PtmsiSignature::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
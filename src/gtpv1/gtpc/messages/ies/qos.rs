@@ -1,11 +1,22 @@
 // QoS IE - according to 3GPP TS 29.060 V15.5.0 (2019-06) and 3GPP TS 24.008 V16.0.0 (2019-03)
 
-use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+use crate::{
+    commons::*,
+    gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*},
+    gtpv2::messages::{Ambr, BearerQos},
+};
 
 // QoS IE Type
 
 pub const QOS: u8 = 135;
 
+// Mapping of the pre-Rel-8 Allocation/Retention Priority to the EPS ARP priority level
+// according to 3GPP TS 23.401 Annex E: priority levels 1 to QOS_ARP_HIGH_PRIORITY_LEVEL map
+// to ARP 1, the ones up to QOS_ARP_MEDIUM_PRIORITY_LEVEL to ARP 2 and the rest to ARP 3
+
+pub const QOS_ARP_HIGH_PRIORITY_LEVEL: u8 = 5;
+pub const QOS_ARP_MEDIUM_PRIORITY_LEVEL: u8 = 10;
+
 // PCO IE implementation
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -27,6 +38,134 @@ impl Default for Qos {
     }
 }
 
+impl Qos {
+    // Decode the QoS profile octets into a typed QoS profile
+
+    pub fn qos_profile(&self) -> Result<QosProfile, GTPV1Error> {
+        QosProfile::unmarshal(&self.qos).map_err(|_| GTPV1Error::IEIncorrect)
+    }
+
+    pub fn set_qos_profile(&mut self, profile: &QosProfile) {
+        let mut buffer: Vec<u8> = vec![];
+        profile.marshal(&mut buffer);
+        self.length = buffer.len() as u16 + 1;
+        self.qos = buffer;
+    }
+
+    // Map the QoS profile to the EPS Bearer QoS for Gn/Gp SGSN interworking, 3GPP TS 23.401 Annex E.
+    // MBR and GBR are only set for the GBR QCIs, the MBR of non-GBR bearers is carried by the APN-AMBR
+
+    pub fn bearer_qos(&self) -> Result<BearerQos, GTPV1Error> {
+        let profile = self.qos_profile()?;
+        let qci = match profile.traffic_class {
+            QOS_TRAFFIC_CLASS_CONVERSATIONAL => {
+                if profile.source_statistics_descriptor == QOS_SOURCE_STATISTICS_SPEECH {
+                    1
+                } else if profile.transfer_delay >= 150 {
+                    2
+                } else {
+                    3
+                }
+            }
+            QOS_TRAFFIC_CLASS_STREAMING => 4,
+            QOS_TRAFFIC_CLASS_INTERACTIVE => match profile.traffic_handling_priority {
+                1 if profile.signalling_indication => 5,
+                1 => 6,
+                2 => 7,
+                _ => 8,
+            },
+            _ => 9,
+        };
+        let priority_level = match self.arp & 0x03 {
+            1 => 1,
+            2 => QOS_ARP_HIGH_PRIORITY_LEVEL + 1,
+            _ => QOS_ARP_MEDIUM_PRIORITY_LEVEL + 1,
+        };
+        let mut data = BearerQos {
+            pre_emption_capability: 1,
+            pre_emption_vulnerability: 0,
+            priority_level,
+            qci,
+            ..Default::default()
+        };
+        if qci <= 4 {
+            data.maxbr_ul = profile.max_bitrate_ul.unwrap_or_default() as u64;
+            data.maxbr_dl = profile.max_bitrate_dl.unwrap_or_default() as u64;
+            data.gbr_ul = profile.guaranteed_bitrate_ul.unwrap_or_default() as u64;
+            data.gbr_dl = profile.guaranteed_bitrate_dl.unwrap_or_default() as u64;
+        }
+        Ok(data)
+    }
+
+    // Map the maximum bitrates of the QoS profile to the APN-AMBR, 3GPP TS 23.401 Annex E
+
+    pub fn apn_ambr(&self) -> Result<Ambr, GTPV1Error> {
+        let profile = self.qos_profile()?;
+        Ok(Ambr {
+            ambr_ul: profile.max_bitrate_ul.unwrap_or_default(),
+            ambr_dl: profile.max_bitrate_dl.unwrap_or_default(),
+            ..Default::default()
+        })
+    }
+
+    // Map the EPS Bearer QoS and APN-AMBR to a QoS profile, 3GPP TS 23.401 Annex E.
+    // The maximum bitrates of non-GBR bearers are taken from the APN-AMBR, if any
+
+    pub fn from_bearer_qos(bearer_qos: &BearerQos, apn_ambr: Option<&Ambr>) -> Qos {
+        let bitrate = |i: u64| Some(i.min(u32::MAX as u64) as u32);
+        let mut profile = QosProfile::default();
+        match bearer_qos.qci {
+            1 => {
+                profile.traffic_class = QOS_TRAFFIC_CLASS_CONVERSATIONAL;
+                profile.source_statistics_descriptor = QOS_SOURCE_STATISTICS_SPEECH;
+                profile.transfer_delay = 100;
+            }
+            2 => {
+                profile.traffic_class = QOS_TRAFFIC_CLASS_CONVERSATIONAL;
+                profile.transfer_delay = 150;
+            }
+            3 => {
+                profile.traffic_class = QOS_TRAFFIC_CLASS_CONVERSATIONAL;
+                profile.transfer_delay = 50;
+            }
+            4 => {
+                profile.traffic_class = QOS_TRAFFIC_CLASS_STREAMING;
+                profile.transfer_delay = 300;
+            }
+            5..=8 => {
+                profile.traffic_class = QOS_TRAFFIC_CLASS_INTERACTIVE;
+                profile.signalling_indication = bearer_qos.qci == 5;
+                profile.traffic_handling_priority = match bearer_qos.qci {
+                    5 | 6 => 1,
+                    7 => 2,
+                    _ => 3,
+                };
+            }
+            _ => profile.traffic_class = QOS_TRAFFIC_CLASS_BACKGROUND,
+        }
+        if (1..=4).contains(&bearer_qos.qci) {
+            profile.max_bitrate_ul = bitrate(bearer_qos.maxbr_ul);
+            profile.max_bitrate_dl = bitrate(bearer_qos.maxbr_dl);
+            profile.guaranteed_bitrate_ul = bitrate(bearer_qos.gbr_ul);
+            profile.guaranteed_bitrate_dl = bitrate(bearer_qos.gbr_dl);
+        } else if let Some(i) = apn_ambr {
+            profile.max_bitrate_ul = bitrate(i.ambr_ul as u64);
+            profile.max_bitrate_dl = bitrate(i.ambr_dl as u64);
+        }
+        let arp = match bearer_qos.priority_level {
+            i if i <= QOS_ARP_HIGH_PRIORITY_LEVEL => 1,
+            i if i <= QOS_ARP_MEDIUM_PRIORITY_LEVEL => 2,
+            _ => 3,
+        };
+        let mut data = Qos {
+            arp,
+            ..Default::default()
+        };
+        data.set_qos_profile(&profile);
+        data
+    }
+}
+
 impl IEs for Qos {
     fn marshal(&self, buffer: &mut Vec<u8>) {
         let mut buffer_ie: Vec<u8> = vec![];
@@ -105,3 +244,64 @@ fn pco_ie_unmarshal_test() {
     };
     assert_eq!(Qos::unmarshal(&ie_to_unmarshal).unwrap(), ie_unmarshalled);
 }
+
+#[test]
+fn qos_ie_qos_profile_test() {
+    let ie_to_unmarshal: [u8; 15] = [
+        0x87, 0x00, 0x0c, 0x03, 0x1b, 0x93, 0x1f, 0x73, 0x96, 0x97, 0x97, 0x44, 0xfb, 0x10, 0x40,
+    ];
+    let qos = Qos::unmarshal(&ie_to_unmarshal).unwrap();
+    let profile = qos.qos_profile().unwrap();
+    assert_eq!(profile.traffic_class, QOS_TRAFFIC_CLASS_INTERACTIVE);
+    assert_eq!(profile.max_bitrate_dl, Some(2048));
+    let mut data = Qos {
+        arp: 3,
+        ..Default::default()
+    };
+    data.set_qos_profile(&profile);
+    assert_eq!(data, qos);
+}
+
+#[test]
+fn qos_ie_bearer_qos_mapping_test() {
+    let ie_to_unmarshal: [u8; 15] = [
+        0x87, 0x00, 0x0c, 0x03, 0x1b, 0x93, 0x1f, 0x73, 0x96, 0x97, 0x97, 0x44, 0xfb, 0x10, 0x40,
+    ];
+    let qos = Qos::unmarshal(&ie_to_unmarshal).unwrap();
+    let bearer_qos = qos.bearer_qos().unwrap();
+    assert_eq!(bearer_qos.qci, 8);
+    assert_eq!(bearer_qos.priority_level, QOS_ARP_MEDIUM_PRIORITY_LEVEL + 1);
+    assert_eq!((bearer_qos.maxbr_ul, bearer_qos.gbr_dl), (0, 0));
+    let apn_ambr = qos.apn_ambr().unwrap();
+    assert_eq!((apn_ambr.ambr_ul, apn_ambr.ambr_dl), (2048, 2048));
+    let mapped = Qos::from_bearer_qos(&bearer_qos, Some(&apn_ambr));
+    assert_eq!(mapped.arp, 3);
+    assert_eq!(mapped.bearer_qos().unwrap(), bearer_qos);
+    assert_eq!(mapped.apn_ambr().unwrap(), apn_ambr);
+}
+
+#[test]
+fn qos_ie_gbr_bearer_qos_mapping_test() {
+    for qci in 1..=9 {
+        let bearer_qos = BearerQos {
+            priority_level: 1,
+            qci,
+            maxbr_ul: 64,
+            maxbr_dl: 20_000,
+            gbr_ul: 32,
+            gbr_dl: 10_000,
+            ..Default::default()
+        };
+        let qos = Qos::from_bearer_qos(&bearer_qos, None);
+        assert_eq!(qos.arp, 1);
+        let mapped = qos.bearer_qos().unwrap();
+        assert_eq!(mapped.qci, qci);
+        if qci <= 4 {
+            assert_eq!(mapped.maxbr_dl, 20_000);
+            assert_eq!(mapped.gbr_dl, 10_000);
+            assert_eq!(qos.length as usize, QOS_EXTENDED_DL_LENGTH + 1);
+        } else {
+            assert_eq!(mapped.maxbr_dl, 0);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+// RAB Context IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*};
+
+// RAB Context IE TL
+
+pub const RAB_CONTEXT: u8 = 22;
+pub const RAB_CONTEXT_LENGTH: usize = 9;
+
+// RAB Context IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RabContext {
+    pub t: u8,
+    pub nsapi: u8,
+    pub dl_gtpu_sqn: u16,
+    pub ul_gtpu_sqn: u16,
+    pub dl_pdcp_sqn: u16,
+    pub ul_pdcp_sqn: u16,
+}
+
+impl Default for RabContext {
+    fn default() -> Self {
+        RabContext {
+            t: RAB_CONTEXT,
+            nsapi: 0,
+            dl_gtpu_sqn: 0,
+            ul_gtpu_sqn: 0,
+            dl_pdcp_sqn: 0,
+            ul_pdcp_sqn: 0,
+        }
+    }
+}
+
+impl IEs for RabContext {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.push(self.nsapi & 0x0f);
+        buffer.extend_from_slice(&self.dl_gtpu_sqn.to_be_bytes());
+        buffer.extend_from_slice(&self.ul_gtpu_sqn.to_be_bytes());
+        buffer.extend_from_slice(&self.dl_pdcp_sqn.to_be_bytes());
+        buffer.extend_from_slice(&self.ul_pdcp_sqn.to_be_bytes());
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() > RAB_CONTEXT_LENGTH {
+            Ok(RabContext {
+                nsapi: buffer[1] & 0x0f,
+                dl_gtpu_sqn: u16::from_be_bytes([buffer[2], buffer[3]]),
+                ul_gtpu_sqn: u16::from_be_bytes([buffer[4], buffer[5]]),
+                dl_pdcp_sqn: u16::from_be_bytes([buffer[6], buffer[7]]),
+                ul_pdcp_sqn: u16::from_be_bytes([buffer[8], buffer[9]]),
+                ..Default::default()
+            })
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        RAB_CONTEXT_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn rab_context_ie_marshal_test() {
+    let ie_to_marshal = RabContext {
+        t: RAB_CONTEXT,
+        nsapi: 5,
+        dl_gtpu_sqn: 1,
+        ul_gtpu_sqn: 2,
+        dl_pdcp_sqn: 3,
+        ul_pdcp_sqn: 4,
+    };
+    let ie_marshalled: [u8; 10] = [0x16, 0x05, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn rab_context_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 10] = [0x16, 0x05, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04];
+    let ie_unmarshalled = RabContext {
+        t: RAB_CONTEXT,
+        nsapi: 5,
+        dl_gtpu_sqn: 1,
+        ul_gtpu_sqn: 2,
+        dl_pdcp_sqn: 3,
+        ul_pdcp_sqn: 4,
+    };
+    assert_eq!(
+        RabContext::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
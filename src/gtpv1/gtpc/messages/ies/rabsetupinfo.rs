@@ -0,0 +1,137 @@
+// RAB Setup Information IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+use std::net::IpAddr;
+
+// RAB Setup Information IE Type
+
+pub const RAB_SETUP_INFO: u8 = 146;
+
+// RAB Setup Information IE implementation
+// Only the NSAPI is present when data forwarding is not supported or the RAB is released
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RabSetupInfo {
+    pub t: u8,
+    pub length: u16,
+    pub nsapi: u8,
+    pub forwarding: Option<(u32, IpAddr)>, // TEID Data I and RNC IP Address
+}
+
+impl Default for RabSetupInfo {
+    fn default() -> Self {
+        RabSetupInfo {
+            t: RAB_SETUP_INFO,
+            length: 1,
+            nsapi: 0,
+            forwarding: None,
+        }
+    }
+}
+
+impl IEs for RabSetupInfo {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.push(self.nsapi & 0x0f);
+        if let Some((teid, ip)) = self.forwarding {
+            buffer_ie.extend_from_slice(&teid.to_be_bytes());
+            match ip {
+                IpAddr::V4(i) => buffer_ie.extend_from_slice(&i.octets()),
+                IpAddr::V6(i) => buffer_ie.extend_from_slice(&i.octets()),
+            }
+        }
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() >= 3 {
+            let mut data = RabSetupInfo {
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if check_tlv_ie_buffer(data.length, buffer) {
+                data.nsapi = buffer[3] & 0x0f;
+                let ip = match data.length {
+                    1 => return Ok(data),
+                    9 => IpAddr::from([buffer[8], buffer[9], buffer[10], buffer[11]]),
+                    21 => {
+                        let mut dst = [0; 16];
+                        dst.copy_from_slice(&buffer[8..24]);
+                        IpAddr::from(dst)
+                    }
+                    _ => return Err(GTPV1Error::IEIncorrect),
+                };
+                let teid = u32::from_be_bytes([buffer[4], buffer[5], buffer[6], buffer[7]]);
+                data.forwarding = Some((teid, ip));
+                Ok(data)
+            } else {
+                Err(GTPV1Error::IEInvalidLength)
+            }
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn rab_setup_info_ie_marshal_test() {
+    let ie_marshalled: [u8; 12] = [
+        0x92, 0x00, 0x09, 0x05, 0x00, 0x00, 0x40, 0x01, 0x0a, 0x00, 0x00, 0x03,
+    ];
+    let ie_to_marshal = RabSetupInfo {
+        length: 9,
+        nsapi: 5,
+        forwarding: Some((0x4001, IpAddr::from([10, 0, 0, 3]))),
+        ..Default::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn rab_setup_info_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 12] = [
+        0x92, 0x00, 0x09, 0x05, 0x00, 0x00, 0x40, 0x01, 0x0a, 0x00, 0x00, 0x03,
+    ];
+    let ie_unmarshalled = RabSetupInfo {
+        length: 9,
+        nsapi: 5,
+        forwarding: Some((0x4001, IpAddr::from([10, 0, 0, 3]))),
+        ..Default::default()
+    };
+    assert_eq!(
+        RabSetupInfo::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
+
+#[test]
+fn rab_setup_info_ie_nsapi_only_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 4] = [0x92, 0x00, 0x01, 0x06];
+    let ie_unmarshalled = RabSetupInfo {
+        nsapi: 6,
+        ..Default::default()
+    };
+    assert_eq!(
+        RabSetupInfo::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+    assert_eq!(
+        RabSetupInfo::unmarshal(&[0x92, 0x00, 0x03, 0x06, 0x00, 0x00]),
+        Err(GTPV1Error::IEIncorrect)
+    );
+}
@@ -0,0 +1,80 @@
+// Radio Priority IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*};
+
+// Radio Priority IE TL
+
+pub const RADIO_PRIORITY: u8 = 24;
+pub const RADIO_PRIORITY_LENGTH: usize = 1;
+
+// Radio Priority IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RadioPriority {
+    pub t: u8,
+    pub nsapi: u8,
+    pub value: u8,
+}
+
+impl Default for RadioPriority {
+    fn default() -> Self {
+        RadioPriority {
+            t: RADIO_PRIORITY,
+            nsapi: 0,
+            value: 0,
+        }
+    }
+}
+
+impl IEs for RadioPriority {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.push((self.nsapi << 4) | (self.value & 0x07));
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() > RADIO_PRIORITY_LENGTH {
+            Ok(RadioPriority {
+                nsapi: buffer[1] >> 4,
+                value: buffer[1] & 0x07,
+                ..Default::default()
+            })
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        RADIO_PRIORITY_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn radio_priority_ie_marshal_test() {
+    let ie_to_marshal = RadioPriority {
+        t: RADIO_PRIORITY,
+        nsapi: 5,
+        value: 2,
+    };
+    let ie_marshalled: [u8; 2] = [0x18, 0x52];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn radio_priority_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 2] = [0x18, 0x52];
+    let ie_unmarshalled = RadioPriority {
+        t: RADIO_PRIORITY,
+        nsapi: 5,
+        value: 2,
+    };
+    assert_eq!(
+        RadioPriority::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
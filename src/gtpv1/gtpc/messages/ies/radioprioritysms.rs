@@ -0,0 +1,75 @@
+// Radio Priority SMS IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*};
+
+// Radio Priority SMS IE TL
+
+pub const RADIO_PRIORITY_SMS: u8 = 23;
+pub const RADIO_PRIORITY_SMS_LENGTH: usize = 1;
+
+// Radio Priority SMS IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RadioPrioritySms {
+    pub t: u8,
+    pub value: u8,
+}
+
+impl Default for RadioPrioritySms {
+    fn default() -> Self {
+        RadioPrioritySms {
+            t: RADIO_PRIORITY_SMS,
+            value: 0,
+        }
+    }
+}
+
+impl IEs for RadioPrioritySms {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.push(self.value & 0x07);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() > RADIO_PRIORITY_SMS_LENGTH {
+            Ok(RadioPrioritySms {
+                value: buffer[1] & 0x07,
+                ..Default::default()
+            })
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        RADIO_PRIORITY_SMS_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn radio_priority_sms_ie_marshal_test() {
+    let ie_to_marshal = RadioPrioritySms {
+        t: RADIO_PRIORITY_SMS,
+        value: 4,
+    };
+    let ie_marshalled: [u8; 2] = [0x17, 0x04];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn radio_priority_sms_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 2] = [0x17, 0x04];
+    let ie_unmarshalled = RadioPrioritySms {
+        t: RADIO_PRIORITY_SMS,
+        value: 4,
+    };
+    assert_eq!(
+        RadioPrioritySms::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
@@ -0,0 +1,75 @@
+// RANAP Cause IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*};
+
+// RANAP Cause IE TL
+
+pub const RANAP_CAUSE: u8 = 21;
+pub const RANAP_CAUSE_LENGTH: usize = 1;
+
+// RANAP Cause IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RanapCause {
+    pub t: u8,
+    pub value: u8,
+}
+
+impl Default for RanapCause {
+    fn default() -> Self {
+        RanapCause {
+            t: RANAP_CAUSE,
+            value: 0,
+        }
+    }
+}
+
+impl IEs for RanapCause {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.push(self.value);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() > RANAP_CAUSE_LENGTH {
+            Ok(RanapCause {
+                value: buffer[1],
+                ..Default::default()
+            })
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        RANAP_CAUSE_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn ranap_cause_ie_marshal_test() {
+    let ie_to_marshal = RanapCause {
+        t: RANAP_CAUSE,
+        value: 46,
+    };
+    let ie_marshalled: [u8; 2] = [0x15, 0x2e];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn ranap_cause_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 2] = [0x15, 0x2e];
+    let ie_unmarshalled = RanapCause {
+        t: RANAP_CAUSE,
+        value: 46,
+    };
+    assert_eq!(
+        RanapCause::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
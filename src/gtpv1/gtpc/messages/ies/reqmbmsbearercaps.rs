@@ -0,0 +1,92 @@
+// Required MBMS Bearer Capabilities IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+
+// Required MBMS Bearer Capabilities IE Type
+
+pub const REQUIRED_MBMS_BEARER_CAPS: u8 = 177;
+
+// Required MBMS Bearer Capabilities IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequiredMbmsBearerCapabilities {
+    pub t: u8,
+    pub length: u16,
+    pub capabilities: Vec<u8>,
+}
+
+impl Default for RequiredMbmsBearerCapabilities {
+    fn default() -> Self {
+        RequiredMbmsBearerCapabilities {
+            t: REQUIRED_MBMS_BEARER_CAPS,
+            length: 0,
+            capabilities: vec![],
+        }
+    }
+}
+
+impl IEs for RequiredMbmsBearerCapabilities {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.extend_from_slice(&self.capabilities);
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() >= 3 {
+            let mut data = RequiredMbmsBearerCapabilities {
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if check_tlv_ie_buffer(data.length, buffer) {
+                data.capabilities
+                    .extend_from_slice(&buffer[3..(3 + data.length as usize)]);
+                Ok(data)
+            } else {
+                Err(GTPV1Error::IEInvalidLength)
+            }
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn required_mbms_bearer_caps_ie_marshal_test() {
+    let ie_marshalled: [u8; 7] = [0xb1, 0x00, 0x04, 0x01, 0x02, 0x03, 0x04];
+    let ie_to_marshal = RequiredMbmsBearerCapabilities {
+        t: REQUIRED_MBMS_BEARER_CAPS,
+        length: 4,
+        capabilities: vec![0x01, 0x02, 0x03, 0x04],
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn required_mbms_bearer_caps_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 7] = [0xb1, 0x00, 0x04, 0x01, 0x02, 0x03, 0x04];
+    let ie_unmarshalled = RequiredMbmsBearerCapabilities {
+        t: REQUIRED_MBMS_BEARER_CAPS,
+        length: 4,
+        capabilities: vec![0x01, 0x02, 0x03, 0x04],
+    };
+    assert_eq!(
+        RequiredMbmsBearerCapabilities::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
@@ -0,0 +1,92 @@
+// Source RNC PDCP Context Info IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+
+// Source RNC PDCP Context Info IE Type
+
+pub const SOURCE_RNC_PDCP_CTX_INFO: u8 = 161;
+
+// Source RNC PDCP Context Info IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceRncPdcpContextInfo {
+    pub t: u8,
+    pub length: u16,
+    pub rrc_container: Vec<u8>,
+}
+
+impl Default for SourceRncPdcpContextInfo {
+    fn default() -> Self {
+        SourceRncPdcpContextInfo {
+            t: SOURCE_RNC_PDCP_CTX_INFO,
+            length: 0,
+            rrc_container: vec![],
+        }
+    }
+}
+
+impl IEs for SourceRncPdcpContextInfo {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.extend_from_slice(&self.rrc_container);
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() >= 3 {
+            let mut data = SourceRncPdcpContextInfo {
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if check_tlv_ie_buffer(data.length, buffer) {
+                data.rrc_container
+                    .extend_from_slice(&buffer[3..(3 + data.length as usize)]);
+                Ok(data)
+            } else {
+                Err(GTPV1Error::IEInvalidLength)
+            }
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn source_rnc_pdcp_ctx_info_ie_marshal_test() {
+    let ie_marshalled: [u8; 7] = [0xa1, 0x00, 0x04, 0x01, 0x02, 0x03, 0x04];
+    let ie_to_marshal = SourceRncPdcpContextInfo {
+        t: SOURCE_RNC_PDCP_CTX_INFO,
+        length: 4,
+        rrc_container: vec![0x01, 0x02, 0x03, 0x04],
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn source_rnc_pdcp_ctx_info_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 7] = [0xa1, 0x00, 0x04, 0x01, 0x02, 0x03, 0x04];
+    let ie_unmarshalled = SourceRncPdcpContextInfo {
+        t: SOURCE_RNC_PDCP_CTX_INFO,
+        length: 4,
+        rrc_container: vec![0x01, 0x02, 0x03, 0x04],
+    };
+    assert_eq!(
+        SourceRncPdcpContextInfo::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
@@ -0,0 +1,132 @@
+// Target Identification IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+
+// Target Identification IE Type
+
+pub const TARGET_ID: u8 = 138;
+pub const TARGET_ID_LENGTH: u16 = 8;
+
+// Target Identification IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetIdentification {
+    pub t: u8,
+    pub length: u16,
+    pub mcc: u16,
+    pub mnc: u16,
+    pub mnc_is_three_digits: bool,
+    pub lac: u16,
+    pub rac: u8,
+    pub rnc_id: u16,
+    pub ext_rnc_id: Option<u16>,
+}
+
+impl Default for TargetIdentification {
+    fn default() -> Self {
+        TargetIdentification {
+            t: TARGET_ID,
+            length: TARGET_ID_LENGTH,
+            mcc: 0,
+            mnc: 0,
+            mnc_is_three_digits: false,
+            lac: 0,
+            rac: 0,
+            rnc_id: 0,
+            ext_rnc_id: None,
+        }
+    }
+}
+
+impl IEs for TargetIdentification {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.append(&mut mcc_mnc_encode(
+            self.mcc,
+            self.mnc,
+            self.mnc_is_three_digits,
+        ));
+        buffer_ie.extend_from_slice(&self.lac.to_be_bytes());
+        buffer_ie.push(self.rac);
+        buffer_ie.extend_from_slice(&(self.rnc_id & 0x0fff).to_be_bytes());
+        if let Some(i) = self.ext_rnc_id {
+            buffer_ie.extend_from_slice(&i.to_be_bytes());
+        }
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() >= 3 {
+            let mut data = TargetIdentification {
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if check_tlv_ie_buffer(data.length, buffer) && data.length >= TARGET_ID_LENGTH {
+                (data.mcc, data.mnc, data.mnc_is_three_digits) = mcc_mnc_decode(&buffer[3..6]);
+                data.lac = u16::from_be_bytes([buffer[6], buffer[7]]);
+                data.rac = buffer[8];
+                data.rnc_id = u16::from_be_bytes([buffer[9], buffer[10]]) & 0x0fff;
+                if data.length >= TARGET_ID_LENGTH + 2 {
+                    data.ext_rnc_id = Some(u16::from_be_bytes([buffer[11], buffer[12]]));
+                }
+                Ok(data)
+            } else {
+                Err(GTPV1Error::IEInvalidLength)
+            }
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn target_id_ie_marshal_test() {
+    let ie_marshalled: [u8; 11] = [
+        0x8a, 0x00, 0x08, 0x62, 0xf2, 0x10, 0x00, 0x01, 0x05, 0x01, 0x23,
+    ];
+    let ie_to_marshal = TargetIdentification {
+        mcc: 262,
+        mnc: 1,
+        lac: 1,
+        rac: 5,
+        rnc_id: 0x123,
+        ..Default::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn target_id_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 13] = [
+        0x8a, 0x00, 0x0a, 0x62, 0xf2, 0x10, 0x00, 0x01, 0x05, 0x01, 0x23, 0x10, 0x00,
+    ];
+    let ie_unmarshalled = TargetIdentification {
+        length: 10,
+        mcc: 262,
+        mnc: 1,
+        lac: 1,
+        rac: 5,
+        rnc_id: 0x123,
+        ext_rnc_id: Some(0x1000),
+        ..Default::default()
+    };
+    assert_eq!(
+        TargetIdentification::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
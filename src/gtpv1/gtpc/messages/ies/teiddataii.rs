@@ -0,0 +1,81 @@
+// TEID Data II IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*};
+
+// TEID Data II IE TL
+
+pub const TEID_DATA_II: u8 = 18;
+pub const TEID_DATA_II_LENGTH: usize = 5;
+
+// TEID Data II IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeidDataII {
+    pub t: u8,
+    pub nsapi: u8,
+    pub teid: u32,
+}
+
+impl Default for TeidDataII {
+    fn default() -> Self {
+        TeidDataII {
+            t: TEID_DATA_II,
+            nsapi: 0,
+            teid: 0,
+        }
+    }
+}
+
+impl IEs for TeidDataII {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.push(self.nsapi & 0x0f);
+        buffer.extend_from_slice(&self.teid.to_be_bytes());
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() > TEID_DATA_II_LENGTH {
+            Ok(TeidDataII {
+                nsapi: buffer[1] & 0x0f,
+                teid: u32::from_be_bytes([buffer[2], buffer[3], buffer[4], buffer[5]]),
+                ..Default::default()
+            })
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        TEID_DATA_II_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn teid_data_ii_ie_marshal_test() {
+    let ie_to_marshal = TeidDataII {
+        t: TEID_DATA_II,
+        nsapi: 5,
+        teid: 0x01020304,
+    };
+    let ie_marshalled: [u8; 6] = [0x12, 0x05, 0x01, 0x02, 0x03, 0x04];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn teid_data_ii_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 6] = [0x12, 0x05, 0x01, 0x02, 0x03, 0x04];
+    let ie_unmarshalled = TeidDataII {
+        t: TEID_DATA_II,
+        nsapi: 5,
+        teid: 0x01020304,
+    };
+    assert_eq!(
+        TeidDataII::unmarshal(&ie_to_unmarshal).unwrap(),
+        ie_unmarshalled
+    );
+}
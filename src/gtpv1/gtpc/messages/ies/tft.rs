@@ -1,6 +1,9 @@
 // TFT IE - according to 3GPP TS 29.060 V15.5.0 (2019-06) and 3GPP TS 24.008 V16.0.0 (2019-03)
 
-use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+use crate::{
+    commons::TrafficFlowTemplate,
+    gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*},
+};
 
 // TFT IE Type
 
@@ -25,6 +28,29 @@ impl Default for Tft {
     }
 }
 
+impl Tft {
+    // Decode the TFT octets into a typed Traffic Flow Template
+
+    pub fn traffic_flow_template(&self) -> Result<TrafficFlowTemplate, GTPV1Error> {
+        TrafficFlowTemplate::unmarshal(&self.tft).map_err(|_| GTPV1Error::IEIncorrect)
+    }
+
+    pub fn set_traffic_flow_template(&mut self, tft: &TrafficFlowTemplate) {
+        let mut buffer: Vec<u8> = vec![];
+        tft.marshal(&mut buffer);
+        self.length = buffer.len() as u16;
+        self.tft = buffer;
+    }
+}
+
+impl From<TrafficFlowTemplate> for Tft {
+    fn from(i: TrafficFlowTemplate) -> Self {
+        let mut data = Tft::default();
+        data.set_traffic_flow_template(&i);
+        data
+    }
+}
+
 impl IEs for Tft {
     fn marshal(&self, buffer: &mut Vec<u8>) {
         let mut buffer_ie: Vec<u8> = vec![];
@@ -87,3 +113,24 @@ fn tft_ie_unmarshal_test() {
     };
     assert_eq!(Tft::unmarshal(&ie_to_unmarshal).unwrap(), ie_unmarshalled);
 }
+
+#[test]
+fn tft_ie_traffic_flow_template_test() {
+    use crate::commons::*;
+    let ie_marshalled: [u8; 6] = [0x89, 0x00, 0x03, 0xa2, 0x01, 0x02];
+    let tft = TrafficFlowTemplate {
+        operation: TftOperationCode::DeletePacketFilters,
+        packet_filter_ids: vec![1, 2],
+        ..TrafficFlowTemplate::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    Tft::from(tft.clone()).marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+    assert_eq!(
+        Tft::unmarshal(&ie_marshalled)
+            .unwrap()
+            .traffic_flow_template()
+            .unwrap(),
+        tft
+    );
+}
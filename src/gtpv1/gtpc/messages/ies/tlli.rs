@@ -0,0 +1,69 @@
+// TLLI IE - according to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*};
+
+// TLLI IE TL
+
+pub const TLLI: u8 = 4;
+pub const TLLI_LENGTH: usize = 4;
+
+// Temporary Logical Link Identifier IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tlli {
+    pub t: u8,
+    pub value: u32,
+}
+
+impl Default for Tlli {
+    fn default() -> Self {
+        Tlli { t: TLLI, value: 0 }
+    }
+}
+
+impl IEs for Tlli {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        buffer.extend_from_slice(&self.value.to_be_bytes());
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() > TLLI_LENGTH {
+            Ok(Tlli {
+                value: u32::from_be_bytes([buffer[1], buffer[2], buffer[3], buffer[4]]),
+                ..Default::default()
+            })
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        TLLI_LENGTH + 1
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn tlli_ie_marshal_test() {
+    let ie_to_marshal = Tlli {
+        t: TLLI,
+        value: 0xc0a1b2c3,
+    };
+    let ie_marshalled: [u8; 5] = [0x04, 0xc0, 0xa1, 0xb2, 0xc3];
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn tlli_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 5] = [0x04, 0xc0, 0xa1, 0xb2, 0xc3];
+    let ie_unmarshalled = Tlli {
+        t: TLLI,
+        value: 0xc0a1b2c3,
+    };
+    assert_eq!(Tlli::unmarshal(&ie_to_unmarshal).unwrap(), ie_unmarshalled);
+}
@@ -0,0 +1,103 @@
+// Temporary Mobile Group Identity (TMGI) IE - according to 3GPP TS 29.060 V15.5.0 (2019-06) and 3GPP TS 24.008
+
+use crate::gtpv1::{errors::GTPV1Error, gtpc::messages::ies::commons::*, utils::*};
+
+// TMGI IE Type
+
+pub const TMGI: u8 = 157;
+pub const TMGI_LENGTH: u16 = 6;
+
+// TMGI IE implementation
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tmgi {
+    pub t: u8,
+    pub length: u16,
+    pub mbms_service_id: u32, // 24 bits
+    pub mcc: u16,
+    pub mnc: u16,
+    pub mnc_is_three_digits: bool,
+}
+
+impl Default for Tmgi {
+    fn default() -> Self {
+        Tmgi {
+            t: TMGI,
+            length: TMGI_LENGTH,
+            mbms_service_id: 0,
+            mcc: 0,
+            mnc: 0,
+            mnc_is_three_digits: false,
+        }
+    }
+}
+
+impl IEs for Tmgi {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut buffer_ie: Vec<u8> = vec![];
+        buffer_ie.push(self.t);
+        buffer_ie.extend_from_slice(&self.length.to_be_bytes());
+        buffer_ie.extend_from_slice(&self.mbms_service_id.to_be_bytes()[1..]);
+        buffer_ie.append(&mut mcc_mnc_encode(
+            self.mcc,
+            self.mnc,
+            self.mnc_is_three_digits,
+        ));
+        set_tlv_ie_length(&mut buffer_ie);
+        buffer.append(&mut buffer_ie);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.len() >= 3 {
+            let mut data = Tmgi {
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if check_tlv_ie_buffer(data.length, buffer) && data.length >= TMGI_LENGTH {
+                data.mbms_service_id = u32::from_be_bytes([0x00, buffer[3], buffer[4], buffer[5]]);
+                (data.mcc, data.mnc, data.mnc_is_three_digits) = mcc_mnc_decode(&buffer[6..9]);
+                Ok(data)
+            } else {
+                Err(GTPV1Error::IEInvalidLength)
+            }
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.length + 3) as usize
+    }
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn tmgi_ie_marshal_test() {
+    let ie_marshalled: [u8; 9] = [0x9d, 0x00, 0x06, 0x00, 0x10, 0x01, 0x62, 0xf2, 0x10];
+    let ie_to_marshal = Tmgi {
+        mbms_service_id: 0x1001,
+        mcc: 262,
+        mnc: 1,
+        ..Default::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    ie_to_marshal.marshal(&mut buffer);
+    assert_eq!(buffer, ie_marshalled);
+}
+
+#[test]
+fn tmgi_ie_unmarshal_test() {
+    let ie_to_unmarshal: [u8; 9] = [0x9d, 0x00, 0x06, 0x00, 0x10, 0x01, 0x62, 0xf2, 0x10];
+    let ie_unmarshalled = Tmgi {
+        mbms_service_id: 0x1001,
+        mcc: 262,
+        mnc: 1,
+        ..Default::default()
+    };
+    assert_eq!(Tmgi::unmarshal(&ie_to_unmarshal).unwrap(), ie_unmarshalled);
+}
@@ -0,0 +1,105 @@
+// Unknown IE - for internal message handling purposes
+
+use crate::gtpv1::{
+    errors::GTPV1Error,
+    gtpc::messages::ies::{commons::*, ie::*},
+};
+
+// Unknown IE implementation
+// TV IEs are only decodable if their fixed length is known from 3GPP TS 29.060 Table 37
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UnknownIe {
+    pub t: u8,
+    pub length: u16,
+    pub value: Vec<u8>,
+}
+
+impl IEs for UnknownIe {
+    fn marshal(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.t);
+        if self.t >= TLV_IE_TYPE_MIN {
+            buffer.extend_from_slice(&(self.value.len() as u16).to_be_bytes());
+        }
+        buffer.extend_from_slice(&self.value);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error>
+    where
+        Self: Sized,
+    {
+        if buffer.is_empty() {
+            return Err(GTPV1Error::IEInvalidLength);
+        }
+        if buffer[0] < TLV_IE_TYPE_MIN {
+            let length = tv_ie_value_length(buffer[0]).ok_or(GTPV1Error::IEIncorrect)?;
+            if buffer.len() <= length {
+                return Err(GTPV1Error::IEInvalidLength);
+            }
+            Ok(UnknownIe {
+                t: buffer[0],
+                length: length as u16,
+                value: buffer[1..=length].to_vec(),
+            })
+        } else if buffer.len() >= 3 {
+            let mut data = UnknownIe {
+                t: buffer[0],
+                length: u16::from_be_bytes([buffer[1], buffer[2]]),
+                ..Default::default()
+            };
+            if (data.length as usize) + 3 <= buffer.len() {
+                data.value
+                    .extend_from_slice(&buffer[3..(data.length + 3) as usize]);
+                Ok(data)
+            } else {
+                Err(GTPV1Error::IEInvalidLength)
+            }
+        } else {
+            Err(GTPV1Error::IEInvalidLength)
+        }
+    }
+
+    fn len(&self) -> usize {
+        if self.t >= TLV_IE_TYPE_MIN {
+            self.length as usize + 3
+        } else {
+            self.length as usize + 1
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[test]
+fn unknown_tlv_ie_unmarshal_test() {
+    let encoded: [u8; 6] = [0xfe, 0x00, 0x03, 0x01, 0x02, 0x03];
+    let decoded = UnknownIe {
+        t: 0xfe,
+        length: 3,
+        value: vec![0x01, 0x02, 0x03],
+    };
+    assert_eq!(UnknownIe::unmarshal(&encoded).unwrap(), decoded);
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn unknown_tv_ie_unmarshal_test() {
+    let encoded: [u8; 5] = [0x04, 0xc0, 0x00, 0x00, 0x01];
+    let decoded = UnknownIe {
+        t: 0x04,
+        length: 4,
+        value: vec![0xc0, 0x00, 0x00, 0x01],
+    };
+    assert_eq!(UnknownIe::unmarshal(&encoded).unwrap(), decoded);
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+    assert_eq!(
+        UnknownIe::unmarshal(&[0x06, 0x00]),
+        Err(GTPV1Error::IEIncorrect)
+    );
+}
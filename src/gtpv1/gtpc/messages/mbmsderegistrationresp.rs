@@ -0,0 +1,142 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const MBMS_DE_REGISTRATION_RESPONSE: u8 = 115;
+
+// Definition of GTPv1-C MBMS De-Registration Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MBMSDeRegistrationResponse {
+    pub header: Gtpv1Header,
+    pub cause: Cause,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for MBMSDeRegistrationResponse {
+    fn default() -> MBMSDeRegistrationResponse {
+        let hdr = Gtpv1Header {
+            msgtype: MBMS_DE_REGISTRATION_RESPONSE,
+            ..Default::default()
+        };
+        MBMSDeRegistrationResponse {
+            header: hdr,
+            cause: Cause::default(),
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for MBMSDeRegistrationResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = MBMSDeRegistrationResponse {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != MBMS_DE_REGISTRATION_RESPONSE {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let mut cause = None;
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match cause {
+            Some(i) => {
+                message.cause = i;
+                Ok(message)
+            }
+            None => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[test]
+fn mbms_de_registration_resp_unmarshal_test() {
+    let encoded: [u8; 14] = [
+        0x32, 0x73, 0x00, 0x06, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00, 0x01, 0x80,
+    ];
+    let decoded = MBMSDeRegistrationResponse {
+        header: Gtpv1Header {
+            msgtype: MBMS_DE_REGISTRATION_RESPONSE,
+            length: 6,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        private_extension: None,
+    };
+    assert_eq!(
+        MBMSDeRegistrationResponse::unmarshal(&encoded).unwrap(),
+        decoded
+    );
+}
+
+#[test]
+fn mbms_de_registration_resp_marshal_test() {
+    let encoded: [u8; 14] = [
+        0x32, 0x73, 0x00, 0x06, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00, 0x01, 0x80,
+    ];
+    let decoded = MBMSDeRegistrationResponse {
+        header: Gtpv1Header {
+            msgtype: MBMS_DE_REGISTRATION_RESPONSE,
+            length: 6,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        private_extension: None,
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn mbms_de_registration_resp_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x73, 0x00, 0x04, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        MBMSDeRegistrationResponse::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
@@ -0,0 +1,176 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const MBMS_NOTIFICATION_REJECT_REQUEST: u8 = 98;
+
+// Definition of GTPv1-C MBMS Notification Reject Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MBMSNotificationRejectRequest {
+    pub header: Gtpv1Header,
+    pub cause: Cause,
+    pub teid_control: Teid,
+    pub nsapi: Nsapi,
+    pub end_user_address: EndUserAddress,
+    pub apn: Apn,
+    pub sgsn_ip_control: Option<GsnAddress>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for MBMSNotificationRejectRequest {
+    fn default() -> MBMSNotificationRejectRequest {
+        let hdr = Gtpv1Header {
+            msgtype: MBMS_NOTIFICATION_REJECT_REQUEST,
+            ..Default::default()
+        };
+        MBMSNotificationRejectRequest {
+            header: hdr,
+            cause: Cause::default(),
+            teid_control: Teid {
+                t: TEID_CONTROL,
+                ..Default::default()
+            },
+            nsapi: Nsapi::default(),
+            end_user_address: EndUserAddress::default(),
+            apn: Apn::default(),
+            sgsn_ip_control: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for MBMSNotificationRejectRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        elements.push(self.teid_control.into());
+        elements.push(self.nsapi.into());
+        elements.push(self.end_user_address.into());
+        elements.push(self.apn.into());
+        if let Some(i) = self.sgsn_ip_control {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = MBMSNotificationRejectRequest {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != MBMS_NOTIFICATION_REJECT_REQUEST {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let (mut cause, mut teid_control, mut nsapi, mut end_user_address, mut apn) =
+            (None, None, None, None, None);
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::Teid(i) if i.t == TEID_CONTROL && teid_control.is_none() => {
+                    teid_control = Some(i)
+                }
+                InformationElement::Nsapi(i) if nsapi.is_none() => nsapi = Some(i),
+                InformationElement::EndUserAddress(i) if end_user_address.is_none() => {
+                    end_user_address = Some(i)
+                }
+                InformationElement::Apn(i) if apn.is_none() => apn = Some(i),
+                InformationElement::GsnAddress(i) if message.sgsn_ip_control.is_none() => {
+                    message.sgsn_ip_control = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match (cause, teid_control, nsapi, end_user_address, apn) {
+            (Some(cause), Some(teid_control), Some(nsapi), Some(end_user_address), Some(apn)) => {
+                message.cause = cause;
+                message.teid_control = teid_control;
+                message.nsapi = nsapi;
+                message.end_user_address = end_user_address;
+                message.apn = apn;
+                Ok(message)
+            }
+            _ => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+fn mbms_notification_reject_req_test() -> MBMSNotificationRejectRequest {
+    MBMSNotificationRejectRequest {
+        header: Gtpv1Header {
+            msgtype: MBMS_NOTIFICATION_REJECT_REQUEST,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            ..Default::default()
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        teid_control: Teid {
+            t: TEID_CONTROL,
+            teid: 0x2002,
+        },
+        nsapi: Nsapi { t: NSAPI, value: 5 },
+        end_user_address: EndUserAddress {
+            length: 6,
+            ipv4: Some(std::net::Ipv4Addr::new(239, 0, 0, 1)),
+            ..Default::default()
+        },
+        apn: Apn {
+            length: 17,
+            name: "mbms.example.com".to_string(),
+            ..Default::default()
+        },
+        sgsn_ip_control: Some(GsnAddress {
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 2)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn mbms_notification_reject_req_marshal_unmarshal_test() {
+    let mut message = mbms_notification_reject_req_test();
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - 8) as u16;
+    assert_eq!(&buffer[..2], &[0x32, 0x62]);
+    assert_eq!(
+        MBMSNotificationRejectRequest::unmarshal(&buffer).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn mbms_notification_reject_req_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x62, 0x00, 0x04, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        MBMSNotificationRejectRequest::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
@@ -0,0 +1,142 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const MBMS_NOTIFICATION_REJECT_RESPONSE: u8 = 99;
+
+// Definition of GTPv1-C MBMS Notification Reject Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MBMSNotificationRejectResponse {
+    pub header: Gtpv1Header,
+    pub cause: Cause,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for MBMSNotificationRejectResponse {
+    fn default() -> MBMSNotificationRejectResponse {
+        let hdr = Gtpv1Header {
+            msgtype: MBMS_NOTIFICATION_REJECT_RESPONSE,
+            ..Default::default()
+        };
+        MBMSNotificationRejectResponse {
+            header: hdr,
+            cause: Cause::default(),
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for MBMSNotificationRejectResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = MBMSNotificationRejectResponse {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != MBMS_NOTIFICATION_REJECT_RESPONSE {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let mut cause = None;
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match cause {
+            Some(i) => {
+                message.cause = i;
+                Ok(message)
+            }
+            None => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[test]
+fn mbms_notification_reject_resp_unmarshal_test() {
+    let encoded: [u8; 14] = [
+        0x32, 0x63, 0x00, 0x06, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00, 0x01, 0x80,
+    ];
+    let decoded = MBMSNotificationRejectResponse {
+        header: Gtpv1Header {
+            msgtype: MBMS_NOTIFICATION_REJECT_RESPONSE,
+            length: 6,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        private_extension: None,
+    };
+    assert_eq!(
+        MBMSNotificationRejectResponse::unmarshal(&encoded).unwrap(),
+        decoded
+    );
+}
+
+#[test]
+fn mbms_notification_reject_resp_marshal_test() {
+    let encoded: [u8; 14] = [
+        0x32, 0x63, 0x00, 0x06, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00, 0x01, 0x80,
+    ];
+    let decoded = MBMSNotificationRejectResponse {
+        header: Gtpv1Header {
+            msgtype: MBMS_NOTIFICATION_REJECT_RESPONSE,
+            length: 6,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        private_extension: None,
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn mbms_notification_reject_resp_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x63, 0x00, 0x04, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        MBMSNotificationRejectResponse::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
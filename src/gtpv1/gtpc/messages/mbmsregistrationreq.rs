@@ -0,0 +1,157 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const MBMS_REGISTRATION_REQUEST: u8 = 112;
+
+// Definition of GTPv1-C MBMS Registration Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MBMSRegistrationRequest {
+    pub header: Gtpv1Header,
+    pub end_user_address: EndUserAddress,
+    pub apn: Apn,
+    pub sgsn_ip_control: GsnAddress,
+    pub alt_sgsn_ip_control: Option<GsnAddress>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for MBMSRegistrationRequest {
+    fn default() -> MBMSRegistrationRequest {
+        let hdr = Gtpv1Header {
+            msgtype: MBMS_REGISTRATION_REQUEST,
+            ..Default::default()
+        };
+        MBMSRegistrationRequest {
+            header: hdr,
+            end_user_address: EndUserAddress::default(),
+            apn: Apn::default(),
+            sgsn_ip_control: GsnAddress::default(),
+            alt_sgsn_ip_control: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for MBMSRegistrationRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.end_user_address.into()];
+        elements.push(self.apn.into());
+        elements.push(self.sgsn_ip_control.into());
+        if let Some(i) = self.alt_sgsn_ip_control {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = MBMSRegistrationRequest {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != MBMS_REGISTRATION_REQUEST {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let (mut end_user_address, mut apn, mut sgsn_ip_control) = (None, None, None);
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::EndUserAddress(i) if end_user_address.is_none() => {
+                    end_user_address = Some(i)
+                }
+                InformationElement::Apn(i) if apn.is_none() => apn = Some(i),
+                InformationElement::GsnAddress(i) if sgsn_ip_control.is_none() => {
+                    sgsn_ip_control = Some(i)
+                }
+                InformationElement::GsnAddress(i) if message.alt_sgsn_ip_control.is_none() => {
+                    message.alt_sgsn_ip_control = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match (end_user_address, apn, sgsn_ip_control) {
+            (Some(end_user_address), Some(apn), Some(sgsn_ip_control)) => {
+                message.end_user_address = end_user_address;
+                message.apn = apn;
+                message.sgsn_ip_control = sgsn_ip_control;
+                Ok(message)
+            }
+            _ => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+fn mbms_registration_req_test() -> MBMSRegistrationRequest {
+    MBMSRegistrationRequest {
+        header: Gtpv1Header {
+            msgtype: MBMS_REGISTRATION_REQUEST,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            ..Default::default()
+        },
+        end_user_address: EndUserAddress {
+            length: 6,
+            ipv4: Some(std::net::Ipv4Addr::new(239, 0, 0, 1)),
+            ..Default::default()
+        },
+        apn: Apn {
+            length: 17,
+            name: "mbms.example.com".to_string(),
+            ..Default::default()
+        },
+        sgsn_ip_control: GsnAddress {
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 2)),
+            ..Default::default()
+        },
+        alt_sgsn_ip_control: Some(GsnAddress {
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 1, 2)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn mbms_registration_req_marshal_unmarshal_test() {
+    let mut message = mbms_registration_req_test();
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - 8) as u16;
+    assert_eq!(&buffer[..2], &[0x32, 0x70]);
+    assert_eq!(
+        MBMSRegistrationRequest::unmarshal(&buffer).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn mbms_registration_req_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x70, 0x00, 0x04, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        MBMSRegistrationRequest::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
@@ -0,0 +1,148 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const MBMS_REGISTRATION_RESPONSE: u8 = 113;
+
+// Definition of GTPv1-C MBMS Registration Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MBMSRegistrationResponse {
+    pub header: Gtpv1Header,
+    pub cause: Cause,
+    pub tmgi: Option<Tmgi>,
+    pub required_mbms_bearer_caps: Option<RequiredMbmsBearerCapabilities>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for MBMSRegistrationResponse {
+    fn default() -> MBMSRegistrationResponse {
+        let hdr = Gtpv1Header {
+            msgtype: MBMS_REGISTRATION_RESPONSE,
+            ..Default::default()
+        };
+        MBMSRegistrationResponse {
+            header: hdr,
+            cause: Cause::default(),
+            tmgi: None,
+            required_mbms_bearer_caps: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for MBMSRegistrationResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        if let Some(i) = self.tmgi {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.required_mbms_bearer_caps {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = MBMSRegistrationResponse {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != MBMS_REGISTRATION_RESPONSE {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let mut cause = None;
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::Tmgi(i) if message.tmgi.is_none() => message.tmgi = Some(i),
+                InformationElement::RequiredMbmsBearerCapabilities(i)
+                    if message.required_mbms_bearer_caps.is_none() =>
+                {
+                    message.required_mbms_bearer_caps = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match cause {
+            Some(i) => {
+                message.cause = i;
+                Ok(message)
+            }
+            None => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+fn mbms_registration_resp_test() -> MBMSRegistrationResponse {
+    MBMSRegistrationResponse {
+        header: Gtpv1Header {
+            msgtype: MBMS_REGISTRATION_RESPONSE,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            ..Default::default()
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        tmgi: Some(Tmgi {
+            mbms_service_id: 0x1001,
+            mcc: 262,
+            mnc: 1,
+            ..Default::default()
+        }),
+        required_mbms_bearer_caps: Some(RequiredMbmsBearerCapabilities {
+            length: 3,
+            capabilities: vec![0x01, 0x02, 0x03],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn mbms_registration_resp_marshal_unmarshal_test() {
+    let mut message = mbms_registration_resp_test();
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - 8) as u16;
+    assert_eq!(&buffer[..2], &[0x32, 0x71]);
+    assert_eq!(
+        MBMSRegistrationResponse::unmarshal(&buffer).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn mbms_registration_resp_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x71, 0x00, 0x04, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        MBMSRegistrationResponse::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
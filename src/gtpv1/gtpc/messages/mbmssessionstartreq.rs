@@ -0,0 +1,345 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const MBMS_SESSION_START_REQUEST: u8 = 116;
+
+// Definition of GTPv1-C MBMS Session Start Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MBMSSessionStartRequest {
+    pub header: Gtpv1Header,
+    pub recovery: Option<Recovery>,
+    pub teid_control: Teid,
+    pub end_user_address: EndUserAddress,
+    pub apn: Apn,
+    pub ggsn_ip_control: GsnAddress,
+    pub alt_ggsn_ip_control: Option<GsnAddress>,
+    pub qos: Qos,
+    pub common_flags: CommonFlags,
+    pub tmgi: Tmgi,
+    pub mbms_service_area: MbmsServiceArea,
+    pub mbms_session_id: Option<MbmsSessionIdentifier>,
+    pub mbms_2g3g_indicator: Mbms2g3gIndicator,
+    pub mbms_session_duration: MbmsSessionDuration,
+    pub mbms_session_repetition_number: Option<MbmsSessionRepetitionNumber>,
+    pub mbms_time_to_data_transfer: MbmsTimeToDataTransfer,
+    pub mbms_flow_id: Option<MbmsFlowIdentifier>,
+    pub mbms_ip_multicast: Option<MbmsIpMulticastDistribution>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for MBMSSessionStartRequest {
+    fn default() -> MBMSSessionStartRequest {
+        let hdr = Gtpv1Header {
+            msgtype: MBMS_SESSION_START_REQUEST,
+            ..Default::default()
+        };
+        MBMSSessionStartRequest {
+            header: hdr,
+            recovery: None,
+            teid_control: Teid {
+                t: TEID_CONTROL,
+                ..Default::default()
+            },
+            end_user_address: EndUserAddress::default(),
+            apn: Apn::default(),
+            ggsn_ip_control: GsnAddress::default(),
+            alt_ggsn_ip_control: None,
+            qos: Qos::default(),
+            common_flags: CommonFlags::default(),
+            tmgi: Tmgi::default(),
+            mbms_service_area: MbmsServiceArea::default(),
+            mbms_session_id: None,
+            mbms_2g3g_indicator: Mbms2g3gIndicator::default(),
+            mbms_session_duration: MbmsSessionDuration::default(),
+            mbms_session_repetition_number: None,
+            mbms_time_to_data_transfer: MbmsTimeToDataTransfer::default(),
+            mbms_flow_id: None,
+            mbms_ip_multicast: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for MBMSSessionStartRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![];
+        if let Some(i) = self.recovery {
+            elements.push(i.into());
+        }
+        elements.push(self.teid_control.into());
+        elements.push(self.end_user_address.into());
+        elements.push(self.apn.into());
+        elements.push(self.ggsn_ip_control.into());
+        if let Some(i) = self.alt_ggsn_ip_control {
+            elements.push(i.into());
+        }
+        elements.push(self.qos.into());
+        elements.push(self.common_flags.into());
+        elements.push(self.tmgi.into());
+        elements.push(self.mbms_service_area.into());
+        if let Some(i) = self.mbms_session_id {
+            elements.push(i.into());
+        }
+        elements.push(self.mbms_2g3g_indicator.into());
+        elements.push(self.mbms_session_duration.into());
+        if let Some(i) = self.mbms_session_repetition_number {
+            elements.push(i.into());
+        }
+        elements.push(self.mbms_time_to_data_transfer.into());
+        if let Some(i) = self.mbms_flow_id {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.mbms_ip_multicast {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = MBMSSessionStartRequest {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != MBMS_SESSION_START_REQUEST {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let (
+            mut teid_control,
+            mut end_user_address,
+            mut apn,
+            mut ggsn_ip_control,
+            mut qos,
+            mut common_flags,
+            mut tmgi,
+            mut mbms_service_area,
+            mut mbms_2g3g_indicator,
+            mut mbms_session_duration,
+            mut mbms_time_to_data_transfer,
+        ) = (
+            None, None, None, None, None, None, None, None, None, None, None,
+        );
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Recovery(i) if message.recovery.is_none() => {
+                    message.recovery = Some(i)
+                }
+                InformationElement::Teid(i) if i.t == TEID_CONTROL && teid_control.is_none() => {
+                    teid_control = Some(i)
+                }
+                InformationElement::EndUserAddress(i) if end_user_address.is_none() => {
+                    end_user_address = Some(i)
+                }
+                InformationElement::Apn(i) if apn.is_none() => apn = Some(i),
+                InformationElement::GsnAddress(i) if ggsn_ip_control.is_none() => {
+                    ggsn_ip_control = Some(i)
+                }
+                InformationElement::GsnAddress(i) if message.alt_ggsn_ip_control.is_none() => {
+                    message.alt_ggsn_ip_control = Some(i)
+                }
+                InformationElement::Qos(i) if qos.is_none() => qos = Some(i),
+                InformationElement::CommonFlags(i) if common_flags.is_none() => {
+                    common_flags = Some(i)
+                }
+                InformationElement::Tmgi(i) if tmgi.is_none() => tmgi = Some(i),
+                InformationElement::MbmsServiceArea(i) if mbms_service_area.is_none() => {
+                    mbms_service_area = Some(i)
+                }
+                InformationElement::MbmsSessionIdentifier(i)
+                    if message.mbms_session_id.is_none() =>
+                {
+                    message.mbms_session_id = Some(i)
+                }
+                InformationElement::Mbms2g3gIndicator(i) if mbms_2g3g_indicator.is_none() => {
+                    mbms_2g3g_indicator = Some(i)
+                }
+                InformationElement::MbmsSessionDuration(i) if mbms_session_duration.is_none() => {
+                    mbms_session_duration = Some(i)
+                }
+                InformationElement::MbmsSessionRepetitionNumber(i)
+                    if message.mbms_session_repetition_number.is_none() =>
+                {
+                    message.mbms_session_repetition_number = Some(i)
+                }
+                InformationElement::MbmsTimeToDataTransfer(i)
+                    if mbms_time_to_data_transfer.is_none() =>
+                {
+                    mbms_time_to_data_transfer = Some(i)
+                }
+                InformationElement::MbmsFlowIdentifier(i) if message.mbms_flow_id.is_none() => {
+                    message.mbms_flow_id = Some(i)
+                }
+                InformationElement::MbmsIpMulticastDistribution(i)
+                    if message.mbms_ip_multicast.is_none() =>
+                {
+                    message.mbms_ip_multicast = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match (
+            teid_control,
+            end_user_address,
+            apn,
+            ggsn_ip_control,
+            qos,
+            common_flags,
+            tmgi,
+            mbms_service_area,
+            mbms_2g3g_indicator,
+            mbms_session_duration,
+            mbms_time_to_data_transfer,
+        ) {
+            (
+                Some(teid_control),
+                Some(end_user_address),
+                Some(apn),
+                Some(ggsn_ip_control),
+                Some(qos),
+                Some(common_flags),
+                Some(tmgi),
+                Some(mbms_service_area),
+                Some(mbms_2g3g_indicator),
+                Some(mbms_session_duration),
+                Some(mbms_time_to_data_transfer),
+            ) => {
+                message.teid_control = teid_control;
+                message.end_user_address = end_user_address;
+                message.apn = apn;
+                message.ggsn_ip_control = ggsn_ip_control;
+                message.qos = qos;
+                message.common_flags = common_flags;
+                message.tmgi = tmgi;
+                message.mbms_service_area = mbms_service_area;
+                message.mbms_2g3g_indicator = mbms_2g3g_indicator;
+                message.mbms_session_duration = mbms_session_duration;
+                message.mbms_time_to_data_transfer = mbms_time_to_data_transfer;
+                Ok(message)
+            }
+            _ => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+fn mbms_session_start_req_test() -> MBMSSessionStartRequest {
+    MBMSSessionStartRequest {
+        header: Gtpv1Header {
+            msgtype: MBMS_SESSION_START_REQUEST,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            ..Default::default()
+        },
+        recovery: Some(Recovery {
+            t: RECOVERY,
+            value: 7,
+        }),
+        teid_control: Teid {
+            t: TEID_CONTROL,
+            teid: 0x2002,
+        },
+        end_user_address: EndUserAddress {
+            length: 6,
+            ipv4: Some(std::net::Ipv4Addr::new(239, 0, 0, 1)),
+            ..Default::default()
+        },
+        apn: Apn {
+            length: 17,
+            name: "mbms.example.com".to_string(),
+            ..Default::default()
+        },
+        ggsn_ip_control: GsnAddress {
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 1)),
+            ..Default::default()
+        },
+        qos: Qos {
+            length: 4,
+            arp: 2,
+            qos: vec![0x1b, 0x93, 0x1f],
+            ..Default::default()
+        },
+        common_flags: CommonFlags {
+            mbms_service_type: true,
+            ..Default::default()
+        },
+        tmgi: Tmgi {
+            mbms_service_id: 0x1001,
+            mcc: 262,
+            mnc: 1,
+            ..Default::default()
+        },
+        mbms_service_area: MbmsServiceArea {
+            length: 5,
+            service_areas: vec![1, 2],
+            ..Default::default()
+        },
+        mbms_session_id: Some(MbmsSessionIdentifier {
+            value: 7,
+            ..Default::default()
+        }),
+        mbms_2g3g_indicator: Mbms2g3gIndicator {
+            value: 2,
+            ..Default::default()
+        },
+        mbms_session_duration: MbmsSessionDuration {
+            seconds: 900,
+            ..Default::default()
+        },
+        mbms_time_to_data_transfer: MbmsTimeToDataTransfer {
+            value: 9,
+            ..Default::default()
+        },
+        mbms_flow_id: Some(MbmsFlowIdentifier {
+            length: 2,
+            flow_id: vec![0x00, 0x01],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn mbms_session_start_req_marshal_unmarshal_test() {
+    let mut message = mbms_session_start_req_test();
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - 8) as u16;
+    assert_eq!(&buffer[..2], &[0x32, 0x74]);
+    assert_eq!(
+        MBMSSessionStartRequest::unmarshal(&buffer).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn mbms_session_start_req_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x74, 0x00, 0x04, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        MBMSSessionStartRequest::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
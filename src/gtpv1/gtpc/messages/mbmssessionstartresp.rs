@@ -0,0 +1,205 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const MBMS_SESSION_START_RESPONSE: u8 = 117;
+
+// Definition of GTPv1-C MBMS Session Start Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MBMSSessionStartResponse {
+    pub header: Gtpv1Header,
+    pub cause: Cause,
+    pub recovery: Option<Recovery>,
+    pub teid_data: Option<Teid>,
+    pub teid_control: Option<Teid>,
+    pub sgsn_ip_control: Option<GsnAddress>,
+    pub sgsn_ip_user: Option<GsnAddress>,
+    pub alt_sgsn_ip_user: Option<GsnAddress>,
+    pub mbms_distribution_ack: Option<MbmsDistributionAck>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for MBMSSessionStartResponse {
+    fn default() -> MBMSSessionStartResponse {
+        let hdr = Gtpv1Header {
+            msgtype: MBMS_SESSION_START_RESPONSE,
+            ..Default::default()
+        };
+        MBMSSessionStartResponse {
+            header: hdr,
+            cause: Cause::default(),
+            recovery: None,
+            teid_data: None,
+            teid_control: None,
+            sgsn_ip_control: None,
+            sgsn_ip_user: None,
+            alt_sgsn_ip_user: None,
+            mbms_distribution_ack: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for MBMSSessionStartResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        if let Some(i) = self.recovery {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.teid_data {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.teid_control {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.sgsn_ip_control {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.sgsn_ip_user {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.alt_sgsn_ip_user {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.mbms_distribution_ack {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = MBMSSessionStartResponse {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != MBMS_SESSION_START_RESPONSE {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let mut cause = None;
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::Recovery(i) if message.recovery.is_none() => {
+                    message.recovery = Some(i)
+                }
+                InformationElement::Teid(i) if i.t == TEID_DATA && message.teid_data.is_none() => {
+                    message.teid_data = Some(i)
+                }
+                InformationElement::Teid(i)
+                    if i.t == TEID_CONTROL && message.teid_control.is_none() =>
+                {
+                    message.teid_control = Some(i)
+                }
+                InformationElement::GsnAddress(i) if message.sgsn_ip_control.is_none() => {
+                    message.sgsn_ip_control = Some(i)
+                }
+                InformationElement::GsnAddress(i) if message.sgsn_ip_user.is_none() => {
+                    message.sgsn_ip_user = Some(i)
+                }
+                InformationElement::GsnAddress(i) if message.alt_sgsn_ip_user.is_none() => {
+                    message.alt_sgsn_ip_user = Some(i)
+                }
+                InformationElement::MbmsDistributionAck(i)
+                    if message.mbms_distribution_ack.is_none() =>
+                {
+                    message.mbms_distribution_ack = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match cause {
+            Some(i) => {
+                message.cause = i;
+                Ok(message)
+            }
+            None => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+fn mbms_session_start_resp_test() -> MBMSSessionStartResponse {
+    MBMSSessionStartResponse {
+        header: Gtpv1Header {
+            msgtype: MBMS_SESSION_START_RESPONSE,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            ..Default::default()
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        teid_data: Some(Teid {
+            t: TEID_DATA,
+            teid: 0x3001,
+        }),
+        teid_control: Some(Teid {
+            t: TEID_CONTROL,
+            teid: 0x2002,
+        }),
+        sgsn_ip_control: Some(GsnAddress {
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 2)),
+            ..Default::default()
+        }),
+        sgsn_ip_user: Some(GsnAddress {
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 3)),
+            ..Default::default()
+        }),
+        alt_sgsn_ip_user: Some(GsnAddress {
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 1, 3)),
+            ..Default::default()
+        }),
+        mbms_distribution_ack: Some(MbmsDistributionAck {
+            distribution_ind: 1,
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn mbms_session_start_resp_marshal_unmarshal_test() {
+    let mut message = mbms_session_start_resp_test();
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - 8) as u16;
+    assert_eq!(&buffer[..2], &[0x32, 0x75]);
+    assert_eq!(
+        MBMSSessionStartResponse::unmarshal(&buffer).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn mbms_session_start_resp_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x75, 0x00, 0x04, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        MBMSSessionStartResponse::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
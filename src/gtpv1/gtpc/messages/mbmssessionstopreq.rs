@@ -0,0 +1,144 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const MBMS_SESSION_STOP_REQUEST: u8 = 118;
+
+// Definition of GTPv1-C MBMS Session Stop Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MBMSSessionStopRequest {
+    pub header: Gtpv1Header,
+    pub end_user_address: EndUserAddress,
+    pub apn: Apn,
+    pub mbms_flow_id: Option<MbmsFlowIdentifier>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for MBMSSessionStopRequest {
+    fn default() -> MBMSSessionStopRequest {
+        let hdr = Gtpv1Header {
+            msgtype: MBMS_SESSION_STOP_REQUEST,
+            ..Default::default()
+        };
+        MBMSSessionStopRequest {
+            header: hdr,
+            end_user_address: EndUserAddress::default(),
+            apn: Apn::default(),
+            mbms_flow_id: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for MBMSSessionStopRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.end_user_address.into()];
+        elements.push(self.apn.into());
+        if let Some(i) = self.mbms_flow_id {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = MBMSSessionStopRequest {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != MBMS_SESSION_STOP_REQUEST {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let (mut end_user_address, mut apn) = (None, None);
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::EndUserAddress(i) if end_user_address.is_none() => {
+                    end_user_address = Some(i)
+                }
+                InformationElement::Apn(i) if apn.is_none() => apn = Some(i),
+                InformationElement::MbmsFlowIdentifier(i) if message.mbms_flow_id.is_none() => {
+                    message.mbms_flow_id = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match (end_user_address, apn) {
+            (Some(end_user_address), Some(apn)) => {
+                message.end_user_address = end_user_address;
+                message.apn = apn;
+                Ok(message)
+            }
+            _ => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+fn mbms_session_stop_req_test() -> MBMSSessionStopRequest {
+    MBMSSessionStopRequest {
+        header: Gtpv1Header {
+            msgtype: MBMS_SESSION_STOP_REQUEST,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            ..Default::default()
+        },
+        end_user_address: EndUserAddress {
+            length: 6,
+            ipv4: Some(std::net::Ipv4Addr::new(239, 0, 0, 1)),
+            ..Default::default()
+        },
+        apn: Apn {
+            length: 17,
+            name: "mbms.example.com".to_string(),
+            ..Default::default()
+        },
+        mbms_flow_id: Some(MbmsFlowIdentifier {
+            length: 2,
+            flow_id: vec![0x00, 0x01],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn mbms_session_stop_req_marshal_unmarshal_test() {
+    let mut message = mbms_session_stop_req_test();
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - 8) as u16;
+    assert_eq!(&buffer[..2], &[0x32, 0x76]);
+    assert_eq!(MBMSSessionStopRequest::unmarshal(&buffer).unwrap(), message);
+}
+
+#[test]
+fn mbms_session_stop_req_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x76, 0x00, 0x04, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        MBMSSessionStopRequest::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
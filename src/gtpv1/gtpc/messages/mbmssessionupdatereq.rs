@@ -0,0 +1,222 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const MBMS_SESSION_UPDATE_REQUEST: u8 = 120;
+
+// Definition of GTPv1-C MBMS Session Update Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MBMSSessionUpdateRequest {
+    pub header: Gtpv1Header,
+    pub teid_control: Option<Teid>,
+    pub end_user_address: EndUserAddress,
+    pub apn: Apn,
+    pub ggsn_ip_control: Option<GsnAddress>,
+    pub tmgi: Tmgi,
+    pub mbms_service_area: MbmsServiceArea,
+    pub mbms_session_duration: MbmsSessionDuration,
+    pub mbms_flow_id: Option<MbmsFlowIdentifier>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for MBMSSessionUpdateRequest {
+    fn default() -> MBMSSessionUpdateRequest {
+        let hdr = Gtpv1Header {
+            msgtype: MBMS_SESSION_UPDATE_REQUEST,
+            ..Default::default()
+        };
+        MBMSSessionUpdateRequest {
+            header: hdr,
+            teid_control: None,
+            end_user_address: EndUserAddress::default(),
+            apn: Apn::default(),
+            ggsn_ip_control: None,
+            tmgi: Tmgi::default(),
+            mbms_service_area: MbmsServiceArea::default(),
+            mbms_session_duration: MbmsSessionDuration::default(),
+            mbms_flow_id: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for MBMSSessionUpdateRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![];
+        if let Some(i) = self.teid_control {
+            elements.push(i.into());
+        }
+        elements.push(self.end_user_address.into());
+        elements.push(self.apn.into());
+        if let Some(i) = self.ggsn_ip_control {
+            elements.push(i.into());
+        }
+        elements.push(self.tmgi.into());
+        elements.push(self.mbms_service_area.into());
+        elements.push(self.mbms_session_duration.into());
+        if let Some(i) = self.mbms_flow_id {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = MBMSSessionUpdateRequest {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != MBMS_SESSION_UPDATE_REQUEST {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let (
+            mut end_user_address,
+            mut apn,
+            mut tmgi,
+            mut mbms_service_area,
+            mut mbms_session_duration,
+        ) = (None, None, None, None, None);
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Teid(i)
+                    if i.t == TEID_CONTROL && message.teid_control.is_none() =>
+                {
+                    message.teid_control = Some(i)
+                }
+                InformationElement::EndUserAddress(i) if end_user_address.is_none() => {
+                    end_user_address = Some(i)
+                }
+                InformationElement::Apn(i) if apn.is_none() => apn = Some(i),
+                InformationElement::GsnAddress(i) if message.ggsn_ip_control.is_none() => {
+                    message.ggsn_ip_control = Some(i)
+                }
+                InformationElement::Tmgi(i) if tmgi.is_none() => tmgi = Some(i),
+                InformationElement::MbmsServiceArea(i) if mbms_service_area.is_none() => {
+                    mbms_service_area = Some(i)
+                }
+                InformationElement::MbmsSessionDuration(i) if mbms_session_duration.is_none() => {
+                    mbms_session_duration = Some(i)
+                }
+                InformationElement::MbmsFlowIdentifier(i) if message.mbms_flow_id.is_none() => {
+                    message.mbms_flow_id = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match (
+            end_user_address,
+            apn,
+            tmgi,
+            mbms_service_area,
+            mbms_session_duration,
+        ) {
+            (
+                Some(end_user_address),
+                Some(apn),
+                Some(tmgi),
+                Some(mbms_service_area),
+                Some(mbms_session_duration),
+            ) => {
+                message.end_user_address = end_user_address;
+                message.apn = apn;
+                message.tmgi = tmgi;
+                message.mbms_service_area = mbms_service_area;
+                message.mbms_session_duration = mbms_session_duration;
+                Ok(message)
+            }
+            _ => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+fn mbms_session_update_req_test() -> MBMSSessionUpdateRequest {
+    MBMSSessionUpdateRequest {
+        header: Gtpv1Header {
+            msgtype: MBMS_SESSION_UPDATE_REQUEST,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            ..Default::default()
+        },
+        teid_control: Some(Teid {
+            t: TEID_CONTROL,
+            teid: 0x2002,
+        }),
+        end_user_address: EndUserAddress {
+            length: 6,
+            ipv4: Some(std::net::Ipv4Addr::new(239, 0, 0, 1)),
+            ..Default::default()
+        },
+        apn: Apn {
+            length: 17,
+            name: "mbms.example.com".to_string(),
+            ..Default::default()
+        },
+        tmgi: Tmgi {
+            mbms_service_id: 0x1001,
+            mcc: 262,
+            mnc: 1,
+            ..Default::default()
+        },
+        mbms_service_area: MbmsServiceArea {
+            length: 5,
+            service_areas: vec![1, 2],
+            ..Default::default()
+        },
+        mbms_session_duration: MbmsSessionDuration {
+            seconds: 900,
+            ..Default::default()
+        },
+        mbms_flow_id: Some(MbmsFlowIdentifier {
+            length: 2,
+            flow_id: vec![0x00, 0x01],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn mbms_session_update_req_marshal_unmarshal_test() {
+    let mut message = mbms_session_update_req_test();
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - 8) as u16;
+    assert_eq!(&buffer[..2], &[0x32, 0x78]);
+    assert_eq!(
+        MBMSSessionUpdateRequest::unmarshal(&buffer).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn mbms_session_update_req_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x78, 0x00, 0x04, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        MBMSSessionUpdateRequest::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
@@ -0,0 +1,552 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+// Definition of GTPv1-C Message enum covering all implemented GTPv1-C messages
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
+pub enum GTPV1CMessage {
+    EchoRequest(EchoRequest),
+    EchoResponse(EchoResponse),
+    VersionNotSupported(VersionNotSupported),
+    CreatePDPContextRequest(CreatePDPContextRequest),
+    CreatePDPContextResponse(CreatePDPContextResponse),
+    UpdatePDPContextRequest(UpdatePDPContextRequest),
+    UpdatePDPContextResponse(UpdatePDPContextResponse),
+    UpdatePDPContextRequestGGSN(UpdatePDPContextRequestGGSN),
+    UpdatePDPContextResponseGGSN(UpdatePDPContextResponseGGSN),
+    DeletePDPContextRequest(DeletePDPContextRequest),
+    DeletePDPContextResponse(DeletePDPContextResponse),
+    InitiatePDPContextActivationRequest(InitiatePDPContextActivationRequest),
+    InitiatePDPContextActivationResponse(InitiatePDPContextActivationResponse),
+    PDUNotificationRequest(PDUNotificationRequest),
+    PDUNotificationResponse(PDUNotificationResponse),
+    PDUNotificationRejectRequest(PDUNotificationRejectRequest),
+    PDUNotificationRejectResponse(PDUNotificationRejectResponse),
+    SendRoutingInfoRequest(SendRoutingInfoRequest),
+    SendRoutingInfoResponse(SendRoutingInfoResponse),
+    FailureReportRequest(FailureReportRequest),
+    FailureReportResponse(FailureReportResponse),
+    NoteMSGPRSPresentRequest(NoteMSGPRSPresentRequest),
+    NoteMSGPRSPresentResponse(NoteMSGPRSPresentResponse),
+    SupportedExtensionHeadersNotification(SupportedExtensionHeadersNotification),
+    MSInfoChangeNotificationRequest(MSInfoChangeNotificationRequest),
+    MSInfoChangeNotificationResponse(MSInfoChangeNotificationResponse),
+    SGSNContextRequest(SGSNContextRequest),
+    SGSNContextResponse(SGSNContextResponse),
+    SGSNContextAcknowledge(SGSNContextAcknowledge),
+    ForwardRelocationRequest(ForwardRelocationRequest),
+    ForwardRelocationResponse(ForwardRelocationResponse),
+    ForwardRelocationComplete(ForwardRelocationComplete),
+    RelocationCancelRequest(RelocationCancelRequest),
+    RelocationCancelResponse(RelocationCancelResponse),
+    ForwardSRNSContext(ForwardSRNSContext),
+    ForwardRelocationCompleteAcknowledge(ForwardRelocationCompleteAcknowledge),
+    ForwardSRNSContextAcknowledge(ForwardSRNSContextAcknowledge),
+    MBMSNotificationRequest(MBMSNotificationRequest),
+    MBMSNotificationResponse(MBMSNotificationResponse),
+    MBMSNotificationRejectRequest(MBMSNotificationRejectRequest),
+    MBMSNotificationRejectResponse(MBMSNotificationRejectResponse),
+    CreateMBMSContextRequest(CreateMBMSContextRequest),
+    CreateMBMSContextResponse(CreateMBMSContextResponse),
+    UpdateMBMSContextRequest(UpdateMBMSContextRequest),
+    UpdateMBMSContextResponse(UpdateMBMSContextResponse),
+    DeleteMBMSContextRequest(DeleteMBMSContextRequest),
+    DeleteMBMSContextResponse(DeleteMBMSContextResponse),
+    MBMSRegistrationRequest(MBMSRegistrationRequest),
+    MBMSRegistrationResponse(MBMSRegistrationResponse),
+    MBMSDeRegistrationRequest(MBMSDeRegistrationRequest),
+    MBMSDeRegistrationResponse(MBMSDeRegistrationResponse),
+    MBMSSessionStartRequest(MBMSSessionStartRequest),
+    MBMSSessionStartResponse(MBMSSessionStartResponse),
+    MBMSSessionStopRequest(MBMSSessionStopRequest),
+    MBMSSessionStopResponse(MBMSSessionStopResponse),
+    MBMSSessionUpdateRequest(MBMSSessionUpdateRequest),
+    MBMSSessionUpdateResponse(MBMSSessionUpdateResponse),
+    Unknown { header: Gtpv1Header, body: Vec<u8> },
+}
+
+impl GTPV1CMessage {
+    // Decode any GTPv1-C message based on the message type in the header
+    // SGSN and GGSN initiated Update PDP Context messages share the same message type,
+    // so the variant is picked from the IEs carried by the message.
+    // A request with a TEID or GSN Address IE is SGSN initiated, a response with ULI or MS Time Zone is
+    // a response to a GGSN initiated request. Other responses decode as the SGSN initiated variant first and
+    // report its error when the GGSN initiated variant does not decode either
+
+    pub fn parse(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let header = Gtpv1Header::unmarshal(buffer)?;
+        if header.length as usize + MIN_HEADER_LENGTH < header.len() {
+            return Err(GTPV1Error::MessageLengthError);
+        }
+        match header.msgtype {
+            ECHO_REQUEST => EchoRequest::unmarshal(buffer).map(GTPV1CMessage::EchoRequest),
+            ECHO_RESPONSE => EchoResponse::unmarshal(buffer).map(GTPV1CMessage::EchoResponse),
+            VERSION_NOT_SUPPORTED => {
+                VersionNotSupported::unmarshal(buffer).map(GTPV1CMessage::VersionNotSupported)
+            }
+            CREATE_PDP_CONTEXT_REQUEST => CreatePDPContextRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::CreatePDPContextRequest),
+            CREATE_PDP_CONTEXT_RESPONSE => CreatePDPContextResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::CreatePDPContextResponse),
+            UPDATE_PDP_CONTEXT_REQUEST => {
+                if message_ie_types(buffer)
+                    .iter()
+                    .any(|t| matches!(*t, TEID_DATA | TEID_CONTROL | GSN_ADDRESS))
+                {
+                    UpdatePDPContextRequest::unmarshal(buffer)
+                        .map(GTPV1CMessage::UpdatePDPContextRequest)
+                } else {
+                    UpdatePDPContextRequestGGSN::unmarshal(buffer)
+                        .map(GTPV1CMessage::UpdatePDPContextRequestGGSN)
+                }
+            }
+            UPDATE_PDP_CONTEXT_RESPONSE => {
+                if message_ie_types(buffer)
+                    .iter()
+                    .any(|t| matches!(*t, ULI | MSTIMEZONETYPE))
+                {
+                    UpdatePDPContextResponseGGSN::unmarshal(buffer)
+                        .map(GTPV1CMessage::UpdatePDPContextResponseGGSN)
+                } else {
+                    match UpdatePDPContextResponse::unmarshal(buffer) {
+                        Ok(i) => Ok(GTPV1CMessage::UpdatePDPContextResponse(i)),
+                        Err(e) => UpdatePDPContextResponseGGSN::unmarshal(buffer)
+                            .map(GTPV1CMessage::UpdatePDPContextResponseGGSN)
+                            .map_err(|_| e),
+                    }
+                }
+            }
+            DELETE_PDP_CONTEXT_REQUEST => DeletePDPContextRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::DeletePDPContextRequest),
+            DELETE_PDP_CONTEXT_RESPONSE => DeletePDPContextResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::DeletePDPContextResponse),
+            INITIATE_PDP_CTX_ACTIVATION_REQUEST => {
+                InitiatePDPContextActivationRequest::unmarshal(buffer)
+                    .map(GTPV1CMessage::InitiatePDPContextActivationRequest)
+            }
+            INITIATE_PDP_CTX_ACTIVATION_RESPONSE => {
+                InitiatePDPContextActivationResponse::unmarshal(buffer)
+                    .map(GTPV1CMessage::InitiatePDPContextActivationResponse)
+            }
+            PDU_NOTIFICATION_REQUEST => {
+                PDUNotificationRequest::unmarshal(buffer).map(GTPV1CMessage::PDUNotificationRequest)
+            }
+            PDU_NOTIFICATION_RESPONSE => PDUNotificationResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::PDUNotificationResponse),
+            PDU_NOTIFICATION_REJECT_REQUEST => PDUNotificationRejectRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::PDUNotificationRejectRequest),
+            PDU_NOTIFICATION_REJECT_RESPONSE => PDUNotificationRejectResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::PDUNotificationRejectResponse),
+            SEND_ROUTING_INFO_REQUEST => {
+                SendRoutingInfoRequest::unmarshal(buffer).map(GTPV1CMessage::SendRoutingInfoRequest)
+            }
+            SEND_ROUTING_INFO_RESPONSE => SendRoutingInfoResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::SendRoutingInfoResponse),
+            FAILURE_REPORT_REQUEST => {
+                FailureReportRequest::unmarshal(buffer).map(GTPV1CMessage::FailureReportRequest)
+            }
+            FAILURE_REPORT_RESPONSE => {
+                FailureReportResponse::unmarshal(buffer).map(GTPV1CMessage::FailureReportResponse)
+            }
+            NOTE_MS_GPRS_PRESENT_REQUEST => NoteMSGPRSPresentRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::NoteMSGPRSPresentRequest),
+            NOTE_MS_GPRS_PRESENT_RESPONSE => NoteMSGPRSPresentResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::NoteMSGPRSPresentResponse),
+            SUPPORTED_EXTENSION_HEADERS_NOTIFICATION => {
+                SupportedExtensionHeadersNotification::unmarshal(buffer)
+                    .map(GTPV1CMessage::SupportedExtensionHeadersNotification)
+            }
+            MS_INFO_CHANGE_NOTIFICATION_REQUEST => {
+                MSInfoChangeNotificationRequest::unmarshal(buffer)
+                    .map(GTPV1CMessage::MSInfoChangeNotificationRequest)
+            }
+            MS_INFO_CHANGE_NOTIFICATION_RESPONSE => {
+                MSInfoChangeNotificationResponse::unmarshal(buffer)
+                    .map(GTPV1CMessage::MSInfoChangeNotificationResponse)
+            }
+            SGSN_CONTEXT_REQUEST => {
+                SGSNContextRequest::unmarshal(buffer).map(GTPV1CMessage::SGSNContextRequest)
+            }
+            SGSN_CONTEXT_RESPONSE => {
+                SGSNContextResponse::unmarshal(buffer).map(GTPV1CMessage::SGSNContextResponse)
+            }
+            SGSN_CONTEXT_ACK => {
+                SGSNContextAcknowledge::unmarshal(buffer).map(GTPV1CMessage::SGSNContextAcknowledge)
+            }
+            FORWARD_RELOCATION_REQUEST => ForwardRelocationRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::ForwardRelocationRequest),
+            FORWARD_RELOCATION_RESPONSE => ForwardRelocationResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::ForwardRelocationResponse),
+            FORWARD_RELOCATION_COMPLETE => ForwardRelocationComplete::unmarshal(buffer)
+                .map(GTPV1CMessage::ForwardRelocationComplete),
+            RELOCATION_CANCEL_REQUEST => RelocationCancelRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::RelocationCancelRequest),
+            RELOCATION_CANCEL_RESPONSE => RelocationCancelResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::RelocationCancelResponse),
+            FORWARD_SRNS_CONTEXT => {
+                ForwardSRNSContext::unmarshal(buffer).map(GTPV1CMessage::ForwardSRNSContext)
+            }
+            FORWARD_RELOCATION_COMPLETE_ACK => {
+                ForwardRelocationCompleteAcknowledge::unmarshal(buffer)
+                    .map(GTPV1CMessage::ForwardRelocationCompleteAcknowledge)
+            }
+            FORWARD_SRNS_CONTEXT_ACK => ForwardSRNSContextAcknowledge::unmarshal(buffer)
+                .map(GTPV1CMessage::ForwardSRNSContextAcknowledge),
+            MBMS_NOTIFICATION_REQUEST => MBMSNotificationRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::MBMSNotificationRequest),
+            MBMS_NOTIFICATION_RESPONSE => MBMSNotificationResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::MBMSNotificationResponse),
+            MBMS_NOTIFICATION_REJECT_REQUEST => MBMSNotificationRejectRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::MBMSNotificationRejectRequest),
+            MBMS_NOTIFICATION_REJECT_RESPONSE => MBMSNotificationRejectResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::MBMSNotificationRejectResponse),
+            CREATE_MBMS_CONTEXT_REQUEST => CreateMBMSContextRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::CreateMBMSContextRequest),
+            CREATE_MBMS_CONTEXT_RESPONSE => CreateMBMSContextResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::CreateMBMSContextResponse),
+            UPDATE_MBMS_CONTEXT_REQUEST => UpdateMBMSContextRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::UpdateMBMSContextRequest),
+            UPDATE_MBMS_CONTEXT_RESPONSE => UpdateMBMSContextResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::UpdateMBMSContextResponse),
+            DELETE_MBMS_CONTEXT_REQUEST => DeleteMBMSContextRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::DeleteMBMSContextRequest),
+            DELETE_MBMS_CONTEXT_RESPONSE => DeleteMBMSContextResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::DeleteMBMSContextResponse),
+            MBMS_REGISTRATION_REQUEST => MBMSRegistrationRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::MBMSRegistrationRequest),
+            MBMS_REGISTRATION_RESPONSE => MBMSRegistrationResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::MBMSRegistrationResponse),
+            MBMS_DE_REGISTRATION_REQUEST => MBMSDeRegistrationRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::MBMSDeRegistrationRequest),
+            MBMS_DE_REGISTRATION_RESPONSE => MBMSDeRegistrationResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::MBMSDeRegistrationResponse),
+            MBMS_SESSION_START_REQUEST => MBMSSessionStartRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::MBMSSessionStartRequest),
+            MBMS_SESSION_START_RESPONSE => MBMSSessionStartResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::MBMSSessionStartResponse),
+            MBMS_SESSION_STOP_REQUEST => {
+                MBMSSessionStopRequest::unmarshal(buffer).map(GTPV1CMessage::MBMSSessionStopRequest)
+            }
+            MBMS_SESSION_STOP_RESPONSE => MBMSSessionStopResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::MBMSSessionStopResponse),
+            MBMS_SESSION_UPDATE_REQUEST => MBMSSessionUpdateRequest::unmarshal(buffer)
+                .map(GTPV1CMessage::MBMSSessionUpdateRequest),
+            MBMS_SESSION_UPDATE_RESPONSE => MBMSSessionUpdateResponse::unmarshal(buffer)
+                .map(GTPV1CMessage::MBMSSessionUpdateResponse),
+            _ => {
+                let offset = header.length as usize + MIN_HEADER_LENGTH;
+                if offset <= buffer.len() {
+                    let body = buffer[header.len()..offset].to_vec();
+                    Ok(GTPV1CMessage::Unknown { header, body })
+                } else {
+                    Err(GTPV1Error::MessageLengthError)
+                }
+            }
+        }
+    }
+
+    // Decode any GTPv1-C message, reporting failures with the offset and type of the faulty IE.
+    // On failure the message is walked again to locate the faulty IE
+
+    pub fn parse_detailed(buffer: &[u8]) -> Result<Self, GTPV1DecodeError> {
+        GTPV1CMessage::parse(buffer).map_err(|e| locate_decode_error(buffer, e))
+    }
+
+    pub fn marshal(self, buffer: &mut Vec<u8>) {
+        match self {
+            GTPV1CMessage::EchoRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::EchoResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::VersionNotSupported(i) => i.marshal(buffer),
+            GTPV1CMessage::CreatePDPContextRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::CreatePDPContextResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::UpdatePDPContextRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::UpdatePDPContextResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::UpdatePDPContextRequestGGSN(i) => i.marshal(buffer),
+            GTPV1CMessage::UpdatePDPContextResponseGGSN(i) => i.marshal(buffer),
+            GTPV1CMessage::DeletePDPContextRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::DeletePDPContextResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::InitiatePDPContextActivationRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::InitiatePDPContextActivationResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::PDUNotificationRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::PDUNotificationResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::PDUNotificationRejectRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::PDUNotificationRejectResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::SendRoutingInfoRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::SendRoutingInfoResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::FailureReportRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::FailureReportResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::NoteMSGPRSPresentRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::NoteMSGPRSPresentResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::SupportedExtensionHeadersNotification(i) => i.marshal(buffer),
+            GTPV1CMessage::MSInfoChangeNotificationRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::MSInfoChangeNotificationResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::SGSNContextRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::SGSNContextResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::SGSNContextAcknowledge(i) => i.marshal(buffer),
+            GTPV1CMessage::ForwardRelocationRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::ForwardRelocationResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::ForwardRelocationComplete(i) => i.marshal(buffer),
+            GTPV1CMessage::RelocationCancelRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::RelocationCancelResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::ForwardSRNSContext(i) => i.marshal(buffer),
+            GTPV1CMessage::ForwardRelocationCompleteAcknowledge(i) => i.marshal(buffer),
+            GTPV1CMessage::ForwardSRNSContextAcknowledge(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSNotificationRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSNotificationResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSNotificationRejectRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSNotificationRejectResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::CreateMBMSContextRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::CreateMBMSContextResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::UpdateMBMSContextRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::UpdateMBMSContextResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::DeleteMBMSContextRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::DeleteMBMSContextResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSRegistrationRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSRegistrationResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSDeRegistrationRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSDeRegistrationResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSSessionStartRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSSessionStartResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSSessionStopRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSSessionStopResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSSessionUpdateRequest(i) => i.marshal(buffer),
+            GTPV1CMessage::MBMSSessionUpdateResponse(i) => i.marshal(buffer),
+            GTPV1CMessage::Unknown { header, body } => {
+                header.marshal(buffer);
+                buffer.extend_from_slice(&body);
+                set_length(buffer);
+            }
+        }
+    }
+
+    pub fn header(&self) -> &Gtpv1Header {
+        match self {
+            GTPV1CMessage::EchoRequest(i) => &i.header,
+            GTPV1CMessage::EchoResponse(i) => &i.header,
+            GTPV1CMessage::VersionNotSupported(i) => &i.header,
+            GTPV1CMessage::CreatePDPContextRequest(i) => &i.header,
+            GTPV1CMessage::CreatePDPContextResponse(i) => &i.header,
+            GTPV1CMessage::UpdatePDPContextRequest(i) => &i.header,
+            GTPV1CMessage::UpdatePDPContextResponse(i) => &i.header,
+            GTPV1CMessage::UpdatePDPContextRequestGGSN(i) => &i.header,
+            GTPV1CMessage::UpdatePDPContextResponseGGSN(i) => &i.header,
+            GTPV1CMessage::DeletePDPContextRequest(i) => &i.header,
+            GTPV1CMessage::DeletePDPContextResponse(i) => &i.header,
+            GTPV1CMessage::InitiatePDPContextActivationRequest(i) => &i.header,
+            GTPV1CMessage::InitiatePDPContextActivationResponse(i) => &i.header,
+            GTPV1CMessage::PDUNotificationRequest(i) => &i.header,
+            GTPV1CMessage::PDUNotificationResponse(i) => &i.header,
+            GTPV1CMessage::PDUNotificationRejectRequest(i) => &i.header,
+            GTPV1CMessage::PDUNotificationRejectResponse(i) => &i.header,
+            GTPV1CMessage::SendRoutingInfoRequest(i) => &i.header,
+            GTPV1CMessage::SendRoutingInfoResponse(i) => &i.header,
+            GTPV1CMessage::FailureReportRequest(i) => &i.header,
+            GTPV1CMessage::FailureReportResponse(i) => &i.header,
+            GTPV1CMessage::NoteMSGPRSPresentRequest(i) => &i.header,
+            GTPV1CMessage::NoteMSGPRSPresentResponse(i) => &i.header,
+            GTPV1CMessage::SupportedExtensionHeadersNotification(i) => &i.header,
+            GTPV1CMessage::MSInfoChangeNotificationRequest(i) => &i.header,
+            GTPV1CMessage::MSInfoChangeNotificationResponse(i) => &i.header,
+            GTPV1CMessage::SGSNContextRequest(i) => &i.header,
+            GTPV1CMessage::SGSNContextResponse(i) => &i.header,
+            GTPV1CMessage::SGSNContextAcknowledge(i) => &i.header,
+            GTPV1CMessage::ForwardRelocationRequest(i) => &i.header,
+            GTPV1CMessage::ForwardRelocationResponse(i) => &i.header,
+            GTPV1CMessage::ForwardRelocationComplete(i) => &i.header,
+            GTPV1CMessage::RelocationCancelRequest(i) => &i.header,
+            GTPV1CMessage::RelocationCancelResponse(i) => &i.header,
+            GTPV1CMessage::ForwardSRNSContext(i) => &i.header,
+            GTPV1CMessage::ForwardRelocationCompleteAcknowledge(i) => &i.header,
+            GTPV1CMessage::ForwardSRNSContextAcknowledge(i) => &i.header,
+            GTPV1CMessage::MBMSNotificationRequest(i) => &i.header,
+            GTPV1CMessage::MBMSNotificationResponse(i) => &i.header,
+            GTPV1CMessage::MBMSNotificationRejectRequest(i) => &i.header,
+            GTPV1CMessage::MBMSNotificationRejectResponse(i) => &i.header,
+            GTPV1CMessage::CreateMBMSContextRequest(i) => &i.header,
+            GTPV1CMessage::CreateMBMSContextResponse(i) => &i.header,
+            GTPV1CMessage::UpdateMBMSContextRequest(i) => &i.header,
+            GTPV1CMessage::UpdateMBMSContextResponse(i) => &i.header,
+            GTPV1CMessage::DeleteMBMSContextRequest(i) => &i.header,
+            GTPV1CMessage::DeleteMBMSContextResponse(i) => &i.header,
+            GTPV1CMessage::MBMSRegistrationRequest(i) => &i.header,
+            GTPV1CMessage::MBMSRegistrationResponse(i) => &i.header,
+            GTPV1CMessage::MBMSDeRegistrationRequest(i) => &i.header,
+            GTPV1CMessage::MBMSDeRegistrationResponse(i) => &i.header,
+            GTPV1CMessage::MBMSSessionStartRequest(i) => &i.header,
+            GTPV1CMessage::MBMSSessionStartResponse(i) => &i.header,
+            GTPV1CMessage::MBMSSessionStopRequest(i) => &i.header,
+            GTPV1CMessage::MBMSSessionStopResponse(i) => &i.header,
+            GTPV1CMessage::MBMSSessionUpdateRequest(i) => &i.header,
+            GTPV1CMessage::MBMSSessionUpdateResponse(i) => &i.header,
+            GTPV1CMessage::Unknown { header, .. } => header,
+        }
+    }
+
+    pub fn msg_type(&self) -> u8 {
+        self.header().msgtype
+    }
+}
+
+#[test]
+fn test_gtpv1c_message_echo_req_parse() {
+    let encoded: [u8; 12] = [
+        0x32, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x49, 0xca, 0x00, 0x00,
+    ];
+    let message = GTPV1CMessage::parse(&encoded).unwrap();
+    assert_eq!(
+        message,
+        GTPV1CMessage::EchoRequest(EchoRequest::unmarshal(&encoded).unwrap())
+    );
+    assert_eq!(message.msg_type(), ECHO_REQUEST);
+}
+
+#[test]
+fn test_gtpv1c_message_update_pdp_ctx_req_ggsn_parse() {
+    let encoded: [u8; 78] = [
+        0x32, 0x12, 0x00, 0x46, 0x10, 0x2b, 0xdf, 0x23, 0xc0, 0x86, 0x00, 0x00, 0x02, 0x09, 0x41,
+        0x50, 0x01, 0x72, 0x67, 0x35, 0xf9, 0x0e, 0xbf, 0x14, 0x05, 0x80, 0x00, 0x16, 0xf1, 0x8d,
+        0x64, 0x75, 0x82, 0x35, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xff, 0xff, 0xff, 0xff, 0x87, 0x00, 0x11, 0x03, 0x23, 0x73, 0x1f, 0x93, 0x96, 0x86,
+        0x86, 0x74, 0x83, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x94, 0x00, 0x01, 0x40, 0xbf,
+        0x00, 0x01, 0x64,
+    ];
+    assert_eq!(
+        GTPV1CMessage::parse(&encoded).unwrap(),
+        GTPV1CMessage::UpdatePDPContextRequestGGSN(
+            UpdatePDPContextRequestGGSN::unmarshal(&encoded).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_gtpv1c_message_update_pdp_ctx_req_sgsn_error_parse() {
+    // SGSN initiated Update PDP Context Request without the mandatory QoS Profile IE
+    let encoded: [u8; 84] = [
+        0x32, 0x12, 0x00, 0x4c, 0x10, 0x2b, 0xdf, 0x23, 0xc0, 0x86, 0x00, 0x00, 0x02, 0x09, 0x41,
+        0x50, 0x01, 0x72, 0x67, 0x35, 0xf9, 0x03, 0x22, 0xf6, 0x01, 0xff, 0xfe, 0xff, 0x0e, 0xbf,
+        0x10, 0x2f, 0x3c, 0x40, 0xc7, 0x11, 0x2f, 0x3c, 0x40, 0xc8, 0x14, 0x05, 0x85, 0x00, 0x04,
+        0x3e, 0xd9, 0xc8, 0x04, 0x85, 0x00, 0x04, 0x3e, 0xd9, 0xc8, 0x28, 0x94, 0x00, 0x01, 0x40,
+        0x97, 0x00, 0x01, 0x01, 0x98, 0x00, 0x08, 0x01, 0x22, 0xf6, 0x01, 0x06, 0x54, 0x3c, 0xa9,
+        0x99, 0x00, 0x02, 0x02, 0x20, 0xbf, 0x00, 0x01, 0x64,
+    ];
+    assert_eq!(
+        GTPV1CMessage::parse(&encoded),
+        Err(UpdatePDPContextRequest::unmarshal(&encoded).unwrap_err())
+    );
+    assert_eq!(
+        GTPV1CMessage::parse(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
+
+#[test]
+fn test_gtpv1c_message_update_pdp_ctx_resp_ggsn_parse() {
+    let encoded: [u8; 59] = [
+        0x32, 0x13, 0x00, 0x33, 0x37, 0x38, 0xbf, 0x7a, 0x9b, 0xcf, 0x00, 0x00, 0x01, 0x80, 0x0e,
+        0x05, 0x10, 0xa6, 0x97, 0x49, 0xf4, 0x85, 0x00, 0x04, 0x3e, 0x99, 0x89, 0x60, 0x87, 0x00,
+        0x0c, 0x03, 0x13, 0x83, 0x1f, 0x71, 0x96, 0x87, 0x87, 0x74, 0xfa, 0xff, 0xff, 0x98, 0x00,
+        0x08, 0x01, 0x22, 0xf6, 0x01, 0x06, 0x54, 0x3c, 0xa9, 0x99, 0x00, 0x02, 0x02, 0x00,
+    ];
+    assert_eq!(
+        GTPV1CMessage::parse(&encoded).unwrap(),
+        GTPV1CMessage::UpdatePDPContextResponseGGSN(
+            UpdatePDPContextResponseGGSN::unmarshal(&encoded).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_gtpv1c_message_unknown_parse() {
+    let encoded: [u8; 14] = [
+        0x32, 0x7f, 0x00, 0x06, 0x00, 0x00, 0x00, 0x01, 0x49, 0xca, 0x00, 0x00, 0x0e, 0x05,
+    ];
+    let decoded = GTPV1CMessage::Unknown {
+        header: Gtpv1Header {
+            msgtype: 0x7f,
+            length: 6,
+            teid: 1,
+            sequence_number: Some(18890),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        body: vec![0x0e, 0x05],
+    };
+    assert_eq!(GTPV1CMessage::parse(&encoded).unwrap(), decoded);
+}
+
+#[test]
+fn test_gtpv1c_message_unknown_marshal() {
+    let encoded: [u8; 14] = [
+        0x32, 0x7f, 0x00, 0x06, 0x00, 0x00, 0x00, 0x01, 0x49, 0xca, 0x00, 0x00, 0x0e, 0x05,
+    ];
+    let decoded = GTPV1CMessage::Unknown {
+        header: Gtpv1Header {
+            msgtype: 0x7f,
+            length: 6,
+            teid: 1,
+            sequence_number: Some(18890),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        body: vec![0x0e, 0x05],
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn test_gtpv1c_message_parse_detailed_ie_error() {
+    use crate::commons::{DecodeErrorReason, IePathElement};
+    let encoded: [u8; 20] = [
+        0x32, 0x33, 0x00, 0x0c, 0x00, 0x00, 0x10, 0x01, 0x12, 0x34, 0x00, 0x00, 0x01, 0x80, 0x85,
+        0x00, 0x03, 0x0a, 0x00, 0x00,
+    ];
+    let error = GTPV1CMessage::parse_detailed(&encoded).unwrap_err();
+    assert_eq!(error.msgtype, SGSN_CONTEXT_RESPONSE);
+    assert_eq!(error.offset, 14);
+    assert_eq!(
+        error.path,
+        vec![IePathElement {
+            t: GSN_ADDRESS,
+            ins: 0,
+            index: 0,
+        }]
+    );
+    assert_eq!(error.reason, DecodeErrorReason::InvalidValue);
+    assert_eq!(error.error, GTPV1Error::IEIncorrect);
+    assert_eq!(
+        error.to_string(),
+        "Message type 51 decoding failed at offset 14 in IE 133[0] ins 0: invalid value (Incorrect IE)"
+    );
+}
+
+#[test]
+fn test_gtpv1c_message_length_shorter_than_header_parse() {
+    let mut encoded: [u8; 12] = [
+        0x32, 0x7f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x49, 0xca, 0x00, 0x00,
+    ];
+    assert_eq!(
+        GTPV1CMessage::parse(&encoded),
+        Err(GTPV1Error::MessageLengthError)
+    );
+    // Same length error for an implemented message type
+    encoded[1] = 0x10;
+    assert_eq!(
+        GTPV1CMessage::parse(&encoded),
+        Err(GTPV1Error::MessageLengthError)
+    );
+}
+
+#[test]
+fn test_gtpv1c_message_extension_header_type_list_overrun_parse() {
+    let encoded: [u8; 15] = [
+        0x32, 0x32, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x8d, 0xfe, 0x00,
+    ];
+    assert!(GTPV1CMessage::parse(&encoded).is_err());
+}
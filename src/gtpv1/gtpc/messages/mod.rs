@@ -1,28 +1,76 @@
 pub use {
-    commons::*, createpdpctxreq::*, createpdpctxresp::*, deletepdpctxreq::*, deletepdpctxresp::*,
-    echoreq::*, echoresp::*, ies::*, initiatepdpctxactivationreq::*,
-    initiatepdpctxactivationresp::*, msinfochangenotifreq::*, msinfochangenotifresp::*,
+    commons::*, creatembmsctxreq::*, creatembmsctxresp::*, createpdpctxreq::*, createpdpctxresp::*,
+    deletembmsctxreq::*, deletembmsctxresp::*, deletepdpctxreq::*, deletepdpctxresp::*, echoreq::*,
+    echoresp::*, failurereportreq::*, failurereportresp::*, fwdrelocationcomplete::*,
+    fwdrelocationcompleteack::*, fwdrelocationreq::*, fwdrelocationresp::*, fwdsrnsctx::*,
+    fwdsrnsctxack::*, ies::*, initiatepdpctxactivationreq::*, initiatepdpctxactivationresp::*,
+    mbmsderegistrationreq::*, mbmsderegistrationresp::*, mbmsnotificationrejectreq::*,
+    mbmsnotificationrejectresp::*, mbmsnotificationreq::*, mbmsnotificationresp::*,
+    mbmsregistrationreq::*, mbmsregistrationresp::*, mbmssessionstartreq::*,
+    mbmssessionstartresp::*, mbmssessionstopreq::*, mbmssessionstopresp::*,
+    mbmssessionupdatereq::*, mbmssessionupdateresp::*, message::*, msinfochangenotifreq::*,
+    msinfochangenotifresp::*, notemsgprspresentreq::*, notemsgprspresentresp::*,
     pdunotificationrejectreq::*, pdunotificationrejectresp::*, pdunotificationreq::*,
-    pdunotificationresp::*, supportedexthdrnotification::*, updatepdpctxreq::*,
+    pdunotificationresp::*, relocationcancelreq::*, relocationcancelresp::*, sendroutinginforeq::*,
+    sendroutinginforesp::*, sgsncontextack::*, sgsncontextreq::*, sgsncontextresp::*,
+    supportedexthdrnotification::*, updatembmsctxreq::*, updatembmsctxresp::*, updatepdpctxreq::*,
     updatepdpctxreq_ggsn::*, updatepdpctxresp::*, updatepdpctxresp_ggsn::*, versionnotsupported::*,
 };
 mod commons;
+mod creatembmsctxreq;
+mod creatembmsctxresp;
 mod createpdpctxreq;
 mod createpdpctxresp;
+mod deletembmsctxreq;
+mod deletembmsctxresp;
 mod deletepdpctxreq;
 mod deletepdpctxresp;
 mod echoreq;
 mod echoresp;
+mod failurereportreq;
+mod failurereportresp;
+mod fwdrelocationcomplete;
+mod fwdrelocationcompleteack;
+mod fwdrelocationreq;
+mod fwdrelocationresp;
+mod fwdsrnsctx;
+mod fwdsrnsctxack;
 mod ies;
 mod initiatepdpctxactivationreq;
 mod initiatepdpctxactivationresp;
+mod mbmsderegistrationreq;
+mod mbmsderegistrationresp;
+mod mbmsnotificationrejectreq;
+mod mbmsnotificationrejectresp;
+mod mbmsnotificationreq;
+mod mbmsnotificationresp;
+mod mbmsregistrationreq;
+mod mbmsregistrationresp;
+mod mbmssessionstartreq;
+mod mbmssessionstartresp;
+mod mbmssessionstopreq;
+mod mbmssessionstopresp;
+mod mbmssessionupdatereq;
+mod mbmssessionupdateresp;
+mod message;
 mod msinfochangenotifreq;
 mod msinfochangenotifresp;
+mod notemsgprspresentreq;
+mod notemsgprspresentresp;
 mod pdunotificationrejectreq;
 mod pdunotificationrejectresp;
 mod pdunotificationreq;
 mod pdunotificationresp;
+mod relocationcancelreq;
+mod relocationcancelresp;
+mod sendroutinginforeq;
+mod sendroutinginforesp;
+mod sgsncontextack;
+mod sgsncontextreq;
+mod sgsncontextresp;
 mod supportedexthdrnotification;
+mod updatembmsctxreq;
+mod updatembmsctxresp;
 mod updatepdpctxreq;
 mod updatepdpctxreq_ggsn;
 mod updatepdpctxresp;
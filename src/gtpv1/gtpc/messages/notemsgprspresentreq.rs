@@ -0,0 +1,160 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const NOTE_MS_GPRS_PRESENT_REQUEST: u8 = 36;
+
+// Definition of GTPv1-C Note MS GPRS Present Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteMSGPRSPresentRequest {
+    pub header: Gtpv1Header,
+    pub imsi: Imsi,
+    pub gsn_address: GsnAddress,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for NoteMSGPRSPresentRequest {
+    fn default() -> NoteMSGPRSPresentRequest {
+        let hdr = Gtpv1Header {
+            msgtype: NOTE_MS_GPRS_PRESENT_REQUEST,
+            ..Default::default()
+        };
+        NoteMSGPRSPresentRequest {
+            header: hdr,
+            imsi: Imsi::default(),
+            gsn_address: GsnAddress::default(),
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for NoteMSGPRSPresentRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.imsi.into()];
+        elements.push(self.gsn_address.into());
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = NoteMSGPRSPresentRequest {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != NOTE_MS_GPRS_PRESENT_REQUEST {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let (mut imsi, mut gsn_address) = (None, None);
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Imsi(i) if imsi.is_none() => imsi = Some(i),
+                InformationElement::GsnAddress(i) if gsn_address.is_none() => gsn_address = Some(i),
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match (imsi, gsn_address) {
+            (Some(i), Some(j)) => {
+                message.imsi = i;
+                message.gsn_address = j;
+                Ok(message)
+            }
+            _ => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[test]
+fn note_ms_gprs_present_req_unmarshal_test() {
+    let encoded: [u8; 28] = [
+        0x32, 0x24, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x02, 0x62, 0x02,
+        0x01, 0x21, 0x43, 0x65, 0x87, 0xf9, 0x85, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x01,
+    ];
+    let decoded = NoteMSGPRSPresentRequest {
+        header: Gtpv1Header {
+            msgtype: NOTE_MS_GPRS_PRESENT_REQUEST,
+            length: 20,
+            teid: 0,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        imsi: Imsi {
+            t: IMSI,
+            imsi: "262010123456789".to_string(),
+        },
+        gsn_address: GsnAddress {
+            t: GSN_ADDRESS,
+            length: 4,
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 1)),
+        },
+        private_extension: None,
+    };
+    assert_eq!(
+        NoteMSGPRSPresentRequest::unmarshal(&encoded).unwrap(),
+        decoded
+    );
+}
+
+#[test]
+fn note_ms_gprs_present_req_marshal_test() {
+    let encoded: [u8; 28] = [
+        0x32, 0x24, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x02, 0x62, 0x02,
+        0x01, 0x21, 0x43, 0x65, 0x87, 0xf9, 0x85, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x01,
+    ];
+    let decoded = NoteMSGPRSPresentRequest {
+        header: Gtpv1Header {
+            msgtype: NOTE_MS_GPRS_PRESENT_REQUEST,
+            length: 20,
+            teid: 0,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        imsi: Imsi {
+            t: IMSI,
+            imsi: "262010123456789".to_string(),
+        },
+        gsn_address: GsnAddress {
+            t: GSN_ADDRESS,
+            length: 4,
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 1)),
+        },
+        private_extension: None,
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn note_ms_gprs_present_req_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 21] = [
+        0x32, 0x24, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x02, 0x62, 0x02,
+        0x01, 0x21, 0x43, 0x65, 0x87, 0xf9,
+    ];
+    assert_eq!(
+        NoteMSGPRSPresentRequest::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
@@ -0,0 +1,142 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const NOTE_MS_GPRS_PRESENT_RESPONSE: u8 = 37;
+
+// Definition of GTPv1-C Note MS GPRS Present Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteMSGPRSPresentResponse {
+    pub header: Gtpv1Header,
+    pub cause: Cause,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for NoteMSGPRSPresentResponse {
+    fn default() -> NoteMSGPRSPresentResponse {
+        let hdr = Gtpv1Header {
+            msgtype: NOTE_MS_GPRS_PRESENT_RESPONSE,
+            ..Default::default()
+        };
+        NoteMSGPRSPresentResponse {
+            header: hdr,
+            cause: Cause::default(),
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for NoteMSGPRSPresentResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = NoteMSGPRSPresentResponse {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != NOTE_MS_GPRS_PRESENT_RESPONSE {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let mut cause = None;
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match cause {
+            Some(i) => {
+                message.cause = i;
+                Ok(message)
+            }
+            None => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[test]
+fn note_ms_gprs_present_resp_unmarshal_test() {
+    let encoded: [u8; 14] = [
+        0x32, 0x25, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x01, 0x80,
+    ];
+    let decoded = NoteMSGPRSPresentResponse {
+        header: Gtpv1Header {
+            msgtype: NOTE_MS_GPRS_PRESENT_RESPONSE,
+            length: 6,
+            teid: 0,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        private_extension: None,
+    };
+    assert_eq!(
+        NoteMSGPRSPresentResponse::unmarshal(&encoded).unwrap(),
+        decoded
+    );
+}
+
+#[test]
+fn note_ms_gprs_present_resp_marshal_test() {
+    let encoded: [u8; 14] = [
+        0x32, 0x25, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x01, 0x80,
+    ];
+    let decoded = NoteMSGPRSPresentResponse {
+        header: Gtpv1Header {
+            msgtype: NOTE_MS_GPRS_PRESENT_RESPONSE,
+            length: 6,
+            teid: 0,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        private_extension: None,
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn note_ms_gprs_present_resp_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x25, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        NoteMSGPRSPresentResponse::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
@@ -0,0 +1,131 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const RELOCATION_CANCEL_REQUEST: u8 = 56;
+
+// Definition of GTPv1-C Relocation Cancel Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelocationCancelRequest {
+    pub header: Gtpv1Header,
+    pub imsi: Option<Imsi>,
+    pub imei: Option<Imei>,
+    pub ext_common_flags: Option<ExtendedCommonFlags>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for RelocationCancelRequest {
+    fn default() -> RelocationCancelRequest {
+        let hdr = Gtpv1Header {
+            msgtype: RELOCATION_CANCEL_REQUEST,
+            ..Default::default()
+        };
+        RelocationCancelRequest {
+            header: hdr,
+            imsi: None,
+            imei: None,
+            ext_common_flags: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for RelocationCancelRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![];
+        if let Some(i) = self.imsi {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.imei {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.ext_common_flags {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = RelocationCancelRequest {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != RELOCATION_CANCEL_REQUEST {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Imsi(i) if message.imsi.is_none() => message.imsi = Some(i),
+                InformationElement::Imei(i) if message.imei.is_none() => message.imei = Some(i),
+                InformationElement::ExtendedCommonFlags(i)
+                    if message.ext_common_flags.is_none() =>
+                {
+                    message.ext_common_flags = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+const RELOCATION_CANCEL_REQUEST_TEST: [u8; 21] = [
+    0x32, 0x38, 0x00, 0x0d, 0x00, 0x00, 0x10, 0x01, 0x23, 0x45, 0x00, 0x00, 0x02, 0x62, 0x02, 0x01,
+    0x21, 0x43, 0x65, 0x87, 0xf9,
+];
+
+#[cfg(test)]
+fn relocation_cancel_request_test() -> RelocationCancelRequest {
+    RelocationCancelRequest {
+        header: Gtpv1Header {
+            msgtype: RELOCATION_CANCEL_REQUEST,
+            length: 13,
+            teid: 0x1001,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        imsi: Some(Imsi {
+            imsi: "262010123456789".to_string(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn relocation_cancel_req_unmarshal_test() {
+    assert_eq!(
+        RelocationCancelRequest::unmarshal(&RELOCATION_CANCEL_REQUEST_TEST).unwrap(),
+        relocation_cancel_request_test()
+    );
+}
+
+#[test]
+fn relocation_cancel_req_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    relocation_cancel_request_test().marshal(&mut buffer);
+    assert_eq!(buffer, RELOCATION_CANCEL_REQUEST_TEST);
+}
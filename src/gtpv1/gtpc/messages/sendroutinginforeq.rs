@@ -0,0 +1,144 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const SEND_ROUTING_INFO_REQUEST: u8 = 32;
+
+// Definition of GTPv1-C Send Routing Information for GPRS Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SendRoutingInfoRequest {
+    pub header: Gtpv1Header,
+    pub imsi: Imsi,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for SendRoutingInfoRequest {
+    fn default() -> SendRoutingInfoRequest {
+        let hdr = Gtpv1Header {
+            msgtype: SEND_ROUTING_INFO_REQUEST,
+            ..Default::default()
+        };
+        SendRoutingInfoRequest {
+            header: hdr,
+            imsi: Imsi::default(),
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for SendRoutingInfoRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.imsi.into()];
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = SendRoutingInfoRequest {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != SEND_ROUTING_INFO_REQUEST {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let mut imsi = None;
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Imsi(i) if imsi.is_none() => imsi = Some(i),
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match imsi {
+            Some(i) => {
+                message.imsi = i;
+                Ok(message)
+            }
+            None => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[test]
+fn send_routing_info_req_unmarshal_test() {
+    let encoded: [u8; 21] = [
+        0x32, 0x20, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x02, 0x62, 0x02,
+        0x01, 0x21, 0x43, 0x65, 0x87, 0xf9,
+    ];
+    let decoded = SendRoutingInfoRequest {
+        header: Gtpv1Header {
+            msgtype: SEND_ROUTING_INFO_REQUEST,
+            length: 13,
+            teid: 0,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        imsi: Imsi {
+            t: IMSI,
+            imsi: "262010123456789".to_string(),
+        },
+        private_extension: None,
+    };
+    assert_eq!(
+        SendRoutingInfoRequest::unmarshal(&encoded).unwrap(),
+        decoded
+    );
+}
+
+#[test]
+fn send_routing_info_req_marshal_test() {
+    let encoded: [u8; 21] = [
+        0x32, 0x20, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x02, 0x62, 0x02,
+        0x01, 0x21, 0x43, 0x65, 0x87, 0xf9,
+    ];
+    let decoded = SendRoutingInfoRequest {
+        header: Gtpv1Header {
+            msgtype: SEND_ROUTING_INFO_REQUEST,
+            length: 13,
+            teid: 0,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        imsi: Imsi {
+            t: IMSI,
+            imsi: "262010123456789".to_string(),
+        },
+        private_extension: None,
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn send_routing_info_req_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 12] = [
+        0x32, 0x20, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00,
+    ];
+    assert_eq!(
+        SendRoutingInfoRequest::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
@@ -0,0 +1,210 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const SEND_ROUTING_INFO_RESPONSE: u8 = 33;
+
+// Definition of GTPv1-C Send Routing Information for GPRS Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SendRoutingInfoResponse {
+    pub header: Gtpv1Header,
+    pub cause: Cause,
+    pub imsi: Imsi,
+    pub map_cause: Option<MapCause>,
+    pub ms_not_reachable_reason: Option<MsNotReachableReason>,
+    pub gsn_address: Option<GsnAddress>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for SendRoutingInfoResponse {
+    fn default() -> SendRoutingInfoResponse {
+        let hdr = Gtpv1Header {
+            msgtype: SEND_ROUTING_INFO_RESPONSE,
+            ..Default::default()
+        };
+        SendRoutingInfoResponse {
+            header: hdr,
+            cause: Cause::default(),
+            imsi: Imsi::default(),
+            map_cause: None,
+            ms_not_reachable_reason: None,
+            gsn_address: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for SendRoutingInfoResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        elements.push(self.imsi.into());
+        if let Some(i) = self.map_cause {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.ms_not_reachable_reason {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.gsn_address {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = SendRoutingInfoResponse {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != SEND_ROUTING_INFO_RESPONSE {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let (mut cause, mut imsi) = (None, None);
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::Imsi(i) if imsi.is_none() => imsi = Some(i),
+                InformationElement::MapCause(i) if message.map_cause.is_none() => {
+                    message.map_cause = Some(i)
+                }
+                InformationElement::MsNotReachableReason(i)
+                    if message.ms_not_reachable_reason.is_none() =>
+                {
+                    message.ms_not_reachable_reason = Some(i)
+                }
+                InformationElement::GsnAddress(i) if message.gsn_address.is_none() => {
+                    message.gsn_address = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match (cause, imsi) {
+            (Some(cause), Some(imsi)) => {
+                message.cause = cause;
+                message.imsi = imsi;
+                Ok(message)
+            }
+            _ => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[test]
+fn send_routing_info_resp_unmarshal_test() {
+    let encoded: [u8; 30] = [
+        0x32, 0x21, 0x00, 0x16, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x01, 0x80, 0x02,
+        0x62, 0x02, 0x01, 0x21, 0x43, 0x65, 0x87, 0xf9, 0x85, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x01,
+    ];
+    let decoded = SendRoutingInfoResponse {
+        header: Gtpv1Header {
+            msgtype: SEND_ROUTING_INFO_RESPONSE,
+            length: 22,
+            teid: 0,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        imsi: Imsi {
+            t: IMSI,
+            imsi: "262010123456789".to_string(),
+        },
+        map_cause: None,
+        ms_not_reachable_reason: None,
+        gsn_address: Some(GsnAddress {
+            t: GSN_ADDRESS,
+            length: 4,
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 1)),
+        }),
+        private_extension: None,
+    };
+    assert_eq!(
+        SendRoutingInfoResponse::unmarshal(&encoded).unwrap(),
+        decoded
+    );
+}
+
+#[test]
+fn send_routing_info_resp_marshal_test() {
+    let encoded: [u8; 30] = [
+        0x32, 0x21, 0x00, 0x16, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x01, 0x80, 0x02,
+        0x62, 0x02, 0x01, 0x21, 0x43, 0x65, 0x87, 0xf9, 0x85, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x01,
+    ];
+    let decoded = SendRoutingInfoResponse {
+        header: Gtpv1Header {
+            msgtype: SEND_ROUTING_INFO_RESPONSE,
+            length: 22,
+            teid: 0,
+            sequence_number: Some(0x2345),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        imsi: Imsi {
+            t: IMSI,
+            imsi: "262010123456789".to_string(),
+        },
+        map_cause: None,
+        ms_not_reachable_reason: None,
+        gsn_address: Some(GsnAddress {
+            t: GSN_ADDRESS,
+            length: 4,
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 1)),
+        }),
+        private_extension: None,
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn send_routing_info_resp_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 14] = [
+        0x32, 0x21, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x01, 0x80,
+    ];
+    assert_eq!(
+        SendRoutingInfoResponse::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
+
+#[test]
+fn send_routing_info_resp_ms_not_reachable_unmarshal_test() {
+    let encoded: [u8; 27] = [
+        0x32, 0x21, 0x00, 0x13, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x00, 0x00, 0x01, 0xe0, 0x02,
+        0x62, 0x02, 0x01, 0x21, 0x43, 0x65, 0x87, 0xf9, 0x0b, 0x1b, 0x1d, 0x01,
+    ];
+    let message = SendRoutingInfoResponse::unmarshal(&encoded).unwrap();
+    assert_eq!(message.cause.value, 0xe0);
+    assert_eq!(message.map_cause.unwrap().value, 27);
+    assert_eq!(message.ms_not_reachable_reason.unwrap().value, 1);
+    assert!(message.gsn_address.is_none());
+}
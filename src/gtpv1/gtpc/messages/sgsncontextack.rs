@@ -0,0 +1,160 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const SGSN_CONTEXT_ACK: u8 = 52;
+
+// Definition of GTPv1-C SGSN Context Acknowledge
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SGSNContextAcknowledge {
+    pub header: Gtpv1Header,
+    pub cause: Cause,
+    pub teid_data_ii: Vec<TeidDataII>,
+    pub sgsn_ip_user: Option<GsnAddress>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for SGSNContextAcknowledge {
+    fn default() -> SGSNContextAcknowledge {
+        let hdr = Gtpv1Header {
+            msgtype: SGSN_CONTEXT_ACK,
+            ..Default::default()
+        };
+        SGSNContextAcknowledge {
+            header: hdr,
+            cause: Cause::default(),
+            teid_data_ii: vec![],
+            sgsn_ip_user: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for SGSNContextAcknowledge {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        elements.extend(self.teid_data_ii.into_iter().map(|x| x.into()));
+        if let Some(i) = self.sgsn_ip_user {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = SGSNContextAcknowledge {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != SGSN_CONTEXT_ACK {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let mut cause = None;
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::TeidDataII(i) => message.teid_data_ii.push(i),
+                InformationElement::GsnAddress(i) if message.sgsn_ip_user.is_none() => {
+                    message.sgsn_ip_user = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match cause {
+            Some(i) => {
+                message.cause = i;
+                Ok(message)
+            }
+            None => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+const SGSN_CONTEXT_ACK_TEST: [u8; 33] = [
+    0x32, 0x34, 0x00, 0x19, 0x00, 0x00, 0x20, 0x02, 0x12, 0x34, 0x00, 0x00, 0x01, 0x80, 0x12, 0x05,
+    0x00, 0x00, 0x30, 0x01, 0x12, 0x06, 0x00, 0x00, 0x30, 0x02, 0x85, 0x00, 0x04, 0x0a, 0x00, 0x00,
+    0x02,
+];
+
+#[cfg(test)]
+fn sgsn_context_ack_test() -> SGSNContextAcknowledge {
+    SGSNContextAcknowledge {
+        header: Gtpv1Header {
+            msgtype: SGSN_CONTEXT_ACK,
+            length: 25,
+            teid: 0x2002,
+            sequence_number: Some(0x1234),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        teid_data_ii: vec![
+            TeidDataII {
+                t: TEID_DATA_II,
+                nsapi: 5,
+                teid: 0x3001,
+            },
+            TeidDataII {
+                t: TEID_DATA_II,
+                nsapi: 6,
+                teid: 0x3002,
+            },
+        ],
+        sgsn_ip_user: Some(GsnAddress {
+            t: GSN_ADDRESS,
+            length: 4,
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 2)),
+        }),
+        private_extension: None,
+    }
+}
+
+#[test]
+fn sgsn_context_ack_unmarshal_test() {
+    assert_eq!(
+        SGSNContextAcknowledge::unmarshal(&SGSN_CONTEXT_ACK_TEST).unwrap(),
+        sgsn_context_ack_test()
+    );
+}
+
+#[test]
+fn sgsn_context_ack_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    sgsn_context_ack_test().marshal(&mut buffer);
+    assert_eq!(buffer, SGSN_CONTEXT_ACK_TEST);
+}
+
+#[test]
+fn sgsn_context_ack_wrong_message_type_unmarshal_test() {
+    let mut encoded = SGSN_CONTEXT_ACK_TEST;
+    encoded[1] = 51;
+    assert_eq!(
+        SGSNContextAcknowledge::unmarshal(&encoded),
+        Err(GTPV1Error::MessageIncorrectMessageType)
+    );
+}
@@ -0,0 +1,269 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const SGSN_CONTEXT_REQUEST: u8 = 50;
+
+// Definition of GTPv1-C SGSN Context Request
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SGSNContextRequest {
+    pub header: Gtpv1Header,
+    pub imsi: Option<Imsi>,
+    pub rai: Rai,
+    pub tlli: Option<Tlli>,
+    pub ptmsi: Option<Ptmsi>,
+    pub ptmsi_signature: Option<PtmsiSignature>,
+    pub ms_validated: Option<MsValidated>,
+    pub teid_control: Teid,
+    pub sgsn_ip_control: GsnAddress,
+    pub alt_sgsn_ip_control: Option<GsnAddress>,
+    pub rat_type: Option<RatType>,
+    pub hop_counter: Option<HopCounter>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for SGSNContextRequest {
+    fn default() -> SGSNContextRequest {
+        let hdr = Gtpv1Header {
+            msgtype: SGSN_CONTEXT_REQUEST,
+            ..Default::default()
+        };
+        SGSNContextRequest {
+            header: hdr,
+            imsi: None,
+            rai: Rai::default(),
+            tlli: None,
+            ptmsi: None,
+            ptmsi_signature: None,
+            ms_validated: None,
+            teid_control: Teid {
+                t: TEID_CONTROL,
+                ..Default::default()
+            },
+            sgsn_ip_control: GsnAddress::default(),
+            alt_sgsn_ip_control: None,
+            rat_type: None,
+            hop_counter: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for SGSNContextRequest {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![];
+        if let Some(i) = self.imsi {
+            elements.push(i.into());
+        }
+        elements.push(self.rai.into());
+        if let Some(i) = self.tlli {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.ptmsi {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.ptmsi_signature {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.ms_validated {
+            elements.push(i.into());
+        }
+        elements.push(self.teid_control.into());
+        elements.push(self.sgsn_ip_control.into());
+        if let Some(i) = self.alt_sgsn_ip_control {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.rat_type {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.hop_counter {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = SGSNContextRequest {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != SGSN_CONTEXT_REQUEST {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let (mut rai, mut teid_control, mut sgsn_ip_control) = (None, None, None);
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Imsi(i) if message.imsi.is_none() => message.imsi = Some(i),
+                InformationElement::Rai(i) if rai.is_none() => rai = Some(i),
+                InformationElement::Tlli(i) if message.tlli.is_none() => message.tlli = Some(i),
+                InformationElement::Ptmsi(i) if message.ptmsi.is_none() => message.ptmsi = Some(i),
+                InformationElement::PtmsiSignature(i) if message.ptmsi_signature.is_none() => {
+                    message.ptmsi_signature = Some(i)
+                }
+                InformationElement::MsValidated(i) if message.ms_validated.is_none() => {
+                    message.ms_validated = Some(i)
+                }
+                InformationElement::Teid(i) if i.t == TEID_CONTROL && teid_control.is_none() => {
+                    teid_control = Some(i)
+                }
+                InformationElement::GsnAddress(i) if sgsn_ip_control.is_none() => {
+                    sgsn_ip_control = Some(i)
+                }
+                InformationElement::GsnAddress(i) if message.alt_sgsn_ip_control.is_none() => {
+                    message.alt_sgsn_ip_control = Some(i)
+                }
+                InformationElement::RatType(i) if message.rat_type.is_none() => {
+                    message.rat_type = Some(i)
+                }
+                InformationElement::HopCounter(i) if message.hop_counter.is_none() => {
+                    message.hop_counter = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match (rai, teid_control, sgsn_ip_control) {
+            (Some(i), Some(j), Some(k)) => {
+                message.rai = i;
+                message.teid_control = j;
+                message.sgsn_ip_control = k;
+                Ok(message)
+            }
+            _ => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+const SGSN_CONTEXT_REQUEST_TEST: [u8; 50] = [
+    0x32, 0x32, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x00, 0x12, 0x34, 0x00, 0x00, 0x03, 0x62, 0xf2, 0x10,
+    0x00, 0x01, 0x05, 0x05, 0xd1, 0xa2, 0xb3, 0xc4, 0x0c, 0xaa, 0xbb, 0xcc, 0x0d, 0xfe, 0x11, 0x00,
+    0x00, 0x10, 0x01, 0x85, 0x00, 0x04, 0xc0, 0xa8, 0x00, 0x01, 0x97, 0x00, 0x01, 0x01, 0xa3, 0x00,
+    0x01, 0x03,
+];
+
+#[cfg(test)]
+fn sgsn_context_request_test() -> SGSNContextRequest {
+    SGSNContextRequest {
+        header: Gtpv1Header {
+            msgtype: SGSN_CONTEXT_REQUEST,
+            length: 42,
+            teid: 0,
+            sequence_number: Some(0x1234),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        imsi: None,
+        rai: Rai {
+            mcc: 262,
+            mnc: 1,
+            mnc_is_three_digits: false,
+            lac: 1,
+            rac: 5,
+            ..Default::default()
+        },
+        tlli: None,
+        ptmsi: Some(Ptmsi {
+            t: PTMSI,
+            value: 0xd1a2b3c4,
+        }),
+        ptmsi_signature: Some(PtmsiSignature {
+            t: PTMSI_SIGNATURE,
+            value: 0xaabbcc,
+        }),
+        ms_validated: Some(MsValidated {
+            t: MS_VALIDATED,
+            validated: false,
+        }),
+        teid_control: Teid {
+            t: TEID_CONTROL,
+            teid: 0x1001,
+        },
+        sgsn_ip_control: GsnAddress {
+            t: GSN_ADDRESS,
+            length: 4,
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 0, 1)),
+        },
+        alt_sgsn_ip_control: None,
+        rat_type: Some(RatType {
+            t: RATTYPE,
+            length: RATTYPE_LENGTH,
+            rat_type: Rat::Utran,
+        }),
+        hop_counter: Some(HopCounter {
+            t: HOP_COUNTER,
+            length: HOP_COUNTER_LENGTH,
+            value: 3,
+        }),
+        private_extension: None,
+    }
+}
+
+#[test]
+fn sgsn_context_req_unmarshal_test() {
+    assert_eq!(
+        SGSNContextRequest::unmarshal(&SGSN_CONTEXT_REQUEST_TEST).unwrap(),
+        sgsn_context_request_test()
+    );
+}
+
+#[test]
+fn sgsn_context_req_marshal_test() {
+    let mut buffer: Vec<u8> = vec![];
+    sgsn_context_request_test().marshal(&mut buffer);
+    assert_eq!(buffer, SGSN_CONTEXT_REQUEST_TEST);
+}
+
+#[test]
+fn sgsn_context_req_missing_mandatory_ie_unmarshal_test() {
+    let mut encoded = SGSN_CONTEXT_REQUEST_TEST[..30].to_vec();
+    encoded[3] = 22;
+    assert_eq!(
+        SGSNContextRequest::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
+
+#[test]
+fn sgsn_context_req_wrong_ie_order_unmarshal_test() {
+    let mut encoded = SGSN_CONTEXT_REQUEST_TEST[..19].to_vec();
+    encoded.extend_from_slice(&SGSN_CONTEXT_REQUEST_TEST[28..30]);
+    encoded.extend_from_slice(&SGSN_CONTEXT_REQUEST_TEST[19..28]);
+    encoded.extend_from_slice(&SGSN_CONTEXT_REQUEST_TEST[30..]);
+    assert_eq!(
+        SGSNContextRequest::unmarshal(&encoded),
+        Err(GTPV1Error::MessageInvalidMessageFormat)
+    );
+}
+
+#[test]
+fn sgsn_context_req_truncated_ie_unmarshal_test() {
+    let encoded: [u8; 15] = [
+        0x32, 0x32, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x03, 0x32, 0xf4,
+    ];
+    assert_eq!(
+        SGSNContextRequest::unmarshal(&encoded),
+        Err(GTPV1Error::IEInvalidLength)
+    );
+}
@@ -0,0 +1,296 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpc::header::*;
+use crate::gtpv1::gtpc::messages::commons::*;
+use crate::gtpv1::gtpc::messages::ies::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.060 V15.5.0 (2019-06)
+
+pub const SGSN_CONTEXT_RESPONSE: u8 = 51;
+
+// Definition of GTPv1-C SGSN Context Response
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SGSNContextResponse {
+    pub header: Gtpv1Header,
+    pub cause: Cause,
+    pub imsi: Option<Imsi>,
+    pub teid_control: Option<Teid>,
+    pub rab_contexts: Vec<RabContext>,
+    pub radio_priority_sms: Option<RadioPrioritySms>,
+    pub radio_priorities: Vec<RadioPriority>,
+    pub packet_flow_ids: Vec<PacketFlowId>,
+    pub charging_char: Option<ChargingCharacteristics>,
+    pub radio_priority_lcs: Option<RadioPriorityLcs>,
+    pub mm_context: Option<MmContext>,
+    pub pdp_contexts: Vec<PdpContext>,
+    pub sgsn_ip_control: Option<GsnAddress>,
+    pub mbms_ue_contexts: Vec<MbmsUeContext>,
+    pub ext_common_flags: Option<ExtendedCommonFlags>,
+    pub ext_common_flags_ii: Option<ExtendedCommonFlagsII>,
+    pub private_extension: Option<PrivateExtension>,
+}
+
+impl Default for SGSNContextResponse {
+    fn default() -> SGSNContextResponse {
+        let hdr = Gtpv1Header {
+            msgtype: SGSN_CONTEXT_RESPONSE,
+            ..Default::default()
+        };
+        SGSNContextResponse {
+            header: hdr,
+            cause: Cause::default(),
+            imsi: None,
+            teid_control: None,
+            rab_contexts: vec![],
+            radio_priority_sms: None,
+            radio_priorities: vec![],
+            packet_flow_ids: vec![],
+            charging_char: None,
+            radio_priority_lcs: None,
+            mm_context: None,
+            pdp_contexts: vec![],
+            sgsn_ip_control: None,
+            mbms_ue_contexts: vec![],
+            ext_common_flags: None,
+            ext_common_flags_ii: None,
+            private_extension: None,
+        }
+    }
+}
+
+impl Messages for SGSNContextResponse {
+    fn marshal(self, buffer: &mut Vec<u8>) {
+        // Marshal header
+
+        self.header.marshal(buffer);
+
+        // Marshal IEs in increasing order of their type
+
+        let mut elements: Vec<InformationElement> = vec![self.cause.into()];
+        if let Some(i) = self.imsi {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.teid_control {
+            elements.push(i.into());
+        }
+        elements.extend(self.rab_contexts.into_iter().map(|x| x.into()));
+        if let Some(i) = self.radio_priority_sms {
+            elements.push(i.into());
+        }
+        elements.extend(self.radio_priorities.into_iter().map(|x| x.into()));
+        elements.extend(self.packet_flow_ids.into_iter().map(|x| x.into()));
+        if let Some(i) = self.charging_char {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.mm_context {
+            elements.push(i.into());
+        }
+        elements.extend(self.pdp_contexts.into_iter().map(|x| x.into()));
+        if let Some(i) = self.sgsn_ip_control {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.radio_priority_lcs {
+            elements.push(i.into());
+        }
+        elements.extend(self.mbms_ue_contexts.into_iter().map(|x| x.into()));
+        if let Some(i) = self.ext_common_flags {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.ext_common_flags_ii {
+            elements.push(i.into());
+        }
+        if let Some(i) = self.private_extension {
+            elements.push(i.into());
+        }
+        InformationElement::encoder(elements, buffer);
+
+        set_length(buffer);
+    }
+
+    fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let mut message = SGSNContextResponse {
+            header: Gtpv1Header::unmarshal(buffer)?,
+            ..Default::default()
+        };
+
+        if message.header.msgtype != SGSN_CONTEXT_RESPONSE {
+            return Err(GTPV1Error::MessageIncorrectMessageType);
+        }
+
+        let mut cause = None;
+        for ie in decode_message_body(&message.header, buffer)? {
+            match ie {
+                InformationElement::Cause(i) if cause.is_none() => cause = Some(i),
+                InformationElement::Imsi(i) if message.imsi.is_none() => message.imsi = Some(i),
+                InformationElement::Teid(i)
+                    if i.t == TEID_CONTROL && message.teid_control.is_none() =>
+                {
+                    message.teid_control = Some(i)
+                }
+                InformationElement::RabContext(i) => message.rab_contexts.push(i),
+                InformationElement::RadioPrioritySms(i) if message.radio_priority_sms.is_none() => {
+                    message.radio_priority_sms = Some(i)
+                }
+                InformationElement::RadioPriority(i) => message.radio_priorities.push(i),
+                InformationElement::PacketFlowId(i) => message.packet_flow_ids.push(i),
+                InformationElement::ChargingCharacteristics(i)
+                    if message.charging_char.is_none() =>
+                {
+                    message.charging_char = Some(i)
+                }
+                InformationElement::MmContext(i) if message.mm_context.is_none() => {
+                    message.mm_context = Some(i)
+                }
+                InformationElement::PdpContext(i) => message.pdp_contexts.push(i),
+                InformationElement::GsnAddress(i) if message.sgsn_ip_control.is_none() => {
+                    message.sgsn_ip_control = Some(i)
+                }
+                InformationElement::RadioPriorityLcs(i) if message.radio_priority_lcs.is_none() => {
+                    message.radio_priority_lcs = Some(i)
+                }
+                InformationElement::MbmsUeContext(i) => message.mbms_ue_contexts.push(i),
+                InformationElement::ExtendedCommonFlags(i)
+                    if message.ext_common_flags.is_none() =>
+                {
+                    message.ext_common_flags = Some(i)
+                }
+                InformationElement::ExtendedCommonFlagsII(i)
+                    if message.ext_common_flags_ii.is_none() =>
+                {
+                    message.ext_common_flags_ii = Some(i)
+                }
+                InformationElement::PrivateExtension(i) if message.private_extension.is_none() => {
+                    message.private_extension = Some(i)
+                }
+                _ => (),
+            }
+        }
+
+        match cause {
+            Some(i) => {
+                message.cause = i;
+                Ok(message)
+            }
+            None => Err(GTPV1Error::MessageMandatoryIEMissing),
+        }
+    }
+}
+
+#[cfg(test)]
+fn sgsn_context_response_test() -> SGSNContextResponse {
+    SGSNContextResponse {
+        header: Gtpv1Header {
+            msgtype: SGSN_CONTEXT_RESPONSE,
+            teid: 0x1001,
+            sequence_number: Some(0x1234),
+            ..Default::default()
+        },
+        cause: Cause {
+            t: CAUSE,
+            value: 128,
+        },
+        imsi: Some(Imsi {
+            imsi: "262010123456789".to_string(),
+            ..Default::default()
+        }),
+        teid_control: Some(Teid {
+            t: TEID_CONTROL,
+            teid: 0x2002,
+        }),
+        rab_contexts: vec![RabContext {
+            nsapi: 5,
+            dl_gtpu_sqn: 1,
+            ul_gtpu_sqn: 2,
+            dl_pdcp_sqn: 3,
+            ul_pdcp_sqn: 4,
+            ..Default::default()
+        }],
+        radio_priority_sms: Some(RadioPrioritySms {
+            value: 4,
+            ..Default::default()
+        }),
+        radio_priorities: vec![RadioPriority {
+            nsapi: 5,
+            value: 2,
+            ..Default::default()
+        }],
+        packet_flow_ids: vec![PacketFlowId {
+            nsapi: 5,
+            value: 0x10,
+            ..Default::default()
+        }],
+        mm_context: Some(MmContext {
+            length: 0x2c,
+            cksn: 1,
+            used_cipher: 2,
+            security: SecurityContext::GsmKeyTriplets {
+                kc: [0xaa; 8],
+                triplets: vec![Triplet {
+                    rand: [0x01; 16],
+                    sres: [0x02; 4],
+                    kc: [0x03; 8],
+                }],
+            },
+            drx_param: [0x0a, 0x00],
+            ms_network_capability: vec![0xe5],
+            ..Default::default()
+        }),
+        pdp_contexts: vec![PdpContext {
+            length: 0x41,
+            nsapi: 5,
+            sapi: 3,
+            qos_subscribed: vec![0x08, 0x1b, 0x93, 0x1f],
+            qos_requested: vec![0x08, 0x1b, 0x93, 0x1f],
+            qos_negotiated: vec![0x08, 0x1b, 0x93, 0x1f],
+            pdp_address: vec![0x0a, 0x00, 0x00, 0x01],
+            ggsn_address_control: std::net::IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 0, 1)),
+            ggsn_address_user: std::net::IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 0, 2)),
+            apn: "test.net.com".to_string(),
+            ..Default::default()
+        }],
+        sgsn_ip_control: Some(GsnAddress {
+            t: GSN_ADDRESS,
+            length: 4,
+            ip: std::net::IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 1)),
+        }),
+        radio_priority_lcs: Some(RadioPriorityLcs {
+            value: 1,
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn sgsn_context_resp_marshal_unmarshal_test() {
+    let mut message = sgsn_context_response_test();
+    let mut buffer: Vec<u8> = vec![];
+    message.clone().marshal(&mut buffer);
+    message.header.length = (buffer.len() - 8) as u16;
+    assert_eq!(&buffer[..2], &[0x32, 0x33]);
+    assert_eq!(&buffer[12..15], &[0x01, 0x80, 0x02]);
+    assert_eq!(SGSNContextResponse::unmarshal(&buffer).unwrap(), message);
+}
+
+#[test]
+fn sgsn_context_resp_unknown_ie_ignored_unmarshal_test() {
+    let encoded: [u8; 18] = [
+        0x32, 0x33, 0x00, 0x0a, 0x00, 0x00, 0x10, 0x01, 0x12, 0x34, 0x00, 0x00, 0x01, 0xc1, 0xc8,
+        0x00, 0x01, 0x01,
+    ];
+    let message = SGSNContextResponse::unmarshal(&encoded).unwrap();
+    assert_eq!(message.cause.value, 0xc1);
+    assert!(message.mm_context.is_none());
+}
+
+#[test]
+fn sgsn_context_resp_missing_mandatory_ie_unmarshal_test() {
+    let encoded: [u8; 14] = [
+        0x32, 0x33, 0x00, 0x06, 0x00, 0x00, 0x10, 0x01, 0x12, 0x34, 0x00, 0x00, 0x0e, 0x05,
+    ];
+    assert_eq!(
+        SGSNContextResponse::unmarshal(&encoded),
+        Err(GTPV1Error::MessageMandatoryIEMissing)
+    );
+}
@@ -74,7 +74,7 @@ impl Messages for SupportedExtensionHeadersNotification {
 #[test]
 fn test_supported_ext_hdr_notification_unmarshal() {
     let encoded: [u8; 19] = [
-        0x32, 0x1f, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0xf6, 0x4b, 0x00, 0x00, 0x8d, 0x01, 0x00,
+        0x32, 0x1f, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0xf6, 0x4b, 0x00, 0x00, 0x8d, 0x05, 0x00,
         0x01, 0x02, 0x03, 0x04,
     ];
     let decoded: SupportedExtensionHeadersNotification = SupportedExtensionHeadersNotification {
@@ -88,7 +88,7 @@ fn test_supported_ext_hdr_notification_unmarshal() {
         },
         list: ExtensionHeaderTypeList {
             t: EXTENSION_HEADER_TYPE_LIST,
-            length: 1,
+            length: 5,
             list: vec![0x00, 0x01, 0x02, 0x03, 0x04],
         },
     };
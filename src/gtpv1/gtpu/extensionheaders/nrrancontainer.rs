@@ -1,4 +1,4 @@
-use crate::gtpv1::{errors::GTPV1Error, gtpu::extensionheaders::commons::*};
+use crate::gtpv1::{errors::GTPV1Error, gtpu::extensionheaders::commons::*, utils::*};
 
 pub const NR_RAN_CONTAINER: u8 = 0x84;
 pub const NR_RAN_CONTAINER_LENGTH: u8 = 1;
@@ -55,6 +55,458 @@ impl ExtensionHeaders for NrRanContainer {
     }
 }
 
+impl NrRanContainer {
+    // Decode the container into NR user plane protocol frame
+
+    pub fn nr_up_frame(&self) -> Result<NrUpFrame, GTPV1Error> {
+        NrUpFrame::unmarshal(&self.container)
+    }
+
+    // Encode the frame into the container, padding it to a multiple of 4 octets
+
+    pub fn set_nr_up_frame(&mut self, frame: &NrUpFrame) {
+        let mut container: Vec<u8> = vec![];
+        frame.marshal(&mut container);
+        while !(container.len() + 2).is_multiple_of(4) {
+            container.push(0x00);
+        }
+        self.length = ((container.len() + 2) / 4) as u8;
+        self.container = container;
+    }
+}
+
+impl From<NrUpFrame> for NrRanContainer {
+    fn from(i: NrUpFrame) -> Self {
+        let mut data = NrRanContainer::default();
+        data.set_nr_up_frame(&i);
+        data
+    }
+}
+
+// NR user plane protocol frames according to 3GPP TS 38.425 V17.0.0 (2022-04)
+
+pub const DL_USER_DATA: u8 = 0;
+pub const DL_DATA_DELIVERY_STATUS: u8 = 1;
+pub const ASSISTANCE_INFORMATION_DATA: u8 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NrUpFrame {
+    DlUserData(DlUserData),
+    DlDataDeliveryStatus(DlDataDeliveryStatus),
+    AssistanceInformationData(AssistanceInformationData),
+}
+
+impl NrUpFrame {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        match self {
+            NrUpFrame::DlUserData(i) => i.marshal(buffer),
+            NrUpFrame::DlDataDeliveryStatus(i) => i.marshal(buffer),
+            NrUpFrame::AssistanceInformationData(i) => i.marshal(buffer),
+        }
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.is_empty() {
+            return Err(GTPV1Error::ExtHeaderInvalidLength);
+        }
+        match buffer[0] >> 4 {
+            DL_USER_DATA => match DlUserData::unmarshal(buffer) {
+                Ok(i) => Ok(NrUpFrame::DlUserData(i)),
+                Err(j) => Err(j),
+            },
+            DL_DATA_DELIVERY_STATUS => match DlDataDeliveryStatus::unmarshal(buffer) {
+                Ok(i) => Ok(NrUpFrame::DlDataDeliveryStatus(i)),
+                Err(j) => Err(j),
+            },
+            ASSISTANCE_INFORMATION_DATA => match AssistanceInformationData::unmarshal(buffer) {
+                Ok(i) => Ok(NrUpFrame::AssistanceInformationData(i)),
+                Err(j) => Err(j),
+            },
+            _ => Err(GTPV1Error::ExtHeaderUnknown),
+        }
+    }
+}
+
+// Range of NR PDCP PDU SNs or NR-U SNs (start and end inclusive)
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct SnRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+// Block of discarded NR PDCP PDUs
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct DiscardBlock {
+    pub start: u32,
+    pub size: u8,
+}
+
+// DL USER DATA (PDU Type 0)
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct DlUserData {
+    pub report_polling: bool,
+    pub request_out_of_seq_report: bool,
+    pub user_data_existence: bool,
+    pub assistance_info_report_polling: bool,
+    pub retransmission: bool,
+    pub nru_sqn: u32,
+    pub dl_discard_sqn: Option<u32>,          // DL Flush flag
+    pub dl_discard_blocks: Vec<DiscardBlock>, // DL Discard Blocks flag
+    pub dl_report_sqn: Option<u32>,           // Report Delivered flag
+}
+
+impl DlUserData {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut flags = DL_USER_DATA << 4;
+        if !self.dl_discard_blocks.is_empty() {
+            flags |= 0x04;
+        }
+        if self.dl_discard_sqn.is_some() {
+            flags |= 0x02;
+        }
+        if self.report_polling {
+            flags |= 0x01;
+        }
+        buffer.push(flags);
+        buffer.push(
+            ((self.request_out_of_seq_report as u8) << 4)
+                | ((self.dl_report_sqn.is_some() as u8) << 3)
+                | ((self.user_data_existence as u8) << 2)
+                | ((self.assistance_info_report_polling as u8) << 1)
+                | (self.retransmission as u8),
+        );
+        buffer.extend_from_slice(&self.nru_sqn.to_be_bytes()[1..]);
+        if let Some(i) = self.dl_discard_sqn {
+            buffer.extend_from_slice(&i.to_be_bytes()[1..]);
+        }
+        if !self.dl_discard_blocks.is_empty() {
+            buffer.push(self.dl_discard_blocks.len() as u8);
+            for i in self.dl_discard_blocks.iter() {
+                buffer.extend_from_slice(&i.start.to_be_bytes()[1..]);
+                buffer.push(i.size);
+            }
+        }
+        if let Some(i) = self.dl_report_sqn {
+            buffer.extend_from_slice(&i.to_be_bytes()[1..]);
+        }
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() < 5 {
+            return Err(GTPV1Error::ExtHeaderInvalidLength);
+        }
+        if buffer[0] >> 4 != DL_USER_DATA {
+            return Err(GTPV1Error::ExtHeaderUnknown);
+        }
+        let mut data = DlUserData {
+            report_polling: buffer[0] & 0x01 == 1,
+            request_out_of_seq_report: (buffer[1] >> 4) & 0x01 == 1,
+            user_data_existence: (buffer[1] >> 2) & 0x01 == 1,
+            assistance_info_report_polling: (buffer[1] >> 1) & 0x01 == 1,
+            retransmission: buffer[1] & 0x01 == 1,
+            nru_sqn: u32::from_be_bytes([0x00, buffer[2], buffer[3], buffer[4]]),
+            ..Default::default()
+        };
+        let mut cursor: usize = 5;
+        if (buffer[0] >> 1) & 0x01 == 1 {
+            match read_u24(buffer, cursor) {
+                Some(i) => data.dl_discard_sqn = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 3;
+        }
+        if (buffer[0] >> 2) & 0x01 == 1 {
+            let blocks = match buffer.get(cursor) {
+                Some(i) => *i,
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            };
+            cursor += 1;
+            for _ in 0..blocks {
+                match (read_u24(buffer, cursor), buffer.get(cursor + 3)) {
+                    (Some(i), Some(j)) => data
+                        .dl_discard_blocks
+                        .push(DiscardBlock { start: i, size: *j }),
+                    _ => return Err(GTPV1Error::ExtHeaderInvalidLength),
+                }
+                cursor += 4;
+            }
+        }
+        if (buffer[1] >> 3) & 0x01 == 1 {
+            match read_u24(buffer, cursor) {
+                Some(i) => data.dl_report_sqn = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+        }
+        Ok(data)
+    }
+}
+
+// DL DATA DELIVERY STATUS (PDU Type 1)
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct DlDataDeliveryStatus {
+    pub final_frame: bool,
+    pub desired_buffer_size: u32,
+    pub desired_data_rate: Option<u32>,       // Data Rate Ind flag
+    pub lost_nru_sqn_ranges: Vec<SnRange>,    // Lost Packet Report flag
+    pub highest_delivered_sqn: Option<u32>,   // Highest Delivered NR PDCP SN Ind flag
+    pub highest_transmitted_sqn: Option<u32>, // Highest Transmitted NR PDCP SN Ind flag
+    pub cause: Option<u8>,                    // Cause Report flag
+    pub highest_delivered_retransmitted_sqn: Option<u32>, // Highest Delivered Retransmitted NR PDCP SN Ind flag
+    pub highest_retransmitted_sqn: Option<u32>, // Highest Retransmitted NR PDCP SN Ind flag
+    pub delivered_sqn_ranges: Vec<SnRange>,     // Delivered NR PDCP SN Range Ind flag
+}
+
+impl DlDataDeliveryStatus {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut flags = DL_DATA_DELIVERY_STATUS << 4;
+        if self.highest_transmitted_sqn.is_some() {
+            flags |= 0x08;
+        }
+        if self.highest_delivered_sqn.is_some() {
+            flags |= 0x04;
+        }
+        if self.final_frame {
+            flags |= 0x02;
+        }
+        if !self.lost_nru_sqn_ranges.is_empty() {
+            flags |= 0x01;
+        }
+        buffer.push(flags);
+        buffer.push(
+            ((!self.delivered_sqn_ranges.is_empty() as u8) << 4)
+                | ((self.desired_data_rate.is_some() as u8) << 3)
+                | ((self.highest_retransmitted_sqn.is_some() as u8) << 2)
+                | ((self.highest_delivered_retransmitted_sqn.is_some() as u8) << 1)
+                | (self.cause.is_some() as u8),
+        );
+        buffer.extend_from_slice(&self.desired_buffer_size.to_be_bytes());
+        if let Some(i) = self.desired_data_rate {
+            buffer.extend_from_slice(&i.to_be_bytes());
+        }
+        if !self.lost_nru_sqn_ranges.is_empty() {
+            marshal_sn_ranges(&self.lost_nru_sqn_ranges, buffer);
+        }
+        if let Some(i) = self.highest_delivered_sqn {
+            buffer.extend_from_slice(&i.to_be_bytes()[1..]);
+        }
+        if let Some(i) = self.highest_transmitted_sqn {
+            buffer.extend_from_slice(&i.to_be_bytes()[1..]);
+        }
+        if let Some(i) = self.cause {
+            buffer.push(i);
+        }
+        if let Some(i) = self.highest_delivered_retransmitted_sqn {
+            buffer.extend_from_slice(&i.to_be_bytes()[1..]);
+        }
+        if let Some(i) = self.highest_retransmitted_sqn {
+            buffer.extend_from_slice(&i.to_be_bytes()[1..]);
+        }
+        if !self.delivered_sqn_ranges.is_empty() {
+            marshal_sn_ranges(&self.delivered_sqn_ranges, buffer);
+        }
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() < 6 {
+            return Err(GTPV1Error::ExtHeaderInvalidLength);
+        }
+        if buffer[0] >> 4 != DL_DATA_DELIVERY_STATUS {
+            return Err(GTPV1Error::ExtHeaderUnknown);
+        }
+        let mut data = DlDataDeliveryStatus {
+            final_frame: (buffer[0] >> 1) & 0x01 == 1,
+            desired_buffer_size: u32::from_be_bytes([buffer[2], buffer[3], buffer[4], buffer[5]]),
+            ..Default::default()
+        };
+        let mut cursor: usize = 6;
+        if (buffer[1] >> 3) & 0x01 == 1 {
+            match read_u32(buffer, cursor) {
+                Some(i) => data.desired_data_rate = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 4;
+        }
+        if buffer[0] & 0x01 == 1 {
+            match unmarshal_sn_ranges(buffer, cursor) {
+                Some(i) => {
+                    cursor += 1 + i.len() * 6;
+                    data.lost_nru_sqn_ranges = i;
+                }
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+        }
+        if (buffer[0] >> 2) & 0x01 == 1 {
+            match read_u24(buffer, cursor) {
+                Some(i) => data.highest_delivered_sqn = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 3;
+        }
+        if (buffer[0] >> 3) & 0x01 == 1 {
+            match read_u24(buffer, cursor) {
+                Some(i) => data.highest_transmitted_sqn = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 3;
+        }
+        if buffer[1] & 0x01 == 1 {
+            match buffer.get(cursor) {
+                Some(i) => data.cause = Some(*i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 1;
+        }
+        if (buffer[1] >> 1) & 0x01 == 1 {
+            match read_u24(buffer, cursor) {
+                Some(i) => data.highest_delivered_retransmitted_sqn = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 3;
+        }
+        if (buffer[1] >> 2) & 0x01 == 1 {
+            match read_u24(buffer, cursor) {
+                Some(i) => data.highest_retransmitted_sqn = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 3;
+        }
+        if (buffer[1] >> 4) & 0x01 == 1 {
+            match unmarshal_sn_ranges(buffer, cursor) {
+                Some(i) => data.delivered_sqn_ranges = i,
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+        }
+        Ok(data)
+    }
+}
+
+// ASSISTANCE INFORMATION DATA (PDU Type 2)
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct AssistanceInformationData {
+    pub pdcp_duplication_activation_suggestion: Option<bool>, // PDCP Duplication Indication flag
+    pub assistance_info: Vec<AssistanceInformation>,          // Assistance Information Ind flag
+    pub ul_delay_du_result: Option<u32>,                      // UL Delay Ind flag
+    pub dl_delay_du_result: Option<u32>,                      // DL Delay Ind flag
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct AssistanceInformation {
+    pub assistance_info_type: u8,
+    pub radio_quality_info: Vec<u8>,
+}
+
+impl AssistanceInformationData {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut flags = ASSISTANCE_INFORMATION_DATA << 4;
+        if self.pdcp_duplication_activation_suggestion.is_some() {
+            flags |= 0x08;
+        }
+        if !self.assistance_info.is_empty() {
+            flags |= 0x04;
+        }
+        if self.ul_delay_du_result.is_some() {
+            flags |= 0x02;
+        }
+        if self.dl_delay_du_result.is_some() {
+            flags |= 0x01;
+        }
+        buffer.push(flags);
+        buffer.push(self.pdcp_duplication_activation_suggestion.unwrap_or(false) as u8);
+        if !self.assistance_info.is_empty() {
+            buffer.push(self.assistance_info.len() as u8);
+            for i in self.assistance_info.iter() {
+                buffer.push(i.assistance_info_type);
+                buffer.push(i.radio_quality_info.len() as u8);
+                buffer.extend_from_slice(&i.radio_quality_info);
+            }
+        }
+        if let Some(i) = self.ul_delay_du_result {
+            buffer.extend_from_slice(&i.to_be_bytes());
+        }
+        if let Some(i) = self.dl_delay_du_result {
+            buffer.extend_from_slice(&i.to_be_bytes());
+        }
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() < 2 {
+            return Err(GTPV1Error::ExtHeaderInvalidLength);
+        }
+        if buffer[0] >> 4 != ASSISTANCE_INFORMATION_DATA {
+            return Err(GTPV1Error::ExtHeaderUnknown);
+        }
+        let mut data = AssistanceInformationData::default();
+        if (buffer[0] >> 3) & 0x01 == 1 {
+            data.pdcp_duplication_activation_suggestion = Some(buffer[1] & 0x01 == 1);
+        }
+        let mut cursor: usize = 2;
+        if (buffer[0] >> 2) & 0x01 == 1 {
+            let fields = match buffer.get(cursor) {
+                Some(i) => *i,
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            };
+            cursor += 1;
+            for _ in 0..fields {
+                let (info_type, length) = match (buffer.get(cursor), buffer.get(cursor + 1)) {
+                    (Some(i), Some(j)) => (*i, *j as usize),
+                    _ => return Err(GTPV1Error::ExtHeaderInvalidLength),
+                };
+                cursor += 2;
+                match buffer.get(cursor..cursor + length) {
+                    Some(i) => data.assistance_info.push(AssistanceInformation {
+                        assistance_info_type: info_type,
+                        radio_quality_info: i.to_vec(),
+                    }),
+                    None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+                }
+                cursor += length;
+            }
+        }
+        if (buffer[0] >> 1) & 0x01 == 1 {
+            match read_u32(buffer, cursor) {
+                Some(i) => data.ul_delay_du_result = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 4;
+        }
+        if buffer[0] & 0x01 == 1 {
+            match read_u32(buffer, cursor) {
+                Some(i) => data.dl_delay_du_result = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+        }
+        Ok(data)
+    }
+}
+
+// Helper functions for SN ranges preceded by the number of ranges
+
+fn marshal_sn_ranges(ranges: &[SnRange], buffer: &mut Vec<u8>) {
+    buffer.push(ranges.len() as u8);
+    for i in ranges.iter() {
+        buffer.extend_from_slice(&i.start.to_be_bytes()[1..]);
+        buffer.extend_from_slice(&i.end.to_be_bytes()[1..]);
+    }
+}
+
+fn unmarshal_sn_ranges(buffer: &[u8], cursor: usize) -> Option<Vec<SnRange>> {
+    let count = *buffer.get(cursor)? as usize;
+    let mut ranges: Vec<SnRange> = vec![];
+    for n in 0..count {
+        let offset = cursor + 1 + n * 6;
+        ranges.push(SnRange {
+            start: read_u24(buffer, offset)?,
+            end: read_u24(buffer, offset + 3)?,
+        });
+    }
+    Some(ranges)
+}
+
 #[test]
 fn nr_ran_container_exthdr_unmarshal_test() {
     let encoded_ie: [u8; 8] = [0x84, 0x02, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05];
@@ -79,3 +531,134 @@ fn nr_ran_container_exthdr_marshal_test() {
     test_struct.marshal(&mut buffer);
     assert_eq!(buffer, encoded_ie);
 }
+
+#[test]
+fn nr_ran_container_dl_user_data_unmarshal_test() {
+    let encoded_ie: [u8; 12] = [
+        0x84, 0x03, 0x03, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00,
+    ];
+    let frame = DlUserData {
+        report_polling: true,
+        request_out_of_seq_report: false,
+        user_data_existence: false,
+        assistance_info_report_polling: false,
+        retransmission: true,
+        nru_sqn: 256,
+        dl_discard_sqn: Some(0xff),
+        dl_discard_blocks: vec![],
+        dl_report_sqn: None,
+    };
+    assert_eq!(
+        NrRanContainer::unmarshal(&encoded_ie)
+            .unwrap()
+            .nr_up_frame()
+            .unwrap(),
+        NrUpFrame::DlUserData(frame)
+    );
+}
+
+#[test]
+fn nr_ran_container_dl_user_data_round_trip_test() {
+    let frame = NrUpFrame::DlUserData(DlUserData {
+        user_data_existence: true,
+        nru_sqn: 0xabcdef,
+        dl_discard_blocks: vec![
+            DiscardBlock { start: 10, size: 2 },
+            DiscardBlock { start: 20, size: 5 },
+        ],
+        ..Default::default()
+    });
+    let container = NrRanContainer::from(frame.clone());
+    assert_eq!(container.len() % 4, 0);
+    let mut buffer: Vec<u8> = vec![];
+    container.marshal(&mut buffer);
+    let decoded = NrRanContainer::unmarshal(&buffer).unwrap();
+    assert_eq!(decoded, container);
+    assert_eq!(decoded.nr_up_frame().unwrap(), frame);
+}
+
+#[test]
+fn nr_ran_container_dl_data_delivery_status_marshal_test() {
+    let encoded_ie: [u8; 20] = [
+        0x84, 0x05, 0x15, 0x01, 0x00, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x05, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x04, 0x01, 0x00,
+    ];
+    let frame = NrUpFrame::DlDataDeliveryStatus(DlDataDeliveryStatus {
+        desired_buffer_size: 0x10000,
+        lost_nru_sqn_ranges: vec![SnRange { start: 5, end: 7 }],
+        highest_delivered_sqn: Some(4),
+        cause: Some(1),
+        ..Default::default()
+    });
+    let mut buffer: Vec<u8> = vec![];
+    NrRanContainer::from(frame.clone()).marshal(&mut buffer);
+    assert_eq!(buffer, encoded_ie);
+    assert_eq!(
+        NrRanContainer::unmarshal(&encoded_ie)
+            .unwrap()
+            .nr_up_frame()
+            .unwrap(),
+        frame
+    );
+}
+
+#[test]
+fn nr_ran_container_dl_data_delivery_status_round_trip_test() {
+    let frame = NrUpFrame::DlDataDeliveryStatus(DlDataDeliveryStatus {
+        final_frame: true,
+        desired_buffer_size: 1000,
+        desired_data_rate: Some(2000),
+        lost_nru_sqn_ranges: vec![SnRange { start: 1, end: 2 }, SnRange { start: 4, end: 9 }],
+        highest_delivered_sqn: Some(100),
+        highest_transmitted_sqn: Some(110),
+        cause: Some(3),
+        highest_delivered_retransmitted_sqn: Some(90),
+        highest_retransmitted_sqn: Some(95),
+        delivered_sqn_ranges: vec![SnRange {
+            start: 101,
+            end: 105,
+        }],
+    });
+    let container = NrRanContainer::from(frame.clone());
+    assert_eq!(container.nr_up_frame().unwrap(), frame);
+}
+
+#[test]
+fn nr_ran_container_assistance_info_round_trip_test() {
+    let frame = NrUpFrame::AssistanceInformationData(AssistanceInformationData {
+        pdcp_duplication_activation_suggestion: Some(true),
+        assistance_info: vec![AssistanceInformation {
+            assistance_info_type: 1,
+            radio_quality_info: vec![0x01, 0x02, 0x03],
+        }],
+        ul_delay_du_result: Some(15),
+        dl_delay_du_result: None,
+    });
+    let container = NrRanContainer::from(frame.clone());
+    let mut buffer: Vec<u8> = vec![];
+    container.marshal(&mut buffer);
+    assert_eq!(
+        buffer[2..13],
+        [0x2e, 0x01, 0x01, 0x01, 0x03, 0x01, 0x02, 0x03, 0x00, 0x00, 0x00]
+    );
+    assert_eq!(
+        NrRanContainer::unmarshal(&buffer)
+            .unwrap()
+            .nr_up_frame()
+            .unwrap(),
+        frame
+    );
+}
+
+#[test]
+fn nr_ran_container_frame_invalid_length_test() {
+    let container = NrRanContainer {
+        container: vec![0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x05],
+        length: 3,
+        ..Default::default()
+    };
+    assert_eq!(
+        container.nr_up_frame(),
+        Err(GTPV1Error::ExtHeaderInvalidLength)
+    );
+}
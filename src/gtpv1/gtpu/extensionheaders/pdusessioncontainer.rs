@@ -1,4 +1,4 @@
-use crate::gtpv1::{errors::GTPV1Error, gtpu::extensionheaders::commons::*};
+use crate::gtpv1::{errors::GTPV1Error, gtpu::extensionheaders::commons::*, utils::*};
 
 pub const PDU_SESSION_CONTAINER: u8 = 0x85;
 pub const PDU_SESSION_CONTAINER_LENGTH: u8 = 1;
@@ -55,6 +55,298 @@ impl ExtensionHeaders for PduSessionContainer {
     }
 }
 
+impl PduSessionContainer {
+    // Decode the container into DL or UL PDU SESSION INFORMATION frame
+
+    pub fn pdu_session_info(&self) -> Result<PduSessionInformation, GTPV1Error> {
+        PduSessionInformation::unmarshal(&self.container)
+    }
+
+    // Encode the frame into the container, padding it to a multiple of 4 octets
+
+    pub fn set_pdu_session_info(&mut self, info: &PduSessionInformation) {
+        let mut container: Vec<u8> = vec![];
+        info.marshal(&mut container);
+        while !(container.len() + 2).is_multiple_of(4) {
+            container.push(0x00);
+        }
+        self.length = ((container.len() + 2) / 4) as u8;
+        self.container = container;
+    }
+}
+
+impl From<PduSessionInformation> for PduSessionContainer {
+    fn from(i: PduSessionInformation) -> Self {
+        let mut data = PduSessionContainer::default();
+        data.set_pdu_session_info(&i);
+        data
+    }
+}
+
+// PDU Session Information frames according to 3GPP TS 38.415 V17.0.0 (2022-04)
+
+pub const DL_PDU_SESSION_INFORMATION: u8 = 0;
+pub const UL_PDU_SESSION_INFORMATION: u8 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PduSessionInformation {
+    Dl(DlPduSessionInformation),
+    Ul(UlPduSessionInformation),
+}
+
+impl PduSessionInformation {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        match self {
+            PduSessionInformation::Dl(i) => i.marshal(buffer),
+            PduSessionInformation::Ul(i) => i.marshal(buffer),
+        }
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.is_empty() {
+            return Err(GTPV1Error::ExtHeaderInvalidLength);
+        }
+        match buffer[0] >> 4 {
+            DL_PDU_SESSION_INFORMATION => match DlPduSessionInformation::unmarshal(buffer) {
+                Ok(i) => Ok(PduSessionInformation::Dl(i)),
+                Err(j) => Err(j),
+            },
+            UL_PDU_SESSION_INFORMATION => match UlPduSessionInformation::unmarshal(buffer) {
+                Ok(i) => Ok(PduSessionInformation::Ul(i)),
+                Err(j) => Err(j),
+            },
+            _ => Err(GTPV1Error::ExtHeaderUnknown),
+        }
+    }
+
+    pub fn qfi(&self) -> u8 {
+        match self {
+            PduSessionInformation::Dl(i) => i.qfi,
+            PduSessionInformation::Ul(i) => i.qfi,
+        }
+    }
+}
+
+// DL PDU SESSION INFORMATION (PDU Type 0)
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct DlPduSessionInformation {
+    pub rqi: bool,
+    pub qfi: u8,
+    pub ppi: Option<u8>,                   // PPP flag
+    pub dl_sending_timestamp: Option<u64>, // QMP flag
+    pub dl_qfi_sqn: Option<u32>,           // SNP flag
+    pub dl_mbs_qfi_sqn: Option<u32>,       // MSNP flag
+}
+
+impl DlPduSessionInformation {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut flags = DL_PDU_SESSION_INFORMATION << 4;
+        if self.dl_sending_timestamp.is_some() {
+            flags |= 0x08;
+        }
+        if self.dl_qfi_sqn.is_some() {
+            flags |= 0x04;
+        }
+        if self.dl_mbs_qfi_sqn.is_some() {
+            flags |= 0x02;
+        }
+        buffer.push(flags);
+        buffer
+            .push(((self.ppi.is_some() as u8) << 7) | ((self.rqi as u8) << 6) | (self.qfi & 0x3f));
+        if let Some(i) = self.ppi {
+            buffer.push((i & 0x07) << 5);
+        }
+        if let Some(i) = self.dl_sending_timestamp {
+            buffer.extend_from_slice(&i.to_be_bytes());
+        }
+        if let Some(i) = self.dl_qfi_sqn {
+            buffer.extend_from_slice(&i.to_be_bytes()[1..]);
+        }
+        if let Some(i) = self.dl_mbs_qfi_sqn {
+            buffer.extend_from_slice(&i.to_be_bytes());
+        }
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() < 2 {
+            return Err(GTPV1Error::ExtHeaderInvalidLength);
+        }
+        if buffer[0] >> 4 != DL_PDU_SESSION_INFORMATION {
+            return Err(GTPV1Error::ExtHeaderUnknown);
+        }
+        let mut data = DlPduSessionInformation {
+            rqi: (buffer[1] >> 6) & 0x01 == 1,
+            qfi: buffer[1] & 0x3f,
+            ..Default::default()
+        };
+        let mut cursor: usize = 2;
+        if buffer[1] >> 7 == 1 {
+            match buffer.get(cursor) {
+                Some(i) => data.ppi = Some(i >> 5),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 1;
+        }
+        if (buffer[0] >> 3) & 0x01 == 1 {
+            match read_u64(buffer, cursor) {
+                Some(i) => data.dl_sending_timestamp = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 8;
+        }
+        if (buffer[0] >> 2) & 0x01 == 1 {
+            match read_u24(buffer, cursor) {
+                Some(i) => data.dl_qfi_sqn = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 3;
+        }
+        if (buffer[0] >> 1) & 0x01 == 1 {
+            match read_u32(buffer, cursor) {
+                Some(i) => data.dl_mbs_qfi_sqn = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+        }
+        Ok(data)
+    }
+}
+
+// UL PDU SESSION INFORMATION (PDU Type 1)
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct UlPduSessionInformation {
+    pub qfi: u8,
+    pub qos_monitoring: Option<QosMonitoringTimestamps>, // QMP flag
+    pub dl_delay_result: Option<u32>,                    // DL Delay Ind flag
+    pub ul_delay_result: Option<u32>,                    // UL Delay Ind flag
+    pub ul_qfi_sqn: Option<u32>,                         // SNP flag
+    pub n3n9_delay_result: Option<u32>,                  // N3/N9 Delay Ind flag
+    pub d1_ul_pdcp_delay_result: Option<u32>, // New IE Flag with D1 UL PDCP Delay Result Ind
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct QosMonitoringTimestamps {
+    pub dl_sending_timestamp_repeated: u64,
+    pub dl_received_timestamp: u64,
+    pub ul_sending_timestamp: u64,
+}
+
+impl UlPduSessionInformation {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut flags = UL_PDU_SESSION_INFORMATION << 4;
+        if self.qos_monitoring.is_some() {
+            flags |= 0x08;
+        }
+        if self.dl_delay_result.is_some() {
+            flags |= 0x04;
+        }
+        if self.ul_delay_result.is_some() {
+            flags |= 0x02;
+        }
+        if self.ul_qfi_sqn.is_some() {
+            flags |= 0x01;
+        }
+        buffer.push(flags);
+        buffer.push(
+            ((self.n3n9_delay_result.is_some() as u8) << 7)
+                | ((self.d1_ul_pdcp_delay_result.is_some() as u8) << 6)
+                | (self.qfi & 0x3f),
+        );
+        if let Some(i) = &self.qos_monitoring {
+            buffer.extend_from_slice(&i.dl_sending_timestamp_repeated.to_be_bytes());
+            buffer.extend_from_slice(&i.dl_received_timestamp.to_be_bytes());
+            buffer.extend_from_slice(&i.ul_sending_timestamp.to_be_bytes());
+        }
+        if let Some(i) = self.dl_delay_result {
+            buffer.extend_from_slice(&i.to_be_bytes());
+        }
+        if let Some(i) = self.ul_delay_result {
+            buffer.extend_from_slice(&i.to_be_bytes());
+        }
+        if let Some(i) = self.ul_qfi_sqn {
+            buffer.extend_from_slice(&i.to_be_bytes()[1..]);
+        }
+        if let Some(i) = self.n3n9_delay_result {
+            buffer.extend_from_slice(&i.to_be_bytes());
+        }
+        if let Some(i) = self.d1_ul_pdcp_delay_result {
+            buffer.push(0x80);
+            buffer.extend_from_slice(&i.to_be_bytes());
+        }
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        if buffer.len() < 2 {
+            return Err(GTPV1Error::ExtHeaderInvalidLength);
+        }
+        if buffer[0] >> 4 != UL_PDU_SESSION_INFORMATION {
+            return Err(GTPV1Error::ExtHeaderUnknown);
+        }
+        let mut data = UlPduSessionInformation {
+            qfi: buffer[1] & 0x3f,
+            ..Default::default()
+        };
+        let mut cursor: usize = 2;
+        if (buffer[0] >> 3) & 0x01 == 1 {
+            match (
+                read_u64(buffer, cursor),
+                read_u64(buffer, cursor + 8),
+                read_u64(buffer, cursor + 16),
+            ) {
+                (Some(i), Some(j), Some(k)) => {
+                    data.qos_monitoring = Some(QosMonitoringTimestamps {
+                        dl_sending_timestamp_repeated: i,
+                        dl_received_timestamp: j,
+                        ul_sending_timestamp: k,
+                    })
+                }
+                _ => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 24;
+        }
+        if (buffer[0] >> 2) & 0x01 == 1 {
+            match read_u32(buffer, cursor) {
+                Some(i) => data.dl_delay_result = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 4;
+        }
+        if (buffer[0] >> 1) & 0x01 == 1 {
+            match read_u32(buffer, cursor) {
+                Some(i) => data.ul_delay_result = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 4;
+        }
+        if buffer[0] & 0x01 == 1 {
+            match read_u24(buffer, cursor) {
+                Some(i) => data.ul_qfi_sqn = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 3;
+        }
+        if buffer[1] >> 7 == 1 {
+            match read_u32(buffer, cursor) {
+                Some(i) => data.n3n9_delay_result = Some(i),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+            cursor += 4;
+        }
+        if (buffer[1] >> 6) & 0x01 == 1 {
+            match buffer.get(cursor) {
+                Some(i) if i >> 7 == 1 => match read_u32(buffer, cursor + 1) {
+                    Some(j) => data.d1_ul_pdcp_delay_result = Some(j),
+                    None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+                },
+                Some(_) => (),
+                None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            }
+        }
+        Ok(data)
+    }
+}
+
 #[test]
 fn pdu_session_container_exthdr_unmarshal_test() {
     let encoded_ie: [u8; 8] = [0x85, 0x02, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05];
@@ -79,3 +371,114 @@ fn pdu_session_container_exthdr_marshal_test() {
     test_struct.marshal(&mut buffer);
     assert_eq!(buffer, encoded_ie);
 }
+
+#[test]
+fn pdu_session_container_dl_info_unmarshal_test() {
+    let encoded_ie: [u8; 4] = [0x85, 0x01, 0x00, 0x49];
+    let container = PduSessionContainer::unmarshal(&encoded_ie).unwrap();
+    let info = DlPduSessionInformation {
+        rqi: true,
+        qfi: 9,
+        ..Default::default()
+    };
+    assert_eq!(
+        container.pdu_session_info().unwrap(),
+        PduSessionInformation::Dl(info)
+    );
+}
+
+#[test]
+fn pdu_session_container_dl_info_marshal_test() {
+    let encoded_ie: [u8; 16] = [
+        0x85, 0x04, 0x0c, 0xc9, 0xa0, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x00, 0x10,
+        0x01,
+    ];
+    let info = DlPduSessionInformation {
+        rqi: true,
+        qfi: 9,
+        ppi: Some(5),
+        dl_sending_timestamp: Some(0x0102030405060708),
+        dl_qfi_sqn: Some(0x1001),
+        dl_mbs_qfi_sqn: None,
+    };
+    let container = PduSessionContainer::from(PduSessionInformation::Dl(info.clone()));
+    let mut buffer: Vec<u8> = vec![];
+    container.marshal(&mut buffer);
+    assert_eq!(buffer, encoded_ie);
+    assert_eq!(
+        PduSessionContainer::unmarshal(&encoded_ie)
+            .unwrap()
+            .pdu_session_info()
+            .unwrap(),
+        PduSessionInformation::Dl(info)
+    );
+}
+
+#[test]
+fn pdu_session_container_ul_info_round_trip_test() {
+    let info = UlPduSessionInformation {
+        qfi: 5,
+        qos_monitoring: Some(QosMonitoringTimestamps {
+            dl_sending_timestamp_repeated: 1,
+            dl_received_timestamp: 2,
+            ul_sending_timestamp: 3,
+        }),
+        dl_delay_result: Some(10),
+        ul_delay_result: Some(20),
+        ul_qfi_sqn: Some(0xffffff),
+        n3n9_delay_result: Some(30),
+        d1_ul_pdcp_delay_result: Some(40),
+    };
+    let container = PduSessionContainer::from(PduSessionInformation::Ul(info.clone()));
+    assert_eq!(container.len() % 4, 0);
+    let mut buffer: Vec<u8> = vec![];
+    container.marshal(&mut buffer);
+    let decoded = PduSessionContainer::unmarshal(&buffer).unwrap();
+    assert_eq!(decoded, container);
+    assert_eq!(
+        decoded.pdu_session_info().unwrap(),
+        PduSessionInformation::Ul(info)
+    );
+}
+
+#[test]
+fn pdu_session_container_ul_info_unmarshal_test() {
+    let encoded_ie: [u8; 8] = [0x85, 0x02, 0x11, 0x05, 0x00, 0x00, 0x2a, 0x00];
+    let info = UlPduSessionInformation {
+        qfi: 5,
+        ul_qfi_sqn: Some(42),
+        ..Default::default()
+    };
+    assert_eq!(
+        PduSessionContainer::unmarshal(&encoded_ie)
+            .unwrap()
+            .pdu_session_info()
+            .unwrap(),
+        PduSessionInformation::Ul(info)
+    );
+}
+
+#[test]
+fn pdu_session_container_info_invalid_length_test() {
+    let container = PduSessionContainer {
+        container: vec![0x08, 0x09, 0x00, 0x00, 0x00, 0x00],
+        length: 2,
+        ..Default::default()
+    };
+    assert_eq!(
+        container.pdu_session_info(),
+        Err(GTPV1Error::ExtHeaderInvalidLength)
+    );
+}
+
+#[test]
+fn pdu_session_container_info_unknown_pdu_type_test() {
+    let container = PduSessionContainer {
+        container: vec![0x20, 0x09],
+        ..Default::default()
+    };
+    assert_eq!(
+        container.pdu_session_info(),
+        Err(GTPV1Error::ExtHeaderUnknown)
+    );
+}
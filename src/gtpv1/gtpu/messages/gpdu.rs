@@ -31,7 +31,7 @@ impl Default for Gpdu {
 impl Messages for Gpdu {
     fn marshal(self, buffer: &mut Vec<u8>) {
         self.header.marshal(buffer);
-        buffer.append(&mut self.tpdu.clone());
+        buffer.extend_from_slice(&self.tpdu);
         set_length(buffer);
     }
 
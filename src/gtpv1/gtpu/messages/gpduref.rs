@@ -0,0 +1,351 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpu::extensionheaders::*;
+use crate::gtpv1::gtpu::header::*;
+use crate::gtpv1::gtpu::messages::gpdu::*;
+use std::ops::Range;
+
+// According to 3GPP TS 29.281 V16.0.0 (2019-12)
+
+pub const GPDU_MAX_HEADER_LENGTH: usize = MIN_HEADER_LENGTH + 4;
+
+// Definition of zero-copy GTPv1-U G-PDU view borrowing header fields, extension headers and T-PDU from the input buffer
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GpduRef<'a> {
+    pub teid: u32,
+    pub sequence_number: Option<u16>,
+    pub npdu_number: Option<u8>,
+    pub extension_headers: Option<&'a [u8]>,
+    pub tpdu: &'a [u8],
+}
+
+impl<'a> GpduRef<'a> {
+    pub fn unmarshal(buffer: &'a [u8]) -> Result<Self, GTPV1Error> {
+        let (header_size, end) = decode_gpdu_bounds(buffer)?;
+        let flags = buffer[0];
+        let mut data = GpduRef {
+            teid: u32::from_be_bytes([buffer[4], buffer[5], buffer[6], buffer[7]]),
+            sequence_number: None,
+            npdu_number: None,
+            extension_headers: None,
+            tpdu: &buffer[header_size..end],
+        };
+        if flags & 0x02 != 0 {
+            data.sequence_number = Some(u16::from_be_bytes([buffer[8], buffer[9]]));
+        }
+        if flags & 0x01 != 0 {
+            data.npdu_number = Some(buffer[10]);
+        }
+        if flags & 0x04 != 0 {
+            data.extension_headers = Some(&buffer[GPDU_MAX_HEADER_LENGTH - 1..header_size - 1]);
+        }
+        Ok(data)
+    }
+
+    // Iterate over the raw extension headers, each one starting with its Extension Header Type
+
+    pub fn extension_headers(&self) -> ExtensionHeaderRefIter<'a> {
+        ExtensionHeaderRefIter {
+            buffer: self.extension_headers.unwrap_or(&[]),
+        }
+    }
+
+    // Size of the header which would be produced by encapsulate()
+
+    pub fn header_size(&self) -> usize {
+        match (
+            self.sequence_number.is_some(),
+            self.npdu_number.is_some(),
+            self.extension_headers,
+        ) {
+            (false, false, None) => MIN_HEADER_LENGTH,
+            (_, _, None) => GPDU_MAX_HEADER_LENGTH,
+            (_, _, Some(i)) => GPDU_MAX_HEADER_LENGTH + i.len(),
+        }
+    }
+
+    pub fn to_gpdu(&self) -> Result<Gpdu, GTPV1Error> {
+        let extension_headers = match self.extension_headers {
+            Some(_) => {
+                let mut ext_hdrs: Vec<ExtensionHeader> = vec![];
+                for i in self.extension_headers() {
+                    match i.to_extension_header() {
+                        Ok(j) => ext_hdrs.push(j),
+                        Err(j) => return Err(j),
+                    }
+                }
+                Some(ext_hdrs)
+            }
+            None => None,
+        };
+        Ok(Gpdu {
+            header: Gtpv1Header {
+                msgtype: GPDU,
+                length: (self.header_size() - MIN_HEADER_LENGTH + self.tpdu.len()) as u16,
+                teid: self.teid,
+                sequence_number: self.sequence_number,
+                npdu_number: self.npdu_number,
+                extension_headers,
+            },
+            tpdu: self.tpdu.to_vec(),
+        })
+    }
+
+    // Write the G-PDU header into the headroom in front of the T-PDU which starts at buffer[offset..]
+    // The T-PDU is left in place, returns the offset of the first byte of the encapsulated G-PDU
+
+    pub fn encapsulate(&self, buffer: &mut [u8], offset: usize) -> Result<usize, GTPV1Error> {
+        let header_size = self.header_size();
+        if offset < header_size || offset > buffer.len() {
+            return Err(GTPV1Error::HeaderInvalidLength);
+        }
+        let length = buffer.len() - offset + header_size - MIN_HEADER_LENGTH;
+        if length > u16::MAX as usize {
+            return Err(GTPV1Error::MessageLengthError);
+        }
+        let start = offset - header_size;
+        let header = &mut buffer[start..offset];
+        header[0] = self.construct_flags();
+        header[1] = GPDU;
+        header[2..4].copy_from_slice(&(length as u16).to_be_bytes());
+        header[4..8].copy_from_slice(&self.teid.to_be_bytes());
+        if header_size > MIN_HEADER_LENGTH {
+            header[8..10].copy_from_slice(&self.sequence_number.unwrap_or_default().to_be_bytes());
+            header[10] = self.npdu_number.unwrap_or_default();
+            match self.extension_headers {
+                Some(i) => {
+                    header[11..11 + i.len()].copy_from_slice(i);
+                    header[11 + i.len()] = NO_MORE_EXTENSION_HEADERS;
+                }
+                None => header[11] = NO_MORE_EXTENSION_HEADERS,
+            }
+        }
+        Ok(start)
+    }
+
+    // Strip the G-PDU header without copying, returns the range of the T-PDU within the buffer
+
+    pub fn decapsulate(buffer: &[u8]) -> Result<Range<usize>, GTPV1Error> {
+        let (header_size, end) = decode_gpdu_bounds(buffer)?;
+        Ok(header_size..end)
+    }
+
+    // Struct helper functions
+
+    fn construct_flags(&self) -> u8 {
+        let mut flags: u8 = 0;
+        if self.extension_headers.is_some() {
+            flags = 0x04;
+        }
+        if self.sequence_number.is_some() {
+            flags |= 0x02;
+        }
+        if self.npdu_number.is_some() {
+            flags |= 0x01;
+        }
+        flags | 0x30
+    }
+}
+
+// Validate G-PDU header and return header size and the end of T-PDU
+
+fn decode_gpdu_bounds(buffer: &[u8]) -> Result<(usize, usize), GTPV1Error> {
+    if buffer.len() < MIN_HEADER_LENGTH {
+        return Err(GTPV1Error::HeaderInvalidLength);
+    }
+    if buffer[0] >> 4 != 0x03 {
+        return Err(GTPV1Error::HeaderVersionNotSupported);
+    }
+    if buffer[1] != GPDU {
+        return Err(GTPV1Error::MessageIncorrectMessageType);
+    }
+    let end = u16::from_be_bytes([buffer[2], buffer[3]]) as usize + MIN_HEADER_LENGTH;
+    if end > buffer.len() {
+        return Err(GTPV1Error::MessageLengthError);
+    }
+    if buffer[0] & 0x07 == 0 {
+        return Ok((MIN_HEADER_LENGTH, end));
+    }
+    if end < GPDU_MAX_HEADER_LENGTH {
+        return Err(GTPV1Error::HeaderInvalidLength);
+    }
+    if buffer[0] & 0x04 == 0 {
+        return Ok((GPDU_MAX_HEADER_LENGTH, end));
+    }
+    let mut cursor = GPDU_MAX_HEADER_LENGTH - 1;
+    while buffer[cursor] != NO_MORE_EXTENSION_HEADERS {
+        match buffer.get(cursor + 1) {
+            Some(0) | None => return Err(GTPV1Error::ExtHeaderInvalidLength),
+            Some(i) => cursor += *i as usize * 4,
+        }
+        if cursor >= end {
+            return Err(GTPV1Error::ExtHeaderInvalidLength);
+        }
+    }
+    Ok((cursor + 1, end))
+}
+
+// Borrowed Extension Header, raw contains Extension Header Type, Length and Content
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtensionHeaderRef<'a> {
+    pub raw: &'a [u8],
+}
+
+impl<'a> ExtensionHeaderRef<'a> {
+    pub fn extension_header_type(&self) -> u8 {
+        self.raw[0]
+    }
+
+    pub fn content(&self) -> &'a [u8] {
+        &self.raw[2..]
+    }
+
+    pub fn to_extension_header(&self) -> Result<ExtensionHeader, GTPV1Error> {
+        ExtensionHeader::unmarshal(self.raw)
+    }
+}
+
+pub struct ExtensionHeaderRefIter<'a> {
+    buffer: &'a [u8],
+}
+
+impl<'a> Iterator for ExtensionHeaderRefIter<'a> {
+    type Item = ExtensionHeaderRef<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.len() < 2 || self.buffer[0] == NO_MORE_EXTENSION_HEADERS {
+            return None;
+        }
+        let length = (self.buffer[1] as usize * 4).min(self.buffer.len());
+        if length < 2 {
+            return None;
+        }
+        let (raw, rest) = self.buffer.split_at(length);
+        self.buffer = rest;
+        Some(ExtensionHeaderRef { raw })
+    }
+}
+
+#[test]
+fn test_gpdu_ref_unmarshal() {
+    let encoded: [u8; 13] = [
+        0x32, 0xff, 0x00, 0x05, 0x00, 0x00, 0x00, 0x01, 0x49, 0xca, 0x00, 0x00, 0x01,
+    ];
+    let decoded = GpduRef {
+        teid: 1,
+        sequence_number: Some(18890),
+        npdu_number: None,
+        extension_headers: None,
+        tpdu: &encoded[12..],
+    };
+    assert_eq!(GpduRef::unmarshal(&encoded).unwrap(), decoded);
+}
+
+#[test]
+fn test_gpdu_ref_with_ext_headers_unmarshal() {
+    use crate::gtpv1::gtpu::messages::commons::Messages;
+    let encoded: [u8; 22] = [
+        0x36, 0xff, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0xf6, 0x4e, 0x00, 0x40, 0x01, 0x10, 0x00,
+        0xc0, 0x01, 0x10, 0x00, 0x00, 0xaa, 0xbb,
+    ];
+    let message = GpduRef::unmarshal(&encoded).unwrap();
+    assert_eq!(message.tpdu, &[0xaa, 0xbb]);
+    assert_eq!(message.extension_headers, Some(&encoded[11..19]));
+    let ext_hdrs: Vec<ExtensionHeader> = message
+        .extension_headers()
+        .map(|x| x.to_extension_header().unwrap())
+        .collect();
+    assert_eq!(
+        ext_hdrs,
+        vec![
+            ExtensionHeader::UDPPort(UDPPort {
+                extension_header_type: UDP_PORT,
+                length: UDP_PORT_LENGTH,
+                udp_port: 4096,
+            }),
+            ExtensionHeader::PDCPPDUNumber(PDCPPDUNumber {
+                extension_header_type: PDCP_PDU_NUMBER,
+                length: PDCP_PDU_NUMBER_LENGTH,
+                pdcp_pdu_number: 4096,
+            }),
+        ]
+    );
+    assert_eq!(
+        message.to_gpdu().unwrap(),
+        Gpdu::unmarshal(&encoded).unwrap()
+    );
+}
+
+#[test]
+fn test_gpdu_ref_invalid_ext_header_unmarshal() {
+    let encoded: [u8; 16] = [
+        0x36, 0xff, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0xf6, 0x4e, 0x00, 0x40, 0x00, 0x10, 0x00,
+        0x00,
+    ];
+    assert_eq!(
+        GpduRef::unmarshal(&encoded),
+        Err(GTPV1Error::ExtHeaderInvalidLength)
+    );
+}
+
+#[test]
+fn test_gpdu_ref_missing_tpdu_unmarshal() {
+    let encoded: [u8; 12] = [
+        0x32, 0xff, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x49, 0xca, 0x00, 0x00,
+    ];
+    assert_eq!(
+        GpduRef::unmarshal(&encoded),
+        Err(GTPV1Error::MessageLengthError)
+    );
+}
+
+#[test]
+fn test_gpdu_ref_encapsulate() {
+    let encoded: [u8; 22] = [
+        0x36, 0xff, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0xf6, 0x4e, 0x00, 0x40, 0x01, 0x10, 0x00,
+        0xc0, 0x01, 0x10, 0x00, 0x00, 0xaa, 0xbb,
+    ];
+    let ext_hdrs: [u8; 8] = [0x40, 0x01, 0x10, 0x00, 0xc0, 0x01, 0x10, 0x00];
+    let mut buffer: [u8; 26] = [0; 26];
+    buffer[24..].copy_from_slice(&[0xaa, 0xbb]);
+    let template = GpduRef {
+        teid: 0,
+        sequence_number: Some(0xf64e),
+        npdu_number: None,
+        extension_headers: Some(&ext_hdrs),
+        tpdu: &[],
+    };
+    let start = template.encapsulate(&mut buffer, 24).unwrap();
+    assert_eq!(start, 4);
+    assert_eq!(buffer[start..], encoded);
+}
+
+#[test]
+fn test_gpdu_ref_encapsulate_no_headroom() {
+    let mut buffer: [u8; 10] = [0; 10];
+    let template = GpduRef {
+        teid: 1,
+        sequence_number: None,
+        npdu_number: None,
+        extension_headers: None,
+        tpdu: &[],
+    };
+    assert_eq!(
+        template.encapsulate(&mut buffer, 7),
+        Err(GTPV1Error::HeaderInvalidLength)
+    );
+    assert_eq!(template.encapsulate(&mut buffer, 8), Ok(0));
+    assert_eq!(
+        buffer[..8],
+        [0x30, 0xff, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01]
+    );
+}
+
+#[test]
+fn test_gpdu_ref_decapsulate() {
+    let encoded: [u8; 22] = [
+        0x36, 0xff, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0xf6, 0x4e, 0x00, 0x40, 0x01, 0x10, 0x00,
+        0xc0, 0x01, 0x10, 0x00, 0x00, 0xaa, 0xbb,
+    ];
+    assert_eq!(GpduRef::decapsulate(&encoded), Ok(20..22));
+}
@@ -0,0 +1,172 @@
+use crate::gtpv1::errors::*;
+use crate::gtpv1::gtpu::header::*;
+use crate::gtpv1::gtpu::messages::*;
+use crate::gtpv1::utils::*;
+
+// According to 3GPP TS 29.281 V16.0.0 (2019-12)
+
+// Definition of GTPv1-U Message enum covering all implemented GTPv1-U messages
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GTPV1UMessage {
+    EchoRequest(EchoRequest),
+    EchoResponse(EchoResponse),
+    ErrorIndication(ErrorIndication),
+    SupportedExtensionHeadersNotification(SupportedExtensionHeadersNotification),
+    EndMarker(EndMarker),
+    Gpdu(Gpdu),
+    Unknown { header: Gtpv1Header, body: Vec<u8> },
+}
+
+impl GTPV1UMessage {
+    // Decode any GTPv1-U message based on the message type in the header
+
+    pub fn parse(buffer: &[u8]) -> Result<Self, GTPV1Error> {
+        let header = Gtpv1Header::unmarshal(buffer)?;
+        if header.length as usize + MIN_HEADER_LENGTH < header.get_header_size() {
+            return Err(GTPV1Error::MessageLengthError);
+        }
+        match header.msgtype {
+            ECHO_REQUEST => EchoRequest::unmarshal(buffer).map(GTPV1UMessage::EchoRequest),
+            ECHO_RESPONSE => EchoResponse::unmarshal(buffer).map(GTPV1UMessage::EchoResponse),
+            ERROR_INDICATION => {
+                ErrorIndication::unmarshal(buffer).map(GTPV1UMessage::ErrorIndication)
+            }
+            SUPPORTED_EXTENSION_HEADERS_NOTIFICATION => {
+                SupportedExtensionHeadersNotification::unmarshal(buffer)
+                    .map(GTPV1UMessage::SupportedExtensionHeadersNotification)
+            }
+            END_MARKER => EndMarker::unmarshal(buffer).map(GTPV1UMessage::EndMarker),
+            GPDU => Gpdu::unmarshal(buffer).map(GTPV1UMessage::Gpdu),
+            _ => {
+                let offset = header.length as usize + MIN_HEADER_LENGTH;
+                if offset <= buffer.len() {
+                    let body = buffer[header.get_header_size()..offset].to_vec();
+                    Ok(GTPV1UMessage::Unknown { header, body })
+                } else {
+                    Err(GTPV1Error::MessageLengthError)
+                }
+            }
+        }
+    }
+
+    pub fn marshal(self, buffer: &mut Vec<u8>) {
+        match self {
+            GTPV1UMessage::EchoRequest(i) => i.marshal(buffer),
+            GTPV1UMessage::EchoResponse(i) => i.marshal(buffer),
+            GTPV1UMessage::ErrorIndication(i) => i.marshal(buffer),
+            GTPV1UMessage::SupportedExtensionHeadersNotification(i) => i.marshal(buffer),
+            GTPV1UMessage::EndMarker(i) => i.marshal(buffer),
+            GTPV1UMessage::Gpdu(i) => i.marshal(buffer),
+            GTPV1UMessage::Unknown { header, body } => {
+                header.marshal(buffer);
+                buffer.extend_from_slice(&body);
+                set_length(buffer);
+            }
+        }
+    }
+
+    pub fn header(&self) -> &Gtpv1Header {
+        match self {
+            GTPV1UMessage::EchoRequest(i) => &i.header,
+            GTPV1UMessage::EchoResponse(i) => &i.header,
+            GTPV1UMessage::ErrorIndication(i) => &i.header,
+            GTPV1UMessage::SupportedExtensionHeadersNotification(i) => &i.header,
+            GTPV1UMessage::EndMarker(i) => &i.header,
+            GTPV1UMessage::Gpdu(i) => &i.header,
+            GTPV1UMessage::Unknown { header, .. } => header,
+        }
+    }
+
+    pub fn msg_type(&self) -> u8 {
+        self.header().msgtype
+    }
+}
+
+#[test]
+fn test_gtpv1u_message_gpdu_parse() {
+    let encoded: [u8; 13] = [
+        0x32, 0xff, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x49, 0xca, 0x00, 0x00, 0x01,
+    ];
+    let message = GTPV1UMessage::parse(&encoded).unwrap();
+    assert_eq!(
+        message,
+        GTPV1UMessage::Gpdu(Gpdu::unmarshal(&encoded).unwrap())
+    );
+    assert_eq!(message.msg_type(), GPDU);
+}
+
+#[test]
+fn test_gtpv1u_message_end_marker_parse() {
+    let encoded: [u8; 12] = [
+        0x32, 0xfe, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02, 0x49, 0xca, 0x00, 0x00,
+    ];
+    let message = GTPV1UMessage::parse(&encoded).unwrap();
+    assert_eq!(
+        message,
+        GTPV1UMessage::EndMarker(EndMarker::unmarshal(&encoded).unwrap())
+    );
+    assert_eq!(message.header().teid, 2);
+}
+
+#[test]
+fn test_gtpv1u_message_unknown_parse() {
+    let encoded: [u8; 14] = [
+        0x32, 0x7f, 0x00, 0x06, 0x00, 0x00, 0x00, 0x02, 0x49, 0xca, 0x00, 0x00, 0xaa, 0xbb,
+    ];
+    let decoded = GTPV1UMessage::Unknown {
+        header: Gtpv1Header {
+            msgtype: 0x7f,
+            length: 6,
+            teid: 2,
+            sequence_number: Some(18890),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        body: vec![0xaa, 0xbb],
+    };
+    assert_eq!(GTPV1UMessage::parse(&encoded).unwrap(), decoded);
+}
+
+#[test]
+fn test_gtpv1u_message_unknown_marshal() {
+    let encoded: [u8; 14] = [
+        0x32, 0x7f, 0x00, 0x06, 0x00, 0x00, 0x00, 0x02, 0x49, 0xca, 0x00, 0x00, 0xaa, 0xbb,
+    ];
+    let decoded = GTPV1UMessage::Unknown {
+        header: Gtpv1Header {
+            msgtype: 0x7f,
+            length: 6,
+            teid: 2,
+            sequence_number: Some(18890),
+            npdu_number: None,
+            extension_headers: None,
+        },
+        body: vec![0xaa, 0xbb],
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn test_gtpv1u_message_unknown_invalid_length_parse() {
+    let encoded: [u8; 13] = [
+        0x32, 0x7f, 0x00, 0x06, 0x00, 0x00, 0x00, 0x02, 0x49, 0xca, 0x00, 0x00, 0xaa,
+    ];
+    assert_eq!(
+        GTPV1UMessage::parse(&encoded),
+        Err(GTPV1Error::MessageLengthError)
+    );
+}
+
+#[test]
+fn test_gtpv1u_message_length_shorter_than_header_parse() {
+    let encoded: [u8; 12] = [
+        0x32, 0x5c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x49, 0xca, 0x00, 0x00,
+    ];
+    assert_eq!(
+        GTPV1UMessage::parse(&encoded),
+        Err(GTPV1Error::MessageLengthError)
+    );
+}
@@ -1,6 +1,6 @@
 pub use {
-    commons::*, echoreq::*, echoresp::*, endmarker::*, errorindication::*, gpdu::*, ies::*,
-    supportedexthdrnotification::*,
+    commons::*, echoreq::*, echoresp::*, endmarker::*, errorindication::*, gpdu::*, gpduref::*,
+    ies::*, message::*, supportedexthdrnotification::*,
 };
 mod commons;
 mod echoreq;
@@ -8,5 +8,7 @@ mod echoresp;
 mod endmarker;
 mod errorindication;
 mod gpdu;
+mod gpduref;
 mod ies;
+mod message;
 mod supportedexthdrnotification;
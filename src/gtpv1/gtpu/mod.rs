@@ -1,4 +1,5 @@
-pub use {extensionheaders::*, header::*, messages::*};
+pub use {extensionheaders::*, header::*, messages::*, paths::*};
 mod extensionheaders;
 mod header;
 mod messages;
+mod paths;
@@ -0,0 +1,349 @@
+// GTP-U path management - according to 3GPP TS 29.281 V16.0.0 (2019-12), clauses 4.4.2 and 7.2
+
+use crate::{
+    commons::{DEFAULT_ECHO_INTERVAL, DEFAULT_N3_REQUESTS, DEFAULT_T3_RESPONSE},
+    gtpv1::gtpu::{
+        header::*,
+        messages::{EchoRequest, EchoResponse, GTPV1UMessage, Messages},
+    },
+};
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+
+pub const GTPU_PORT: u16 = 2152;
+
+// Echo Request period and retransmission timers
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathSupervisorConfig {
+    pub echo_interval: Duration,
+    pub t3_response: Duration,
+    pub n3_requests: u8,
+}
+
+impl Default for PathSupervisorConfig {
+    fn default() -> Self {
+        PathSupervisorConfig {
+            echo_interval: DEFAULT_ECHO_INTERVAL,
+            t3_response: DEFAULT_T3_RESPONSE,
+            n3_requests: DEFAULT_N3_REQUESTS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathState {
+    Unknown,
+    Up,
+    Down,
+}
+
+// Event returned when the state of the path to a peer changes
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathStateChange {
+    pub peer: SocketAddr,
+    pub state: PathState,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PendingEcho {
+    sqn: u16,
+    buffer: Vec<u8>,
+    retransmissions: u8,
+    deadline: Instant,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PeerPath {
+    state: PathState,
+    next_echo: Instant,
+    pending: Option<PendingEcho>,
+}
+
+// GTP-U path supervisor.
+// It does not own a socket: the datagrams received by the GTP-U endpoint are passed to on_message()
+// and the datagrams returned by on_message() and run_timers() are to be sent by the endpoint,
+// along with the path state changes for the tunnel handling.
+
+#[derive(Debug, Clone, Default)]
+pub struct PathSupervisor {
+    pub config: PathSupervisorConfig,
+    peers: HashMap<SocketAddr, PeerPath>,
+    sqn: u16,
+}
+
+impl PathSupervisor {
+    pub fn new(config: PathSupervisorConfig) -> Self {
+        PathSupervisor {
+            config,
+            ..PathSupervisor::default()
+        }
+    }
+
+    // Supervise the path to a peer, the first Echo Request is sent on the next run of the timers
+
+    pub fn add_peer(&mut self, peer: SocketAddr, now: Instant) {
+        self.peers.entry(peer).or_insert(PeerPath {
+            state: PathState::Unknown,
+            next_echo: now,
+            pending: None,
+        });
+    }
+
+    pub fn remove_peer(&mut self, peer: SocketAddr) {
+        self.peers.remove(&peer);
+    }
+
+    pub fn state(&self, peer: SocketAddr) -> Option<PathState> {
+        self.peers.get(&peer).map(|x| x.state)
+    }
+
+    // Handle a message received from a peer. Returns the Echo Response to send, with its destination,
+    // when the message is an Echo Request, and the path state change triggered by an Echo Response
+
+    pub fn on_message(
+        &mut self,
+        peer: SocketAddr,
+        message: &GTPV1UMessage,
+    ) -> (Option<(SocketAddr, Vec<u8>)>, Option<PathStateChange>) {
+        match message {
+            GTPV1UMessage::EchoRequest(i) => {
+                let mut buffer: Vec<u8> = vec![];
+                echo_response(i).marshal(&mut buffer);
+                (Some((echo_response_destination(peer, i), buffer)), None)
+            }
+            GTPV1UMessage::EchoResponse(i) => {
+                let answered = self.peers.get_mut(&peer).is_some_and(|x| match &x.pending {
+                    Some(j) if Some(j.sqn) == i.header.sequence_number => {
+                        x.pending = None;
+                        true
+                    }
+                    _ => false,
+                });
+                match answered {
+                    true => (None, self.set_state(peer, PathState::Up)),
+                    false => (None, None),
+                }
+            }
+            _ => (None, None),
+        }
+    }
+
+    // Run the timers: returns the Echo Requests to send or retransmit and the paths going down.
+    // A path is down when an Echo Request is left unanswered after N3-REQUESTS retransmissions.
+
+    pub fn run_timers(
+        &mut self,
+        now: Instant,
+    ) -> (Vec<(SocketAddr, Vec<u8>)>, Vec<PathStateChange>) {
+        let mut datagrams: Vec<(SocketAddr, Vec<u8>)> = vec![];
+        let mut down: Vec<SocketAddr> = vec![];
+        for (peer, path) in self.peers.iter_mut() {
+            match path.pending.as_mut() {
+                Some(i) if i.deadline <= now => {
+                    if i.retransmissions < self.config.n3_requests {
+                        i.retransmissions += 1;
+                        i.deadline = now + self.config.t3_response;
+                        datagrams.push((*peer, i.buffer.clone()));
+                    } else {
+                        path.pending = None;
+                        down.push(*peer);
+                    }
+                }
+                Some(_) => (),
+                None if path.next_echo <= now => {
+                    self.sqn = self.sqn.wrapping_add(1);
+                    let mut buffer: Vec<u8> = vec![];
+                    echo_request(self.sqn).marshal(&mut buffer);
+                    datagrams.push((*peer, buffer.clone()));
+                    path.next_echo = now + self.config.echo_interval;
+                    path.pending = Some(PendingEcho {
+                        sqn: self.sqn,
+                        buffer,
+                        retransmissions: 0,
+                        deadline: now + self.config.t3_response,
+                    });
+                }
+                None => (),
+            }
+        }
+        let changes = down
+            .into_iter()
+            .filter_map(|x| self.set_state(x, PathState::Down))
+            .collect();
+        (datagrams, changes)
+    }
+
+    // Earliest instant at which the timers need to run
+
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.peers
+            .values()
+            .map(|x| x.pending.as_ref().map_or(x.next_echo, |y| y.deadline))
+            .min()
+    }
+
+    fn set_state(&mut self, peer: SocketAddr, state: PathState) -> Option<PathStateChange> {
+        let path = match self.peers.get_mut(&peer) {
+            Some(i) if i.state != state => i,
+            _ => return None,
+        };
+        path.state = state;
+        Some(PathStateChange { peer, state })
+    }
+}
+
+pub fn echo_request(sqn: u16) -> EchoRequest {
+    let mut message = EchoRequest::default();
+    message.header.sequence_number = Some(sqn);
+    message
+}
+
+// Echo Response to an Echo Request, the sequence number is copied from the request
+// and the Restart Counter is set to zero
+
+pub fn echo_response(request: &EchoRequest) -> EchoResponse {
+    let mut message = EchoResponse::default();
+    message.header.sequence_number = request.header.sequence_number;
+    message
+}
+
+// The Echo Response is sent back to the source of the Echo Request,
+// or to the port given by the UDP Port extension header of the request if any
+
+pub fn echo_response_destination(peer: SocketAddr, request: &EchoRequest) -> SocketAddr {
+    let port = request
+        .header
+        .extension_headers
+        .iter()
+        .flatten()
+        .find_map(|x| match x {
+            ExtensionHeader::UDPPort(i) => Some(i.udp_port),
+            _ => None,
+        });
+    match port {
+        Some(i) => SocketAddr::new(peer.ip(), i),
+        None => peer,
+    }
+}
+
+#[cfg(test)]
+fn test_peer() -> SocketAddr {
+    "127.0.0.1:2152".parse().unwrap()
+}
+
+#[cfg(test)]
+fn test_config() -> PathSupervisorConfig {
+    PathSupervisorConfig {
+        echo_interval: Duration::from_millis(1000),
+        t3_response: Duration::from_millis(100),
+        n3_requests: 1,
+    }
+}
+
+#[test]
+fn path_supervisor_echo_responder_test() {
+    use crate::gtpv1::gtpu::extensionheaders::UDPPort;
+    let mut paths = PathSupervisor::default();
+    let mut request = echo_request(0x1234);
+    assert_eq!(
+        paths.on_message(test_peer(), &GTPV1UMessage::EchoRequest(request.clone())),
+        (
+            Some((
+                test_peer(),
+                vec![
+                    0x32, 0x02, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x12, 0x34, 0x00, 0x00, 0x0e,
+                    0x00
+                ]
+            )),
+            None
+        )
+    );
+    request.header.extension_headers = Some(vec![ExtensionHeader::UDPPort(UDPPort {
+        udp_port: 40000,
+        ..UDPPort::default()
+    })]);
+    let (destination, _) = paths
+        .on_message(test_peer(), &GTPV1UMessage::EchoRequest(request))
+        .0
+        .unwrap();
+    assert_eq!(destination, "127.0.0.1:40000".parse().unwrap());
+}
+
+#[test]
+fn path_supervisor_path_up_test() {
+    let mut paths = PathSupervisor::new(test_config());
+    let start = Instant::now();
+    paths.add_peer(test_peer(), start);
+    assert_eq!(paths.state(test_peer()), Some(PathState::Unknown));
+    let (datagrams, changes) = paths.run_timers(start);
+    assert!(changes.is_empty());
+    assert_eq!(datagrams.len(), 1);
+    let request = match GTPV1UMessage::parse(&datagrams[0].1).unwrap() {
+        GTPV1UMessage::EchoRequest(i) => i,
+        i => panic!("Unexpected message {:?}", i),
+    };
+    assert_eq!(
+        paths.next_deadline(),
+        Some(start + test_config().t3_response)
+    );
+    let response = GTPV1UMessage::EchoResponse(echo_response(&request));
+    assert_eq!(
+        paths.on_message(test_peer(), &response),
+        (
+            None,
+            Some(PathStateChange {
+                peer: test_peer(),
+                state: PathState::Up
+            })
+        )
+    );
+    assert_eq!(paths.state(test_peer()), Some(PathState::Up));
+    // Duplicated response does not trigger another event
+    assert_eq!(paths.on_message(test_peer(), &response), (None, None));
+    assert_eq!(
+        paths.next_deadline(),
+        Some(start + test_config().echo_interval)
+    );
+}
+
+#[test]
+fn path_supervisor_path_down_test() {
+    let mut paths = PathSupervisor::new(test_config());
+    let start = Instant::now();
+    paths.add_peer(test_peer(), start);
+    let (first, _) = paths.run_timers(start);
+    assert_eq!(
+        paths.run_timers(start + Duration::from_millis(50)),
+        (vec![], vec![])
+    );
+    let (retransmitted, _) = paths.run_timers(start + Duration::from_millis(100));
+    assert_eq!(first, retransmitted);
+    assert_eq!(
+        paths.run_timers(start + Duration::from_millis(200)),
+        (
+            vec![],
+            vec![PathStateChange {
+                peer: test_peer(),
+                state: PathState::Down
+            }]
+        )
+    );
+    assert_eq!(paths.state(test_peer()), Some(PathState::Down));
+    // Path keeps being echoed and comes back up on the next answered Echo Request
+    let (datagrams, _) = paths.run_timers(start + Duration::from_millis(1000));
+    let request = match GTPV1UMessage::parse(&datagrams[0].1).unwrap() {
+        GTPV1UMessage::EchoRequest(i) => i,
+        i => panic!("Unexpected message {:?}", i),
+    };
+    assert_eq!(request.header.sequence_number, Some(2));
+    let (_, change) = paths.on_message(
+        test_peer(),
+        &GTPV1UMessage::EchoResponse(echo_response(&request)),
+    );
+    assert_eq!(change.unwrap().state, PathState::Up);
+}
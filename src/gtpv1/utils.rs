@@ -1,5 +1,7 @@
 // Common utils
 
+use crate::gtpv1::errors::GTPV1Error;
+
 // IETF Protocol Numbers
 
 pub const IPV4: u8 = 0x21;
@@ -19,7 +21,7 @@ pub fn tbcd_encode(number: &str) -> Vec<u8> {
         .flat_map(|c| c.to_digit(10))
         .map(|x| x as u8)
         .collect();
-    if chr.len() % 2 != 0 {
+    if !chr.len().is_multiple_of(2) {
         chr.push(0x0f);
     }
     let tbcd: Vec<u8> = chr
@@ -107,7 +109,7 @@ fn mcc_mnc_encode_test() {
 
     // Forced 3-digit MNC (MCC=310, MNC=012)
     let test_mnc_012: u16 = 12;
-    let encoded_number_310_012: [u8; 3] = [0x13, 0x00, 0x21];
+    let encoded_number_310_012: [u8; 3] = [0x13, 0x20, 0x10];
     assert_eq!(
         mcc_mnc_encode(test_mcc_310, test_mnc_012, true),
         encoded_number_310_012
@@ -171,7 +173,7 @@ fn mcc_mnc_decode_test() {
 
     // Forced 3-digit MNC (MCC=310, MNC=012)
     let test_mnc_012: u16 = 12;
-    let encoded_number_310_012: [u8; 3] = [0x13, 0x00, 0x21];
+    let encoded_number_310_012: [u8; 3] = [0x13, 0x20, 0x10];
     assert_eq!(
         mcc_mnc_decode(&encoded_number_310_012),
         (test_mcc_310, test_mnc_012, true)
@@ -193,6 +195,42 @@ pub fn to_digits<T: ToString>(i: T) -> Vec<u8> {
     result
 }
 
+// Read big-endian fields at the cursor position, None if buffer is too short
+
+pub fn read_u24(buffer: &[u8], cursor: usize) -> Option<u32> {
+    buffer
+        .get(cursor..cursor + 3)
+        .map(|i| u32::from_be_bytes([0x00, i[0], i[1], i[2]]))
+}
+
+pub fn read_u32(buffer: &[u8], cursor: usize) -> Option<u32> {
+    buffer
+        .get(cursor..cursor + 4)
+        .map(|i| u32::from_be_bytes([i[0], i[1], i[2], i[3]]))
+}
+
+pub fn read_u64(buffer: &[u8], cursor: usize) -> Option<u64> {
+    buffer
+        .get(cursor..cursor + 8)
+        .map(|i| u64::from_be_bytes([i[0], i[1], i[2], i[3], i[4], i[5], i[6], i[7]]))
+}
+
+// Take len octets at the cursor position and advance the cursor past them
+
+pub fn read_slice<'a>(
+    buffer: &'a [u8],
+    cursor: &mut usize,
+    len: usize,
+) -> Result<&'a [u8], GTPV1Error> {
+    match buffer.get(*cursor..*cursor + len) {
+        Some(i) => {
+            *cursor += len;
+            Ok(i)
+        }
+        None => Err(GTPV1Error::IEInvalidLength),
+    }
+}
+
 // Set the right size of GTP message based on buffer size
 
 pub fn set_length(buffer: &mut [u8]) {
@@ -1,6 +1,7 @@
+use crate::commons::{DecodeError, DecodeErrorReason};
 use std::fmt::Display;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 
 pub enum GTPV2Error {
     // GTPv2 Header Errors
@@ -12,17 +13,49 @@ pub enum GTPV2Error {
     // GTPv2 IE Errors
     IEInvalidLength(u8),
     IEIncorrect(u8),
+    IEInvalidSpareBits(u8),
     // GTPv2 Message Errors
     MessageInvalidLength(u8),
     MessageNotSupported,
     MessageMandatoryIEMissing(u8),
+    MessageMandatoryIEIncorrect(u8),
     MessageOptionalIEIncorrect(u8),
+    MessageUnexpectedIE(u8),
     MessageInvalidMessageFormat,
     MessageIncorrectMessageType,
 }
 
 impl std::error::Error for GTPV2Error {}
 
+// GTPv2 decoding error with the location of the faulty IE
+
+pub type GTPV2DecodeError = DecodeError<GTPV2Error>;
+
+impl GTPV2Error {
+    pub fn reason(&self) -> DecodeErrorReason {
+        match self {
+            GTPV2Error::HeaderInvalidLength | GTPV2Error::MessageInvalidLength(_) => {
+                DecodeErrorReason::Truncated
+            }
+            GTPV2Error::IEInvalidLength(_) => DecodeErrorReason::InvalidLength,
+            GTPV2Error::IEInvalidSpareBits(_) => DecodeErrorReason::InvalidSpareBits,
+            GTPV2Error::MessageMandatoryIEMissing(_) => DecodeErrorReason::MandatoryIEMissing,
+            _ => DecodeErrorReason::InvalidValue,
+        }
+    }
+
+    // IE sub-structures do not know the type of the IE carrying them and report type 0,
+    // which is replaced by the type of the enclosing IE
+
+    pub fn with_ie_type(self, t: u8) -> Self {
+        match self {
+            GTPV2Error::IEInvalidLength(0) => GTPV2Error::IEInvalidLength(t),
+            GTPV2Error::IEIncorrect(0) => GTPV2Error::IEIncorrect(t),
+            _ => self,
+        }
+    }
+}
+
 impl Display for GTPV2Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -38,15 +71,20 @@ impl Display for GTPV2Error {
             // GTPv2 IE Errors
             GTPV2Error::IEInvalidLength(i) => write!(f, "Invalid IE type {} length", i),
             GTPV2Error::IEIncorrect(i) => write!(f, "Incorrect IE type {}", i),
+            GTPV2Error::IEInvalidSpareBits(i) => write!(f, "Spare bits set in IE type {}", i),
             // GTPv2 Message Errors
             GTPV2Error::MessageInvalidLength(i) => write!(f, "Message invalid length {}", i),
             GTPV2Error::MessageMandatoryIEMissing(i) => {
                 write!(f, "Mandatory IE of type {} missing", i)
             }
+            GTPV2Error::MessageMandatoryIEIncorrect(i) => {
+                write!(f, "Mandatory IE of type {} incorrect", i)
+            }
             GTPV2Error::MessageNotSupported => write!(f, "Message not supported"),
             GTPV2Error::MessageOptionalIEIncorrect(i) => {
                 write!(f, "Optional IE of type {} incorrect", i)
             }
+            GTPV2Error::MessageUnexpectedIE(i) => write!(f, "Unexpected IE of type {}", i),
             GTPV2Error::MessageInvalidMessageFormat => write!(f, "Invalid Mesage format"),
             GTPV2Error::MessageIncorrectMessageType => write!(f, "Incorrect Message type"),
         }
@@ -243,10 +243,8 @@ impl Messages for BearerResourceCommand {
                         self.spi = Some(j.clone())
                     };
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 2 {
-                        self.overload_info.push(j.clone())
-                    };
+                InformationElement::OverloadControlInfo(j) if j.ins < 2 => {
+                    self.overload_info.push(j.clone())
                 }
                 InformationElement::Fcontainer(j) => {
                     if let (0, true) = (j.ins, self.nbifom.is_none()) {
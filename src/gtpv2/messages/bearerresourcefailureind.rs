@@ -134,10 +134,8 @@ impl Messages for BearerResourceFailureInd {
                         self.indication = Some(j.clone())
                     };
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 2 {
-                        self.overload_info.push(j.clone())
-                    };
+                InformationElement::OverloadControlInfo(j) if j.ins < 2 => {
+                    self.overload_info.push(j.clone())
                 }
                 InformationElement::Recovery(j) => {
                     if let (0, true) = (j.ins, self.recovery.is_none()) {
@@ -1,4 +1,10 @@
-use crate::gtpv2::{errors::*, messages::ies::*};
+use crate::commons::{DecodeErrorReason, IePathElement};
+use crate::gtpv2::{
+    errors::*,
+    header::*,
+    messages::{ies::*, ECHO_REQUEST, ECHO_RESPONSE, VERSION_NOT_SUPPORTED},
+    utils::*,
+};
 
 // Common traits of GTPv2 Messages
 
@@ -9,6 +15,15 @@ pub trait Messages {
         Self: Sized;
     fn tovec(&self) -> Vec<InformationElement>;
     fn fromvec(&mut self, elements: Vec<InformationElement>) -> Result<bool, GTPV2Error>;
+    fn unmarshal_with_options(
+        buffer: &[u8],
+        options: &DecodeOptions,
+    ) -> Result<Decoded<Self>, GTPV2Error>
+    where
+        Self: Sized,
+    {
+        decode_with_options(buffer, options)
+    }
     //fn len (&self) -> usize;
 }
 
@@ -29,3 +44,584 @@ pub fn vec_by_ins<'a, T: IEs>(ins: u8, vec: &'a [T]) -> Vec<&'a T> {
         .filter(|x| (*x).get_ins() == ins)
         .collect::<Vec<&'a T>>()
 }
+
+// Grouped IEs whose embedded IEs are walked when locating a decoding error
+
+const GROUPED_IES: [u8; 9] = [
+    BEARER_CTX,
+    PDN_CONN,
+    OVERLOAD_CNTRL,
+    LOAD_CNTRL,
+    REMOTE_UE_CTX,
+    SCEF_PDN_CONN,
+    V2X_INFO,
+    PC5_QOS_PARAM,
+    PGW_CHNG_INFO,
+];
+
+// Spare bits of IE values as (IE type, octet of the value, mask of the spare bits), checked by the strict profile
+// along with the spare bits of the instance octet of every IE header
+
+const SPARE_BITS: [(u8, usize, u8); 8] = [
+    (CAUSE, 1, 0xf8),
+    (EBI, 0, 0xf0),
+    (BEARERQOS, 0, 0x82),
+    (PAA, 0, 0xf8),
+    (PDNTYPE, 0, 0xf8),
+    (UETIMEZONE, 1, 0xfc),
+    (SELECTION_MODE, 0, 0xfc),
+    (ARP, 0, 0x82),
+];
+
+fn spare_bits_set(ie: &[u8]) -> bool {
+    ie[3] & 0xf0 != 0
+        || SPARE_BITS.iter().any(|(t, octet, mask)| {
+            *t == ie[0] && ie.get(MIN_IE_SIZE + octet).is_some_and(|x| x & mask != 0)
+        })
+}
+
+// Locate the first IE with spare bits set, descending into grouped IEs.
+// Returns the offset of the IE, the path to it being left in path
+
+fn locate_spare_bits(
+    buffer: &[u8],
+    mut cursor: usize,
+    path: &mut Vec<IePathElement>,
+) -> Option<usize> {
+    let mut seen: Vec<(u8, u8)> = vec![];
+    while cursor + MIN_IE_SIZE <= buffer.len() {
+        let (t, ins) = (buffer[cursor], buffer[cursor + 3] & 0x0f);
+        let end = cursor
+            + MIN_IE_SIZE
+            + u16::from_be_bytes([buffer[cursor + 1], buffer[cursor + 2]]) as usize;
+        if end > buffer.len() {
+            break;
+        }
+        let index = seen.iter().filter(|x| **x == (t, ins)).count();
+        seen.push((t, ins));
+        path.push(IePathElement { t, ins, index });
+        if spare_bits_set(&buffer[cursor..end]) {
+            return Some(cursor);
+        }
+        if GROUPED_IES.contains(&t) {
+            if let Some(i) = locate_spare_bits(&buffer[..end], cursor + MIN_IE_SIZE, path) {
+                return Some(i);
+            }
+        }
+        path.pop();
+        cursor = end;
+    }
+    None
+}
+
+// Locate the faulty IE of a message which failed to decode with the given error.
+// The message is walked IE by IE, descending into grouped IEs, so the error is reported with
+// the byte offset and path of the first IE which does not decode, or with spare bits set for
+// an IEInvalidSpareBits error of the strict profile
+
+pub fn locate_decode_error(buffer: &[u8], error: GTPV2Error) -> GTPV2DecodeError {
+    let mut data = GTPV2DecodeError {
+        msgtype: buffer.get(1).copied().unwrap_or_default(),
+        offset: 0,
+        path: vec![],
+        reason: error.reason(),
+        error,
+    };
+    let header = match Gtpv2Header::unmarshal(buffer) {
+        Ok(i) => i,
+        Err(_) => return data,
+    };
+    let end = header.length as usize + MANDATORY_HDR_LENGTH;
+    if end > buffer.len() {
+        data.offset = buffer.len();
+        data.reason = DecodeErrorReason::Truncated;
+        return data;
+    }
+    let start = match header.teid {
+        Some(_) => MAX_HEADER_LENGTH,
+        None => MIN_HEADER_LENGTH,
+    };
+    if let GTPV2Error::IEInvalidSpareBits(_) = data.error {
+        data.offset = locate_spare_bits(&buffer[..end], start, &mut data.path).unwrap_or(end);
+        return data;
+    }
+    if !locate_ie(&buffer[..end], start, &mut data) {
+        data.offset = end;
+    }
+    data
+}
+
+fn locate_ie(buffer: &[u8], mut cursor: usize, data: &mut GTPV2DecodeError) -> bool {
+    let mut seen: Vec<(u8, u8)> = vec![];
+    while cursor < buffer.len() {
+        let (t, ins) = (
+            buffer[cursor],
+            buffer.get(cursor + 3).map_or(0, |x| x & 0x0f),
+        );
+        let index = seen.iter().filter(|x| **x == (t, ins)).count();
+        seen.push((t, ins));
+        data.path.push(IePathElement { t, ins, index });
+        data.offset = cursor;
+        if buffer.len() < cursor + MIN_IE_SIZE {
+            data.reason = DecodeErrorReason::Truncated;
+            data.error = GTPV2Error::IEInvalidLength(t);
+            return true;
+        }
+        let end = cursor
+            + MIN_IE_SIZE
+            + u16::from_be_bytes([buffer[cursor + 1], buffer[cursor + 2]]) as usize;
+        if end > buffer.len() {
+            data.reason = DecodeErrorReason::Truncated;
+            data.error = GTPV2Error::IEInvalidLength(t);
+            return true;
+        }
+        if let Err(i) = InformationElement::decoder(&buffer[cursor..end]) {
+            if GROUPED_IES.contains(&t) && locate_ie(&buffer[..end], cursor + MIN_IE_SIZE, data) {
+                return true;
+            }
+            data.offset = cursor;
+            data.reason = i.reason();
+            data.error = i;
+            return true;
+        }
+        data.path.pop();
+        cursor = end;
+    }
+    false
+}
+
+// Decoding profiles selecting how IEs deviating from the message definition are handled
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecodeProfile {
+    // Reject the message on any deviation from its definition other than unknown IEs:
+    // on top of the errors of the lenient profile, an incorrect optional IE is rejected with
+    // MessageOptionalIEIncorrect, an unexpected or repeated IE with MessageUnexpectedIE, and an IE
+    // with spare bits set, embedded IEs included, with IEInvalidSpareBits.
+    // TS 29.274 clause 7.7 has these errors ignored by a receiver, see errorresponse::is_ignored()
+    Strict,
+    // Ignore the deviating IEs and report them. The message is only rejected when a mandatory IE
+    // is missing (MessageMandatoryIEMissing) or was received but does not decode (MessageMandatoryIEIncorrect)
+    #[default]
+    Lenient,
+    // As lenient, but keep the ignored IEs so that re-marshalling yields the original message
+    Transparent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DecodeOptions {
+    pub profile: DecodeProfile,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviationKind {
+    Invalid(GTPV2Error), // IE failed to decode
+    Repeated,            // IE type and instance already received
+    Unexpected,          // IE type or instance not defined for the message
+    Unknown,             // IE type not known
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deviation {
+    pub t: u8,
+    pub ins: u8,
+    pub offset: usize,
+    pub kind: DeviationKind,
+}
+
+// Decoded message with the deviations found and, for the transparent profile, the ignored IEs
+// along with their position among the IEs of the message, and the received encoding of the kept IEs
+// which do not marshal back to it (e.g. grouped IEs carrying unknown IEs)
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decoded<T> {
+    pub message: T,
+    pub deviations: Vec<Deviation>,
+    pub extra: Vec<(usize, InformationElement)>,
+    pub originals: Vec<(InformationElement, InformationElement)>,
+}
+
+impl<T: Messages> Decoded<T> {
+    // Marshal the message with the ignored IEs put back at their original position and the unchanged IEs
+    // in their received encoding. The original message is reproduced as long as the message is left unchanged
+    // and its IEs were received in the order the message marshals them
+
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        let mut message: Vec<u8> = vec![];
+        self.message.marshal(&mut message);
+        let start = match Gtpv2Header::unmarshal(&message) {
+            Ok(Gtpv2Header { teid: Some(_), .. }) => MAX_HEADER_LENGTH,
+            _ => MIN_HEADER_LENGTH,
+        };
+        message.truncate(start);
+        let mut originals = self.originals.clone();
+        let mut elements: Vec<InformationElement> = self
+            .message
+            .tovec()
+            .into_iter()
+            .map(|x| match originals.iter().position(|(i, _)| *i == x) {
+                Some(i) => originals.remove(i).1,
+                None => x,
+            })
+            .collect();
+        for (i, ie) in self.extra.iter() {
+            elements.insert((*i).min(elements.len()), ie.clone());
+        }
+        elements.into_iter().for_each(|k| k.marshal(&mut message));
+        set_msg_length(&mut message);
+        buffer.append(&mut message);
+    }
+}
+
+// Header length expected by the message decoders: messages other than Echo and Version Not Supported
+// are decoded after a header carrying a TEID
+
+pub fn msg_header_length(msgtype: u8) -> usize {
+    match msgtype {
+        ECHO_REQUEST | ECHO_RESPONSE | VERSION_NOT_SUPPORTED => MIN_HEADER_LENGTH,
+        _ => MAX_HEADER_LENGTH,
+    }
+}
+
+// IE kept as received, with its instance byte spare bits
+
+fn raw_ie(buffer: &[u8]) -> InformationElement {
+    Unknown {
+        t: buffer[0],
+        length: (buffer.len() - MIN_IE_SIZE) as u16,
+        ins: buffer[3],
+        value: buffer[MIN_IE_SIZE..].to_vec(),
+    }
+    .into()
+}
+
+// Type and instance of an IE, read from its encoding
+
+fn ie_key(ie: &InformationElement) -> (u8, u8) {
+    let mut buffer: Vec<u8> = vec![];
+    ie.clone().marshal(&mut buffer);
+    (
+        buffer.first().copied().unwrap_or_default(),
+        buffer.get(3).map_or(0, |x| x & 0x0f),
+    )
+}
+
+// Decode a message IE by IE, so faulty IEs are dropped instead of aborting the decoding.
+// The received IEs are then matched by position against the IEs kept by the message: the message keeping
+// the first occurrences of each type and instance, the IEs received beyond the number of kept ones are ignored
+
+fn decode_with_options<T: Messages>(
+    buffer: &[u8],
+    options: &DecodeOptions,
+) -> Result<Decoded<T>, GTPV2Error> {
+    let header = Gtpv2Header::unmarshal(buffer)?;
+    let start = msg_header_length(header.msgtype);
+    let end = header.length as usize + MANDATORY_HDR_LENGTH;
+    if end < start || end > buffer.len() {
+        return Err(GTPV2Error::MessageInvalidLength(header.msgtype));
+    }
+    let mut body = buffer[..start].to_vec();
+    let mut received: Vec<(usize, Deviation, InformationElement, &[u8])> = vec![];
+    let mut deviations: Vec<Deviation> = vec![];
+    let mut extra: Vec<(usize, InformationElement)> = vec![];
+    let mut invalid: Vec<u8> = vec![];
+    let mut cursor = start;
+    let mut position = 0;
+    while cursor < end {
+        let t = buffer[cursor];
+        if end < cursor + MIN_IE_SIZE {
+            return Err(GTPV2Error::IEInvalidLength(t));
+        }
+        let ie_end = cursor
+            + MIN_IE_SIZE
+            + u16::from_be_bytes([buffer[cursor + 1], buffer[cursor + 2]]) as usize;
+        if ie_end > end {
+            return Err(GTPV2Error::IEInvalidLength(t));
+        }
+        let mut deviation = Deviation {
+            t,
+            ins: buffer[cursor + 3] & 0x0f,
+            offset: cursor,
+            kind: DeviationKind::Unknown,
+        };
+        match InformationElement::decoder(&buffer[cursor..ie_end]) {
+            Ok(i) => {
+                body.extend_from_slice(&buffer[cursor..ie_end]);
+                received.extend(
+                    i.into_iter()
+                        .map(|x| (position, deviation.clone(), x, &buffer[cursor..ie_end])),
+                );
+            }
+            Err(j) => {
+                invalid.push(t);
+                deviation.kind = DeviationKind::Invalid(j);
+                deviations.push(deviation);
+                extra.push((position, raw_ie(&buffer[cursor..ie_end])));
+            }
+        }
+        cursor = ie_end;
+        position += 1;
+    }
+    set_msg_length(&mut body);
+    let message = match T::unmarshal(&body) {
+        Ok(i) => i,
+        // The mandatory IE was received but dropped as it failed to decode
+        Err(GTPV2Error::MessageMandatoryIEMissing(i)) if invalid.contains(&i) => {
+            return Err(GTPV2Error::MessageMandatoryIEIncorrect(i))
+        }
+        Err(i) => return Err(i),
+    };
+
+    let keys: Vec<(u8, u8)> = message.tovec().iter().map(ie_key).collect();
+    let mut kept = keys.clone();
+    let mut originals: Vec<(InformationElement, InformationElement)> = vec![];
+    for (position, mut deviation, ie, raw) in received.into_iter() {
+        let key = (deviation.t, deviation.ins);
+        if let Some(i) = kept.iter().position(|x| *x == key) {
+            kept.remove(i);
+            if options.profile == DecodeProfile::Transparent {
+                let mut encoded: Vec<u8> = vec![];
+                ie.clone().marshal(&mut encoded);
+                if encoded != raw {
+                    originals.push((ie, raw_ie(raw)));
+                }
+            }
+            continue;
+        }
+        if !matches!(ie, InformationElement::Unknown(_)) {
+            deviation.kind = if keys.contains(&key) {
+                DeviationKind::Repeated
+            } else {
+                DeviationKind::Unexpected
+            };
+        }
+        deviations.push(deviation);
+        extra.push((position, ie));
+    }
+    deviations.sort_by_key(|x| x.offset);
+    if options.profile == DecodeProfile::Strict {
+        if let Some(i) = deviations
+            .iter()
+            .find(|x| matches!(x.kind, DeviationKind::Invalid(_)))
+        {
+            return Err(GTPV2Error::MessageOptionalIEIncorrect(i.t));
+        }
+        if let Some(i) = deviations
+            .iter()
+            .find(|x| matches!(x.kind, DeviationKind::Repeated | DeviationKind::Unexpected))
+        {
+            return Err(GTPV2Error::MessageUnexpectedIE(i.t));
+        }
+        if let Some(i) = locate_spare_bits(&buffer[..end], start, &mut vec![]) {
+            return Err(GTPV2Error::IEInvalidSpareBits(buffer[i]));
+        }
+    }
+    if options.profile != DecodeProfile::Transparent {
+        extra.clear();
+    }
+    extra.sort_by_key(|x| x.0);
+    Ok(Decoded {
+        message,
+        deviations,
+        extra,
+        originals,
+    })
+}
+
+#[cfg(test)]
+const ECHO_REQ_WITH_DEVIATIONS: [u8; 33] = [
+    0x40, 0x01, 0x00, 0x1d, 0x00, 0x00, 0x01, 0x00, 0x03, 0x00, 0x01, 0x00, 0x0a, 0x02, 0x00, 0x02,
+    0x00, 0x10, 0x00, 0x03, 0x00, 0x01, 0x00, 0x0b, 0x57, 0x00, 0x00, 0x00, 0xf0, 0x00, 0x01, 0x00,
+    0xff,
+];
+
+#[test]
+fn test_unmarshal_with_lenient_profile() {
+    use crate::gtpv2::messages::EchoRequest;
+    let decoded =
+        EchoRequest::unmarshal_with_options(&ECHO_REQ_WITH_DEVIATIONS, &DecodeOptions::default())
+            .unwrap();
+    assert_eq!(decoded.message.recovery.recovery, 0x0a);
+    assert_eq!(
+        decoded
+            .deviations
+            .iter()
+            .map(|x| (x.t, x.offset, x.kind.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (CAUSE, 13, DeviationKind::Unexpected),
+            (RECOVERY, 19, DeviationKind::Repeated),
+            (
+                FTEID,
+                24,
+                DeviationKind::Invalid(GTPV2Error::IEInvalidLength(FTEID))
+            ),
+            (0xf0, 28, DeviationKind::Unknown),
+        ]
+    );
+    assert!(decoded.extra.is_empty());
+}
+
+#[test]
+fn test_unmarshal_with_strict_profile() {
+    use crate::gtpv2::messages::EchoRequest;
+    let options = DecodeOptions {
+        profile: DecodeProfile::Strict,
+    };
+    // Incorrect optional IE, tolerated by the lenient profile
+    assert_eq!(
+        EchoRequest::unmarshal_with_options(&ECHO_REQ_WITH_DEVIATIONS, &options),
+        Err(GTPV2Error::MessageOptionalIEIncorrect(FTEID))
+    );
+    // Unexpected IE
+    let mut encoded = ECHO_REQ_WITH_DEVIATIONS[..19].to_vec();
+    encoded[3] = 0x0f;
+    assert_eq!(
+        EchoRequest::unmarshal_with_options(&encoded, &options),
+        Err(GTPV2Error::MessageUnexpectedIE(CAUSE))
+    );
+    // Repeated IE
+    let mut encoded = ECHO_REQ_WITH_DEVIATIONS[..13].to_vec();
+    encoded.extend_from_slice(&ECHO_REQ_WITH_DEVIATIONS[19..24]);
+    encoded[3] = 0x0e;
+    assert_eq!(
+        EchoRequest::unmarshal_with_options(&encoded, &options),
+        Err(GTPV2Error::MessageUnexpectedIE(RECOVERY))
+    );
+    assert!(EchoRequest::unmarshal_with_options(&encoded, &DecodeOptions::default()).is_ok());
+    // Unknown IEs are ignored and reported
+    let mut encoded = ECHO_REQ_WITH_DEVIATIONS[..13].to_vec();
+    encoded.extend_from_slice(&ECHO_REQ_WITH_DEVIATIONS[28..]);
+    encoded[3] = 0x0e;
+    let decoded = EchoRequest::unmarshal_with_options(&encoded, &options).unwrap();
+    assert_eq!(decoded.message.recovery.recovery, 0x0a);
+    assert_eq!(
+        decoded
+            .deviations
+            .iter()
+            .map(|x| (x.t, x.offset, x.kind.clone()))
+            .collect::<Vec<_>>(),
+        vec![(0xf0, 13, DeviationKind::Unknown)]
+    );
+    // Incorrect mandatory IE, in every profile
+    let encoded: [u8; 12] = [
+        0x40, 0x01, 0x00, 0x08, 0x00, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00,
+    ];
+    for profile in [
+        DecodeProfile::Strict,
+        DecodeProfile::Lenient,
+        DecodeProfile::Transparent,
+    ] {
+        assert_eq!(
+            EchoRequest::unmarshal_with_options(&encoded, &DecodeOptions { profile }),
+            Err(GTPV2Error::MessageMandatoryIEIncorrect(RECOVERY))
+        );
+    }
+    assert_eq!(
+        EchoRequest::unmarshal_with_options(&encoded[..8], &options),
+        Err(GTPV2Error::MessageInvalidLength(ECHO_REQUEST))
+    );
+    // Missing mandatory IE
+    let encoded: [u8; 8] = [0x40, 0x01, 0x00, 0x04, 0x00, 0x00, 0x01, 0x00];
+    assert_eq!(
+        EchoRequest::unmarshal_with_options(&encoded, &DecodeOptions::default()),
+        Err(GTPV2Error::MessageMandatoryIEMissing(RECOVERY))
+    );
+}
+
+#[test]
+fn test_unmarshal_with_options_short_header() {
+    use crate::gtpv2::messages::{CreateSessionRequest, CREATE_SESSION_REQ};
+    // Message without TEID whose length does not cover the header the message is decoded after
+    let encoded: [u8; 8] = [0x40, 0x20, 0x00, 0x04, 0x00, 0x00, 0x01, 0x00];
+    assert_eq!(
+        CreateSessionRequest::unmarshal_with_options(&encoded, &DecodeOptions::default()),
+        Err(GTPV2Error::MessageInvalidLength(CREATE_SESSION_REQ))
+    );
+}
+
+#[test]
+fn test_unmarshal_with_transparent_profile() {
+    use crate::gtpv2::messages::EchoRequest;
+    let options = DecodeOptions {
+        profile: DecodeProfile::Transparent,
+    };
+    let decoded = EchoRequest::unmarshal_with_options(&ECHO_REQ_WITH_DEVIATIONS, &options).unwrap();
+    assert_eq!(decoded.deviations.len(), 4);
+    assert_eq!(decoded.extra.len(), 4);
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, ECHO_REQ_WITH_DEVIATIONS);
+}
+
+#[test]
+fn test_unmarshal_with_transparent_profile_grouped_ie() {
+    use crate::gtpv2::messages::CreateBearerResponse;
+    // Create Bearer Response with an unknown IE inside the Bearer Context
+    let encoded: [u8; 32] = [
+        0x48, 0x60, 0x00, 0x1c, 0x0a, 0x0b, 0x0c, 0x0d, 0x00, 0x00, 0x03, 0x00, 0x02, 0x00, 0x02,
+        0x00, 0x10, 0x00, 0x5d, 0x00, 0x0a, 0x00, 0x49, 0x00, 0x01, 0x00, 0x05, 0xf0, 0x00, 0x01,
+        0x00, 0xff,
+    ];
+    let options = DecodeOptions {
+        profile: DecodeProfile::Transparent,
+    };
+    let decoded = CreateBearerResponse::unmarshal_with_options(&encoded, &options).unwrap();
+    assert_eq!(decoded.message.bearer_ctxs[0].ebi.value, 5);
+    assert_eq!(decoded.originals.len(), 1);
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+    // A modified Bearer Context is marshalled from the message
+    let mut modified = decoded.clone();
+    modified.message.bearer_ctxs[0].ebi.value = 6;
+    let mut buffer: Vec<u8> = vec![];
+    modified.marshal(&mut buffer);
+    assert_eq!(buffer.len(), encoded.len() - 5);
+}
+
+#[test]
+fn test_unmarshal_with_strict_profile_spare_bits() {
+    use crate::commons::{DecodeErrorReason, IePathElement};
+    use crate::gtpv2::messages::{DeleteBearerCommand, EchoRequest};
+    let options = DecodeOptions {
+        profile: DecodeProfile::Strict,
+    };
+    // Spare bits of the instance octet, ignored by the lenient profile
+    let encoded: [u8; 13] = [
+        0x40, 0x01, 0x00, 0x09, 0x00, 0x00, 0x01, 0x00, 0x03, 0x00, 0x01, 0x10, 0x0a,
+    ];
+    assert_eq!(
+        EchoRequest::unmarshal_with_options(&encoded, &options),
+        Err(GTPV2Error::IEInvalidSpareBits(RECOVERY))
+    );
+    assert!(EchoRequest::unmarshal_with_options(&encoded, &DecodeOptions::default()).is_ok());
+    // Spare bits of an EBI embedded in a Bearer Context
+    let encoded: [u8; 21] = [
+        0x48, 0x42, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x5d, 0x00, 0x05,
+        0x00, 0x49, 0x00, 0x01, 0x00, 0x15,
+    ];
+    assert!(
+        DeleteBearerCommand::unmarshal_with_options(&encoded, &DecodeOptions::default()).is_ok()
+    );
+    let error = DeleteBearerCommand::unmarshal_with_options(&encoded, &options).unwrap_err();
+    assert_eq!(error, GTPV2Error::IEInvalidSpareBits(EBI));
+    let error = locate_decode_error(&encoded, error);
+    assert_eq!(error.offset, 16);
+    assert_eq!(
+        error.path,
+        vec![
+            IePathElement {
+                t: BEARER_CTX,
+                ins: 0,
+                index: 0
+            },
+            IePathElement {
+                t: EBI,
+                ins: 0,
+                index: 0
+            },
+        ]
+    );
+    assert_eq!(error.reason, DecodeErrorReason::InvalidSpareBits);
+}
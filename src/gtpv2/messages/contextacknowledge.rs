@@ -128,20 +128,14 @@ impl Messages for ContextAcknowledge {
                         self.fwd_fteid = Some(j);
                     }
                 }
-                InformationElement::BearerContext(j) => {
-                    if j.ins == 0 {
-                        self.bearer_ctxs.push(j);
-                    }
+                InformationElement::BearerContext(j) if j.ins == 0 => {
+                    self.bearer_ctxs.push(j);
                 }
-                InformationElement::NodeNumber(j) => {
-                    if j.ins < 3 {
-                        self.node_nbrs.push(j);
-                    }
+                InformationElement::NodeNumber(j) if j.ins < 3 => {
+                    self.node_nbrs.push(j);
                 }
-                InformationElement::NodeIdentifier(j) => {
-                    if j.ins < 3 {
-                        self.node_ids.push(j);
-                    }
+                InformationElement::NodeIdentifier(j) if j.ins < 3 => {
+                    self.node_ids.push(j);
                 }
                 InformationElement::PrivateExtension(j) => self.private_ext.push(j),
                 _ => (),
@@ -254,10 +254,8 @@ impl Messages for ContextResponse {
                         self.mmctx = Some(j);
                     }
                 }
-                InformationElement::PdnConnections(j) => {
-                    if j.ins == 0 {
-                        self.ue_eps_pdn.push(*j);
-                    }
+                InformationElement::PdnConnections(j) if j.ins == 0 => {
+                    self.ue_eps_pdn.push(*j);
                 }
                 InformationElement::Fteid(j) => {
                     match (
@@ -270,10 +268,8 @@ impl Messages for ContextResponse {
                         (_, _, _) => (),
                     }
                 }
-                InformationElement::Fqdn(j) => {
-                    if j.ins < 3 {
-                        self.fqdns.push(j);
-                    }
+                InformationElement::Fqdn(j) if j.ins < 3 => {
+                    self.fqdns.push(j);
                 }
                 InformationElement::Indication(j) => {
                     if let (0, true) = (j.ins, self.indication.is_none()) {
@@ -285,15 +281,11 @@ impl Messages for ContextResponse {
                         self.trace_info = Some(j);
                     }
                 }
-                InformationElement::IpAddress(j) => {
-                    if j.ins < 2 {
-                        self.ip_addresses.push(j);
-                    }
+                InformationElement::IpAddress(j) if j.ins < 2 => {
+                    self.ip_addresses.push(j);
                 }
-                InformationElement::RfspIndex(j) => {
-                    if j.ins < 2 {
-                        self.rfsp_indexes.push(j);
-                    }
+                InformationElement::RfspIndex(j) if j.ins < 2 => {
+                    self.rfsp_indexes.push(j);
                 }
                 InformationElement::UeTimeZone(j) => {
                     if let (0, true) = (j.ins, self.ue_time_zone.is_none()) {
@@ -315,15 +307,11 @@ impl Messages for ContextResponse {
                         self.uci = Some(j);
                     }
                 }
-                InformationElement::MonitoringEventInformation(j) => {
-                    if j.ins == 0 {
-                        self.monitor_event_info.push(j);
-                    }
+                InformationElement::MonitoringEventInformation(j) if j.ins == 0 => {
+                    self.monitor_event_info.push(j);
                 }
-                InformationElement::MonitoringEventExtensionInfo(j) => {
-                    if j.ins == 0 {
-                        self.monitor_even_ext_info.push(j);
-                    }
+                InformationElement::MonitoringEventExtensionInfo(j) if j.ins == 0 => {
+                    self.monitor_even_ext_info.push(j);
                 }
                 InformationElement::IntegerNumber(j) => {
                     match (
@@ -336,10 +324,8 @@ impl Messages for ContextResponse {
                         (_, _, _) => (),
                     }
                 }
-                InformationElement::ScefPdnConnections(j) => {
-                    if j.ins == 0 {
-                        self.scef_pdn_connections.push(j);
-                    }
+                InformationElement::ScefPdnConnections(j) if j.ins == 0 => {
+                    self.scef_pdn_connections.push(j);
                 }
                 InformationElement::RatType(j) => {
                     if let (0, true) = (j.ins, self.rat_type.is_none()) {
@@ -361,10 +347,8 @@ impl Messages for ContextResponse {
                         self.ext_trace_info = Some(j);
                     }
                 }
-                InformationElement::AdditionalRrmPolicyIndex(j) => {
-                    if j.ins < 2 {
-                        self.add_rrm_policy_index.push(j);
-                    }
+                InformationElement::AdditionalRrmPolicyIndex(j) if j.ins < 2 => {
+                    self.add_rrm_policy_index.push(j);
                 }
                 InformationElement::NodeIdentifier(j) => {
                     if let (0, true) = (j.ins, self.iwk_scef_id.is_none()) {
@@ -187,11 +187,9 @@ impl Messages for CreateBearerRequest {
                         self.pco = Some(j.clone())
                     };
                 }
-                InformationElement::BearerContext(j) => {
-                    if j.ins == 0 {
-                        mandatory[1] = true;
-                        self.bearer_ctxs.push(j.clone());
-                    }
+                InformationElement::BearerContext(j) if j.ins == 0 => {
+                    mandatory[1] = true;
+                    self.bearer_ctxs.push(j.clone());
                 }
                 InformationElement::Fqcsid(j) => {
                     // 2 instances
@@ -226,25 +224,19 @@ impl Messages for CreateBearerRequest {
                         self.indication = Some(j.clone())
                     };
                 }
-                InformationElement::LoadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.load_control.push(j.clone())
-                    };
+                InformationElement::LoadControlInfo(j) if j.ins < 3 => {
+                    self.load_control.push(j.clone())
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 2 {
-                        self.overload_info.push(j.clone())
-                    };
+                InformationElement::OverloadControlInfo(j) if j.ins < 2 => {
+                    self.overload_info.push(j.clone())
                 }
                 InformationElement::Fcontainer(j) => {
                     if let (0, true) = (j.ins, self.nbifom.is_none()) {
                         self.nbifom = Some(j.clone())
                     };
                 }
-                InformationElement::PgwChangeInfo(j) => {
-                    if j.ins == 0 {
-                        self.pgw_change_info.push(j.clone())
-                    };
+                InformationElement::PgwChangeInfo(j) if j.ins == 0 => {
+                    self.pgw_change_info.push(j.clone())
                 }
                 InformationElement::Fteid(j) => {
                     if let (0, true) = (j.ins, self.sender_fteid_cntrl_plane.is_none()) {
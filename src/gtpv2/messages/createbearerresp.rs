@@ -202,11 +202,9 @@ impl Messages for CreateBearerResponse {
                         (self.cause, mandatory[0]) = (j.clone(), true)
                     };
                 }
-                InformationElement::BearerContext(j) => {
-                    if j.ins == 0 {
-                        mandatory[1] = true;
-                        self.bearer_ctxs.push(j.clone());
-                    }
+                InformationElement::BearerContext(j) if j.ins == 0 => {
+                    mandatory[1] = true;
+                    self.bearer_ctxs.push(j.clone());
                 }
                 InformationElement::Recovery(j) => {
                     if let (0, true) = (j.ins, self.recovery.is_none()) {
@@ -251,10 +249,8 @@ impl Messages for CreateBearerResponse {
                         _ => (),
                     }
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.overload_info.push(j.clone())
-                    };
+                InformationElement::OverloadControlInfo(j) if j.ins < 3 => {
+                    self.overload_info.push(j.clone())
                 }
                 InformationElement::PresenceReportingAreaInformation(j) => {
                     if let (0, true) = (j.ins, self.prai.is_none()) {
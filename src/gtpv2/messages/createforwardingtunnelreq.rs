@@ -84,11 +84,9 @@ impl Messages for CreateForwardingTunnelRequest {
         let mut mandatory = false;
         for e in elements.iter() {
             match e {
-                InformationElement::S103pdf(j) => {
-                    if j.ins == 0 {
-                        self.s103_pdf.push(j.clone());
-                        mandatory = true;
-                    }
+                InformationElement::S103pdf(j) if j.ins == 0 => {
+                    self.s103_pdf.push(j.clone());
+                    mandatory = true;
                 }
                 InformationElement::PrivateExtension(j) => self.private_ext.push(j.clone()),
                 _ => (),
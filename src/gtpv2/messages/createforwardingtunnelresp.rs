@@ -88,16 +88,12 @@ impl Messages for CreateForwardingTunnelResponse {
         let mut mandatory = false;
         for e in elements.iter() {
             match e {
-                InformationElement::Cause(j) => {
-                    if j.ins == 0 {
-                        self.cause = j.clone();
-                        mandatory = true;
-                    }
+                InformationElement::Cause(j) if j.ins == 0 => {
+                    self.cause = j.clone();
+                    mandatory = true;
                 }
-                InformationElement::S1udf(j) => {
-                    if j.ins == 0 {
-                        self.s1udf.push(j.clone());
-                    }
+                InformationElement::S1udf(j) if j.ins == 0 => {
+                    self.s1udf.push(j.clone());
                 }
                 InformationElement::PrivateExtension(j) => self.private_ext.push(j.clone()),
                 _ => (),
@@ -134,11 +134,9 @@ impl Messages for CreateIndirectDataForwardingTunnelRequest {
                         self.fteid_control = Some(j.clone())
                     };
                 }
-                InformationElement::BearerContext(j) => {
-                    if j.ins == 0 {
-                        mandatory = true;
-                        self.bearer_ctxs.push(j.clone());
-                    }
+                InformationElement::BearerContext(j) if j.ins == 0 => {
+                    mandatory = true;
+                    self.bearer_ctxs.push(j.clone());
                 }
                 InformationElement::Recovery(j) => {
                     if let (0, true) = (j.ins, self.recovery.is_none()) {
@@ -100,22 +100,18 @@ impl Messages for CreateIndirectDataForwardingTunnelResponse {
         let mut mandatory: [bool; 2] = [false, false];
         for e in elements.iter() {
             match e {
-                InformationElement::Cause(j) => {
-                    if j.ins == 0 {
-                        mandatory[0] = true;
-                        self.cause = j.clone();
-                    };
+                InformationElement::Cause(j) if j.ins == 0 => {
+                    mandatory[0] = true;
+                    self.cause = j.clone();
                 }
                 InformationElement::Fteid(j) => {
                     if let (0, true) = (j.ins, self.fteid_control.is_none()) {
                         self.fteid_control = Some(j.clone())
                     };
                 }
-                InformationElement::BearerContext(j) => {
-                    if j.ins == 0 {
-                        mandatory[1] = true;
-                        self.bearer_ctxs.push(j.clone());
-                    }
+                InformationElement::BearerContext(j) if j.ins == 0 => {
+                    mandatory[1] = true;
+                    self.bearer_ctxs.push(j.clone());
                 }
                 InformationElement::Recovery(j) => {
                     if let (0, true) = (j.ins, self.recovery.is_none()) {
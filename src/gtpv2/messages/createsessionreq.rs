@@ -633,10 +633,8 @@ impl Messages for CreateSessionRequest {
                         self.prai = Some(j.clone())
                     };
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.overload_info.push(j.clone())
-                    };
+                InformationElement::OverloadControlInfo(j) if j.ins < 3 => {
+                    self.overload_info.push(j.clone())
                 }
                 InformationElement::MilliSecondTimeStamp(j) => {
                     if let (0, true) = (j.ins, self.origination_timestamp.is_none()) {
@@ -658,10 +656,8 @@ impl Messages for CreateSessionRequest {
                         self.nbifom = Some(j.clone())
                     };
                 }
-                InformationElement::RemoteUeContext(j) => {
-                    if j.ins == 0 {
-                        self.remote_ue_ctx_connected.push(j.clone());
-                    }
+                InformationElement::RemoteUeContext(j) if j.ins == 0 => {
+                    self.remote_ue_ctx_connected.push(j.clone());
                 }
                 InformationElement::NodeIdentifier(j) => {
                     if let (0, true) = (j.ins, self.aaaserver_id.is_none()) {
@@ -693,10 +689,8 @@ impl Messages for CreateSessionRequest {
                         self.sgwu_node = Some(j.clone())
                     };
                 }
-                InformationElement::SecondaryRatUsageDataReport(j) => {
-                    if j.ins == 0 {
-                        self.secondary_rat_usage_report.push(j.clone());
-                    }
+                InformationElement::SecondaryRatUsageDataReport(j) if j.ins == 0 => {
+                    self.secondary_rat_usage_report.push(j.clone());
                 }
                 InformationElement::UpFunctionSelectionIndicationFlags(j) => {
                     if let (0, true) = (j.ins, self.up_function_selection_flags.is_none()) {
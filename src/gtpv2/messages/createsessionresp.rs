@@ -343,11 +343,9 @@ impl Messages for CreateSessionResponse {
                         self.pco = Some(j.clone())
                     };
                 }
-                InformationElement::BearerContext(j) => {
-                    if j.ins == 0 {
-                        mandatory[1] = true;
-                        self.bearer_ctxs.push(j.clone());
-                    }
+                InformationElement::BearerContext(j) if j.ins == 0 => {
+                    mandatory[1] = true;
+                    self.bearer_ctxs.push(j.clone());
                 }
                 InformationElement::Recovery(j) => {
                     if let (0, true) = (j.ins, self.recovery.is_none()) {
@@ -395,15 +393,11 @@ impl Messages for CreateSessionResponse {
                         self.praa = Some(j.clone())
                     };
                 }
-                InformationElement::LoadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.load_control.push(j.clone())
-                    };
+                InformationElement::LoadControlInfo(j) if j.ins < 3 => {
+                    self.load_control.push(j.clone())
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 2 {
-                        self.overload_info.push(j.clone())
-                    };
+                InformationElement::OverloadControlInfo(j) if j.ins < 2 => {
+                    self.overload_info.push(j.clone())
                 }
                 InformationElement::Fcontainer(j) => {
                     if let (0, true) = (j.ins, self.nbifom.is_none()) {
@@ -125,11 +125,9 @@ impl Messages for DeleteBearerCommand {
         let mut mandatory = false;
         for e in elements.iter() {
             match e {
-                InformationElement::BearerContext(j) => {
-                    if j.ins == 0 {
-                        self.bearer_ctxs.push(j.clone());
-                        mandatory = true;
-                    }
+                InformationElement::BearerContext(j) if j.ins == 0 => {
+                    self.bearer_ctxs.push(j.clone());
+                    mandatory = true;
                 }
                 InformationElement::Uli(j) => {
                     if let (0, true) = (j.ins, self.uli.is_none()) {
@@ -146,20 +144,16 @@ impl Messages for DeleteBearerCommand {
                         self.uetimezone = Some(j.clone());
                     }
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 2 {
-                        self.overload_info.push(j.clone());
-                    }
+                InformationElement::OverloadControlInfo(j) if j.ins < 2 => {
+                    self.overload_info.push(j.clone());
                 }
                 InformationElement::Fteid(j) => {
                     if let (0, true) = (j.ins, self.fteid_control.is_none()) {
                         self.fteid_control = Some(j.clone());
                     }
                 }
-                InformationElement::SecondaryRatUsageDataReport(j) => {
-                    if j.ins == 0 {
-                        self.secondary_rat_usage_report.push(j.clone());
-                    }
+                InformationElement::SecondaryRatUsageDataReport(j) if j.ins == 0 => {
+                    self.secondary_rat_usage_report.push(j.clone());
                 }
                 InformationElement::PSCellId(j) => {
                     if let (0, true) = (j.ins, self.pscellid.is_none()) {
@@ -126,10 +126,8 @@ impl Messages for DeleteBearerFailureInd {
                         self.indication = Some(j.clone());
                     }
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 2 {
-                        self.overload_info.push(j.clone());
-                    }
+                InformationElement::OverloadControlInfo(j) if j.ins < 2 => {
+                    self.overload_info.push(j.clone());
                 }
                 InformationElement::PrivateExtension(j) => self.private_ext.push(j.clone()),
                 _ => (),
@@ -182,11 +182,7 @@ impl Messages for DeleteBearerRequest {
                         _ => (),
                     }
                 }
-                InformationElement::BearerContext(j) => {
-                    if j.ins == 0 {
-                        self.bearer_ctxs.push(j)
-                    };
-                }
+                InformationElement::BearerContext(j) if j.ins == 0 => self.bearer_ctxs.push(j),
                 InformationElement::Pti(j) => {
                     if let (0, true) = (j.ins, self.pti.is_none()) {
                         self.pti = Some(j)
@@ -215,15 +211,9 @@ impl Messages for DeleteBearerRequest {
                         self.indication = Some(j)
                     };
                 }
-                InformationElement::LoadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.load_control.push(j)
-                    };
-                }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 2 {
-                        self.overload_info.push(j)
-                    };
+                InformationElement::LoadControlInfo(j) if j.ins < 3 => self.load_control.push(j),
+                InformationElement::OverloadControlInfo(j) if j.ins < 2 => {
+                    self.overload_info.push(j)
                 }
                 InformationElement::Fcontainer(j) => {
                     if let (0, true) = (j.ins, self.nbifom.is_none()) {
@@ -240,11 +230,7 @@ impl Messages for DeleteBearerRequest {
                         self.epco = Some(j)
                     };
                 }
-                InformationElement::PgwChangeInfo(j) => {
-                    if j.ins == 2 {
-                        self.pgw_change_info.push(j)
-                    };
-                }
+                InformationElement::PgwChangeInfo(j) if j.ins == 2 => self.pgw_change_info.push(j),
                 InformationElement::Fteid(j) => {
                     if let (0, true) = (j.ins, self.fteid_control.is_none()) {
                         self.fteid_control = Some(j)
@@ -210,10 +210,8 @@ impl Messages for DeleteBearerResponse {
                         self.linked_ebi = Some(j);
                     }
                 }
-                InformationElement::BearerContext(j) => {
-                    if j.ins == 0 {
-                        self.bearer_ctxs.push(j);
-                    }
+                InformationElement::BearerContext(j) if j.ins == 0 => {
+                    self.bearer_ctxs.push(j);
                 }
                 InformationElement::Recovery(j) => {
                     if let (0, true) = (j.ins, self.recovery.is_none()) {
@@ -276,10 +274,8 @@ impl Messages for DeleteBearerResponse {
                         _ => (),
                     }
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.overload_info.push(j);
-                    }
+                InformationElement::OverloadControlInfo(j) if j.ins < 3 => {
+                    self.overload_info.push(j);
                 }
                 InformationElement::IpAddress(j) => {
                     if let (0, true) = (j.ins, self.ip.is_none()) {
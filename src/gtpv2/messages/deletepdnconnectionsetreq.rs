@@ -109,30 +109,20 @@ impl Messages for DeletePdnConnectionSetRequest {
         for e in elements.iter() {
             match e {
                 InformationElement::Fqcsid(j) => match j.ins {
-                    0 => {
-                        if self.mme_fqcsid.is_none() {
-                            self.mme_fqcsid = Some(j.clone());
-                        }
+                    0 if self.mme_fqcsid.is_none() => {
+                        self.mme_fqcsid = Some(j.clone());
                     }
-                    1 => {
-                        if self.sgw_fqcsid.is_none() {
-                            self.sgw_fqcsid = Some(j.clone());
-                        }
+                    1 if self.sgw_fqcsid.is_none() => {
+                        self.sgw_fqcsid = Some(j.clone());
                     }
-                    2 => {
-                        if self.pgw_fqcsid.is_none() {
-                            self.pgw_fqcsid = Some(j.clone());
-                        }
+                    2 if self.pgw_fqcsid.is_none() => {
+                        self.pgw_fqcsid = Some(j.clone());
                     }
-                    3 => {
-                        if self.epdg_fqcsid.is_none() {
-                            self.epdg_fqcsid = Some(j.clone());
-                        }
+                    3 if self.epdg_fqcsid.is_none() => {
+                        self.epdg_fqcsid = Some(j.clone());
                     }
-                    4 => {
-                        if self.twan_fqcsid.is_none() {
-                            self.twan_fqcsid = Some(j.clone());
-                        }
+                    4 if self.twan_fqcsid.is_none() => {
+                        self.twan_fqcsid = Some(j.clone());
                     }
                     _ => (),
                 },
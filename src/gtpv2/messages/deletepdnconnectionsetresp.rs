@@ -88,11 +88,9 @@ impl Messages for DeletePndConnectionSetResponse {
         let mut mandatory = false;
         for e in elements.iter() {
             match e {
-                InformationElement::Cause(j) => {
-                    if j.ins == 0 {
-                        mandatory = true;
-                        self.cause = j.clone();
-                    };
+                InformationElement::Cause(j) if j.ins == 0 => {
+                    mandatory = true;
+                    self.cause = j.clone();
                 }
                 InformationElement::Recovery(j) => {
                     if let (0, true) = (j.ins, self.recovery.is_none()) {
@@ -273,10 +273,8 @@ impl Messages for DeleteSessionRequest {
                         _ => (),
                     }
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.overload_info.push(j)
-                    };
+                InformationElement::OverloadControlInfo(j) if j.ins < 3 => {
+                    self.overload_info.push(j)
                 }
                 InformationElement::IpAddress(j) => {
                     if let (0, true) = (j.ins, self.ue_localip.is_none()) {
@@ -296,10 +294,8 @@ impl Messages for DeleteSessionRequest {
                         self.epco = Some(j)
                     };
                 }
-                InformationElement::SecondaryRatUsageDataReport(j) => {
-                    if j.ins == 0 {
-                        self.secondary_rat_usage_report.push(j)
-                    };
+                InformationElement::SecondaryRatUsageDataReport(j) if j.ins == 0 => {
+                    self.secondary_rat_usage_report.push(j)
                 }
                 InformationElement::PSCellId(j) => {
                     if let (0, true) = (j.ins, self.pscellid.is_none()) {
@@ -384,7 +380,7 @@ fn test_delete_session_req_unmarshal() {
             t: RAN_NAS_CAUSE,
             length: 2,
             ins: 0,
-            cause: CauseValue::S1ap(S1APCause::RadioLayer(26)),
+            cause: RanNasCauseValue::S1ap(S1APCause::RadioLayer(26)),
         }),
         private_ext: vec![PrivateExtension {
             t: PRIVATE_EXT,
@@ -469,7 +465,7 @@ fn test_delete_session_req_marshal() {
             t: RAN_NAS_CAUSE,
             length: 2,
             ins: 0,
-            cause: CauseValue::S1ap(S1APCause::RadioLayer(26)),
+            cause: RanNasCauseValue::S1ap(S1APCause::RadioLayer(26)),
         }),
         private_ext: vec![PrivateExtension {
             t: PRIVATE_EXT,
@@ -145,15 +145,9 @@ impl Messages for DeleteSessionResponse {
                         self.indication = Some(j)
                     };
                 }
-                InformationElement::LoadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.load_control.push(j)
-                    };
-                }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 2 {
-                        self.overload_info.push(j)
-                    };
+                InformationElement::LoadControlInfo(j) if j.ins < 3 => self.load_control.push(j),
+                InformationElement::OverloadControlInfo(j) if j.ins < 2 => {
+                    self.overload_info.push(j)
                 }
                 InformationElement::Epco(j) => {
                     if let (0, true) = (j.ins, self.epco.is_none()) {
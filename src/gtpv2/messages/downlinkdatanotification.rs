@@ -7,7 +7,7 @@ use crate::gtpv2::{
 
 // According to 3GPP TS 29.274 V17.10.0 (2023-12)
 
-pub const DL_DATA_NOTIF: u8 = 179;
+pub const DL_DATA_NOTIF: u8 = 176;
 
 // Definition of GTPv2-C Downlink Data Notification Message
 
@@ -163,15 +163,11 @@ impl Messages for DownlinkDataNotification {
                         self.indication = Some(j.clone());
                     }
                 }
-                InformationElement::LoadControlInfo(j) => {
-                    if j.ins == 0 {
-                        self.load_control.push(j.clone());
-                    }
+                InformationElement::LoadControlInfo(j) if j.ins == 0 => {
+                    self.load_control.push(j.clone());
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins == 0 {
-                        self.overload_info.push(j.clone());
-                    }
+                InformationElement::OverloadControlInfo(j) if j.ins == 0 => {
+                    self.overload_info.push(j.clone());
                 }
                 InformationElement::PagingServiceInfo(j) => {
                     if let (0, true) = (j.ins, self.psi.is_none()) {
@@ -195,7 +191,7 @@ impl Messages for DownlinkDataNotification {
 fn test_dl_data_notification_unmarshal() {
     use std::net::Ipv4Addr;
     let encoded: [u8; 129] = [
-        0x48, 0xb3, 0x00, 0x7d, 0x09, 0x09, 0xa4, 0x56, 0x00, 0x00, 0x2f, 0x00, 0x02, 0x00, 0x02,
+        0x48, 0xb0, 0x00, 0x7d, 0x09, 0x09, 0xa4, 0x56, 0x00, 0x00, 0x2f, 0x00, 0x02, 0x00, 0x02,
         0x00, 0x06, 0x00, 0x49, 0x00, 0x01, 0x00, 0x05, 0x9b, 0x00, 0x01, 0x00, 0x00, 0x01, 0x00,
         0x06, 0x00, 0x09, 0x41, 0x50, 0x01, 0x01, 0x37, 0x57, 0x00, 0x09, 0x00, 0x85, 0x3b, 0x95,
         0x98, 0x5a, 0x3e, 0x99, 0x89, 0x55, 0x4d, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
@@ -313,7 +309,7 @@ fn test_dl_data_notification_unmarshal() {
 fn test_dl_data_notification_marshal() {
     use std::net::Ipv4Addr;
     let encoded: [u8; 129] = [
-        0x48, 0xb3, 0x00, 0x7d, 0x09, 0x09, 0xa4, 0x56, 0x00, 0x00, 0x2f, 0x00, 0x02, 0x00, 0x02,
+        0x48, 0xb0, 0x00, 0x7d, 0x09, 0x09, 0xa4, 0x56, 0x00, 0x00, 0x2f, 0x00, 0x02, 0x00, 0x02,
         0x00, 0x06, 0x00, 0x49, 0x00, 0x01, 0x00, 0x05, 0x9b, 0x00, 0x01, 0x00, 0x00, 0x01, 0x00,
         0x06, 0x00, 0x09, 0x41, 0x50, 0x01, 0x01, 0x37, 0x57, 0x00, 0x09, 0x00, 0x85, 0x3b, 0x95,
         0x98, 0x5a, 0x3e, 0x99, 0x89, 0x55, 0x4d, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
@@ -118,11 +118,9 @@ impl Messages for DownlinkDataNotificationAcknowledge {
         let mut mandatory = false;
         for e in elements.iter() {
             match e {
-                InformationElement::Cause(j) => {
-                    if j.ins == 0 {
-                        mandatory = true;
-                        self.cause = j.clone();
-                    };
+                InformationElement::Cause(j) if j.ins == 0 => {
+                    mandatory = true;
+                    self.cause = j.clone();
                 }
                 InformationElement::DelayValue(j) => {
                     if let (0, true) = (j.ins, self.data_notification_delay.is_none()) {
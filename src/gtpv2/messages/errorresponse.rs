@@ -0,0 +1,615 @@
+use crate::gtpv2::{errors::*, header::*, messages::*};
+
+// According to 3GPP TS 29.274 V17.10.0 (2023-12), clause 7.7
+
+// Handling of a request which failed to decode: reject it with a response carrying the cause of the failure,
+// silently discard it, or ignore the error and handle the request without the faulty IE
+// (e.g. by decoding it again with the lenient profile)
+
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum ErrorResponse {
+    Discard,
+    Ignore,
+    Reject(GTPV2Message),
+}
+
+// Response message type triggered by a request message type, failure indications being the
+// response to commands
+
+pub fn response_type(msgtype: u8) -> Option<u8> {
+    match msgtype {
+        CREATE_SESSION_REQ => Some(CREATE_SESSION_RESP),
+        MODIFY_BEARER_REQ => Some(MODIFY_BEARER_RESP),
+        DELETE_SESSION_REQ => Some(DELETE_SESSION_RESP),
+        CHNG_NOTIF_REQ => Some(CHNG_NOTIF_RESP),
+        REMOTE_UE_REPORT_NOTIF => Some(REMOTE_UE_REPORT_ACK),
+        MODIFY_BEARER_CMD => Some(MODIFY_BEARER_FAIL_IND),
+        DELETE_BEARER_CMD => Some(DELETE_BEARER_FAIL),
+        BEARER_RSRC_CMD => Some(BEARER_RSRC_FAIL),
+        CREATE_BEARER_REQ => Some(CREATE_BEARER_RESP),
+        UPD_BEARER_REQ => Some(UPD_BEARER_RESP),
+        DELETE_BEARER_REQ => Some(DELETE_BEARER_RESP),
+        DEL_PDN_CONN_SET_REQ => Some(DEL_PDN_CONN_SET_RESP),
+        PGW_DL_TRIGGER_NOTIF => Some(PGW_DL_TRIGGER_ACK),
+        IDENTIFICATION_REQ => Some(IDENTIFICATION_RESP),
+        CTX_REQ => Some(CTX_RESP),
+        FWD_RELOC_REQ => Some(FWD_RELOC_RESP),
+        FWD_RELOC_COMPLETE_NOTIF => Some(FWD_RELOC_COMPLETE_ACK),
+        FWD_ACCESS_CTX_NOTIF => Some(FWD_ACCESS_CTX_ACK),
+        RELOC_CANCEL_REQ => Some(RELOC_CANCEL_RESP),
+        DETACH_NOTIF => Some(DETACH_ACK),
+        ALERT_MME_NOTIF => Some(ALERT_MME_ACK),
+        UE_ACTIVITY_NOTIF => Some(UE_ACTIVITY_ACK),
+        UE_REG_QUERY_REQ => Some(UE_REG_QUERY_RESP),
+        CREATE_FWD_TUNNEL_REQ => Some(CREATE_FWD_TUNNEL_RESP),
+        SUSPEND_NOTIF => Some(SUSPEND_ACK),
+        RESUME_NOTIF => Some(RESUME_ACK),
+        CREATE_IND_DATA_FW_TUN_REQ => Some(CREATE_IND_DATA_FW_TUN_RESP),
+        DELETE_IND_DATA_FW_TUN_REQ => Some(DELETE_IND_DATA_FW_TUN_RESP),
+        RELEASE_ACCESS_BRS_REQ => Some(RELEASE_ACCESS_BRS_RESP),
+        DL_DATA_NOTIF => Some(DL_DATA_NOTIF_ACK),
+        PGW_RESTART_NOTIF => Some(PGW_RESTART_NOTIF_ACK),
+        UPD_PDN_CONN_SET_REQ => Some(UPD_PDN_CONN_SET_RESP),
+        MODIFY_ACCESS_BRS_REQ => Some(MODIFY_ACCESS_BRS_RESP),
+        MBMS_SESSION_STRT_REQ => Some(MBMS_SESSION_STRT_RESP),
+        MBMS_SESSION_UPD_REQ => Some(MBMS_SESSION_UPD_RESP),
+        MBMS_SESSION_STOP_REQ => Some(MBMS_SESSION_STOP_RESP),
+        _ => None,
+    }
+}
+
+// Incorrect optional IEs and unexpected IEs are ignored, the request being handled as if they were absent
+
+pub fn is_ignored(error: &GTPV2Error) -> bool {
+    matches!(
+        error,
+        GTPV2Error::MessageOptionalIEIncorrect(_) | GTPV2Error::MessageUnexpectedIE(_)
+    )
+}
+
+// The IE decoding errors returned by the message decoders do not tell whether the faulty IE is mandatory
+// for the message: the request is decoded again IE by IE with the strict profile, so the error becomes
+// a mandatory IE incorrect, or an optional IE incorrect when the request decodes without the faulty IE
+
+pub fn classify_error(buffer: &[u8], error: &GTPV2Error) -> GTPV2Error {
+    let t = match error {
+        GTPV2Error::IEInvalidLength(i) | GTPV2Error::IEIncorrect(i) => *i,
+        _ => return error.clone(),
+    };
+    match strict_decode_error(buffer) {
+        Some(i) => i,
+        None => GTPV2Error::MessageOptionalIEIncorrect(t),
+    }
+}
+
+fn strict_decode_error(buffer: &[u8]) -> Option<GTPV2Error> {
+    match buffer.get(1).copied()? {
+        CREATE_SESSION_REQ => strict_error::<CreateSessionRequest>(buffer),
+        MODIFY_BEARER_REQ => strict_error::<ModifyBearerRequest>(buffer),
+        DELETE_SESSION_REQ => strict_error::<DeleteSessionRequest>(buffer),
+        CHNG_NOTIF_REQ => strict_error::<ChangeNotificationRequest>(buffer),
+        REMOTE_UE_REPORT_NOTIF => strict_error::<RemoteUeReportNotification>(buffer),
+        MODIFY_BEARER_CMD => strict_error::<ModifyBearerCommand>(buffer),
+        DELETE_BEARER_CMD => strict_error::<DeleteBearerCommand>(buffer),
+        BEARER_RSRC_CMD => strict_error::<BearerResourceCommand>(buffer),
+        CREATE_BEARER_REQ => strict_error::<CreateBearerRequest>(buffer),
+        UPD_BEARER_REQ => strict_error::<UpdateBearerRequest>(buffer),
+        DELETE_BEARER_REQ => strict_error::<DeleteBearerRequest>(buffer),
+        DEL_PDN_CONN_SET_REQ => strict_error::<DeletePdnConnectionSetRequest>(buffer),
+        PGW_DL_TRIGGER_NOTIF => strict_error::<PgwDownlinkTriggeringNotification>(buffer),
+        IDENTIFICATION_REQ => strict_error::<IdentificationRequest>(buffer),
+        CTX_REQ => strict_error::<ContextRequest>(buffer),
+        FWD_RELOC_REQ => strict_error::<ForwardRelocationRequest>(buffer),
+        FWD_RELOC_COMPLETE_NOTIF => strict_error::<ForwardRelocationCompleteNotification>(buffer),
+        FWD_ACCESS_CTX_NOTIF => strict_error::<ForwardAccessContextNotification>(buffer),
+        RELOC_CANCEL_REQ => strict_error::<RelocationCancelRequest>(buffer),
+        DETACH_NOTIF => strict_error::<DetachNotification>(buffer),
+        ALERT_MME_NOTIF => strict_error::<AlertMmeNotification>(buffer),
+        UE_ACTIVITY_NOTIF => strict_error::<UeActivityNotification>(buffer),
+        UE_REG_QUERY_REQ => strict_error::<UeRegistrationQueryRequest>(buffer),
+        CREATE_FWD_TUNNEL_REQ => strict_error::<CreateForwardingTunnelRequest>(buffer),
+        SUSPEND_NOTIF => strict_error::<SuspendNotification>(buffer),
+        RESUME_NOTIF => strict_error::<ResumeNotification>(buffer),
+        CREATE_IND_DATA_FW_TUN_REQ => {
+            strict_error::<CreateIndirectDataForwardingTunnelRequest>(buffer)
+        }
+        DELETE_IND_DATA_FW_TUN_REQ => {
+            strict_error::<DeleteIndirectDataForwardingTunnelRequest>(buffer)
+        }
+        RELEASE_ACCESS_BRS_REQ => strict_error::<ReleaseAccessBearersRequest>(buffer),
+        DL_DATA_NOTIF => strict_error::<DownlinkDataNotification>(buffer),
+        PGW_RESTART_NOTIF => strict_error::<PgwRestartNotification>(buffer),
+        UPD_PDN_CONN_SET_REQ => strict_error::<UpdatePdnConnectionSetRequest>(buffer),
+        MODIFY_ACCESS_BRS_REQ => strict_error::<ModifyAccessBearersRequest>(buffer),
+        MBMS_SESSION_STRT_REQ => strict_error::<MbmsSessionStartRequest>(buffer),
+        MBMS_SESSION_UPD_REQ => strict_error::<MbmsSessionUpdateRequest>(buffer),
+        MBMS_SESSION_STOP_REQ => strict_error::<MbmsSessionStopRequest>(buffer),
+        _ => None,
+    }
+}
+
+fn strict_error<T: Messages>(buffer: &[u8]) -> Option<GTPV2Error> {
+    let options = DecodeOptions {
+        profile: DecodeProfile::Strict,
+    };
+    T::unmarshal_with_options(buffer, &options).err()
+}
+
+// Cause of the reject triggered by a decoding error, None when the message is not rejected.
+// IE decoding errors are to be classified with classify_error() beforehand
+
+pub fn reject_cause(error: &GTPV2Error) -> Option<Cause> {
+    let (value, offend_ie_type) = match error {
+        GTPV2Error::MessageMandatoryIEMissing(i) => (CAUSE_MANDATORY_IE_MISSING, Some(*i)),
+        GTPV2Error::MessageMandatoryIEIncorrect(i) => (CAUSE_MANDATORY_IE_INCORRECT, Some(*i)),
+        GTPV2Error::MessageInvalidLength(_) => (CAUSE_INVALID_LENGTH, None),
+        GTPV2Error::MessageInvalidMessageFormat => (CAUSE_INVALID_MESSAGE_FORMAT, None),
+        _ => return None,
+    };
+    let offend_ie_type = offend_ie_type.filter(|i| *i != 0);
+    Some(Cause {
+        length: match offend_ie_type {
+            Some(_) => LONG_CAUSE_LENGTH as u16,
+            None => SHORT_CAUSE_LENGTH as u16,
+        },
+        value,
+        offend_ie_type,
+        ..Cause::default()
+    })
+}
+
+// Build the response to a request which failed to decode with the given error.
+// The response takes the sequence number of the request and is sent to the TEID of the Sender
+// F-TEID for Control Plane when the request carries one, or to TEID 0 otherwise, so callers
+// knowing the peer TEID of an existing session should set it in the response header.
+// Responses, unknown messages and messages with a faulty header are silently discarded
+
+pub fn error_response(buffer: &[u8], error: &GTPV2Error) -> ErrorResponse {
+    let request = match Gtpv2Header::unmarshal(buffer) {
+        Ok(i) => i,
+        Err(_) => return ErrorResponse::Discard,
+    };
+    let msgtype = match response_type(request.msgtype) {
+        Some(i) => i,
+        None => return ErrorResponse::Discard,
+    };
+    let error = classify_error(buffer, error);
+    if is_ignored(&error) {
+        return ErrorResponse::Ignore;
+    }
+    let cause = match reject_cause(&error) {
+        Some(i) => i,
+        None => return ErrorResponse::Discard,
+    };
+    let header = Gtpv2Header {
+        msgtype,
+        teid: Some(sender_cp_teid(buffer, &request).unwrap_or_default()),
+        sqn: request.sqn,
+        ..Gtpv2Header::default()
+    };
+    ErrorResponse::Reject(match msgtype {
+        CREATE_SESSION_RESP => GTPV2Message::CreateSessionResponse(CreateSessionResponse {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        MODIFY_BEARER_RESP => GTPV2Message::ModifyBearerResponse(ModifyBearerResponse {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        DELETE_SESSION_RESP => GTPV2Message::DeleteSessionResponse(DeleteSessionResponse {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        CHNG_NOTIF_RESP => GTPV2Message::ChangeNotificationResponse(ChangeNotificationResponse {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        REMOTE_UE_REPORT_ACK => {
+            GTPV2Message::RemoteUeReportAcknowledge(RemoteUeReportAcknowledge {
+                header,
+                cause,
+                ..Default::default()
+            })
+        }
+        MODIFY_BEARER_FAIL_IND => GTPV2Message::ModifyBearerFailureInd(ModifyBearerFailureInd {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        DELETE_BEARER_FAIL => GTPV2Message::DeleteBearerFailureInd(DeleteBearerFailureInd {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        BEARER_RSRC_FAIL => GTPV2Message::BearerResourceFailureInd(BearerResourceFailureInd {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        CREATE_BEARER_RESP => GTPV2Message::CreateBearerResponse(CreateBearerResponse {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        UPD_BEARER_RESP => GTPV2Message::UpdateBearerResponse(UpdateBearerResponse {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        DELETE_BEARER_RESP => GTPV2Message::DeleteBearerResponse(DeleteBearerResponse {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        DEL_PDN_CONN_SET_RESP => {
+            GTPV2Message::DeletePndConnectionSetResponse(DeletePndConnectionSetResponse {
+                header,
+                cause,
+                ..Default::default()
+            })
+        }
+        PGW_DL_TRIGGER_ACK => {
+            GTPV2Message::PgwDownlinkTriggeringAcknowledge(PgwDownlinkTriggeringAcknowledge {
+                header,
+                cause,
+                ..Default::default()
+            })
+        }
+        IDENTIFICATION_RESP => GTPV2Message::IdentificationResponse(IdentificationResponse {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        CTX_RESP => GTPV2Message::ContextResponse(ContextResponse {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        FWD_RELOC_RESP => GTPV2Message::ForwardRelocationResponse(ForwardRelocationResponse {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        FWD_RELOC_COMPLETE_ACK => GTPV2Message::ForwardRelocationCompleteAcknowledge(
+            ForwardRelocationCompleteAcknowledge {
+                header,
+                cause,
+                ..Default::default()
+            },
+        ),
+        FWD_ACCESS_CTX_ACK => {
+            GTPV2Message::ForwardAccessContextAcknowledge(ForwardAccessContextAcknowledge {
+                header,
+                cause,
+                ..Default::default()
+            })
+        }
+        RELOC_CANCEL_RESP => GTPV2Message::RelocationCancelResponse(RelocationCancelResponse {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        DETACH_ACK => GTPV2Message::DetachAcknowledge(DetachAcknowledge {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        ALERT_MME_ACK => GTPV2Message::AlertMmeAcknowledge(AlertMmeAcknowledge {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        UE_ACTIVITY_ACK => GTPV2Message::UeActivityAcknowledge(UeActivityAcknowledge {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        UE_REG_QUERY_RESP => {
+            GTPV2Message::UeRegistrationQueryResponse(UeRegistrationQueryResponse {
+                header,
+                cause,
+                ..Default::default()
+            })
+        }
+        CREATE_FWD_TUNNEL_RESP => {
+            GTPV2Message::CreateForwardingTunnelResponse(CreateForwardingTunnelResponse {
+                header,
+                cause,
+                ..Default::default()
+            })
+        }
+        SUSPEND_ACK => GTPV2Message::SuspendAcknowledge(SuspendAcknowledge {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        RESUME_ACK => GTPV2Message::ResumeAcknowledge(ResumeAcknowledge {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        CREATE_IND_DATA_FW_TUN_RESP => GTPV2Message::CreateIndirectDataForwardingTunnelResponse(
+            CreateIndirectDataForwardingTunnelResponse {
+                header,
+                cause,
+                ..Default::default()
+            },
+        ),
+        DELETE_IND_DATA_FW_TUN_RESP => GTPV2Message::DeleteIndirectDataForwardingTunnelResponse(
+            DeleteIndirectDataForwardingTunnelResponse {
+                header,
+                cause,
+                ..Default::default()
+            },
+        ),
+        RELEASE_ACCESS_BRS_RESP => {
+            GTPV2Message::ReleaseAccessBearersResponse(ReleaseAccessBearersResponse {
+                header,
+                cause,
+                ..Default::default()
+            })
+        }
+        DL_DATA_NOTIF_ACK => {
+            GTPV2Message::DownlinkDataNotificationAcknowledge(DownlinkDataNotificationAcknowledge {
+                header,
+                cause,
+                ..Default::default()
+            })
+        }
+        PGW_RESTART_NOTIF_ACK => {
+            GTPV2Message::PgwRestartNotificationAcknowledge(PgwRestartNotificationAcknowledge {
+                header,
+                cause,
+                ..Default::default()
+            })
+        }
+        UPD_PDN_CONN_SET_RESP => {
+            GTPV2Message::UpdatePndConnectionSetResponse(UpdatePndConnectionSetResponse {
+                header,
+                cause,
+                ..Default::default()
+            })
+        }
+        MODIFY_ACCESS_BRS_RESP => {
+            GTPV2Message::ModifyAccessBearersResponse(ModifyAccessBearersResponse {
+                header,
+                cause,
+                ..Default::default()
+            })
+        }
+        MBMS_SESSION_STRT_RESP => {
+            GTPV2Message::MbmsSessionStartResponse(MbmsSessionStartResponse {
+                header,
+                cause,
+                ..Default::default()
+            })
+        }
+        MBMS_SESSION_UPD_RESP => {
+            GTPV2Message::MbmsSessionUpdateResponse(MbmsSessionUpdateResponse {
+                header,
+                cause,
+                ..Default::default()
+            })
+        }
+        MBMS_SESSION_STOP_RESP => GTPV2Message::MbmsSessionStopResponse(MbmsSessionStopResponse {
+            header,
+            cause,
+            ..Default::default()
+        }),
+        _ => return ErrorResponse::Discard,
+    })
+}
+
+// TEID of the first F-TEID IE with instance 0 found at the top level of the request
+
+fn sender_cp_teid(buffer: &[u8], header: &Gtpv2Header) -> Option<u32> {
+    let end = buffer
+        .len()
+        .min(header.length as usize + MANDATORY_HDR_LENGTH);
+    let mut cursor = match header.teid {
+        Some(_) => MAX_HEADER_LENGTH,
+        None => MIN_HEADER_LENGTH,
+    };
+    while cursor + MIN_IE_SIZE <= end {
+        let length = u16::from_be_bytes([buffer[cursor + 1], buffer[cursor + 2]]) as usize;
+        if (buffer[cursor], buffer[cursor + 3] & 0x0f) == (FTEID, 0) {
+            return match buffer.get(cursor + 5..cursor + 9) {
+                Some(i) if length >= 5 => Some(u32::from_be_bytes([i[0], i[1], i[2], i[3]])),
+                _ => None,
+            };
+        }
+        cursor += MIN_IE_SIZE + length;
+    }
+    None
+}
+
+#[cfg(test)]
+const CREATE_SESSION_REQ_SENDER_FTEID_ONLY: [u8; 25] = [
+    0x48, 0x20, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x34, 0x00, 0x57, 0x00, 0x09, 0x00,
+    0x8a, 0x0a, 0x0b, 0x0c, 0x0d, 0x0a, 0x00, 0x00, 0x01,
+];
+
+#[test]
+fn test_error_response_mandatory_ie_missing() {
+    let error = CreateSessionRequest::unmarshal(&CREATE_SESSION_REQ_SENDER_FTEID_ONLY).unwrap_err();
+    let response = CreateSessionResponse {
+        header: Gtpv2Header {
+            msgtype: CREATE_SESSION_RESP,
+            teid: Some(0x0a0b0c0d),
+            sqn: 0x1234,
+            ..Gtpv2Header::default()
+        },
+        cause: Cause {
+            length: LONG_CAUSE_LENGTH as u16,
+            value: CAUSE_MANDATORY_IE_MISSING,
+            offend_ie_type: Some(RATTYPE),
+            ..Cause::default()
+        },
+        ..CreateSessionResponse::default()
+    };
+    assert_eq!(error, GTPV2Error::MessageMandatoryIEMissing(RATTYPE));
+    assert_eq!(
+        error_response(&CREATE_SESSION_REQ_SENDER_FTEID_ONLY, &error),
+        ErrorResponse::Reject(GTPV2Message::CreateSessionResponse(response))
+    );
+}
+
+#[test]
+fn test_error_response_marshal() {
+    let encoded: [u8; 22] = [
+        0x48, 0x21, 0x00, 0x12, 0x0a, 0x0b, 0x0c, 0x0d, 0x00, 0x12, 0x34, 0x00, 0x02, 0x00, 0x06,
+        0x00, 0x45, 0x00, 0x57, 0x00, 0x00, 0x00,
+    ];
+    let mut buffer: Vec<u8> = vec![];
+    match error_response(
+        &CREATE_SESSION_REQ_SENDER_FTEID_ONLY,
+        &GTPV2Error::MessageMandatoryIEIncorrect(FTEID),
+    ) {
+        ErrorResponse::Reject(i) => i.marshal(&mut buffer),
+        i => panic!("unexpected {:?}", i),
+    }
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn test_error_response_without_sender_fteid() {
+    let encoded: [u8; 12] = [
+        0x48, 0x22, 0x00, 0x08, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x05, 0x00,
+    ];
+    match error_response(&encoded, &GTPV2Error::MessageInvalidMessageFormat) {
+        ErrorResponse::Reject(GTPV2Message::ModifyBearerResponse(i)) => {
+            assert_eq!(i.header.teid, Some(0));
+            assert_eq!(i.header.sqn, 5);
+            assert_eq!(i.cause.value, CAUSE_INVALID_MESSAGE_FORMAT);
+            assert_eq!(i.cause.offend_ie_type, None);
+        }
+        i => panic!("unexpected {:?}", i),
+    }
+}
+
+#[test]
+fn test_error_response_discard() {
+    let response: [u8; 12] = [
+        0x48, 0x21, 0x00, 0x08, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x05, 0x00,
+    ];
+    assert_eq!(
+        error_response(&response, &GTPV2Error::MessageMandatoryIEMissing(CAUSE)),
+        ErrorResponse::Discard
+    );
+    assert_eq!(
+        error_response(&response[..6], &GTPV2Error::HeaderInvalidLength),
+        ErrorResponse::Discard
+    );
+    assert_eq!(reject_cause(&GTPV2Error::MessageIncorrectMessageType), None);
+}
+
+#[cfg(test)]
+fn test_reject_cause(error: GTPV2Error) -> Option<(u8, Option<u8>)> {
+    reject_cause(&error).map(|x| (x.value, x.offend_ie_type))
+}
+
+#[test]
+fn test_reject_cause_mandatory_ie_incorrect() {
+    assert_eq!(
+        test_reject_cause(GTPV2Error::MessageMandatoryIEIncorrect(RATTYPE)),
+        Some((CAUSE_MANDATORY_IE_INCORRECT, Some(RATTYPE)))
+    );
+    assert_eq!(test_reject_cause(GTPV2Error::IEInvalidLength(FTEID)), None);
+}
+
+#[test]
+fn test_reject_cause_mandatory_ie_missing() {
+    assert_eq!(
+        test_reject_cause(GTPV2Error::MessageMandatoryIEMissing(RATTYPE)),
+        Some((CAUSE_MANDATORY_IE_MISSING, Some(RATTYPE)))
+    );
+}
+
+#[test]
+fn test_reject_cause_invalid_length() {
+    assert_eq!(
+        test_reject_cause(GTPV2Error::MessageInvalidLength(CREATE_SESSION_REQ)),
+        Some((CAUSE_INVALID_LENGTH, None))
+    );
+}
+
+#[test]
+fn test_reject_cause_invalid_message_format() {
+    assert_eq!(
+        test_reject_cause(GTPV2Error::MessageInvalidMessageFormat),
+        Some((CAUSE_INVALID_MESSAGE_FORMAT, None))
+    );
+}
+
+#[test]
+fn test_error_response_ignored() {
+    for error in [
+        GTPV2Error::MessageOptionalIEIncorrect(PCO),
+        GTPV2Error::MessageUnexpectedIE(CAUSE),
+    ] {
+        assert_eq!(reject_cause(&error), None);
+        assert_eq!(
+            error_response(&CREATE_SESSION_REQ_SENDER_FTEID_ONLY, &error),
+            ErrorResponse::Ignore
+        );
+    }
+}
+
+#[cfg(test)]
+use crate::gtpv2::utils::set_msg_length;
+
+#[cfg(test)]
+fn test_create_session_req_with(ie: &[u8]) -> Vec<u8> {
+    let request = CreateSessionRequest {
+        bearer_ctxs: vec![BearerContext::default()],
+        ..CreateSessionRequest::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    request.marshal(&mut buffer);
+    assert!(CreateSessionRequest::unmarshal(&buffer).is_ok());
+    buffer.extend_from_slice(ie);
+    set_msg_length(&mut buffer);
+    buffer
+}
+
+#[test]
+fn test_error_response_incorrect_optional_ie() {
+    // Create Session Request with an empty PCO
+    let buffer = test_create_session_req_with(&[0x4e, 0x00, 0x00, 0x00]);
+    let error = CreateSessionRequest::unmarshal(&buffer).unwrap_err();
+    assert_eq!(error, GTPV2Error::IEInvalidLength(PCO));
+    assert_eq!(
+        classify_error(&buffer, &error),
+        GTPV2Error::MessageOptionalIEIncorrect(PCO)
+    );
+    assert_eq!(error_response(&buffer, &error), ErrorResponse::Ignore);
+}
+
+#[test]
+fn test_error_response_incorrect_mandatory_ie() {
+    // Create Session Request with an empty RAT Type
+    let mut buffer = test_create_session_req_with(&[]);
+    let i = buffer
+        .windows(4)
+        .position(|x| x == [RATTYPE, 0x00, 0x01, 0x00])
+        .unwrap();
+    buffer.splice(i..i + 5, [RATTYPE, 0x00, 0x00, 0x00]);
+    set_msg_length(&mut buffer);
+    let error = CreateSessionRequest::unmarshal(&buffer).unwrap_err();
+    assert_eq!(
+        classify_error(&buffer, &error),
+        GTPV2Error::MessageMandatoryIEIncorrect(RATTYPE)
+    );
+    match error_response(&buffer, &error) {
+        ErrorResponse::Reject(GTPV2Message::CreateSessionResponse(i)) => {
+            assert_eq!(i.cause.value, CAUSE_MANDATORY_IE_INCORRECT);
+            assert_eq!(i.cause.offend_ie_type, Some(RATTYPE));
+        }
+        i => panic!("unexpected {:?}", i),
+    }
+}
@@ -101,11 +101,7 @@ impl Messages for ForwardAccessContextNotification {
     fn fromvec(&mut self, elements: Vec<InformationElement>) -> Result<bool, GTPV2Error> {
         for e in elements.iter() {
             match e {
-                InformationElement::RabContext(j) => {
-                    if j.ins == 0 {
-                        self.rab_ctxs.push(j.clone())
-                    };
-                }
+                InformationElement::RabContext(j) if j.ins == 0 => self.rab_ctxs.push(j.clone()),
                 InformationElement::SrcRncPdcpCtxInfo(j) => {
                     if let (0, true) = (j.ins, self.src_rnc_pdcp_ctx.is_none()) {
                         self.src_rnc_pdcp_ctx = Some(j.clone())
@@ -116,10 +112,8 @@ impl Messages for ForwardAccessContextNotification {
                         self.pdu_numbers = Some(j.clone())
                     };
                 }
-                InformationElement::Fcontainer(j) => {
-                    if j.ins < 2 {
-                        self.eutran_containers.push(j.clone())
-                    };
+                InformationElement::Fcontainer(j) if j.ins < 2 => {
+                    self.eutran_containers.push(j.clone())
                 }
                 InformationElement::PrivateExtension(j) => self.private_ext.push(j.clone()),
                 _ => (),
@@ -104,10 +104,8 @@ impl Messages for ForwardRelocationCompleteAcknowledge {
                         self.recovery = Some(j);
                     }
                 }
-                InformationElement::SecondaryRatUsageDataReport(j) => {
-                    if j.ins < 2 {
-                        self.scnd_rat_udrs.push(j)
-                    }
+                InformationElement::SecondaryRatUsageDataReport(j) if j.ins < 2 => {
+                    self.scnd_rat_udrs.push(j)
                 }
                 InformationElement::PrivateExtension(j) => self.private_ext.push(j),
                 _ => (),
@@ -317,15 +317,11 @@ impl Messages for ForwardRelocationRequest {
                         (_, _, _) => (),
                     }
                 }
-                InformationElement::PdnConnections(j) => {
-                    if j.ins == 0 {
-                        self.ue_eps_pdn.push(*j);
-                    }
+                InformationElement::PdnConnections(j) if j.ins == 0 => {
+                    self.ue_eps_pdn.push(*j);
                 }
-                InformationElement::Fqdn(j) => {
-                    if j.ins < 3 {
-                        self.fqdns.push(j);
-                    }
+                InformationElement::Fqdn(j) if j.ins < 3 => {
+                    self.fqdns.push(j);
                 }
                 InformationElement::MmContext(j) => {
                     if let (0, false) = (j.get_ins(), mandatory[1]) {
@@ -338,25 +334,19 @@ impl Messages for ForwardRelocationRequest {
                         self.indication = Some(j);
                     }
                 }
-                InformationElement::Fcontainer(j) => {
-                    if j.ins < 3 {
-                        self.f_containers.push(j);
-                    }
+                InformationElement::Fcontainer(j) if j.ins < 3 => {
+                    self.f_containers.push(j);
                 }
                 InformationElement::TargetIdentification(j) => {
                     if let (0, true) = (j.ins, self.target_id.is_none()) {
                         self.target_id = Some(j);
                     }
                 }
-                InformationElement::IpAddress(j) => {
-                    if j.ins < 2 {
-                        self.ip_addresses.push(j);
-                    }
+                InformationElement::IpAddress(j) if j.ins < 2 => {
+                    self.ip_addresses.push(j);
                 }
-                InformationElement::Fcause(j) => {
-                    if j.ins < 3 {
-                        self.f_causes.push(j);
-                    }
+                InformationElement::Fcause(j) if j.ins < 3 => {
+                    self.f_causes.push(j);
                 }
                 InformationElement::SourceIdentification(j) => {
                     if let (0, true) = (j.ins, self.source_id.is_none()) {
@@ -378,10 +368,8 @@ impl Messages for ForwardRelocationRequest {
                         self.trace_info = Some(j);
                     }
                 }
-                InformationElement::RfspIndex(j) => {
-                    if j.ins < 2 {
-                        self.rfsp_indexes.push(j);
-                    }
+                InformationElement::RfspIndex(j) if j.ins < 2 => {
+                    self.rfsp_indexes.push(j);
                 }
                 InformationElement::CsgId(j) => {
                     if let (0, true) = (j.ins, self.csg_id.is_none()) {
@@ -423,10 +411,8 @@ impl Messages for ForwardRelocationRequest {
                         self.stnsr = Some(j);
                     }
                 }
-                InformationElement::Msisdn(j) => {
-                    if j.ins < 2 {
-                        self.msisdns.push(j);
-                    }
+                InformationElement::Msisdn(j) if j.ins < 2 => {
+                    self.msisdns.push(j);
                 }
                 InformationElement::MdtConfiguration(j) => {
                     if let (0, true) = (j.ins, self.mdt_cfg.is_none()) {
@@ -438,25 +424,19 @@ impl Messages for ForwardRelocationRequest {
                         self.uci = Some(j);
                     }
                 }
-                InformationElement::MonitoringEventInformation(j) => {
-                    if j.ins == 0 {
-                        self.monitor_event_info.push(j);
-                    }
+                InformationElement::MonitoringEventInformation(j) if j.ins == 0 => {
+                    self.monitor_event_info.push(j);
                 }
-                InformationElement::MonitoringEventExtensionInfo(j) => {
-                    if j.ins == 0 {
-                        self.monitor_even_ext_info.push(j);
-                    }
+                InformationElement::MonitoringEventExtensionInfo(j) if j.ins == 0 => {
+                    self.monitor_even_ext_info.push(j);
                 }
                 InformationElement::IntegerNumber(j) => {
                     if let (0, true) = (j.ins, self.ue_usage_type.is_none()) {
                         self.ue_usage_type = Some(j);
                     }
                 }
-                InformationElement::ScefPdnConnections(j) => {
-                    if j.ins == 0 {
-                        self.scef_pdn_connections.push(j);
-                    }
+                InformationElement::ScefPdnConnections(j) if j.ins == 0 => {
+                    self.scef_pdn_connections.push(j);
                 }
                 InformationElement::PortNumber(j) => {
                     if let (0, true) = (j.ins, self.src_udp_port.is_none()) {
@@ -473,10 +453,8 @@ impl Messages for ForwardRelocationRequest {
                         self.ext_trace_info = Some(j);
                     }
                 }
-                InformationElement::AdditionalRrmPolicyIndex(j) => {
-                    if j.ins < 2 {
-                        self.add_rrm_policy_index.push(j);
-                    }
+                InformationElement::AdditionalRrmPolicyIndex(j) if j.ins < 2 => {
+                    self.add_rrm_policy_index.push(j);
                 }
                 InformationElement::V2xInformation(j) => {
                     if let (0, true) = (j.ins, self.subcr_v2x_info.is_none()) {
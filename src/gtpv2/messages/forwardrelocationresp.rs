@@ -164,20 +164,14 @@ impl Messages for ForwardRelocationResponse {
                         self.indication = Some(j);
                     }
                 }
-                InformationElement::BearerContext(j) => {
-                    if j.ins < 4 {
-                        self.bearer_ctxs.push(j);
-                    }
+                InformationElement::BearerContext(j) if j.ins < 4 => {
+                    self.bearer_ctxs.push(j);
                 }
-                InformationElement::Fcause(j) => {
-                    if j.ins < 3 {
-                        self.f_causes.push(j);
-                    }
+                InformationElement::Fcause(j) if j.ins < 3 => {
+                    self.f_causes.push(j);
                 }
-                InformationElement::Fcontainer(j) => {
-                    if j.ins < 3 {
-                        self.f_containers.push(j);
-                    }
+                InformationElement::Fcontainer(j) if j.ins < 3 => {
+                    self.f_containers.push(j);
                 }
                 InformationElement::Ldn(j) => {
                     if let (0, true) = (j.ins, self.mme_sgsn_ldn.is_none()) {
@@ -191,15 +185,11 @@ impl Messages for ForwardRelocationResponse {
                         _ => (),
                     }
                 }
-                InformationElement::NodeNumber(j) => {
-                    if j.ins < 3 {
-                        self.node_nbrs.push(j);
-                    }
+                InformationElement::NodeNumber(j) if j.ins < 3 => {
+                    self.node_nbrs.push(j);
                 }
-                InformationElement::NodeIdentifier(j) => {
-                    if j.ins < 4 {
-                        self.node_ids.push(j);
-                    }
+                InformationElement::NodeIdentifier(j) if j.ins < 4 => {
+                    self.node_ids.push(j);
                 }
                 InformationElement::SrvccCause(j) => {
                     if let (0, true) = (j.ins, self.srvcc_reject_cause.is_none()) {
@@ -151,15 +151,11 @@ impl Messages for IdentificationResponse {
                         self.ue_usage_type = Some(j.clone());
                     }
                 }
-                InformationElement::MonitoringEventInformation(j) => {
-                    if j.ins == 0 {
-                        self.monitor_event_info.push(j.clone());
-                    }
+                InformationElement::MonitoringEventInformation(j) if j.ins == 0 => {
+                    self.monitor_event_info.push(j.clone());
                 }
-                InformationElement::MonitoringEventExtensionInfo(j) => {
-                    if j.ins == 0 {
-                        self.monitor_even_ext_info.push(j.clone());
-                    }
+                InformationElement::MonitoringEventExtensionInfo(j) if j.ins == 0 => {
+                    self.monitor_even_ext_info.push(j.clone());
                 }
                 InformationElement::ExtendedTraceInformation(j) => {
                     if let (0, true) = (j.ins, self.ext_trace_info.is_none()) {
@@ -1,10 +1,13 @@
 // Additional PCO IE - according to 3GPP TS 29.274 V17.10.0 (2023-12)
 // Additional Protocol Configuration Options information element is specified in 3GPP TS 29.275
 
-use crate::gtpv2::{
-    errors::GTPV2Error,
-    messages::ies::{commons::*, ie::*},
-    utils::*,
+use crate::{
+    commons::ProtocolConfigurationOptions,
+    gtpv2::{
+        errors::GTPV2Error,
+        messages::ies::{commons::*, ie::*},
+        utils::*,
+    },
 };
 
 // Additional PCO IE Type
@@ -32,6 +35,32 @@ impl Default for Apco {
     }
 }
 
+impl Apco {
+    // Decode the APCO octets into typed Protocol Configuration Options
+
+    pub fn protocol_configuration_options(
+        &self,
+    ) -> Result<ProtocolConfigurationOptions, GTPV2Error> {
+        ProtocolConfigurationOptions::unmarshal(&self.apco)
+            .map_err(|_| GTPV2Error::IEIncorrect(APCO))
+    }
+
+    pub fn set_protocol_configuration_options(&mut self, pco: &ProtocolConfigurationOptions) {
+        let mut buffer: Vec<u8> = vec![];
+        pco.marshal(&mut buffer);
+        self.length = buffer.len() as u16;
+        self.apco = buffer;
+    }
+}
+
+impl From<ProtocolConfigurationOptions> for Apco {
+    fn from(i: ProtocolConfigurationOptions) -> Self {
+        let mut data = Apco::default();
+        data.set_protocol_configuration_options(&i);
+        data
+    }
+}
+
 impl From<Apco> for InformationElement {
     fn from(i: Apco) -> Self {
         InformationElement::Apco(i)
@@ -120,3 +149,28 @@ fn apco_ie_unmarshal_test() {
     };
     assert_eq!(Apco::unmarshal(&encoded).unwrap(), decoded);
 }
+
+#[test]
+fn apco_ie_protocol_configuration_options_test() {
+    use crate::commons::*;
+    let encoded: [u8; 11] = [
+        0xa3, 0x00, 0x07, 0x00, 0x80, 0x00, 0x0d, 0x00, 0x00, 0x0c, 0x00,
+    ];
+    let pco = ProtocolConfigurationOptions {
+        configuration_protocol: PCO_CONFIG_PROTOCOL_PPP,
+        containers: vec![
+            PcoContainer::DnsServerIpv4AddressRequest,
+            PcoContainer::PcscfIpv4AddressRequest,
+        ],
+    };
+    let mut buffer: Vec<u8> = vec![];
+    Apco::from(pco.clone()).marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+    assert_eq!(
+        Apco::unmarshal(&encoded)
+            .unwrap()
+            .protocol_configuration_options()
+            .unwrap(),
+        pco
+    );
+}
@@ -1,9 +1,12 @@
 // EPS Bearer Level Traffic Flow Template (TFT) IE - according to 3GPP TS 29.274 V17.10.0 (2023-12)
 
-use crate::gtpv2::{
-    errors::GTPV2Error,
-    messages::ies::{commons::*, ie::*},
-    utils::*,
+use crate::{
+    commons::TrafficFlowTemplate,
+    gtpv2::{
+        errors::GTPV2Error,
+        messages::ies::{commons::*, ie::*},
+        utils::*,
+    },
 };
 
 // EPS Bearer Level Traffic Flow Template (TFT) IE Type
@@ -31,6 +34,29 @@ impl Default for BearerTft {
     }
 }
 
+impl BearerTft {
+    // Decode the TFT octets into a typed Traffic Flow Template
+
+    pub fn traffic_flow_template(&self) -> Result<TrafficFlowTemplate, GTPV2Error> {
+        TrafficFlowTemplate::unmarshal(&self.tft).map_err(|_| GTPV2Error::IEIncorrect(BEARERTFT))
+    }
+
+    pub fn set_traffic_flow_template(&mut self, tft: &TrafficFlowTemplate) {
+        let mut buffer: Vec<u8> = vec![];
+        tft.marshal(&mut buffer);
+        self.length = buffer.len() as u16;
+        self.tft = buffer;
+    }
+}
+
+impl From<TrafficFlowTemplate> for BearerTft {
+    fn from(i: TrafficFlowTemplate) -> Self {
+        let mut data = BearerTft::default();
+        data.set_traffic_flow_template(&i);
+        data
+    }
+}
+
 impl From<BearerTft> for InformationElement {
     fn from(i: BearerTft) -> Self {
         InformationElement::BearerTft(i)
@@ -108,3 +134,35 @@ fn bearertft_ie_unmarshal_test() {
     };
     assert_eq!(BearerTft::unmarshal(&encoded).unwrap(), decoded);
 }
+
+#[test]
+fn bearertft_ie_traffic_flow_template_test() {
+    use crate::commons::*;
+    let encoded: [u8; 13] = [
+        0x54, 0x00, 0x09, 0x00, 0x21, 0x21, 0x00, 0x05, 0x30, 0x11, 0x50, 0x08, 0x68,
+    ];
+    let tft = TrafficFlowTemplate {
+        operation: TftOperationCode::CreateNew,
+        packet_filters: vec![PacketFilter {
+            direction: PacketFilterDirection::Uplink,
+            id: 1,
+            precedence: 0,
+            components: vec![
+                PacketFilterComponent::ProtocolIdentifier(17),
+                PacketFilterComponent::SingleRemotePort(2152),
+            ],
+        }],
+        ..TrafficFlowTemplate::default()
+    };
+    let decoded = BearerTft::from(tft.clone());
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+    assert_eq!(
+        BearerTft::unmarshal(&encoded)
+            .unwrap()
+            .traffic_flow_template()
+            .unwrap(),
+        tft
+    );
+}
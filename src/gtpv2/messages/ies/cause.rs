@@ -5,6 +5,7 @@ use crate::gtpv2::{
     messages::ies::{commons::*, ie::*},
     utils::*,
 };
+use std::fmt::Display;
 
 // Cause IE TL
 
@@ -12,6 +13,509 @@ pub const CAUSE: u8 = 2;
 pub const SHORT_CAUSE_LENGTH: usize = 2;
 pub const LONG_CAUSE_LENGTH: usize = 6;
 
+// Cause values - according to 3GPP TS 29.274 V17.10.0 (2023-12), Table 8.4-1
+
+pub const CAUSE_LOCAL_DETACH: u8 = 2;
+pub const CAUSE_COMPLETE_DETACH: u8 = 3;
+pub const CAUSE_RAT_CHANGED_FROM3GPP_TO_NON3GPP: u8 = 4;
+pub const CAUSE_ISR_DEACTIVATION: u8 = 5;
+pub const CAUSE_ERROR_INDICATION_RECEIVED: u8 = 6;
+pub const CAUSE_IMSI_DETACH_ONLY: u8 = 7;
+pub const CAUSE_REACTIVATION_REQUESTED: u8 = 8;
+pub const CAUSE_PDN_RECONNECTION_DISALLOWED: u8 = 9;
+pub const CAUSE_ACCESS_CHANGED_FROM_NON3GPP_TO3GPP: u8 = 10;
+pub const CAUSE_PDN_CONNECTION_INACTIVITY_TIMER_EXPIRES: u8 = 11;
+pub const CAUSE_PGW_NOT_RESPONDING: u8 = 12;
+pub const CAUSE_NETWORK_FAILURE: u8 = 13;
+pub const CAUSE_QOS_PARAMETER_MISMATCH: u8 = 14;
+pub const CAUSE_EPS_TO5GS_MOBILITY: u8 = 15;
+pub const CAUSE_REQUEST_ACCEPTED: u8 = 16;
+pub const CAUSE_REQUEST_ACCEPTED_PARTIALLY: u8 = 17;
+pub const CAUSE_NEW_PDN_TYPE_NETWORK_PREFERENCE: u8 = 18;
+pub const CAUSE_NEW_PDN_TYPE_SINGLE_ADDRESS_BEARER_ONLY: u8 = 19;
+pub const CAUSE_CONTEXT_NOT_FOUND: u8 = 64;
+pub const CAUSE_INVALID_MESSAGE_FORMAT: u8 = 65;
+pub const CAUSE_VERSION_NOT_SUPPORTED_BY_NEXT_PEER: u8 = 66;
+pub const CAUSE_INVALID_LENGTH: u8 = 67;
+pub const CAUSE_SERVICE_NOT_SUPPORTED: u8 = 68;
+pub const CAUSE_MANDATORY_IE_INCORRECT: u8 = 69;
+pub const CAUSE_MANDATORY_IE_MISSING: u8 = 70;
+pub const CAUSE_SYSTEM_FAILURE: u8 = 72;
+pub const CAUSE_NO_RESOURCES_AVAILABLE: u8 = 73;
+pub const CAUSE_SEMANTIC_ERROR_IN_TFT_OPERATION: u8 = 74;
+pub const CAUSE_SYNTACTIC_ERROR_IN_TFT_OPERATION: u8 = 75;
+pub const CAUSE_SEMANTIC_ERRORS_IN_PACKET_FILTERS: u8 = 76;
+pub const CAUSE_SYNTACTIC_ERRORS_IN_PACKET_FILTERS: u8 = 77;
+pub const CAUSE_MISSING_OR_UNKNOWN_APN: u8 = 78;
+pub const CAUSE_GRE_KEY_NOT_FOUND: u8 = 80;
+pub const CAUSE_RELOCATION_FAILURE: u8 = 81;
+pub const CAUSE_DENIED_IN_RAT: u8 = 82;
+pub const CAUSE_PREFERRED_PDN_TYPE_NOT_SUPPORTED: u8 = 83;
+pub const CAUSE_ALL_DYNAMIC_ADDRESSES_OCCUPIED: u8 = 84;
+pub const CAUSE_UE_CONTEXT_WITHOUT_TFT_ALREADY_ACTIVATED: u8 = 85;
+pub const CAUSE_PROTOCOL_TYPE_NOT_SUPPORTED: u8 = 86;
+pub const CAUSE_UE_NOT_RESPONDING: u8 = 87;
+pub const CAUSE_UE_REFUSES: u8 = 88;
+pub const CAUSE_SERVICE_DENIED: u8 = 89;
+pub const CAUSE_UNABLE_TO_PAGE_UE: u8 = 90;
+pub const CAUSE_NO_MEMORY_AVAILABLE: u8 = 91;
+pub const CAUSE_USER_AUTHENTICATION_FAILED: u8 = 92;
+pub const CAUSE_APN_ACCESS_DENIED_NO_SUBSCRIPTION: u8 = 93;
+pub const CAUSE_REQUEST_REJECTED: u8 = 94;
+pub const CAUSE_PTMSI_SIGNATURE_MISMATCH: u8 = 95;
+pub const CAUSE_IMSI_IMEI_NOT_KNOWN: u8 = 96;
+pub const CAUSE_SEMANTIC_ERROR_IN_TAD_OPERATION: u8 = 97;
+pub const CAUSE_SYNTACTIC_ERROR_IN_TAD_OPERATION: u8 = 98;
+pub const CAUSE_REMOTE_PEER_NOT_RESPONDING: u8 = 100;
+pub const CAUSE_COLLISION_WITH_NETWORK_INITIATED_REQUEST: u8 = 101;
+pub const CAUSE_UNABLE_TO_PAGE_UE_DUE_TO_SUSPENSION: u8 = 102;
+pub const CAUSE_CONDITIONAL_IE_MISSING: u8 = 103;
+pub const CAUSE_APN_RESTRICTION_TYPE_INCOMPATIBLE: u8 = 104;
+pub const CAUSE_INVALID_OVERALL_LENGTH_PIGGYBACKED: u8 = 105;
+pub const CAUSE_DATA_FORWARDING_NOT_SUPPORTED: u8 = 106;
+pub const CAUSE_INVALID_REPLY_FROM_REMOTE_PEER: u8 = 107;
+pub const CAUSE_FALLBACK_TO_GTPV1: u8 = 108;
+pub const CAUSE_INVALID_PEER: u8 = 109;
+pub const CAUSE_TEMPORARILY_REJECTED_HANDOVER_IN_PROGRESS: u8 = 110;
+pub const CAUSE_MODIFICATIONS_NOT_SUPPORTED: u8 = 111;
+pub const CAUSE_REQUEST_REJECTED_PMIPV6_REASON: u8 = 112;
+pub const CAUSE_APN_CONGESTION: u8 = 113;
+pub const CAUSE_BEARER_HANDLING_NOT_SUPPORTED: u8 = 114;
+pub const CAUSE_UE_ALREADY_REATTACHED: u8 = 115;
+pub const CAUSE_MULTIPLE_PDN_CONNECTIONS_NOT_ALLOWED: u8 = 116;
+pub const CAUSE_TARGET_ACCESS_RESTRICTED: u8 = 117;
+pub const CAUSE_REFUSES_DUE_TO_VPLMN_POLICY: u8 = 119;
+pub const CAUSE_GTPC_ENTITY_CONGESTION: u8 = 120;
+pub const CAUSE_LATE_OVERLAPPING_REQUEST: u8 = 121;
+pub const CAUSE_TIMED_OUT_REQUEST: u8 = 122;
+pub const CAUSE_UE_TEMPORARILY_NOT_REACHABLE: u8 = 123;
+pub const CAUSE_RELOCATION_FAILURE_NAS_REDIRECTION: u8 = 124;
+pub const CAUSE_UE_NOT_AUTHORISED_BY_OCS: u8 = 125;
+pub const CAUSE_MULTIPLE_ACCESSES_NOT_ALLOWED: u8 = 126;
+pub const CAUSE_REQUEST_REJECTED_UE_CAPABILITY: u8 = 127;
+pub const CAUSE_S1U_PATH_FAILURE: u8 = 128;
+pub const CAUSE_FIVE_GC_NOT_ALLOWED: u8 = 129;
+pub const CAUSE_PGW_MISMATCH_WITH_NETWORK_SLICE: u8 = 130;
+pub const CAUSE_REJECTION_DUE_TO_PAGING_RESTRICTION: u8 = 131;
+
+// Typed Cause value, unknown and spare values are preserved
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CauseValue {
+    LocalDetach,
+    CompleteDetach,
+    RatChangedFrom3gppToNon3gpp,
+    IsrDeactivation,
+    ErrorIndicationReceived,
+    ImsiDetachOnly,
+    ReactivationRequested,
+    PdnReconnectionDisallowed,
+    AccessChangedFromNon3gppTo3gpp,
+    PdnConnectionInactivityTimerExpires,
+    PgwNotResponding,
+    NetworkFailure,
+    QosParameterMismatch,
+    EpsTo5gsMobility,
+    RequestAccepted,
+    RequestAcceptedPartially,
+    NewPdnTypeNetworkPreference,
+    NewPdnTypeSingleAddressBearerOnly,
+    ContextNotFound,
+    InvalidMessageFormat,
+    VersionNotSupportedByNextPeer,
+    InvalidLength,
+    ServiceNotSupported,
+    MandatoryIeIncorrect,
+    MandatoryIeMissing,
+    SystemFailure,
+    NoResourcesAvailable,
+    SemanticErrorInTftOperation,
+    SyntacticErrorInTftOperation,
+    SemanticErrorsInPacketFilters,
+    SyntacticErrorsInPacketFilters,
+    MissingOrUnknownApn,
+    GreKeyNotFound,
+    RelocationFailure,
+    DeniedInRat,
+    PreferredPdnTypeNotSupported,
+    AllDynamicAddressesOccupied,
+    UeContextWithoutTftAlreadyActivated,
+    ProtocolTypeNotSupported,
+    UeNotResponding,
+    UeRefuses,
+    ServiceDenied,
+    UnableToPageUe,
+    NoMemoryAvailable,
+    UserAuthenticationFailed,
+    ApnAccessDeniedNoSubscription,
+    RequestRejected,
+    PtmsiSignatureMismatch,
+    ImsiImeiNotKnown,
+    SemanticErrorInTadOperation,
+    SyntacticErrorInTadOperation,
+    RemotePeerNotResponding,
+    CollisionWithNetworkInitiatedRequest,
+    UnableToPageUeDueToSuspension,
+    ConditionalIeMissing,
+    ApnRestrictionTypeIncompatible,
+    InvalidOverallLengthPiggybacked,
+    DataForwardingNotSupported,
+    InvalidReplyFromRemotePeer,
+    FallbackToGtpv1,
+    InvalidPeer,
+    TemporarilyRejectedHandoverInProgress,
+    ModificationsNotSupported,
+    RequestRejectedPmipv6Reason,
+    ApnCongestion,
+    BearerHandlingNotSupported,
+    UeAlreadyReattached,
+    MultiplePdnConnectionsNotAllowed,
+    TargetAccessRestricted,
+    RefusesDueToVplmnPolicy,
+    GtpcEntityCongestion,
+    LateOverlappingRequest,
+    TimedOutRequest,
+    UeTemporarilyNotReachable,
+    RelocationFailureNasRedirection,
+    UeNotAuthorisedByOcs,
+    MultipleAccessesNotAllowed,
+    RequestRejectedUeCapability,
+    S1uPathFailure,
+    FiveGcNotAllowed,
+    PgwMismatchWithNetworkSlice,
+    RejectionDueToPagingRestriction,
+    Unknown(u8),
+}
+
+impl From<&CauseValue> for u8 {
+    fn from(i: &CauseValue) -> u8 {
+        match i {
+            CauseValue::LocalDetach => CAUSE_LOCAL_DETACH,
+            CauseValue::CompleteDetach => CAUSE_COMPLETE_DETACH,
+            CauseValue::RatChangedFrom3gppToNon3gpp => CAUSE_RAT_CHANGED_FROM3GPP_TO_NON3GPP,
+            CauseValue::IsrDeactivation => CAUSE_ISR_DEACTIVATION,
+            CauseValue::ErrorIndicationReceived => CAUSE_ERROR_INDICATION_RECEIVED,
+            CauseValue::ImsiDetachOnly => CAUSE_IMSI_DETACH_ONLY,
+            CauseValue::ReactivationRequested => CAUSE_REACTIVATION_REQUESTED,
+            CauseValue::PdnReconnectionDisallowed => CAUSE_PDN_RECONNECTION_DISALLOWED,
+            CauseValue::AccessChangedFromNon3gppTo3gpp => CAUSE_ACCESS_CHANGED_FROM_NON3GPP_TO3GPP,
+            CauseValue::PdnConnectionInactivityTimerExpires => {
+                CAUSE_PDN_CONNECTION_INACTIVITY_TIMER_EXPIRES
+            }
+            CauseValue::PgwNotResponding => CAUSE_PGW_NOT_RESPONDING,
+            CauseValue::NetworkFailure => CAUSE_NETWORK_FAILURE,
+            CauseValue::QosParameterMismatch => CAUSE_QOS_PARAMETER_MISMATCH,
+            CauseValue::EpsTo5gsMobility => CAUSE_EPS_TO5GS_MOBILITY,
+            CauseValue::RequestAccepted => CAUSE_REQUEST_ACCEPTED,
+            CauseValue::RequestAcceptedPartially => CAUSE_REQUEST_ACCEPTED_PARTIALLY,
+            CauseValue::NewPdnTypeNetworkPreference => CAUSE_NEW_PDN_TYPE_NETWORK_PREFERENCE,
+            CauseValue::NewPdnTypeSingleAddressBearerOnly => {
+                CAUSE_NEW_PDN_TYPE_SINGLE_ADDRESS_BEARER_ONLY
+            }
+            CauseValue::ContextNotFound => CAUSE_CONTEXT_NOT_FOUND,
+            CauseValue::InvalidMessageFormat => CAUSE_INVALID_MESSAGE_FORMAT,
+            CauseValue::VersionNotSupportedByNextPeer => CAUSE_VERSION_NOT_SUPPORTED_BY_NEXT_PEER,
+            CauseValue::InvalidLength => CAUSE_INVALID_LENGTH,
+            CauseValue::ServiceNotSupported => CAUSE_SERVICE_NOT_SUPPORTED,
+            CauseValue::MandatoryIeIncorrect => CAUSE_MANDATORY_IE_INCORRECT,
+            CauseValue::MandatoryIeMissing => CAUSE_MANDATORY_IE_MISSING,
+            CauseValue::SystemFailure => CAUSE_SYSTEM_FAILURE,
+            CauseValue::NoResourcesAvailable => CAUSE_NO_RESOURCES_AVAILABLE,
+            CauseValue::SemanticErrorInTftOperation => CAUSE_SEMANTIC_ERROR_IN_TFT_OPERATION,
+            CauseValue::SyntacticErrorInTftOperation => CAUSE_SYNTACTIC_ERROR_IN_TFT_OPERATION,
+            CauseValue::SemanticErrorsInPacketFilters => CAUSE_SEMANTIC_ERRORS_IN_PACKET_FILTERS,
+            CauseValue::SyntacticErrorsInPacketFilters => CAUSE_SYNTACTIC_ERRORS_IN_PACKET_FILTERS,
+            CauseValue::MissingOrUnknownApn => CAUSE_MISSING_OR_UNKNOWN_APN,
+            CauseValue::GreKeyNotFound => CAUSE_GRE_KEY_NOT_FOUND,
+            CauseValue::RelocationFailure => CAUSE_RELOCATION_FAILURE,
+            CauseValue::DeniedInRat => CAUSE_DENIED_IN_RAT,
+            CauseValue::PreferredPdnTypeNotSupported => CAUSE_PREFERRED_PDN_TYPE_NOT_SUPPORTED,
+            CauseValue::AllDynamicAddressesOccupied => CAUSE_ALL_DYNAMIC_ADDRESSES_OCCUPIED,
+            CauseValue::UeContextWithoutTftAlreadyActivated => {
+                CAUSE_UE_CONTEXT_WITHOUT_TFT_ALREADY_ACTIVATED
+            }
+            CauseValue::ProtocolTypeNotSupported => CAUSE_PROTOCOL_TYPE_NOT_SUPPORTED,
+            CauseValue::UeNotResponding => CAUSE_UE_NOT_RESPONDING,
+            CauseValue::UeRefuses => CAUSE_UE_REFUSES,
+            CauseValue::ServiceDenied => CAUSE_SERVICE_DENIED,
+            CauseValue::UnableToPageUe => CAUSE_UNABLE_TO_PAGE_UE,
+            CauseValue::NoMemoryAvailable => CAUSE_NO_MEMORY_AVAILABLE,
+            CauseValue::UserAuthenticationFailed => CAUSE_USER_AUTHENTICATION_FAILED,
+            CauseValue::ApnAccessDeniedNoSubscription => CAUSE_APN_ACCESS_DENIED_NO_SUBSCRIPTION,
+            CauseValue::RequestRejected => CAUSE_REQUEST_REJECTED,
+            CauseValue::PtmsiSignatureMismatch => CAUSE_PTMSI_SIGNATURE_MISMATCH,
+            CauseValue::ImsiImeiNotKnown => CAUSE_IMSI_IMEI_NOT_KNOWN,
+            CauseValue::SemanticErrorInTadOperation => CAUSE_SEMANTIC_ERROR_IN_TAD_OPERATION,
+            CauseValue::SyntacticErrorInTadOperation => CAUSE_SYNTACTIC_ERROR_IN_TAD_OPERATION,
+            CauseValue::RemotePeerNotResponding => CAUSE_REMOTE_PEER_NOT_RESPONDING,
+            CauseValue::CollisionWithNetworkInitiatedRequest => {
+                CAUSE_COLLISION_WITH_NETWORK_INITIATED_REQUEST
+            }
+            CauseValue::UnableToPageUeDueToSuspension => CAUSE_UNABLE_TO_PAGE_UE_DUE_TO_SUSPENSION,
+            CauseValue::ConditionalIeMissing => CAUSE_CONDITIONAL_IE_MISSING,
+            CauseValue::ApnRestrictionTypeIncompatible => CAUSE_APN_RESTRICTION_TYPE_INCOMPATIBLE,
+            CauseValue::InvalidOverallLengthPiggybacked => CAUSE_INVALID_OVERALL_LENGTH_PIGGYBACKED,
+            CauseValue::DataForwardingNotSupported => CAUSE_DATA_FORWARDING_NOT_SUPPORTED,
+            CauseValue::InvalidReplyFromRemotePeer => CAUSE_INVALID_REPLY_FROM_REMOTE_PEER,
+            CauseValue::FallbackToGtpv1 => CAUSE_FALLBACK_TO_GTPV1,
+            CauseValue::InvalidPeer => CAUSE_INVALID_PEER,
+            CauseValue::TemporarilyRejectedHandoverInProgress => {
+                CAUSE_TEMPORARILY_REJECTED_HANDOVER_IN_PROGRESS
+            }
+            CauseValue::ModificationsNotSupported => CAUSE_MODIFICATIONS_NOT_SUPPORTED,
+            CauseValue::RequestRejectedPmipv6Reason => CAUSE_REQUEST_REJECTED_PMIPV6_REASON,
+            CauseValue::ApnCongestion => CAUSE_APN_CONGESTION,
+            CauseValue::BearerHandlingNotSupported => CAUSE_BEARER_HANDLING_NOT_SUPPORTED,
+            CauseValue::UeAlreadyReattached => CAUSE_UE_ALREADY_REATTACHED,
+            CauseValue::MultiplePdnConnectionsNotAllowed => {
+                CAUSE_MULTIPLE_PDN_CONNECTIONS_NOT_ALLOWED
+            }
+            CauseValue::TargetAccessRestricted => CAUSE_TARGET_ACCESS_RESTRICTED,
+            CauseValue::RefusesDueToVplmnPolicy => CAUSE_REFUSES_DUE_TO_VPLMN_POLICY,
+            CauseValue::GtpcEntityCongestion => CAUSE_GTPC_ENTITY_CONGESTION,
+            CauseValue::LateOverlappingRequest => CAUSE_LATE_OVERLAPPING_REQUEST,
+            CauseValue::TimedOutRequest => CAUSE_TIMED_OUT_REQUEST,
+            CauseValue::UeTemporarilyNotReachable => CAUSE_UE_TEMPORARILY_NOT_REACHABLE,
+            CauseValue::RelocationFailureNasRedirection => CAUSE_RELOCATION_FAILURE_NAS_REDIRECTION,
+            CauseValue::UeNotAuthorisedByOcs => CAUSE_UE_NOT_AUTHORISED_BY_OCS,
+            CauseValue::MultipleAccessesNotAllowed => CAUSE_MULTIPLE_ACCESSES_NOT_ALLOWED,
+            CauseValue::RequestRejectedUeCapability => CAUSE_REQUEST_REJECTED_UE_CAPABILITY,
+            CauseValue::S1uPathFailure => CAUSE_S1U_PATH_FAILURE,
+            CauseValue::FiveGcNotAllowed => CAUSE_FIVE_GC_NOT_ALLOWED,
+            CauseValue::PgwMismatchWithNetworkSlice => CAUSE_PGW_MISMATCH_WITH_NETWORK_SLICE,
+            CauseValue::RejectionDueToPagingRestriction => {
+                CAUSE_REJECTION_DUE_TO_PAGING_RESTRICTION
+            }
+            CauseValue::Unknown(j) => *j,
+        }
+    }
+}
+
+impl From<u8> for CauseValue {
+    fn from(i: u8) -> CauseValue {
+        match i {
+            CAUSE_LOCAL_DETACH => CauseValue::LocalDetach,
+            CAUSE_COMPLETE_DETACH => CauseValue::CompleteDetach,
+            CAUSE_RAT_CHANGED_FROM3GPP_TO_NON3GPP => CauseValue::RatChangedFrom3gppToNon3gpp,
+            CAUSE_ISR_DEACTIVATION => CauseValue::IsrDeactivation,
+            CAUSE_ERROR_INDICATION_RECEIVED => CauseValue::ErrorIndicationReceived,
+            CAUSE_IMSI_DETACH_ONLY => CauseValue::ImsiDetachOnly,
+            CAUSE_REACTIVATION_REQUESTED => CauseValue::ReactivationRequested,
+            CAUSE_PDN_RECONNECTION_DISALLOWED => CauseValue::PdnReconnectionDisallowed,
+            CAUSE_ACCESS_CHANGED_FROM_NON3GPP_TO3GPP => CauseValue::AccessChangedFromNon3gppTo3gpp,
+            CAUSE_PDN_CONNECTION_INACTIVITY_TIMER_EXPIRES => {
+                CauseValue::PdnConnectionInactivityTimerExpires
+            }
+            CAUSE_PGW_NOT_RESPONDING => CauseValue::PgwNotResponding,
+            CAUSE_NETWORK_FAILURE => CauseValue::NetworkFailure,
+            CAUSE_QOS_PARAMETER_MISMATCH => CauseValue::QosParameterMismatch,
+            CAUSE_EPS_TO5GS_MOBILITY => CauseValue::EpsTo5gsMobility,
+            CAUSE_REQUEST_ACCEPTED => CauseValue::RequestAccepted,
+            CAUSE_REQUEST_ACCEPTED_PARTIALLY => CauseValue::RequestAcceptedPartially,
+            CAUSE_NEW_PDN_TYPE_NETWORK_PREFERENCE => CauseValue::NewPdnTypeNetworkPreference,
+            CAUSE_NEW_PDN_TYPE_SINGLE_ADDRESS_BEARER_ONLY => {
+                CauseValue::NewPdnTypeSingleAddressBearerOnly
+            }
+            CAUSE_CONTEXT_NOT_FOUND => CauseValue::ContextNotFound,
+            CAUSE_INVALID_MESSAGE_FORMAT => CauseValue::InvalidMessageFormat,
+            CAUSE_VERSION_NOT_SUPPORTED_BY_NEXT_PEER => CauseValue::VersionNotSupportedByNextPeer,
+            CAUSE_INVALID_LENGTH => CauseValue::InvalidLength,
+            CAUSE_SERVICE_NOT_SUPPORTED => CauseValue::ServiceNotSupported,
+            CAUSE_MANDATORY_IE_INCORRECT => CauseValue::MandatoryIeIncorrect,
+            CAUSE_MANDATORY_IE_MISSING => CauseValue::MandatoryIeMissing,
+            CAUSE_SYSTEM_FAILURE => CauseValue::SystemFailure,
+            CAUSE_NO_RESOURCES_AVAILABLE => CauseValue::NoResourcesAvailable,
+            CAUSE_SEMANTIC_ERROR_IN_TFT_OPERATION => CauseValue::SemanticErrorInTftOperation,
+            CAUSE_SYNTACTIC_ERROR_IN_TFT_OPERATION => CauseValue::SyntacticErrorInTftOperation,
+            CAUSE_SEMANTIC_ERRORS_IN_PACKET_FILTERS => CauseValue::SemanticErrorsInPacketFilters,
+            CAUSE_SYNTACTIC_ERRORS_IN_PACKET_FILTERS => CauseValue::SyntacticErrorsInPacketFilters,
+            CAUSE_MISSING_OR_UNKNOWN_APN => CauseValue::MissingOrUnknownApn,
+            CAUSE_GRE_KEY_NOT_FOUND => CauseValue::GreKeyNotFound,
+            CAUSE_RELOCATION_FAILURE => CauseValue::RelocationFailure,
+            CAUSE_DENIED_IN_RAT => CauseValue::DeniedInRat,
+            CAUSE_PREFERRED_PDN_TYPE_NOT_SUPPORTED => CauseValue::PreferredPdnTypeNotSupported,
+            CAUSE_ALL_DYNAMIC_ADDRESSES_OCCUPIED => CauseValue::AllDynamicAddressesOccupied,
+            CAUSE_UE_CONTEXT_WITHOUT_TFT_ALREADY_ACTIVATED => {
+                CauseValue::UeContextWithoutTftAlreadyActivated
+            }
+            CAUSE_PROTOCOL_TYPE_NOT_SUPPORTED => CauseValue::ProtocolTypeNotSupported,
+            CAUSE_UE_NOT_RESPONDING => CauseValue::UeNotResponding,
+            CAUSE_UE_REFUSES => CauseValue::UeRefuses,
+            CAUSE_SERVICE_DENIED => CauseValue::ServiceDenied,
+            CAUSE_UNABLE_TO_PAGE_UE => CauseValue::UnableToPageUe,
+            CAUSE_NO_MEMORY_AVAILABLE => CauseValue::NoMemoryAvailable,
+            CAUSE_USER_AUTHENTICATION_FAILED => CauseValue::UserAuthenticationFailed,
+            CAUSE_APN_ACCESS_DENIED_NO_SUBSCRIPTION => CauseValue::ApnAccessDeniedNoSubscription,
+            CAUSE_REQUEST_REJECTED => CauseValue::RequestRejected,
+            CAUSE_PTMSI_SIGNATURE_MISMATCH => CauseValue::PtmsiSignatureMismatch,
+            CAUSE_IMSI_IMEI_NOT_KNOWN => CauseValue::ImsiImeiNotKnown,
+            CAUSE_SEMANTIC_ERROR_IN_TAD_OPERATION => CauseValue::SemanticErrorInTadOperation,
+            CAUSE_SYNTACTIC_ERROR_IN_TAD_OPERATION => CauseValue::SyntacticErrorInTadOperation,
+            CAUSE_REMOTE_PEER_NOT_RESPONDING => CauseValue::RemotePeerNotResponding,
+            CAUSE_COLLISION_WITH_NETWORK_INITIATED_REQUEST => {
+                CauseValue::CollisionWithNetworkInitiatedRequest
+            }
+            CAUSE_UNABLE_TO_PAGE_UE_DUE_TO_SUSPENSION => CauseValue::UnableToPageUeDueToSuspension,
+            CAUSE_CONDITIONAL_IE_MISSING => CauseValue::ConditionalIeMissing,
+            CAUSE_APN_RESTRICTION_TYPE_INCOMPATIBLE => CauseValue::ApnRestrictionTypeIncompatible,
+            CAUSE_INVALID_OVERALL_LENGTH_PIGGYBACKED => CauseValue::InvalidOverallLengthPiggybacked,
+            CAUSE_DATA_FORWARDING_NOT_SUPPORTED => CauseValue::DataForwardingNotSupported,
+            CAUSE_INVALID_REPLY_FROM_REMOTE_PEER => CauseValue::InvalidReplyFromRemotePeer,
+            CAUSE_FALLBACK_TO_GTPV1 => CauseValue::FallbackToGtpv1,
+            CAUSE_INVALID_PEER => CauseValue::InvalidPeer,
+            CAUSE_TEMPORARILY_REJECTED_HANDOVER_IN_PROGRESS => {
+                CauseValue::TemporarilyRejectedHandoverInProgress
+            }
+            CAUSE_MODIFICATIONS_NOT_SUPPORTED => CauseValue::ModificationsNotSupported,
+            CAUSE_REQUEST_REJECTED_PMIPV6_REASON => CauseValue::RequestRejectedPmipv6Reason,
+            CAUSE_APN_CONGESTION => CauseValue::ApnCongestion,
+            CAUSE_BEARER_HANDLING_NOT_SUPPORTED => CauseValue::BearerHandlingNotSupported,
+            CAUSE_UE_ALREADY_REATTACHED => CauseValue::UeAlreadyReattached,
+            CAUSE_MULTIPLE_PDN_CONNECTIONS_NOT_ALLOWED => {
+                CauseValue::MultiplePdnConnectionsNotAllowed
+            }
+            CAUSE_TARGET_ACCESS_RESTRICTED => CauseValue::TargetAccessRestricted,
+            CAUSE_REFUSES_DUE_TO_VPLMN_POLICY => CauseValue::RefusesDueToVplmnPolicy,
+            CAUSE_GTPC_ENTITY_CONGESTION => CauseValue::GtpcEntityCongestion,
+            CAUSE_LATE_OVERLAPPING_REQUEST => CauseValue::LateOverlappingRequest,
+            CAUSE_TIMED_OUT_REQUEST => CauseValue::TimedOutRequest,
+            CAUSE_UE_TEMPORARILY_NOT_REACHABLE => CauseValue::UeTemporarilyNotReachable,
+            CAUSE_RELOCATION_FAILURE_NAS_REDIRECTION => CauseValue::RelocationFailureNasRedirection,
+            CAUSE_UE_NOT_AUTHORISED_BY_OCS => CauseValue::UeNotAuthorisedByOcs,
+            CAUSE_MULTIPLE_ACCESSES_NOT_ALLOWED => CauseValue::MultipleAccessesNotAllowed,
+            CAUSE_REQUEST_REJECTED_UE_CAPABILITY => CauseValue::RequestRejectedUeCapability,
+            CAUSE_S1U_PATH_FAILURE => CauseValue::S1uPathFailure,
+            CAUSE_FIVE_GC_NOT_ALLOWED => CauseValue::FiveGcNotAllowed,
+            CAUSE_PGW_MISMATCH_WITH_NETWORK_SLICE => CauseValue::PgwMismatchWithNetworkSlice,
+            CAUSE_REJECTION_DUE_TO_PAGING_RESTRICTION => {
+                CauseValue::RejectionDueToPagingRestriction
+            }
+            j => CauseValue::Unknown(j),
+        }
+    }
+}
+
+impl CauseValue {
+    // Cause values 0-15 are used in request/initial messages
+
+    pub fn is_request_initial_value(&self) -> bool {
+        u8::from(self) < CAUSE_REQUEST_ACCEPTED
+    }
+
+    // Cause values 16-63 indicate acceptance in a response/triggered message
+
+    pub fn is_accepted(&self) -> bool {
+        (CAUSE_REQUEST_ACCEPTED..CAUSE_CONTEXT_NOT_FOUND).contains(&u8::from(self))
+    }
+
+    // Acceptance values by which only part of the request was granted
+
+    pub fn is_partially_accepted(&self) -> bool {
+        matches!(
+            self,
+            CauseValue::RequestAcceptedPartially
+                | CauseValue::NewPdnTypeNetworkPreference
+                | CauseValue::NewPdnTypeSingleAddressBearerOnly
+        )
+    }
+
+    // Cause values 64-239 indicate rejection in a response/triggered message, 240-255 are spare
+
+    pub fn is_rejection(&self) -> bool {
+        (CAUSE_CONTEXT_NOT_FOUND..=239).contains(&u8::from(self))
+    }
+}
+
+impl Display for CauseValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CauseValue::LocalDetach => write!(f, "Local Detach"),
+            CauseValue::CompleteDetach => write!(f, "Complete Detach"),
+            CauseValue::RatChangedFrom3gppToNon3gpp => write!(f, "RAT changed from 3GPP to Non-3GPP"),
+            CauseValue::IsrDeactivation => write!(f, "ISR deactivation"),
+            CauseValue::ErrorIndicationReceived => write!(f, "Error Indication received from RNC/eNodeB/S4-SGSN/MME"),
+            CauseValue::ImsiDetachOnly => write!(f, "IMSI Detach Only"),
+            CauseValue::ReactivationRequested => write!(f, "Reactivation Requested"),
+            CauseValue::PdnReconnectionDisallowed => write!(f, "PDN reconnection to this APN disallowed"),
+            CauseValue::AccessChangedFromNon3gppTo3gpp => write!(f, "Access changed from Non-3GPP to 3GPP"),
+            CauseValue::PdnConnectionInactivityTimerExpires => write!(f, "PDN connection inactivity timer expires"),
+            CauseValue::PgwNotResponding => write!(f, "PGW not responding"),
+            CauseValue::NetworkFailure => write!(f, "Network Failure"),
+            CauseValue::QosParameterMismatch => write!(f, "QoS parameter mismatch"),
+            CauseValue::EpsTo5gsMobility => write!(f, "EPS to 5GS Mobility"),
+            CauseValue::RequestAccepted => write!(f, "Request accepted"),
+            CauseValue::RequestAcceptedPartially => write!(f, "Request accepted partially"),
+            CauseValue::NewPdnTypeNetworkPreference => write!(f, "New PDN type due to network preference"),
+            CauseValue::NewPdnTypeSingleAddressBearerOnly => write!(f, "New PDN type due to single address bearer only"),
+            CauseValue::ContextNotFound => write!(f, "Context Not Found"),
+            CauseValue::InvalidMessageFormat => write!(f, "Invalid Message Format"),
+            CauseValue::VersionNotSupportedByNextPeer => write!(f, "Version not supported by next peer"),
+            CauseValue::InvalidLength => write!(f, "Invalid length"),
+            CauseValue::ServiceNotSupported => write!(f, "Service not supported"),
+            CauseValue::MandatoryIeIncorrect => write!(f, "Mandatory IE incorrect"),
+            CauseValue::MandatoryIeMissing => write!(f, "Mandatory IE missing"),
+            CauseValue::SystemFailure => write!(f, "System failure"),
+            CauseValue::NoResourcesAvailable => write!(f, "No resources available"),
+            CauseValue::SemanticErrorInTftOperation => write!(f, "Semantic error in the TFT operation"),
+            CauseValue::SyntacticErrorInTftOperation => write!(f, "Syntactic error in the TFT operation"),
+            CauseValue::SemanticErrorsInPacketFilters => write!(f, "Semantic errors in packet filter(s)"),
+            CauseValue::SyntacticErrorsInPacketFilters => write!(f, "Syntactic errors in packet filter(s)"),
+            CauseValue::MissingOrUnknownApn => write!(f, "Missing or unknown APN"),
+            CauseValue::GreKeyNotFound => write!(f, "GRE key not found"),
+            CauseValue::RelocationFailure => write!(f, "Relocation failure"),
+            CauseValue::DeniedInRat => write!(f, "Denied in RAT"),
+            CauseValue::PreferredPdnTypeNotSupported => write!(f, "Preferred PDN type not supported"),
+            CauseValue::AllDynamicAddressesOccupied => write!(f, "All dynamic addresses are occupied"),
+            CauseValue::UeContextWithoutTftAlreadyActivated => write!(f, "UE context without TFT already activated"),
+            CauseValue::ProtocolTypeNotSupported => write!(f, "Protocol type not supported"),
+            CauseValue::UeNotResponding => write!(f, "UE not responding"),
+            CauseValue::UeRefuses => write!(f, "UE refuses"),
+            CauseValue::ServiceDenied => write!(f, "Service denied"),
+            CauseValue::UnableToPageUe => write!(f, "Unable to page UE"),
+            CauseValue::NoMemoryAvailable => write!(f, "No memory available"),
+            CauseValue::UserAuthenticationFailed => write!(f, "User authentication failed"),
+            CauseValue::ApnAccessDeniedNoSubscription => write!(f, "APN access denied - no subscription"),
+            CauseValue::RequestRejected => write!(f, "Request rejected (reason not specified)"),
+            CauseValue::PtmsiSignatureMismatch => write!(f, "P-TMSI Signature mismatch"),
+            CauseValue::ImsiImeiNotKnown => write!(f, "IMSI/IMEI not known"),
+            CauseValue::SemanticErrorInTadOperation => write!(f, "Semantic error in the TAD operation"),
+            CauseValue::SyntacticErrorInTadOperation => write!(f, "Syntactic error in the TAD operation"),
+            CauseValue::RemotePeerNotResponding => write!(f, "Remote peer not responding"),
+            CauseValue::CollisionWithNetworkInitiatedRequest => write!(f, "Collision with network initiated request"),
+            CauseValue::UnableToPageUeDueToSuspension => write!(f, "Unable to page UE due to Suspension"),
+            CauseValue::ConditionalIeMissing => write!(f, "Conditional IE missing"),
+            CauseValue::ApnRestrictionTypeIncompatible => write!(f, "APN Restriction type Incompatible with currently active PDN connection"),
+            CauseValue::InvalidOverallLengthPiggybacked => write!(f, "Invalid overall length of the triggered response message and a piggybacked initial message"),
+            CauseValue::DataForwardingNotSupported => write!(f, "Data forwarding not supported"),
+            CauseValue::InvalidReplyFromRemotePeer => write!(f, "Invalid reply from remote peer"),
+            CauseValue::FallbackToGtpv1 => write!(f, "Fallback to GTPv1"),
+            CauseValue::InvalidPeer => write!(f, "Invalid peer"),
+            CauseValue::TemporarilyRejectedHandoverInProgress => write!(f, "Temporarily rejected due to handover/TAU/RAU procedure in progress"),
+            CauseValue::ModificationsNotSupported => write!(f, "Modifications not supported"),
+            CauseValue::RequestRejectedPmipv6Reason => write!(f, "Request rejected for a PMIPv6 reason"),
+            CauseValue::ApnCongestion => write!(f, "APN Congestion"),
+            CauseValue::BearerHandlingNotSupported => write!(f, "Bearer handling not supported"),
+            CauseValue::UeAlreadyReattached => write!(f, "UE already re-attached"),
+            CauseValue::MultiplePdnConnectionsNotAllowed => write!(f, "Multiple PDN connections for a given APN not allowed"),
+            CauseValue::TargetAccessRestricted => write!(f, "Target access restricted for the subscriber"),
+            CauseValue::RefusesDueToVplmnPolicy => write!(f, "MME/SGSN refuses due to VPLMN Policy"),
+            CauseValue::GtpcEntityCongestion => write!(f, "GTP-C Entity Congestion"),
+            CauseValue::LateOverlappingRequest => write!(f, "Late Overlapping Request"),
+            CauseValue::TimedOutRequest => write!(f, "Timed out Request"),
+            CauseValue::UeTemporarilyNotReachable => write!(f, "UE is temporarily not reachable due to power saving"),
+            CauseValue::RelocationFailureNasRedirection => write!(f, "Relocation failure due to NAS message redirection"),
+            CauseValue::UeNotAuthorisedByOcs => write!(f, "UE not authorised by OCS or external AAA Server"),
+            CauseValue::MultipleAccessesNotAllowed => write!(f, "Multiple accesses to a PDN connection not allowed"),
+            CauseValue::RequestRejectedUeCapability => write!(f, "Request rejected due to UE capability"),
+            CauseValue::S1uPathFailure => write!(f, "S1-U Path Failure"),
+            CauseValue::FiveGcNotAllowed => write!(f, "5GC not allowed"),
+            CauseValue::PgwMismatchWithNetworkSlice => write!(f, "PGW mismatch with network slice subscribed by the UE"),
+            CauseValue::RejectionDueToPagingRestriction => write!(f, "Rejection due to paging restriction"),
+            CauseValue::Unknown(i) => write!(f, "Unknown cause value {}", i),
+        }
+    }
+}
+
 // Cause IE implementation
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -41,6 +545,25 @@ impl Default for Cause {
     }
 }
 
+impl Cause {
+    pub fn cause_value(&self) -> CauseValue {
+        CauseValue::from(self.value)
+    }
+
+    pub fn set_cause_value(&mut self, value: CauseValue) {
+        self.value = u8::from(&value);
+    }
+}
+
+impl From<CauseValue> for Cause {
+    fn from(i: CauseValue) -> Self {
+        Cause {
+            value: u8::from(&i),
+            ..Cause::default()
+        }
+    }
+}
+
 impl From<Cause> for InformationElement {
     fn from(i: Cause) -> Self {
         InformationElement::Cause(i)
@@ -212,3 +735,46 @@ fn cause_ie_too_incorrect_length_too_small_unmarshal_test() {
         Err(GTPV2Error::IEIncorrect(CAUSE))
     );
 }
+
+#[test]
+fn cause_value_conversion_test() {
+    for i in 0..=255u8 {
+        assert_eq!(u8::from(&CauseValue::from(i)), i);
+    }
+    assert_eq!(CauseValue::from(16), CauseValue::RequestAccepted);
+    assert_eq!(CauseValue::from(100), CauseValue::RemotePeerNotResponding);
+    assert_eq!(CauseValue::from(71), CauseValue::Unknown(71));
+    assert_eq!(
+        Cause::from(CauseValue::MandatoryIeMissing).cause_value(),
+        CauseValue::MandatoryIeMissing
+    );
+}
+
+#[test]
+fn cause_value_classification_test() {
+    assert!(CauseValue::LocalDetach.is_request_initial_value());
+    assert!(!CauseValue::LocalDetach.is_accepted());
+    assert!(CauseValue::RequestAccepted.is_accepted());
+    assert!(!CauseValue::RequestAccepted.is_partially_accepted());
+    assert!(CauseValue::RequestAcceptedPartially.is_accepted());
+    assert!(CauseValue::RequestAcceptedPartially.is_partially_accepted());
+    assert!(CauseValue::Unknown(40).is_accepted());
+    assert!(CauseValue::ContextNotFound.is_rejection());
+    assert!(CauseValue::Unknown(239).is_rejection());
+    assert!(!CauseValue::Unknown(240).is_rejection());
+    assert!(!CauseValue::Unknown(255).is_rejection());
+    assert!(!CauseValue::NoResourcesAvailable.is_accepted());
+}
+
+#[test]
+fn cause_value_display_test() {
+    assert_eq!(CauseValue::RequestAccepted.to_string(), "Request accepted");
+    assert_eq!(
+        CauseValue::MandatoryIeMissing.to_string(),
+        "Mandatory IE missing"
+    );
+    assert_eq!(
+        CauseValue::Unknown(200).to_string(),
+        "Unknown cause value 200"
+    );
+}
@@ -1,10 +1,13 @@
 // Extended PCO IE - according to 3GPP TS 29.274 V17.10.0 (2023-12)
 // Extended Protocol Configuration Options information element is specified as per clause 9.9.4.26 of 3GPP TS 24.301
 
-use crate::gtpv2::{
-    errors::GTPV2Error,
-    messages::ies::{commons::*, ie::*},
-    utils::*,
+use crate::{
+    commons::ProtocolConfigurationOptions,
+    gtpv2::{
+        errors::GTPV2Error,
+        messages::ies::{commons::*, ie::*},
+        utils::*,
+    },
 };
 
 // Extended PCO IE Type
@@ -32,6 +35,32 @@ impl Default for Epco {
     }
 }
 
+impl Epco {
+    // Decode the ePCO octets (2-octet container length) into typed Protocol Configuration Options
+
+    pub fn protocol_configuration_options(
+        &self,
+    ) -> Result<ProtocolConfigurationOptions, GTPV2Error> {
+        ProtocolConfigurationOptions::unmarshal_extended(&self.epco)
+            .map_err(|_| GTPV2Error::IEIncorrect(EPCO))
+    }
+
+    pub fn set_protocol_configuration_options(&mut self, pco: &ProtocolConfigurationOptions) {
+        let mut buffer: Vec<u8> = vec![];
+        pco.marshal_extended(&mut buffer);
+        self.length = buffer.len() as u16;
+        self.epco = buffer;
+    }
+}
+
+impl From<ProtocolConfigurationOptions> for Epco {
+    fn from(i: ProtocolConfigurationOptions) -> Self {
+        let mut data = Epco::default();
+        data.set_protocol_configuration_options(&i);
+        data
+    }
+}
+
 impl From<Epco> for InformationElement {
     fn from(i: Epco) -> Self {
         InformationElement::Epco(i)
@@ -120,3 +149,28 @@ fn apco_ie_unmarshal_test() {
     };
     assert_eq!(Epco::unmarshal(&encoded).unwrap(), decoded);
 }
+
+#[test]
+fn epco_ie_protocol_configuration_options_test() {
+    use crate::commons::*;
+    use std::net::Ipv4Addr;
+    let encoded: [u8; 13] = [
+        0xc5, 0x00, 0x09, 0x00, 0x80, 0x00, 0x0d, 0x00, 0x04, 0x08, 0x08, 0x08, 0x08,
+    ];
+    let pco = ProtocolConfigurationOptions {
+        configuration_protocol: PCO_CONFIG_PROTOCOL_PPP,
+        containers: vec![PcoContainer::DnsServerIpv4Address(Ipv4Addr::new(
+            8, 8, 8, 8,
+        ))],
+    };
+    let mut buffer: Vec<u8> = vec![];
+    Epco::from(pco.clone()).marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+    assert_eq!(
+        Epco::unmarshal(&encoded)
+            .unwrap()
+            .protocol_configuration_options()
+            .unwrap(),
+        pco
+    );
+}
@@ -49,40 +49,26 @@ impl IEs for HeaderCompressionConfiguration {
         let mut i: u8 = 0;
         for profile in &self.rohc_profiles {
             match profile {
-                0x0002 => {
-                    if (i & 0b00000001) == 0b0 {
-                        i += 0b00000001;
-                    }
+                0x0002 if (i & 0b00000001) == 0b0 => {
+                    i += 0b00000001;
                 }
-                0x0003 => {
-                    if (i & 0b00000010) == 0b0 {
-                        i += 0b00000010;
-                    }
+                0x0003 if (i & 0b00000010) == 0b0 => {
+                    i += 0b00000010;
                 }
-                0x0004 => {
-                    if (i & 0b00000100) == 0b0 {
-                        i += 0b00000100;
-                    }
+                0x0004 if (i & 0b00000100) == 0b0 => {
+                    i += 0b00000100;
                 }
-                0x0006 => {
-                    if (i & 0b00001000) == 0b0 {
-                        i += 0b00001000;
-                    }
+                0x0006 if (i & 0b00001000) == 0b0 => {
+                    i += 0b00001000;
                 }
-                0x0102 => {
-                    if (i & 0b00010000) == 0b0 {
-                        i += 0b00010000;
-                    }
+                0x0102 if (i & 0b00010000) == 0b0 => {
+                    i += 0b00010000;
                 }
-                0x0103 => {
-                    if (i & 0b00100000) == 0b0 {
-                        i += 0b00100000;
-                    }
+                0x0103 if (i & 0b00100000) == 0b0 => {
+                    i += 0b00100000;
                 }
-                0x0104 => {
-                    if (i & 0b01000000) == 0b0 {
-                        i += 0b01000000;
-                    }
+                0x0104 if (i & 0b01000000) == 0b0 => {
+                    i += 0b01000000;
                 }
                 _ => (),
             }
@@ -487,727 +487,736 @@ impl InformationElement {
             if cursor >= buffer.len() {
                 break;
             }
+            // The IE header and the announced IE length have to fit in the buffer
+            if buffer.len() < cursor + MIN_IE_SIZE
+                || buffer.len()
+                    < cursor
+                        + MIN_IE_SIZE
+                        + u16::from_be_bytes([buffer[cursor + 1], buffer[cursor + 2]]) as usize
+            {
+                return Err(GTPV2Error::IEInvalidLength(buffer[cursor]));
+            }
             match buffer[cursor] {
                 1 => match Imsi::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Imsi(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 2 => match Cause::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Cause(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 3 => match Recovery::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Recovery(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 51 => match StnSr::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::StnSr(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 56 => match SrvccCause::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::SrvccCause(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 71 => match Apn::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Apn(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 72 => match Ambr::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::ApnAmbr(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 73 => match Ebi::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Ebi(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 74 => match IpAddress::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::IpAddress(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 75 => match Mei::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Mei(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 76 => match Msisdn::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Msisdn(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 77 => match Indication::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Indication(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 78 => match Pco::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Pco(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 79 => match PdnAddressAllocation::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::PdnAddressAllocation(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 80 => match BearerQos::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::BearerQos(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 81 => match FlowQos::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::FlowQos(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 82 => match RatType::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::RatType(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 83 => match ServingNetwork::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::ServingNetwork(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 84 => match BearerTft::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::BearerTft(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 85 => match TrafficAggregateDescription::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::TrafficAggregateDescription(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 86 => match Uli::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Uli(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 87 => match Fteid::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Fteid(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 88 => match Tmsi::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Tmsi(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 89 => match GlobalCnId::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::GlobalCnId(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 90 => match S103pdf::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::S103pdf(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 91 => match S1udf::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::S1udf(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 92 => match DelayValue::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::DelayValue(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 93 => match BearerContext::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::BearerContext(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 94 => match ChargingId::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::ChargingId(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 95 => match ChargingCharacteristics::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::ChargingCharacteristics(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 96 => match TraceInformation::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::TraceInformation(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 97 => match BearerFlags::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::BearerFlags(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 99 => match PdnType::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::PdnType(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 100 => match Pti::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Pti(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 103..=108 => match MmContext::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::MmContext(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 109 => match PdnConnections::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::PdnConnections(Box::new(i)));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 110 => match PduNumbers::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::PduNumbers(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 111 => match Ptmsi::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Ptmsi(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 112 => match PtmsiSignature::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::PtmsiSignature(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 113 => match HopCounter::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::HopCounter(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 114 => match UeTimeZone::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::UeTimeZone(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 115 => match TraceReference::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 116 => match CompleteRequestMessage::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 117 => match Guti::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 118 => match Fcontainer::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 119 => match Fcause::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 120 => match PlmnId::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 121 => match TargetIdentification::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 123 => match PacketFlowId::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 124 => match RabContext::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 125 => match SourceRncPdcpContextInfo::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 126 => match PortNumber::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 127 => match ApnRestriction::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 128 => match SelectionMode::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 129 => match SourceIdentification::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 131 => match ChangeReportingAction::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 132 => match Fqcsid::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 133 => match ChannelNeeded::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 134 => match EmlppPriority::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 135 => match NodeType::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 136 => match Fqdn::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 137 => match TransactionIdentifier::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 138 => match MbmsSessionDuration::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::MbmsSd(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 139 => match MbmsServiceArea::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::MbmsSa(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 140 => match MbmsSessionId::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::MbmsSessionId(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 141 => match MbmsFlowId::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::MbmsFlowId(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 142 => match MbmsIpMulticastDistribution::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::MbmsIpMulticastDistribution(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 143 => match MbmsDistributionAck::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::MbmsDistributionAck(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 144 => match RfspIndex::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 145 => match Uci::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 146 => match CSGInformationReportingAction::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 147 => match CsgId::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 148 => match CsgMembershipIndication::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 149 => match ServiceIndicator::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 150 => match DetachType::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 151 => match Ldn::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 152 => match NodeFeatures::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 153 => match MbmsTimeToDataTransfer::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::MbmsTimeToDataTransfer(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 154 => match Throttling::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 155 => match Arp::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 156 => match EpcTimer::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 157 => match Spi::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 158 => match Tmgi::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 159 => match AdditionalMmContextForSrvcc::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::AdditionalMmContextForSrvcc(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 160 => match AdditionalFlagsSrvcc::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::AdditionalFlagsSrvcc(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 162 => match MdtConfiguration::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::MdtConfiguration(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 163 => match Apco::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Apco(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 164 => match AbsoluteTimeMbmsDataTransfer::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::AbsoluteTimeMbmsDataTransfer(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 165 => match HenbInfoReporting::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 166 => match Ip4Cp::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 167 => match ChangeToReportFlags::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 168 => match ActionIndication::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 169 => match ActionIndication::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 170 => match UliTimestamp::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 171 => match MbmsFlags::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::MbmsFlags(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 172 => match RanNasCause::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 173 => match CnOperatorSelectionEntity::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 174 => match Twmi::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 175 => match NodeNumber::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 176 => match NodeIdentifier::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 177 => match PresenceReportingAreaAction::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 178 => match PresenceReportingAreaInformation::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 179 => match TwanIdTimeStamp::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 180 => {
                     // Overload Control Information
@@ -1216,7 +1225,7 @@ impl InformationElement {
                             cursor += i.len();
                             ies.push(InformationElement::OverloadControlInfo(i));
                         }
-                        Err(j) => return Err(j),
+                        Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                     }
                 }
                 181 => {
@@ -1226,7 +1235,7 @@ impl InformationElement {
                             cursor += i.len();
                             ies.push(InformationElement::LoadControlInfo(i));
                         }
-                        Err(j) => return Err(j),
+                        Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                     }
                 }
                 182 => match Metric::unmarshal(&buffer[cursor..]) {
@@ -1234,63 +1243,63 @@ impl InformationElement {
                         cursor += i.len();
                         ies.push(InformationElement::Metric(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 183 => match Sqn::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Sqn(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 184 => match ApnRelativeCapacity::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::ApnRelativeCapacity(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 185 => match WlanOffloadIndication::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::WlanOffloadIndication(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 186 => match PagingServiceInfo::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 187 => match IntegerNumber::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::IntegerNumber(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 188 => match MilliSecondTimeStamp::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 189 => match MonitoringEventInformation::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 190 => match EcgiList::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::EcgiList(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 191 => {
                     // Remote UE Context
@@ -1299,7 +1308,7 @@ impl InformationElement {
                             cursor += i.len();
                             ies.push(InformationElement::RemoteUeContext(i));
                         }
-                        Err(j) => return Err(j),
+                        Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                     }
                 }
                 192 => match RemoteUserId::unmarshal(&buffer[cursor..]) {
@@ -1307,217 +1316,217 @@ impl InformationElement {
                         cursor += i.len();
                         ies.push(InformationElement::RemoteUserId(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 193 => match RemoteUeIpInformation::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 194 => match CIoTOptimizationSupportIndication::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(i.into());
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 195 => match ScefPdnConnections::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::ScefPdnConnections(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 196 => match HeaderCompressionConfiguration::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::HeaderCompressionConfiguration(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 197 => match Epco::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Epco(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 198 => match ServingPlmnRateControl::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::ServingPlmnRateControl(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 199 => match Counter::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Counter(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 200 => match MappedUeUsageType::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::MappedUeUsageType(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 201 => match SecondaryRatUsageDataReport::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::SecondaryRatUsageDataReport(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 202 => match UpFunctionSelectionIndicationFlags::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::UpFunctionSelectionIndicationFlags(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 203 => match MaxPacketLossRate::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::MaxPacketLossRate(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 204 => match ApnRateControlStatus::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::ApnRateControlStatus(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 205 => match ExtendedTraceInformation::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::ExtendedTraceInformation(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 206 => match MonitoringEventExtensionInfo::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::MonitoringEventExtensionInfo(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 207 => match AdditionalRrmPolicyIndex::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::AdditionalRrmPolicyIndex(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 208 => match V2xInformation::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::V2xInformation(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 209 => match PC5QosParameters::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::PC5QosParameters(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 210 => match ServicesAuthorized::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::ServicesAuthorized(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 211 => match BitRate::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::BitRate(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 212 => match PC5QosFlow::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::PC5QosFlow(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 213 => match SgiPtpTunnelAddress::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::SgiPtpTunnelAddress(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 214 => match PgwChangeInfo::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::PgwChangeInfo(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 215 => match PgwFqdn::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::PgwFqdn(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 216 => match GroupId::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::GroupId(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 217 => match PSCellId::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::PSCellId(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 218 => match UpSecurityPolicy::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::UpSecurityPolicy(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 219 => match AlternativeImsi::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::AlternativeImsi(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 254 => match SpecialIEWithTypeExt::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::SpecialIEWithTypeExt(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 255 => match PrivateExtension::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::PrivateExtension(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
                 _ => match Unknown::unmarshal(&buffer[cursor..]) {
                     Ok(i) => {
                         cursor += i.len();
                         ies.push(InformationElement::Unknown(i));
                     }
-                    Err(j) => return Err(j),
+                    Err(j) => return Err(j.with_ie_type(buffer[cursor])),
                 },
             }
         }
@@ -44,17 +44,17 @@ impl From<GroupedIe> for LoadControl {
     fn from(i: GroupedIe) -> Self {
         let mut data = LoadControl::default();
         (data.t, data.length, data.ins) = (i.t, i.length, i.ins);
+        let mut apns: Vec<ApnRelativeCapacity> = vec![];
         for j in i.elements.into_iter() {
-            let mut apns: Vec<ApnRelativeCapacity> = vec![];
             match j {
                 InformationElement::Sqn(k) => data.sqn = k,
                 InformationElement::Metric(k) => data.load_metric = k,
                 InformationElement::ApnRelativeCapacity(k) => apns.push(k),
                 _ => (),
             }
-            if !apns.is_empty() {
-                data.list = Some(apns);
-            }
+        }
+        if !apns.is_empty() {
+            data.list = Some(apns);
         }
         data
     }
@@ -178,3 +178,28 @@ fn load_control_ie_marshal_test() {
     decoded.marshal(&mut buffer);
     assert_eq!(buffer, encoded);
 }
+
+#[test]
+fn load_control_ie_apn_list_test() {
+    let decoded = LoadControl {
+        length: 36,
+        list: Some(vec![
+            ApnRelativeCapacity {
+                length: 5,
+                relative_cap: 30,
+                name: "ims".to_string(),
+                ..ApnRelativeCapacity::default()
+            },
+            ApnRelativeCapacity {
+                length: 10,
+                relative_cap: 70,
+                name: "internet".to_string(),
+                ..ApnRelativeCapacity::default()
+            },
+        ]),
+        ..LoadControl::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(LoadControl::unmarshal(&buffer).unwrap(), decoded);
+}
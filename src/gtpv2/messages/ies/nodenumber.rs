@@ -60,12 +60,11 @@ impl IEs for NodeNumber {
             };
             if check_tliv_ie_buffer(data.length, buffer) {
                 let cursor = buffer[4] as usize;
-                match buffer[5..(cursor + 5)].try_into() {
-                    Ok(i) => {
-                        let n: &[u8] = i;
-                        data.node_number = tbcd_decode(&n[1..])
+                match buffer.get(5..(cursor + 5)) {
+                    Some(i) if cursor > 0 && cursor < data.length as usize => {
+                        data.node_number = tbcd_decode(&i[1..])
                     }
-                    Err(_) => return Err(GTPV2Error::IEIncorrect(NODE_NMBR)),
+                    _ => return Err(GTPV2Error::IEIncorrect(NODE_NMBR)),
                 }
                 Ok(data)
             } else {
@@ -136,3 +135,19 @@ fn node_number_ie_unmarshal_buffer_test() {
     let i = NodeNumber::unmarshal(&encoded);
     assert_eq!(i.unwrap(), decoded);
 }
+
+#[test]
+fn node_number_ie_unmarshal_inner_length_test() {
+    // Inner length beyond the IE length
+    let encoded: [u8; 8] = [0xaf, 0x00, 0x04, 0x00, 0x22, 0x4a, 0xe6, 0xd1];
+    assert_eq!(
+        NodeNumber::unmarshal(&encoded),
+        Err(GTPV2Error::IEIncorrect(NODE_NMBR))
+    );
+    // Empty number
+    let encoded: [u8; 5] = [0xaf, 0x00, 0x01, 0x00, 0x00];
+    assert_eq!(
+        NodeNumber::unmarshal(&encoded),
+        Err(GTPV2Error::IEIncorrect(NODE_NMBR))
+    );
+}
@@ -46,8 +46,8 @@ impl From<GroupedIe> for OverloadControlInfo {
     fn from(i: GroupedIe) -> Self {
         let mut data = OverloadControlInfo::default();
         (data.t, data.length, data.ins) = (i.t, i.length, i.ins);
+        let mut apns: Vec<Apn> = vec![];
         for j in i.elements.into_iter() {
-            let mut apns: Vec<Apn> = vec![];
             match j {
                 InformationElement::Sqn(k) => data.sqn = k,
                 InformationElement::Metric(k) => data.metric = k,
@@ -55,9 +55,9 @@ impl From<GroupedIe> for OverloadControlInfo {
                 InformationElement::Apn(k) => apns.push(k),
                 _ => (),
             }
-            if !apns.is_empty() {
-                data.list = Some(apns);
-            }
+        }
+        if !apns.is_empty() {
+            data.list = Some(apns);
         }
         data
     }
@@ -194,3 +194,26 @@ fn overload_control_ie_marshal_test() {
     decoded.marshal(&mut buffer);
     assert_eq!(buffer, encoded);
 }
+
+#[test]
+fn overload_control_ie_apn_list_test() {
+    let decoded = OverloadControlInfo {
+        length: 39,
+        list: Some(vec![
+            Apn {
+                length: 4,
+                name: "ims".to_string(),
+                ..Apn::default()
+            },
+            Apn {
+                length: 9,
+                name: "internet".to_string(),
+                ..Apn::default()
+            },
+        ]),
+        ..OverloadControlInfo::default()
+    };
+    let mut buffer: Vec<u8> = vec![];
+    decoded.marshal(&mut buffer);
+    assert_eq!(OverloadControlInfo::unmarshal(&buffer).unwrap(), decoded);
+}
@@ -44,11 +44,7 @@ impl From<GroupedIe> for PC5QosParameters {
         (data.t, data.length, data.ins) = (i.t, i.length, i.ins);
         for j in i.elements.into_iter() {
             match j {
-                InformationElement::PC5QosFlow(k) => {
-                    if k.ins == 0 {
-                        data.pc5_qos_flows.push(k)
-                    }
-                }
+                InformationElement::PC5QosFlow(k) if k.ins == 0 => data.pc5_qos_flows.push(k),
                 InformationElement::BitRate(k) => {
                     if let (0, false) = (k.ins, data.pc5_link_abrs.is_some()) {
                         data.pc5_link_abrs = Some(k)
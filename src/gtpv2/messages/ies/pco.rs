@@ -1,9 +1,12 @@
 // PCO IE - according to 3GPP TS 29.274 V17.10.0 (2023-12)
 
-use crate::gtpv2::{
-    errors::GTPV2Error,
-    messages::ies::{commons::*, ie::*},
-    utils::*,
+use crate::{
+    commons::ProtocolConfigurationOptions,
+    gtpv2::{
+        errors::GTPV2Error,
+        messages::ies::{commons::*, ie::*},
+        utils::*,
+    },
 };
 
 // PCO IE Type
@@ -31,6 +34,31 @@ impl Default for Pco {
     }
 }
 
+impl Pco {
+    // Decode the PCO octets into typed Protocol Configuration Options
+
+    pub fn protocol_configuration_options(
+        &self,
+    ) -> Result<ProtocolConfigurationOptions, GTPV2Error> {
+        ProtocolConfigurationOptions::unmarshal(&self.pco).map_err(|_| GTPV2Error::IEIncorrect(PCO))
+    }
+
+    pub fn set_protocol_configuration_options(&mut self, pco: &ProtocolConfigurationOptions) {
+        let mut buffer: Vec<u8> = vec![];
+        pco.marshal(&mut buffer);
+        self.length = buffer.len() as u16;
+        self.pco = buffer;
+    }
+}
+
+impl From<ProtocolConfigurationOptions> for Pco {
+    fn from(i: ProtocolConfigurationOptions) -> Self {
+        let mut data = Pco::default();
+        data.set_protocol_configuration_options(&i);
+        data
+    }
+}
+
 impl From<Pco> for InformationElement {
     fn from(i: Pco) -> Self {
         InformationElement::Pco(i)
@@ -119,3 +147,28 @@ fn pco_ie_unmarshal_test() {
     };
     assert_eq!(Pco::unmarshal(&encoded).unwrap(), decoded);
 }
+
+#[test]
+fn pco_ie_protocol_configuration_options_test() {
+    use crate::commons::*;
+    let encoded: [u8; 11] = [
+        0x4e, 0x00, 0x07, 0x00, 0x80, 0x00, 0x0d, 0x00, 0x00, 0x0c, 0x00,
+    ];
+    let pco = ProtocolConfigurationOptions {
+        configuration_protocol: PCO_CONFIG_PROTOCOL_PPP,
+        containers: vec![
+            PcoContainer::DnsServerIpv4AddressRequest,
+            PcoContainer::PcscfIpv4AddressRequest,
+        ],
+    };
+    let mut buffer: Vec<u8> = vec![];
+    Pco::from(pco.clone()).marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+    assert_eq!(
+        Pco::unmarshal(&encoded)
+            .unwrap()
+            .protocol_configuration_options()
+            .unwrap(),
+        pco
+    );
+}
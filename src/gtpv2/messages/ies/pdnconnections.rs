@@ -90,10 +90,8 @@ impl From<GroupedIe> for PdnConnections {
         (pdn_conn.t, pdn_conn.length, pdn_conn.ins) = (i.t, i.length, i.ins);
         for j in i.elements.into_iter() {
             match j {
-                InformationElement::Apn(k) => {
-                    if k.ins == 0 {
-                        pdn_conn.apn = k;
-                    }
+                InformationElement::Apn(k) if k.ins == 0 => {
+                    pdn_conn.apn = k;
                 }
                 InformationElement::ApnRestriction(k) => {
                     if let (0, true) = (k.ins, pdn_conn.apn_restriction.is_none()) {
@@ -112,15 +110,11 @@ impl From<GroupedIe> for PdnConnections {
                         _ => (),
                     }
                 }
-                InformationElement::Ebi(k) => {
-                    if k.ins == 0 {
-                        pdn_conn.linked_ebi = k;
-                    }
+                InformationElement::Ebi(k) if k.ins == 0 => {
+                    pdn_conn.linked_ebi = k;
                 }
-                InformationElement::Fteid(k) => {
-                    if k.ins == 0 {
-                        pdn_conn.pgw_addr_control = k;
-                    }
+                InformationElement::Fteid(k) if k.ins == 0 => {
+                    pdn_conn.pgw_addr_control = k;
                 }
                 InformationElement::Fqdn(k) => {
                     match (
@@ -133,15 +127,11 @@ impl From<GroupedIe> for PdnConnections {
                         _ => (),
                     }
                 }
-                InformationElement::BearerContext(k) => {
-                    if k.ins == 0 {
-                        pdn_conn.bearer_ctxs.push(k);
-                    }
+                InformationElement::BearerContext(k) if k.ins == 0 => {
+                    pdn_conn.bearer_ctxs.push(k);
                 }
-                InformationElement::ApnAmbr(k) => {
-                    if k.ins == 0 {
-                        pdn_conn.apn_ambr = k;
-                    }
+                InformationElement::ApnAmbr(k) if k.ins == 0 => {
+                    pdn_conn.apn_ambr = k;
                 }
                 InformationElement::ChargingCharacteristics(k) => {
                     if let (0, true) = (k.ins, pdn_conn.charging_char.is_none()) {
@@ -188,10 +178,8 @@ impl From<GroupedIe> for PdnConnections {
                         pdn_conn.wlan_offloadability = Some(k);
                     }
                 }
-                InformationElement::RemoteUeContext(k) => {
-                    if k.ins == 0 {
-                        pdn_conn.remote_ue_ctxs.push(k);
-                    }
+                InformationElement::RemoteUeContext(k) if k.ins == 0 => {
+                    pdn_conn.remote_ue_ctxs.push(k);
                 }
                 InformationElement::PdnType(k) => {
                     if let (0, true) = (k.ins, pdn_conn.pdn_type.is_none()) {
@@ -48,26 +48,10 @@ impl From<GroupedIe> for PgwChangeInfo {
         (data.t, data.length, data.ins) = (i.t, i.length, i.ins);
         for j in i.elements.into_iter() {
             match j {
-                InformationElement::PgwFqdn(k) => {
-                    if k.ins < 2 {
-                        data.pgw_fqdns.push(k)
-                    }
-                }
-                InformationElement::IpAddress(k) => {
-                    if k.ins < 4 {
-                        data.sgw_pgw_smf_ips.push(k)
-                    }
-                }
-                InformationElement::GroupId(k) => {
-                    if k.ins < 2 {
-                        data.groupids.push(k)
-                    }
-                }
-                InformationElement::Fqcsid(k) => {
-                    if k.ins == 0 {
-                        data.pgw_smf_fqcsid.push(k)
-                    }
-                }
+                InformationElement::PgwFqdn(k) if k.ins < 2 => data.pgw_fqdns.push(k),
+                InformationElement::IpAddress(k) if k.ins < 4 => data.sgw_pgw_smf_ips.push(k),
+                InformationElement::GroupId(k) if k.ins < 2 => data.groupids.push(k),
+                InformationElement::Fqcsid(k) if k.ins == 0 => data.pgw_smf_fqcsid.push(k),
                 _ => (),
             }
         }
@@ -48,7 +48,7 @@ impl S1APCause {
 // Enum of RAN NAS Causes
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum CauseValue {
+pub enum RanNasCauseValue {
     S1ap(S1APCause),
     Emm(u8),
     Esm(u8),
@@ -64,7 +64,7 @@ pub struct RanNasCause {
     pub t: u8,
     pub length: u16,
     pub ins: u8,
-    pub cause: CauseValue,
+    pub cause: RanNasCauseValue,
 }
 
 impl Default for RanNasCause {
@@ -73,7 +73,7 @@ impl Default for RanNasCause {
             t: RAN_NAS_CAUSE,
             length: 0,
             ins: 0,
-            cause: CauseValue::Spare,
+            cause: RanNasCauseValue::Spare,
         }
     }
 }
@@ -91,24 +91,24 @@ impl IEs for RanNasCause {
         buffer_ie.extend_from_slice(&self.length.to_be_bytes());
         buffer_ie.push(self.ins);
         match &self.cause {
-            CauseValue::S1ap(i) => buffer_ie.extend_from_slice(&i.to_u8()),
-            CauseValue::Emm(i) => {
+            RanNasCauseValue::S1ap(i) => buffer_ie.extend_from_slice(&i.to_u8()),
+            RanNasCauseValue::Emm(i) => {
                 buffer_ie.push(0x20);
                 buffer_ie.push(*i);
             }
-            CauseValue::Esm(i) => {
+            RanNasCauseValue::Esm(i) => {
                 buffer_ie.push(0x30);
                 buffer_ie.push(*i);
             }
-            CauseValue::Diameter(i) => {
+            RanNasCauseValue::Diameter(i) => {
                 buffer_ie.push(0x40);
                 buffer_ie.extend_from_slice(&i.to_be_bytes());
             }
-            CauseValue::Ikev2(i) => {
+            RanNasCauseValue::Ikev2(i) => {
                 buffer_ie.push(0x50);
                 buffer_ie.extend_from_slice(&i.to_be_bytes());
             }
-            CauseValue::Spare => {
+            RanNasCauseValue::Spare => {
                 buffer_ie.push(0x60);
                 buffer_ie.push(0);
             }
@@ -130,26 +130,27 @@ impl IEs for RanNasCause {
             match buffer[4] >> 4 {
                 1 => {
                     let c = S1APCause::from_u8(buffer[4] & 0x0f, buffer[5]);
-                    data.cause = CauseValue::S1ap(c);
+                    data.cause = RanNasCauseValue::S1ap(c);
                 }
-                2 => data.cause = CauseValue::Emm(buffer[5]),
-                3 => data.cause = CauseValue::Esm(buffer[5]),
+                2 => data.cause = RanNasCauseValue::Emm(buffer[5]),
+                3 => data.cause = RanNasCauseValue::Esm(buffer[5]),
                 4 => {
                     if buffer.len() >= MIN_IE_SIZE + 3 {
                         data.cause =
-                            CauseValue::Diameter(u16::from_be_bytes([buffer[5], buffer[6]]));
+                            RanNasCauseValue::Diameter(u16::from_be_bytes([buffer[5], buffer[6]]));
                     } else {
                         return Err(GTPV2Error::IEInvalidLength(RAN_NAS_CAUSE));
                     }
                 }
                 5 => {
                     if buffer.len() >= MIN_IE_SIZE + 3 {
-                        data.cause = CauseValue::Ikev2(u16::from_be_bytes([buffer[5], buffer[6]]));
+                        data.cause =
+                            RanNasCauseValue::Ikev2(u16::from_be_bytes([buffer[5], buffer[6]]));
                     } else {
                         return Err(GTPV2Error::IEInvalidLength(RAN_NAS_CAUSE));
                     }
                 }
-                _ => data.cause = CauseValue::Spare,
+                _ => data.cause = RanNasCauseValue::Spare,
             }
             Ok(data)
         } else {
@@ -178,7 +179,7 @@ fn ran_nas_cause_ie_short_marshal_test() {
         t: RAN_NAS_CAUSE,
         length: 2,
         ins: 0,
-        cause: CauseValue::S1ap(S1APCause::RadioLayer(26)),
+        cause: RanNasCauseValue::S1ap(S1APCause::RadioLayer(26)),
     };
     let encoded: [u8; 6] = [0xac, 0x00, 0x02, 0x00, 0x10, 0x1a];
     let mut buffer: Vec<u8> = vec![];
@@ -192,7 +193,7 @@ fn ran_nas_cause_ie_short_unmarshal_test() {
         t: RAN_NAS_CAUSE,
         length: 2,
         ins: 0,
-        cause: CauseValue::S1ap(S1APCause::RadioLayer(26)),
+        cause: RanNasCauseValue::S1ap(S1APCause::RadioLayer(26)),
     };
     let encoded: [u8; 6] = [0xac, 0x00, 0x02, 0x00, 0x10, 0x1a];
     assert_eq!(RanNasCause::unmarshal(&encoded).unwrap(), decoded);
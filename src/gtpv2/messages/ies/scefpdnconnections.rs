@@ -46,20 +46,14 @@ impl From<GroupedIe> for ScefPdnConnections {
         (pdn_conn.t, pdn_conn.length, pdn_conn.ins) = (i.t, i.length, i.ins);
         for j in i.elements.into_iter() {
             match j {
-                InformationElement::Apn(k) => {
-                    if k.ins == 0 {
-                        pdn_conn.apn = k;
-                    }
+                InformationElement::Apn(k) if k.ins == 0 => {
+                    pdn_conn.apn = k;
                 }
-                InformationElement::Ebi(k) => {
-                    if k.ins == 0 {
-                        pdn_conn.default_ebi = k;
-                    }
+                InformationElement::Ebi(k) if k.ins == 0 => {
+                    pdn_conn.default_ebi = k;
                 }
-                InformationElement::NodeIdentifier(k) => {
-                    if k.ins == 0 {
-                        pdn_conn.scef_id = k;
-                    }
+                InformationElement::NodeIdentifier(k) if k.ins == 0 => {
+                    pdn_conn.scef_id = k;
                 }
                 _ => (),
             }
@@ -36,6 +36,7 @@ impl IEs for Unknown {
     fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV2Error> {
         if buffer.len() >= MIN_IE_SIZE {
             let mut data = Unknown {
+                t: buffer[0],
                 length: u16::from_be_bytes([buffer[1], buffer[2]]),
                 ..Default::default()
             };
@@ -46,16 +46,10 @@ impl From<GroupedIe> for V2xInformation {
         (data.t, data.length, data.ins) = (i.t, i.length, i.ins);
         for j in i.elements.into_iter() {
             match j {
-                InformationElement::ServicesAuthorized(k) => {
-                    if k.ins < 2 {
-                        data.services_auth.push(k)
-                    }
-                }
-                InformationElement::BitRate(k) => {
-                    if k.ins < 2 {
-                        data.sidelink_max_brs.push(k)
-                    }
+                InformationElement::ServicesAuthorized(k) if k.ins < 2 => {
+                    data.services_auth.push(k)
                 }
+                InformationElement::BitRate(k) if k.ins < 2 => data.sidelink_max_brs.push(k),
                 InformationElement::PC5QosParameters(k) => {
                     if let (0, false) = (k.ins, data.pc5_qos_params.is_some()) {
                         data.pc5_qos_params = Some(k)
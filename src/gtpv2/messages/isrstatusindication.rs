@@ -82,11 +82,9 @@ impl Messages for IsrStatusIndication {
         let mut mandatory = false;
         for e in elements.iter() {
             match e {
-                InformationElement::ActionIndication(j) => {
-                    if j.ins == 0 {
-                        mandatory = true;
-                        self.action_ind = j.clone();
-                    };
+                InformationElement::ActionIndication(j) if j.ins == 0 => {
+                    mandatory = true;
+                    self.action_ind = j.clone();
                 }
                 InformationElement::PrivateExtension(j) => self.private_ext.push(j.clone()),
                 _ => (),
@@ -188,16 +188,12 @@ impl Messages for MbmsSessionStartRequest {
                     }
                 }
                 InformationElement::MbmsIpMulticastDistribution(j) => match j.ins {
-                    0 => {
-                        if !mandatory[5] {
-                            self.mbms_ip_multicast_dist = j.clone();
-                            mandatory[5] = true;
-                        }
+                    0 if !mandatory[5] => {
+                        self.mbms_ip_multicast_dist = j.clone();
+                        mandatory[5] = true;
                     }
-                    1 => {
-                        if self.mbms_alt_ip_multicast_dist.is_none() {
-                            self.mbms_alt_ip_multicast_dist = Some(j.clone());
-                        }
+                    1 if self.mbms_alt_ip_multicast_dist.is_none() => {
+                        self.mbms_alt_ip_multicast_dist = Some(j.clone());
                     }
                     _ => (),
                 },
@@ -109,16 +109,12 @@ impl Messages for MbmsSessionStartResponse {
                     }
                 }
                 InformationElement::Fteid(j) => match j.ins {
-                    0 => {
-                        if !mandatory[1] {
-                            self.fteid_control = j.clone();
-                            mandatory[1] = true;
-                        }
+                    0 if !mandatory[1] => {
+                        self.fteid_control = j.clone();
+                        mandatory[1] = true;
                     }
-                    1 => {
-                        if self.sgsn_fteid.is_none() {
-                            self.sgsn_fteid = Some(j.clone());
-                        }
+                    1 if self.sgsn_fteid.is_none() => {
+                        self.sgsn_fteid = Some(j.clone());
                     }
                     _ => (),
                 },
@@ -0,0 +1,872 @@
+use crate::gtpv2::{errors::*, header::*, messages::*, utils::*};
+
+// According to 3GPP TS 29.274 V17.10.0 (2023-12)
+
+// Definition of GTPv2-C Message enum covering all implemented GTPv2-C messages
+
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum GTPV2Message {
+    EchoRequest(EchoRequest),
+    EchoResponse(EchoResponse),
+    VersionNotSupported(VersionNotSupported),
+    CreateSessionRequest(CreateSessionRequest),
+    CreateSessionResponse(CreateSessionResponse),
+    ModifyBearerRequest(ModifyBearerRequest),
+    ModifyBearerResponse(ModifyBearerResponse),
+    DeleteSessionRequest(DeleteSessionRequest),
+    DeleteSessionResponse(DeleteSessionResponse),
+    ChangeNotificationRequest(ChangeNotificationRequest),
+    ChangeNotificationResponse(ChangeNotificationResponse),
+    RemoteUeReportNotification(RemoteUeReportNotification),
+    RemoteUeReportAcknowledge(RemoteUeReportAcknowledge),
+    ModifyBearerCommand(ModifyBearerCommand),
+    ModifyBearerFailureInd(ModifyBearerFailureInd),
+    DeleteBearerCommand(DeleteBearerCommand),
+    DeleteBearerFailureInd(DeleteBearerFailureInd),
+    BearerResourceCommand(BearerResourceCommand),
+    BearerResourceFailureInd(BearerResourceFailureInd),
+    DownlinkDataNotificationFailureIndication(DownlinkDataNotificationFailureIndication),
+    TraceSessionActivation(TraceSessionActivation),
+    TraceSessionDeactivation(TraceSessionDeactivation),
+    StopPagingIndication(StopPagingIndication),
+    CreateBearerRequest(CreateBearerRequest),
+    CreateBearerResponse(CreateBearerResponse),
+    UpdateBearerRequest(UpdateBearerRequest),
+    UpdateBearerResponse(UpdateBearerResponse),
+    DeleteBearerRequest(DeleteBearerRequest),
+    DeleteBearerResponse(DeleteBearerResponse),
+    DeletePdnConnectionSetRequest(DeletePdnConnectionSetRequest),
+    DeletePndConnectionSetResponse(DeletePndConnectionSetResponse),
+    PgwDownlinkTriggeringNotification(PgwDownlinkTriggeringNotification),
+    PgwDownlinkTriggeringAcknowledge(PgwDownlinkTriggeringAcknowledge),
+    IdentificationRequest(IdentificationRequest),
+    IdentificationResponse(IdentificationResponse),
+    ContextRequest(ContextRequest),
+    ContextResponse(ContextResponse),
+    ContextAcknowledge(ContextAcknowledge),
+    ForwardRelocationRequest(ForwardRelocationRequest),
+    ForwardRelocationResponse(ForwardRelocationResponse),
+    ForwardRelocationCompleteNotification(ForwardRelocationCompleteNotification),
+    ForwardRelocationCompleteAcknowledge(ForwardRelocationCompleteAcknowledge),
+    ForwardAccessContextNotification(ForwardAccessContextNotification),
+    ForwardAccessContextAcknowledge(ForwardAccessContextAcknowledge),
+    RelocationCancelRequest(RelocationCancelRequest),
+    RelocationCancelResponse(RelocationCancelResponse),
+    DetachNotification(DetachNotification),
+    DetachAcknowledge(DetachAcknowledge),
+    CsPagingIndication(CsPagingIndication),
+    RanInformationRelay(RanInformationRelay),
+    AlertMmeNotification(AlertMmeNotification),
+    AlertMmeAcknowledge(AlertMmeAcknowledge),
+    UeActivityNotification(UeActivityNotification),
+    UeActivityAcknowledge(UeActivityAcknowledge),
+    IsrStatusIndication(IsrStatusIndication),
+    UeRegistrationQueryRequest(UeRegistrationQueryRequest),
+    UeRegistrationQueryResponse(UeRegistrationQueryResponse),
+    CreateForwardingTunnelRequest(CreateForwardingTunnelRequest),
+    CreateForwardingTunnelResponse(CreateForwardingTunnelResponse),
+    SuspendNotification(SuspendNotification),
+    SuspendAcknowledge(SuspendAcknowledge),
+    ResumeNotification(ResumeNotification),
+    ResumeAcknowledge(ResumeAcknowledge),
+    CreateIndirectDataForwardingTunnelRequest(CreateIndirectDataForwardingTunnelRequest),
+    CreateIndirectDataForwardingTunnelResponse(CreateIndirectDataForwardingTunnelResponse),
+    DeleteIndirectDataForwardingTunnelRequest(DeleteIndirectDataForwardingTunnelRequest),
+    DeleteIndirectDataForwardingTunnelResponse(DeleteIndirectDataForwardingTunnelResponse),
+    ReleaseAccessBearersRequest(ReleaseAccessBearersRequest),
+    ReleaseAccessBearersResponse(ReleaseAccessBearersResponse),
+    DownlinkDataNotification(DownlinkDataNotification),
+    DownlinkDataNotificationAcknowledge(DownlinkDataNotificationAcknowledge),
+    PgwRestartNotification(PgwRestartNotification),
+    PgwRestartNotificationAcknowledge(PgwRestartNotificationAcknowledge),
+    UpdatePdnConnectionSetRequest(UpdatePdnConnectionSetRequest),
+    UpdatePndConnectionSetResponse(UpdatePndConnectionSetResponse),
+    ModifyAccessBearersRequest(ModifyAccessBearersRequest),
+    ModifyAccessBearersResponse(ModifyAccessBearersResponse),
+    MbmsSessionStartRequest(MbmsSessionStartRequest),
+    MbmsSessionStartResponse(MbmsSessionStartResponse),
+    MbmsSessionUpdateRequest(MbmsSessionUpdateRequest),
+    MbmsSessionUpdateResponse(MbmsSessionUpdateResponse),
+    MbmsSessionStopRequest(MbmsSessionStopRequest),
+    MbmsSessionStopResponse(MbmsSessionStopResponse),
+    Unknown {
+        header: Gtpv2Header,
+        ies: Vec<InformationElement>,
+    },
+}
+
+impl GTPV2Message {
+    // Decode any GTPv2-C message based on the message type in the header
+
+    pub fn parse(buffer: &[u8]) -> Result<Self, GTPV2Error> {
+        let header = Gtpv2Header::unmarshal(buffer)?;
+        if (header.length as usize) + MANDATORY_HDR_LENGTH < msg_header_length(header.msgtype) {
+            return Err(GTPV2Error::MessageInvalidLength(header.msgtype));
+        }
+        match header.msgtype {
+            ECHO_REQUEST => EchoRequest::unmarshal(buffer).map(GTPV2Message::EchoRequest),
+            ECHO_RESPONSE => EchoResponse::unmarshal(buffer).map(GTPV2Message::EchoResponse),
+            VERSION_NOT_SUPPORTED => {
+                VersionNotSupported::unmarshal(buffer).map(GTPV2Message::VersionNotSupported)
+            }
+            CREATE_SESSION_REQ => {
+                CreateSessionRequest::unmarshal(buffer).map(GTPV2Message::CreateSessionRequest)
+            }
+            CREATE_SESSION_RESP => {
+                CreateSessionResponse::unmarshal(buffer).map(GTPV2Message::CreateSessionResponse)
+            }
+            MODIFY_BEARER_REQ => {
+                ModifyBearerRequest::unmarshal(buffer).map(GTPV2Message::ModifyBearerRequest)
+            }
+            MODIFY_BEARER_RESP => {
+                ModifyBearerResponse::unmarshal(buffer).map(GTPV2Message::ModifyBearerResponse)
+            }
+            DELETE_SESSION_REQ => {
+                DeleteSessionRequest::unmarshal(buffer).map(GTPV2Message::DeleteSessionRequest)
+            }
+            DELETE_SESSION_RESP => {
+                DeleteSessionResponse::unmarshal(buffer).map(GTPV2Message::DeleteSessionResponse)
+            }
+            CHNG_NOTIF_REQ => ChangeNotificationRequest::unmarshal(buffer)
+                .map(GTPV2Message::ChangeNotificationRequest),
+            CHNG_NOTIF_RESP => ChangeNotificationResponse::unmarshal(buffer)
+                .map(GTPV2Message::ChangeNotificationResponse),
+            REMOTE_UE_REPORT_NOTIF => RemoteUeReportNotification::unmarshal(buffer)
+                .map(GTPV2Message::RemoteUeReportNotification),
+            REMOTE_UE_REPORT_ACK => RemoteUeReportAcknowledge::unmarshal(buffer)
+                .map(GTPV2Message::RemoteUeReportAcknowledge),
+            MODIFY_BEARER_CMD => {
+                ModifyBearerCommand::unmarshal(buffer).map(GTPV2Message::ModifyBearerCommand)
+            }
+            MODIFY_BEARER_FAIL_IND => {
+                ModifyBearerFailureInd::unmarshal(buffer).map(GTPV2Message::ModifyBearerFailureInd)
+            }
+            DELETE_BEARER_CMD => {
+                DeleteBearerCommand::unmarshal(buffer).map(GTPV2Message::DeleteBearerCommand)
+            }
+            DELETE_BEARER_FAIL => {
+                DeleteBearerFailureInd::unmarshal(buffer).map(GTPV2Message::DeleteBearerFailureInd)
+            }
+            BEARER_RSRC_CMD => {
+                BearerResourceCommand::unmarshal(buffer).map(GTPV2Message::BearerResourceCommand)
+            }
+            BEARER_RSRC_FAIL => BearerResourceFailureInd::unmarshal(buffer)
+                .map(GTPV2Message::BearerResourceFailureInd),
+            DL_DATA_NOTIF_FAIL_IND => DownlinkDataNotificationFailureIndication::unmarshal(buffer)
+                .map(GTPV2Message::DownlinkDataNotificationFailureIndication),
+            TRACE_SESSION_ACT => {
+                TraceSessionActivation::unmarshal(buffer).map(GTPV2Message::TraceSessionActivation)
+            }
+            TRACE_SESSION_DEACT => TraceSessionDeactivation::unmarshal(buffer)
+                .map(GTPV2Message::TraceSessionDeactivation),
+            STOP_PAGING_IND => {
+                StopPagingIndication::unmarshal(buffer).map(GTPV2Message::StopPagingIndication)
+            }
+            CREATE_BEARER_REQ => {
+                CreateBearerRequest::unmarshal(buffer).map(GTPV2Message::CreateBearerRequest)
+            }
+            CREATE_BEARER_RESP => {
+                CreateBearerResponse::unmarshal(buffer).map(GTPV2Message::CreateBearerResponse)
+            }
+            UPD_BEARER_REQ => {
+                UpdateBearerRequest::unmarshal(buffer).map(GTPV2Message::UpdateBearerRequest)
+            }
+            UPD_BEARER_RESP => {
+                UpdateBearerResponse::unmarshal(buffer).map(GTPV2Message::UpdateBearerResponse)
+            }
+            DELETE_BEARER_REQ => {
+                DeleteBearerRequest::unmarshal(buffer).map(GTPV2Message::DeleteBearerRequest)
+            }
+            DELETE_BEARER_RESP => {
+                DeleteBearerResponse::unmarshal(buffer).map(GTPV2Message::DeleteBearerResponse)
+            }
+            DEL_PDN_CONN_SET_REQ => DeletePdnConnectionSetRequest::unmarshal(buffer)
+                .map(GTPV2Message::DeletePdnConnectionSetRequest),
+            DEL_PDN_CONN_SET_RESP => DeletePndConnectionSetResponse::unmarshal(buffer)
+                .map(GTPV2Message::DeletePndConnectionSetResponse),
+            PGW_DL_TRIGGER_NOTIF => PgwDownlinkTriggeringNotification::unmarshal(buffer)
+                .map(GTPV2Message::PgwDownlinkTriggeringNotification),
+            PGW_DL_TRIGGER_ACK => PgwDownlinkTriggeringAcknowledge::unmarshal(buffer)
+                .map(GTPV2Message::PgwDownlinkTriggeringAcknowledge),
+            IDENTIFICATION_REQ => {
+                IdentificationRequest::unmarshal(buffer).map(GTPV2Message::IdentificationRequest)
+            }
+            IDENTIFICATION_RESP => {
+                IdentificationResponse::unmarshal(buffer).map(GTPV2Message::IdentificationResponse)
+            }
+            CTX_REQ => ContextRequest::unmarshal(buffer).map(GTPV2Message::ContextRequest),
+            CTX_RESP => ContextResponse::unmarshal(buffer).map(GTPV2Message::ContextResponse),
+            CTX_ACK => ContextAcknowledge::unmarshal(buffer).map(GTPV2Message::ContextAcknowledge),
+            FWD_RELOC_REQ => ForwardRelocationRequest::unmarshal(buffer)
+                .map(GTPV2Message::ForwardRelocationRequest),
+            FWD_RELOC_RESP => ForwardRelocationResponse::unmarshal(buffer)
+                .map(GTPV2Message::ForwardRelocationResponse),
+            FWD_RELOC_COMPLETE_NOTIF => ForwardRelocationCompleteNotification::unmarshal(buffer)
+                .map(GTPV2Message::ForwardRelocationCompleteNotification),
+            FWD_RELOC_COMPLETE_ACK => ForwardRelocationCompleteAcknowledge::unmarshal(buffer)
+                .map(GTPV2Message::ForwardRelocationCompleteAcknowledge),
+            FWD_ACCESS_CTX_NOTIF => ForwardAccessContextNotification::unmarshal(buffer)
+                .map(GTPV2Message::ForwardAccessContextNotification),
+            FWD_ACCESS_CTX_ACK => ForwardAccessContextAcknowledge::unmarshal(buffer)
+                .map(GTPV2Message::ForwardAccessContextAcknowledge),
+            RELOC_CANCEL_REQ => RelocationCancelRequest::unmarshal(buffer)
+                .map(GTPV2Message::RelocationCancelRequest),
+            RELOC_CANCEL_RESP => RelocationCancelResponse::unmarshal(buffer)
+                .map(GTPV2Message::RelocationCancelResponse),
+            DETACH_NOTIF => {
+                DetachNotification::unmarshal(buffer).map(GTPV2Message::DetachNotification)
+            }
+            DETACH_ACK => DetachAcknowledge::unmarshal(buffer).map(GTPV2Message::DetachAcknowledge),
+            CS_PAGING_IND => {
+                CsPagingIndication::unmarshal(buffer).map(GTPV2Message::CsPagingIndication)
+            }
+            RAN_INFO_RELAY => {
+                RanInformationRelay::unmarshal(buffer).map(GTPV2Message::RanInformationRelay)
+            }
+            ALERT_MME_NOTIF => {
+                AlertMmeNotification::unmarshal(buffer).map(GTPV2Message::AlertMmeNotification)
+            }
+            ALERT_MME_ACK => {
+                AlertMmeAcknowledge::unmarshal(buffer).map(GTPV2Message::AlertMmeAcknowledge)
+            }
+            UE_ACTIVITY_NOTIF => {
+                UeActivityNotification::unmarshal(buffer).map(GTPV2Message::UeActivityNotification)
+            }
+            UE_ACTIVITY_ACK => {
+                UeActivityAcknowledge::unmarshal(buffer).map(GTPV2Message::UeActivityAcknowledge)
+            }
+            ISR_STATUS_IND => {
+                IsrStatusIndication::unmarshal(buffer).map(GTPV2Message::IsrStatusIndication)
+            }
+            UE_REG_QUERY_REQ => UeRegistrationQueryRequest::unmarshal(buffer)
+                .map(GTPV2Message::UeRegistrationQueryRequest),
+            UE_REG_QUERY_RESP => UeRegistrationQueryResponse::unmarshal(buffer)
+                .map(GTPV2Message::UeRegistrationQueryResponse),
+            CREATE_FWD_TUNNEL_REQ => CreateForwardingTunnelRequest::unmarshal(buffer)
+                .map(GTPV2Message::CreateForwardingTunnelRequest),
+            CREATE_FWD_TUNNEL_RESP => CreateForwardingTunnelResponse::unmarshal(buffer)
+                .map(GTPV2Message::CreateForwardingTunnelResponse),
+            SUSPEND_NOTIF => {
+                SuspendNotification::unmarshal(buffer).map(GTPV2Message::SuspendNotification)
+            }
+            SUSPEND_ACK => {
+                SuspendAcknowledge::unmarshal(buffer).map(GTPV2Message::SuspendAcknowledge)
+            }
+            RESUME_NOTIF => {
+                ResumeNotification::unmarshal(buffer).map(GTPV2Message::ResumeNotification)
+            }
+            RESUME_ACK => ResumeAcknowledge::unmarshal(buffer).map(GTPV2Message::ResumeAcknowledge),
+            CREATE_IND_DATA_FW_TUN_REQ => {
+                CreateIndirectDataForwardingTunnelRequest::unmarshal(buffer)
+                    .map(GTPV2Message::CreateIndirectDataForwardingTunnelRequest)
+            }
+            CREATE_IND_DATA_FW_TUN_RESP => {
+                CreateIndirectDataForwardingTunnelResponse::unmarshal(buffer)
+                    .map(GTPV2Message::CreateIndirectDataForwardingTunnelResponse)
+            }
+            DELETE_IND_DATA_FW_TUN_REQ => {
+                DeleteIndirectDataForwardingTunnelRequest::unmarshal(buffer)
+                    .map(GTPV2Message::DeleteIndirectDataForwardingTunnelRequest)
+            }
+            DELETE_IND_DATA_FW_TUN_RESP => {
+                DeleteIndirectDataForwardingTunnelResponse::unmarshal(buffer)
+                    .map(GTPV2Message::DeleteIndirectDataForwardingTunnelResponse)
+            }
+            RELEASE_ACCESS_BRS_REQ => ReleaseAccessBearersRequest::unmarshal(buffer)
+                .map(GTPV2Message::ReleaseAccessBearersRequest),
+            RELEASE_ACCESS_BRS_RESP => ReleaseAccessBearersResponse::unmarshal(buffer)
+                .map(GTPV2Message::ReleaseAccessBearersResponse),
+            DL_DATA_NOTIF => DownlinkDataNotification::unmarshal(buffer)
+                .map(GTPV2Message::DownlinkDataNotification),
+            DL_DATA_NOTIF_ACK => DownlinkDataNotificationAcknowledge::unmarshal(buffer)
+                .map(GTPV2Message::DownlinkDataNotificationAcknowledge),
+            PGW_RESTART_NOTIF => {
+                PgwRestartNotification::unmarshal(buffer).map(GTPV2Message::PgwRestartNotification)
+            }
+            PGW_RESTART_NOTIF_ACK => PgwRestartNotificationAcknowledge::unmarshal(buffer)
+                .map(GTPV2Message::PgwRestartNotificationAcknowledge),
+            UPD_PDN_CONN_SET_REQ => UpdatePdnConnectionSetRequest::unmarshal(buffer)
+                .map(GTPV2Message::UpdatePdnConnectionSetRequest),
+            UPD_PDN_CONN_SET_RESP => UpdatePndConnectionSetResponse::unmarshal(buffer)
+                .map(GTPV2Message::UpdatePndConnectionSetResponse),
+            MODIFY_ACCESS_BRS_REQ => ModifyAccessBearersRequest::unmarshal(buffer)
+                .map(GTPV2Message::ModifyAccessBearersRequest),
+            MODIFY_ACCESS_BRS_RESP => ModifyAccessBearersResponse::unmarshal(buffer)
+                .map(GTPV2Message::ModifyAccessBearersResponse),
+            MBMS_SESSION_STRT_REQ => MbmsSessionStartRequest::unmarshal(buffer)
+                .map(GTPV2Message::MbmsSessionStartRequest),
+            MBMS_SESSION_STRT_RESP => MbmsSessionStartResponse::unmarshal(buffer)
+                .map(GTPV2Message::MbmsSessionStartResponse),
+            MBMS_SESSION_UPD_REQ => MbmsSessionUpdateRequest::unmarshal(buffer)
+                .map(GTPV2Message::MbmsSessionUpdateRequest),
+            MBMS_SESSION_UPD_RESP => MbmsSessionUpdateResponse::unmarshal(buffer)
+                .map(GTPV2Message::MbmsSessionUpdateResponse),
+            MBMS_SESSION_STOP_REQ => {
+                MbmsSessionStopRequest::unmarshal(buffer).map(GTPV2Message::MbmsSessionStopRequest)
+            }
+            MBMS_SESSION_STOP_RESP => MbmsSessionStopResponse::unmarshal(buffer)
+                .map(GTPV2Message::MbmsSessionStopResponse),
+            _ => {
+                let offset = header.length as usize + MANDATORY_HDR_LENGTH;
+                let start = match header.teid {
+                    Some(_) => MAX_HEADER_LENGTH,
+                    None => MIN_HEADER_LENGTH,
+                };
+                if buffer.len() >= offset {
+                    match InformationElement::decoder(&buffer[start..offset]) {
+                        Ok(ies) => Ok(GTPV2Message::Unknown { header, ies }),
+                        Err(j) => Err(j),
+                    }
+                } else {
+                    Err(GTPV2Error::MessageInvalidMessageFormat)
+                }
+            }
+        }
+    }
+
+    // Decode any GTPv2-C message, reporting failures with the offset and IE path of the faulty IE.
+    // On failure the message is walked again to locate the faulty IE
+
+    pub fn parse_detailed(buffer: &[u8]) -> Result<Self, GTPV2DecodeError> {
+        GTPV2Message::parse(buffer).map_err(|e| locate_decode_error(buffer, e))
+    }
+
+    pub fn marshal(&self, buffer: &mut Vec<u8>) {
+        match self {
+            GTPV2Message::EchoRequest(i) => i.marshal(buffer),
+            GTPV2Message::EchoResponse(i) => i.marshal(buffer),
+            GTPV2Message::VersionNotSupported(i) => i.marshal(buffer),
+            GTPV2Message::CreateSessionRequest(i) => i.marshal(buffer),
+            GTPV2Message::CreateSessionResponse(i) => i.marshal(buffer),
+            GTPV2Message::ModifyBearerRequest(i) => i.marshal(buffer),
+            GTPV2Message::ModifyBearerResponse(i) => i.marshal(buffer),
+            GTPV2Message::DeleteSessionRequest(i) => i.marshal(buffer),
+            GTPV2Message::DeleteSessionResponse(i) => i.marshal(buffer),
+            GTPV2Message::ChangeNotificationRequest(i) => i.marshal(buffer),
+            GTPV2Message::ChangeNotificationResponse(i) => i.marshal(buffer),
+            GTPV2Message::RemoteUeReportNotification(i) => i.marshal(buffer),
+            GTPV2Message::RemoteUeReportAcknowledge(i) => i.marshal(buffer),
+            GTPV2Message::ModifyBearerCommand(i) => i.marshal(buffer),
+            GTPV2Message::ModifyBearerFailureInd(i) => i.marshal(buffer),
+            GTPV2Message::DeleteBearerCommand(i) => i.marshal(buffer),
+            GTPV2Message::DeleteBearerFailureInd(i) => i.marshal(buffer),
+            GTPV2Message::BearerResourceCommand(i) => i.marshal(buffer),
+            GTPV2Message::BearerResourceFailureInd(i) => i.marshal(buffer),
+            GTPV2Message::DownlinkDataNotificationFailureIndication(i) => i.marshal(buffer),
+            GTPV2Message::TraceSessionActivation(i) => i.marshal(buffer),
+            GTPV2Message::TraceSessionDeactivation(i) => i.marshal(buffer),
+            GTPV2Message::StopPagingIndication(i) => i.marshal(buffer),
+            GTPV2Message::CreateBearerRequest(i) => i.marshal(buffer),
+            GTPV2Message::CreateBearerResponse(i) => i.marshal(buffer),
+            GTPV2Message::UpdateBearerRequest(i) => i.marshal(buffer),
+            GTPV2Message::UpdateBearerResponse(i) => i.marshal(buffer),
+            GTPV2Message::DeleteBearerRequest(i) => i.marshal(buffer),
+            GTPV2Message::DeleteBearerResponse(i) => i.marshal(buffer),
+            GTPV2Message::DeletePdnConnectionSetRequest(i) => i.marshal(buffer),
+            GTPV2Message::DeletePndConnectionSetResponse(i) => i.marshal(buffer),
+            GTPV2Message::PgwDownlinkTriggeringNotification(i) => i.marshal(buffer),
+            GTPV2Message::PgwDownlinkTriggeringAcknowledge(i) => i.marshal(buffer),
+            GTPV2Message::IdentificationRequest(i) => i.marshal(buffer),
+            GTPV2Message::IdentificationResponse(i) => i.marshal(buffer),
+            GTPV2Message::ContextRequest(i) => i.marshal(buffer),
+            GTPV2Message::ContextResponse(i) => i.marshal(buffer),
+            GTPV2Message::ContextAcknowledge(i) => i.marshal(buffer),
+            GTPV2Message::ForwardRelocationRequest(i) => i.marshal(buffer),
+            GTPV2Message::ForwardRelocationResponse(i) => i.marshal(buffer),
+            GTPV2Message::ForwardRelocationCompleteNotification(i) => i.marshal(buffer),
+            GTPV2Message::ForwardRelocationCompleteAcknowledge(i) => i.marshal(buffer),
+            GTPV2Message::ForwardAccessContextNotification(i) => i.marshal(buffer),
+            GTPV2Message::ForwardAccessContextAcknowledge(i) => i.marshal(buffer),
+            GTPV2Message::RelocationCancelRequest(i) => i.marshal(buffer),
+            GTPV2Message::RelocationCancelResponse(i) => i.marshal(buffer),
+            GTPV2Message::DetachNotification(i) => i.marshal(buffer),
+            GTPV2Message::DetachAcknowledge(i) => i.marshal(buffer),
+            GTPV2Message::CsPagingIndication(i) => i.marshal(buffer),
+            GTPV2Message::RanInformationRelay(i) => i.marshal(buffer),
+            GTPV2Message::AlertMmeNotification(i) => i.marshal(buffer),
+            GTPV2Message::AlertMmeAcknowledge(i) => i.marshal(buffer),
+            GTPV2Message::UeActivityNotification(i) => i.marshal(buffer),
+            GTPV2Message::UeActivityAcknowledge(i) => i.marshal(buffer),
+            GTPV2Message::IsrStatusIndication(i) => i.marshal(buffer),
+            GTPV2Message::UeRegistrationQueryRequest(i) => i.marshal(buffer),
+            GTPV2Message::UeRegistrationQueryResponse(i) => i.marshal(buffer),
+            GTPV2Message::CreateForwardingTunnelRequest(i) => i.marshal(buffer),
+            GTPV2Message::CreateForwardingTunnelResponse(i) => i.marshal(buffer),
+            GTPV2Message::SuspendNotification(i) => i.marshal(buffer),
+            GTPV2Message::SuspendAcknowledge(i) => i.marshal(buffer),
+            GTPV2Message::ResumeNotification(i) => i.marshal(buffer),
+            GTPV2Message::ResumeAcknowledge(i) => i.marshal(buffer),
+            GTPV2Message::CreateIndirectDataForwardingTunnelRequest(i) => i.marshal(buffer),
+            GTPV2Message::CreateIndirectDataForwardingTunnelResponse(i) => i.marshal(buffer),
+            GTPV2Message::DeleteIndirectDataForwardingTunnelRequest(i) => i.marshal(buffer),
+            GTPV2Message::DeleteIndirectDataForwardingTunnelResponse(i) => i.marshal(buffer),
+            GTPV2Message::ReleaseAccessBearersRequest(i) => i.marshal(buffer),
+            GTPV2Message::ReleaseAccessBearersResponse(i) => i.marshal(buffer),
+            GTPV2Message::DownlinkDataNotification(i) => i.marshal(buffer),
+            GTPV2Message::DownlinkDataNotificationAcknowledge(i) => i.marshal(buffer),
+            GTPV2Message::PgwRestartNotification(i) => i.marshal(buffer),
+            GTPV2Message::PgwRestartNotificationAcknowledge(i) => i.marshal(buffer),
+            GTPV2Message::UpdatePdnConnectionSetRequest(i) => i.marshal(buffer),
+            GTPV2Message::UpdatePndConnectionSetResponse(i) => i.marshal(buffer),
+            GTPV2Message::ModifyAccessBearersRequest(i) => i.marshal(buffer),
+            GTPV2Message::ModifyAccessBearersResponse(i) => i.marshal(buffer),
+            GTPV2Message::MbmsSessionStartRequest(i) => i.marshal(buffer),
+            GTPV2Message::MbmsSessionStartResponse(i) => i.marshal(buffer),
+            GTPV2Message::MbmsSessionUpdateRequest(i) => i.marshal(buffer),
+            GTPV2Message::MbmsSessionUpdateResponse(i) => i.marshal(buffer),
+            GTPV2Message::MbmsSessionStopRequest(i) => i.marshal(buffer),
+            GTPV2Message::MbmsSessionStopResponse(i) => i.marshal(buffer),
+            GTPV2Message::Unknown { header, ies } => {
+                header.marshal(buffer);
+                ies.iter().for_each(|k| k.clone().marshal(buffer));
+                set_msg_length(buffer);
+            }
+        }
+    }
+
+    pub fn header(&self) -> &Gtpv2Header {
+        match self {
+            GTPV2Message::EchoRequest(i) => &i.header,
+            GTPV2Message::EchoResponse(i) => &i.header,
+            GTPV2Message::VersionNotSupported(i) => &i.header,
+            GTPV2Message::CreateSessionRequest(i) => &i.header,
+            GTPV2Message::CreateSessionResponse(i) => &i.header,
+            GTPV2Message::ModifyBearerRequest(i) => &i.header,
+            GTPV2Message::ModifyBearerResponse(i) => &i.header,
+            GTPV2Message::DeleteSessionRequest(i) => &i.header,
+            GTPV2Message::DeleteSessionResponse(i) => &i.header,
+            GTPV2Message::ChangeNotificationRequest(i) => &i.header,
+            GTPV2Message::ChangeNotificationResponse(i) => &i.header,
+            GTPV2Message::RemoteUeReportNotification(i) => &i.header,
+            GTPV2Message::RemoteUeReportAcknowledge(i) => &i.header,
+            GTPV2Message::ModifyBearerCommand(i) => &i.header,
+            GTPV2Message::ModifyBearerFailureInd(i) => &i.header,
+            GTPV2Message::DeleteBearerCommand(i) => &i.header,
+            GTPV2Message::DeleteBearerFailureInd(i) => &i.header,
+            GTPV2Message::BearerResourceCommand(i) => &i.header,
+            GTPV2Message::BearerResourceFailureInd(i) => &i.header,
+            GTPV2Message::DownlinkDataNotificationFailureIndication(i) => &i.header,
+            GTPV2Message::TraceSessionActivation(i) => &i.header,
+            GTPV2Message::TraceSessionDeactivation(i) => &i.header,
+            GTPV2Message::StopPagingIndication(i) => &i.header,
+            GTPV2Message::CreateBearerRequest(i) => &i.header,
+            GTPV2Message::CreateBearerResponse(i) => &i.header,
+            GTPV2Message::UpdateBearerRequest(i) => &i.header,
+            GTPV2Message::UpdateBearerResponse(i) => &i.header,
+            GTPV2Message::DeleteBearerRequest(i) => &i.header,
+            GTPV2Message::DeleteBearerResponse(i) => &i.header,
+            GTPV2Message::DeletePdnConnectionSetRequest(i) => &i.header,
+            GTPV2Message::DeletePndConnectionSetResponse(i) => &i.header,
+            GTPV2Message::PgwDownlinkTriggeringNotification(i) => &i.header,
+            GTPV2Message::PgwDownlinkTriggeringAcknowledge(i) => &i.header,
+            GTPV2Message::IdentificationRequest(i) => &i.header,
+            GTPV2Message::IdentificationResponse(i) => &i.header,
+            GTPV2Message::ContextRequest(i) => &i.header,
+            GTPV2Message::ContextResponse(i) => &i.header,
+            GTPV2Message::ContextAcknowledge(i) => &i.header,
+            GTPV2Message::ForwardRelocationRequest(i) => &i.header,
+            GTPV2Message::ForwardRelocationResponse(i) => &i.header,
+            GTPV2Message::ForwardRelocationCompleteNotification(i) => &i.header,
+            GTPV2Message::ForwardRelocationCompleteAcknowledge(i) => &i.header,
+            GTPV2Message::ForwardAccessContextNotification(i) => &i.header,
+            GTPV2Message::ForwardAccessContextAcknowledge(i) => &i.header,
+            GTPV2Message::RelocationCancelRequest(i) => &i.header,
+            GTPV2Message::RelocationCancelResponse(i) => &i.header,
+            GTPV2Message::DetachNotification(i) => &i.header,
+            GTPV2Message::DetachAcknowledge(i) => &i.header,
+            GTPV2Message::CsPagingIndication(i) => &i.header,
+            GTPV2Message::RanInformationRelay(i) => &i.header,
+            GTPV2Message::AlertMmeNotification(i) => &i.header,
+            GTPV2Message::AlertMmeAcknowledge(i) => &i.header,
+            GTPV2Message::UeActivityNotification(i) => &i.header,
+            GTPV2Message::UeActivityAcknowledge(i) => &i.header,
+            GTPV2Message::IsrStatusIndication(i) => &i.header,
+            GTPV2Message::UeRegistrationQueryRequest(i) => &i.header,
+            GTPV2Message::UeRegistrationQueryResponse(i) => &i.header,
+            GTPV2Message::CreateForwardingTunnelRequest(i) => &i.header,
+            GTPV2Message::CreateForwardingTunnelResponse(i) => &i.header,
+            GTPV2Message::SuspendNotification(i) => &i.header,
+            GTPV2Message::SuspendAcknowledge(i) => &i.header,
+            GTPV2Message::ResumeNotification(i) => &i.header,
+            GTPV2Message::ResumeAcknowledge(i) => &i.header,
+            GTPV2Message::CreateIndirectDataForwardingTunnelRequest(i) => &i.header,
+            GTPV2Message::CreateIndirectDataForwardingTunnelResponse(i) => &i.header,
+            GTPV2Message::DeleteIndirectDataForwardingTunnelRequest(i) => &i.header,
+            GTPV2Message::DeleteIndirectDataForwardingTunnelResponse(i) => &i.header,
+            GTPV2Message::ReleaseAccessBearersRequest(i) => &i.header,
+            GTPV2Message::ReleaseAccessBearersResponse(i) => &i.header,
+            GTPV2Message::DownlinkDataNotification(i) => &i.header,
+            GTPV2Message::DownlinkDataNotificationAcknowledge(i) => &i.header,
+            GTPV2Message::PgwRestartNotification(i) => &i.header,
+            GTPV2Message::PgwRestartNotificationAcknowledge(i) => &i.header,
+            GTPV2Message::UpdatePdnConnectionSetRequest(i) => &i.header,
+            GTPV2Message::UpdatePndConnectionSetResponse(i) => &i.header,
+            GTPV2Message::ModifyAccessBearersRequest(i) => &i.header,
+            GTPV2Message::ModifyAccessBearersResponse(i) => &i.header,
+            GTPV2Message::MbmsSessionStartRequest(i) => &i.header,
+            GTPV2Message::MbmsSessionStartResponse(i) => &i.header,
+            GTPV2Message::MbmsSessionUpdateRequest(i) => &i.header,
+            GTPV2Message::MbmsSessionUpdateResponse(i) => &i.header,
+            GTPV2Message::MbmsSessionStopRequest(i) => &i.header,
+            GTPV2Message::MbmsSessionStopResponse(i) => &i.header,
+            GTPV2Message::Unknown { header, .. } => header,
+        }
+    }
+
+    pub fn header_mut(&mut self) -> &mut Gtpv2Header {
+        match self {
+            GTPV2Message::EchoRequest(i) => &mut i.header,
+            GTPV2Message::EchoResponse(i) => &mut i.header,
+            GTPV2Message::VersionNotSupported(i) => &mut i.header,
+            GTPV2Message::CreateSessionRequest(i) => &mut i.header,
+            GTPV2Message::CreateSessionResponse(i) => &mut i.header,
+            GTPV2Message::ModifyBearerRequest(i) => &mut i.header,
+            GTPV2Message::ModifyBearerResponse(i) => &mut i.header,
+            GTPV2Message::DeleteSessionRequest(i) => &mut i.header,
+            GTPV2Message::DeleteSessionResponse(i) => &mut i.header,
+            GTPV2Message::ChangeNotificationRequest(i) => &mut i.header,
+            GTPV2Message::ChangeNotificationResponse(i) => &mut i.header,
+            GTPV2Message::RemoteUeReportNotification(i) => &mut i.header,
+            GTPV2Message::RemoteUeReportAcknowledge(i) => &mut i.header,
+            GTPV2Message::ModifyBearerCommand(i) => &mut i.header,
+            GTPV2Message::ModifyBearerFailureInd(i) => &mut i.header,
+            GTPV2Message::DeleteBearerCommand(i) => &mut i.header,
+            GTPV2Message::DeleteBearerFailureInd(i) => &mut i.header,
+            GTPV2Message::BearerResourceCommand(i) => &mut i.header,
+            GTPV2Message::BearerResourceFailureInd(i) => &mut i.header,
+            GTPV2Message::DownlinkDataNotificationFailureIndication(i) => &mut i.header,
+            GTPV2Message::TraceSessionActivation(i) => &mut i.header,
+            GTPV2Message::TraceSessionDeactivation(i) => &mut i.header,
+            GTPV2Message::StopPagingIndication(i) => &mut i.header,
+            GTPV2Message::CreateBearerRequest(i) => &mut i.header,
+            GTPV2Message::CreateBearerResponse(i) => &mut i.header,
+            GTPV2Message::UpdateBearerRequest(i) => &mut i.header,
+            GTPV2Message::UpdateBearerResponse(i) => &mut i.header,
+            GTPV2Message::DeleteBearerRequest(i) => &mut i.header,
+            GTPV2Message::DeleteBearerResponse(i) => &mut i.header,
+            GTPV2Message::DeletePdnConnectionSetRequest(i) => &mut i.header,
+            GTPV2Message::DeletePndConnectionSetResponse(i) => &mut i.header,
+            GTPV2Message::PgwDownlinkTriggeringNotification(i) => &mut i.header,
+            GTPV2Message::PgwDownlinkTriggeringAcknowledge(i) => &mut i.header,
+            GTPV2Message::IdentificationRequest(i) => &mut i.header,
+            GTPV2Message::IdentificationResponse(i) => &mut i.header,
+            GTPV2Message::ContextRequest(i) => &mut i.header,
+            GTPV2Message::ContextResponse(i) => &mut i.header,
+            GTPV2Message::ContextAcknowledge(i) => &mut i.header,
+            GTPV2Message::ForwardRelocationRequest(i) => &mut i.header,
+            GTPV2Message::ForwardRelocationResponse(i) => &mut i.header,
+            GTPV2Message::ForwardRelocationCompleteNotification(i) => &mut i.header,
+            GTPV2Message::ForwardRelocationCompleteAcknowledge(i) => &mut i.header,
+            GTPV2Message::ForwardAccessContextNotification(i) => &mut i.header,
+            GTPV2Message::ForwardAccessContextAcknowledge(i) => &mut i.header,
+            GTPV2Message::RelocationCancelRequest(i) => &mut i.header,
+            GTPV2Message::RelocationCancelResponse(i) => &mut i.header,
+            GTPV2Message::DetachNotification(i) => &mut i.header,
+            GTPV2Message::DetachAcknowledge(i) => &mut i.header,
+            GTPV2Message::CsPagingIndication(i) => &mut i.header,
+            GTPV2Message::RanInformationRelay(i) => &mut i.header,
+            GTPV2Message::AlertMmeNotification(i) => &mut i.header,
+            GTPV2Message::AlertMmeAcknowledge(i) => &mut i.header,
+            GTPV2Message::UeActivityNotification(i) => &mut i.header,
+            GTPV2Message::UeActivityAcknowledge(i) => &mut i.header,
+            GTPV2Message::IsrStatusIndication(i) => &mut i.header,
+            GTPV2Message::UeRegistrationQueryRequest(i) => &mut i.header,
+            GTPV2Message::UeRegistrationQueryResponse(i) => &mut i.header,
+            GTPV2Message::CreateForwardingTunnelRequest(i) => &mut i.header,
+            GTPV2Message::CreateForwardingTunnelResponse(i) => &mut i.header,
+            GTPV2Message::SuspendNotification(i) => &mut i.header,
+            GTPV2Message::SuspendAcknowledge(i) => &mut i.header,
+            GTPV2Message::ResumeNotification(i) => &mut i.header,
+            GTPV2Message::ResumeAcknowledge(i) => &mut i.header,
+            GTPV2Message::CreateIndirectDataForwardingTunnelRequest(i) => &mut i.header,
+            GTPV2Message::CreateIndirectDataForwardingTunnelResponse(i) => &mut i.header,
+            GTPV2Message::DeleteIndirectDataForwardingTunnelRequest(i) => &mut i.header,
+            GTPV2Message::DeleteIndirectDataForwardingTunnelResponse(i) => &mut i.header,
+            GTPV2Message::ReleaseAccessBearersRequest(i) => &mut i.header,
+            GTPV2Message::ReleaseAccessBearersResponse(i) => &mut i.header,
+            GTPV2Message::DownlinkDataNotification(i) => &mut i.header,
+            GTPV2Message::DownlinkDataNotificationAcknowledge(i) => &mut i.header,
+            GTPV2Message::PgwRestartNotification(i) => &mut i.header,
+            GTPV2Message::PgwRestartNotificationAcknowledge(i) => &mut i.header,
+            GTPV2Message::UpdatePdnConnectionSetRequest(i) => &mut i.header,
+            GTPV2Message::UpdatePndConnectionSetResponse(i) => &mut i.header,
+            GTPV2Message::ModifyAccessBearersRequest(i) => &mut i.header,
+            GTPV2Message::ModifyAccessBearersResponse(i) => &mut i.header,
+            GTPV2Message::MbmsSessionStartRequest(i) => &mut i.header,
+            GTPV2Message::MbmsSessionStartResponse(i) => &mut i.header,
+            GTPV2Message::MbmsSessionUpdateRequest(i) => &mut i.header,
+            GTPV2Message::MbmsSessionUpdateResponse(i) => &mut i.header,
+            GTPV2Message::MbmsSessionStopRequest(i) => &mut i.header,
+            GTPV2Message::MbmsSessionStopResponse(i) => &mut i.header,
+            GTPV2Message::Unknown { header, .. } => header,
+        }
+    }
+
+    pub fn msg_type(&self) -> u8 {
+        self.header().msgtype
+    }
+
+    // Initial messages (Requests, Notifications and Commands) that trigger a response
+
+    pub fn is_request(&self) -> bool {
+        matches!(
+            self,
+            GTPV2Message::EchoRequest(_)
+                | GTPV2Message::CreateSessionRequest(_)
+                | GTPV2Message::ModifyBearerRequest(_)
+                | GTPV2Message::DeleteSessionRequest(_)
+                | GTPV2Message::ChangeNotificationRequest(_)
+                | GTPV2Message::RemoteUeReportNotification(_)
+                | GTPV2Message::ModifyBearerCommand(_)
+                | GTPV2Message::DeleteBearerCommand(_)
+                | GTPV2Message::BearerResourceCommand(_)
+                | GTPV2Message::CreateBearerRequest(_)
+                | GTPV2Message::UpdateBearerRequest(_)
+                | GTPV2Message::DeleteBearerRequest(_)
+                | GTPV2Message::DeletePdnConnectionSetRequest(_)
+                | GTPV2Message::PgwDownlinkTriggeringNotification(_)
+                | GTPV2Message::IdentificationRequest(_)
+                | GTPV2Message::ContextRequest(_)
+                | GTPV2Message::ForwardRelocationRequest(_)
+                | GTPV2Message::ForwardRelocationCompleteNotification(_)
+                | GTPV2Message::ForwardAccessContextNotification(_)
+                | GTPV2Message::RelocationCancelRequest(_)
+                | GTPV2Message::DetachNotification(_)
+                | GTPV2Message::AlertMmeNotification(_)
+                | GTPV2Message::UeActivityNotification(_)
+                | GTPV2Message::UeRegistrationQueryRequest(_)
+                | GTPV2Message::CreateForwardingTunnelRequest(_)
+                | GTPV2Message::SuspendNotification(_)
+                | GTPV2Message::ResumeNotification(_)
+                | GTPV2Message::CreateIndirectDataForwardingTunnelRequest(_)
+                | GTPV2Message::DeleteIndirectDataForwardingTunnelRequest(_)
+                | GTPV2Message::ReleaseAccessBearersRequest(_)
+                | GTPV2Message::DownlinkDataNotification(_)
+                | GTPV2Message::PgwRestartNotification(_)
+                | GTPV2Message::UpdatePdnConnectionSetRequest(_)
+                | GTPV2Message::ModifyAccessBearersRequest(_)
+                | GTPV2Message::MbmsSessionStartRequest(_)
+                | GTPV2Message::MbmsSessionUpdateRequest(_)
+                | GTPV2Message::MbmsSessionStopRequest(_)
+        )
+    }
+
+    // Command messages, whose triggered request is the response to the command
+
+    pub fn is_command(&self) -> bool {
+        matches!(
+            self,
+            GTPV2Message::ModifyBearerCommand(_)
+                | GTPV2Message::DeleteBearerCommand(_)
+                | GTPV2Message::BearerResourceCommand(_)
+        )
+    }
+
+    // Triggered messages (Responses, Acknowledges and Failure Indications)
+
+    pub fn is_response(&self) -> bool {
+        matches!(
+            self,
+            GTPV2Message::EchoResponse(_)
+                | GTPV2Message::CreateSessionResponse(_)
+                | GTPV2Message::ModifyBearerResponse(_)
+                | GTPV2Message::DeleteSessionResponse(_)
+                | GTPV2Message::ChangeNotificationResponse(_)
+                | GTPV2Message::RemoteUeReportAcknowledge(_)
+                | GTPV2Message::ModifyBearerFailureInd(_)
+                | GTPV2Message::DeleteBearerFailureInd(_)
+                | GTPV2Message::BearerResourceFailureInd(_)
+                | GTPV2Message::CreateBearerResponse(_)
+                | GTPV2Message::UpdateBearerResponse(_)
+                | GTPV2Message::DeleteBearerResponse(_)
+                | GTPV2Message::DeletePndConnectionSetResponse(_)
+                | GTPV2Message::PgwDownlinkTriggeringAcknowledge(_)
+                | GTPV2Message::IdentificationResponse(_)
+                | GTPV2Message::ContextResponse(_)
+                | GTPV2Message::ContextAcknowledge(_)
+                | GTPV2Message::ForwardRelocationResponse(_)
+                | GTPV2Message::ForwardRelocationCompleteAcknowledge(_)
+                | GTPV2Message::ForwardAccessContextAcknowledge(_)
+                | GTPV2Message::RelocationCancelResponse(_)
+                | GTPV2Message::DetachAcknowledge(_)
+                | GTPV2Message::AlertMmeAcknowledge(_)
+                | GTPV2Message::UeActivityAcknowledge(_)
+                | GTPV2Message::UeRegistrationQueryResponse(_)
+                | GTPV2Message::CreateForwardingTunnelResponse(_)
+                | GTPV2Message::SuspendAcknowledge(_)
+                | GTPV2Message::ResumeAcknowledge(_)
+                | GTPV2Message::CreateIndirectDataForwardingTunnelResponse(_)
+                | GTPV2Message::DeleteIndirectDataForwardingTunnelResponse(_)
+                | GTPV2Message::ReleaseAccessBearersResponse(_)
+                | GTPV2Message::DownlinkDataNotificationAcknowledge(_)
+                | GTPV2Message::PgwRestartNotificationAcknowledge(_)
+                | GTPV2Message::UpdatePndConnectionSetResponse(_)
+                | GTPV2Message::ModifyAccessBearersResponse(_)
+                | GTPV2Message::MbmsSessionStartResponse(_)
+                | GTPV2Message::MbmsSessionUpdateResponse(_)
+                | GTPV2Message::MbmsSessionStopResponse(_)
+        )
+    }
+}
+
+#[test]
+fn test_gtpv2_message_echo_req_parse() {
+    let encoded: [u8; 20] = [
+        0x40, 0x01, 0x00, 0x10, 0x2d, 0xcc, 0x38, 0x00, 0x03, 0x00, 0x01, 0x00, 0x0c, 0xff, 0x00,
+        0x03, 0x00, 0x00, 0x0a, 0xff,
+    ];
+    let message = GTPV2Message::parse(&encoded).unwrap();
+    assert_eq!(
+        message,
+        GTPV2Message::EchoRequest(EchoRequest::unmarshal(&encoded).unwrap())
+    );
+    assert_eq!(message.msg_type(), ECHO_REQUEST);
+    assert_eq!(message.header().sqn, 0x2dcc38);
+    assert!(message.is_request());
+    assert!(!message.is_response());
+}
+
+#[test]
+fn test_gtpv2_message_echo_resp_parse() {
+    let encoded: [u8; 13] = [
+        0x40, 0x02, 0x00, 0x09, 0x2d, 0xcc, 0x38, 0x00, 0x03, 0x00, 0x01, 0x00, 0x0c,
+    ];
+    let message = GTPV2Message::parse(&encoded).unwrap();
+    assert_eq!(message.msg_type(), ECHO_RESPONSE);
+    assert!(message.is_response());
+    assert!(!message.is_request());
+}
+
+#[test]
+fn test_gtpv2_message_unknown_parse() {
+    let encoded: [u8; 25] = [
+        0x48, 0xfa, 0x00, 0x15, 0x09, 0x09, 0xa4, 0x56, 0x00, 0x00, 0x2f, 0x00, 0x03, 0x00, 0x01,
+        0x00, 0x0c, 0xff, 0x00, 0x04, 0x00, 0x00, 0x0a, 0xff, 0xaa,
+    ];
+    let decoded = GTPV2Message::Unknown {
+        header: Gtpv2Header {
+            msgtype: 0xfa,
+            piggyback: false,
+            message_prio: None,
+            length: 0x15,
+            teid: Some(0x0909a456),
+            sqn: 0x2f,
+        },
+        ies: vec![
+            InformationElement::Recovery(Recovery {
+                t: RECOVERY,
+                length: 1,
+                ins: 0,
+                recovery: 12,
+            }),
+            InformationElement::PrivateExtension(PrivateExtension {
+                t: PRIVATE_EXT,
+                length: 4,
+                ins: 0,
+                enterprise_id: 0x0a,
+                value: vec![0xff, 0xaa],
+            }),
+        ],
+    };
+    let message = GTPV2Message::parse(&encoded).unwrap();
+    assert_eq!(message, decoded);
+    assert!(!message.is_request());
+    assert!(!message.is_response());
+}
+
+#[test]
+fn test_gtpv2_message_unknown_marshal() {
+    let encoded: [u8; 25] = [
+        0x48, 0xfa, 0x00, 0x15, 0x09, 0x09, 0xa4, 0x56, 0x00, 0x00, 0x2f, 0x00, 0x03, 0x00, 0x01,
+        0x00, 0x0c, 0xff, 0x00, 0x04, 0x00, 0x00, 0x0a, 0xff, 0xaa,
+    ];
+    let message = GTPV2Message::parse(&encoded).unwrap();
+    let mut buffer: Vec<u8> = vec![];
+    message.marshal(&mut buffer);
+    assert_eq!(buffer, encoded);
+}
+
+#[test]
+fn test_gtpv2_message_invalid_length_parse() {
+    let encoded: [u8; 15] = [
+        0x40, 0x01, 0x00, 0x10, 0x2d, 0xcc, 0x38, 0x00, 0x03, 0x00, 0x01, 0x00, 0x0c, 0xff, 0x00,
+    ];
+    assert_eq!(
+        GTPV2Message::parse(&encoded),
+        Err(GTPV2Error::MessageInvalidMessageFormat)
+    );
+}
+
+#[test]
+fn test_gtpv2_message_parse_detailed_grouped_ie_error() {
+    use crate::commons::{DecodeErrorReason, IePathElement};
+    let encoded: [u8; 34] = [
+        0x48, 0x20, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x5d, 0x00, 0x05,
+        0x00, 0x49, 0x00, 0x01, 0x00, 0x05, 0x5d, 0x00, 0x09, 0x00, 0x49, 0x00, 0x01, 0x00, 0x06,
+        0x57, 0x00, 0x00, 0x02,
+    ];
+    let error = GTPV2Message::parse_detailed(&encoded).unwrap_err();
+    assert_eq!(error.msgtype, CREATE_SESSION_REQ);
+    assert_eq!(error.offset, 30);
+    assert_eq!(
+        error.path,
+        vec![
+            IePathElement {
+                t: BEARER_CTX,
+                ins: 0,
+                index: 1,
+            },
+            IePathElement {
+                t: FTEID,
+                ins: 2,
+                index: 0,
+            },
+        ]
+    );
+    assert_eq!(error.reason, DecodeErrorReason::InvalidLength);
+    assert_eq!(error.error, GTPV2Error::IEInvalidLength(FTEID));
+    assert_eq!(
+        error.to_string(),
+        "Message type 32 decoding failed at offset 30 in IE 93[1] ins 0 -> 87[0] ins 2: invalid length (Invalid IE type 87 length)"
+    );
+}
+
+#[test]
+fn test_gtpv2_message_parse_detailed_truncated() {
+    use crate::commons::DecodeErrorReason;
+    let encoded: [u8; 17] = [
+        0x48, 0x20, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x08,
+        0x00, 0x09,
+    ];
+    let error = GTPV2Message::parse_detailed(&encoded).unwrap_err();
+    assert_eq!((error.offset, error.path.len()), (12, 1));
+    assert_eq!(error.reason, DecodeErrorReason::Truncated);
+}
+
+#[test]
+fn test_gtpv2_message_length_shorter_than_header_parse() {
+    let encoded: [u8; 8] = [0x56, 0x98, 0x00, 0x04, 0x7e, 0xe8, 0x31, 0xdf];
+    assert_eq!(
+        GTPV2Message::parse(&encoded),
+        Err(GTPV2Error::MessageInvalidLength(RAN_INFO_RELAY))
+    );
+    let encoded: [u8; 8] = [0x54, 0x60, 0x00, 0x04, 0x00, 0x00, 0x01, 0x00];
+    assert_eq!(
+        GTPV2Message::parse(&encoded),
+        Err(GTPV2Error::MessageInvalidLength(CREATE_BEARER_RESP))
+    );
+}
+
+#[test]
+fn test_gtpv2_message_ie_length_overrun_parse() {
+    // Indication IE announcing 16 octets with only 2 left in the message
+    let encoded: [u8; 18] = [
+        0x48, 0x20, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x4d, 0x00, 0x10,
+        0x00, 0x01, 0x02,
+    ];
+    assert_eq!(
+        GTPV2Message::parse(&encoded),
+        Err(GTPV2Error::IEInvalidLength(INDICATION))
+    );
+}
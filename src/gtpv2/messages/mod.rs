@@ -168,23 +168,23 @@ pub use {
     deletepdnconnectionsetreq::*, deletepdnconnectionsetresp::*, deletesessionreq::*,
     deletesessionresp::*, detachacknowledge::*, detachnotification::*, downlinkdatanotification::*,
     downlinkdatanotificationacknowledge::*, downlinkdatanotificationfailureindication::*,
-    echoreq::*, echoresp::*, forwardaccesscontextacknowledge::*,
+    echoreq::*, echoresp::*, errorresponse::*, forwardaccesscontextacknowledge::*,
     forwardaccesscontextnotification::*, forwardrelocationcompleteacknowledge::*,
     forwardrelocationcompletenotification::*, forwardrelocationreq::*, forwardrelocationresp::*,
     identificationreq::*, identificationresp::*, ies::*, isrstatusindication::*,
     mbmssessionstartreq::*, mbmssessionstartresp::*, mbmssessionstopreq::*, mbmssessionstopresp::*,
-    mbmssessionupdatereq::*, mbmssessionupdateresp::*, modifyaccessbearersreq::*,
+    mbmssessionupdatereq::*, mbmssessionupdateresp::*, message::*, modifyaccessbearersreq::*,
     modifyaccessbearersresp::*, modifybearercommand::*, modifybearerfailureind::*,
     modifybearerreq::*, modifybearerresp::*, pgwdownlinktriggeringacknowledge::*,
     pgwdownlinktriggeringnotification::*, pgwrestartnotification::*,
-    pgwrestartnotificationacknowledge::*, raninformationrelay::*, releaseaccessbearersreq::*,
-    releaseaccessbearersresp::*, relocationcancelreq::*, relocationcancelresp::*,
-    remoteuereportacknowledge::*, remoteuereportnotification::*, resumeacknowledge::*,
-    resumenotification::*, stoppagingindication::*, suspendacknowledge::*, suspendnotification::*,
-    tracesessionactivation::*, tracesessiondeactivation::*, ueactivityacknowledge::*,
-    ueactivitynotification::*, ueregistrationqueryreq::*, ueregistrationqueryresp::*,
-    updatebearerreq::*, updatebearerresp::*, updatepdnconnectionsetreq::*,
-    updatepdnconnectionsetresp::*, versionnotsupported::*,
+    pgwrestartnotificationacknowledge::*, piggyback::*, raninformationrelay::*,
+    releaseaccessbearersreq::*, releaseaccessbearersresp::*, relocationcancelreq::*,
+    relocationcancelresp::*, remoteuereportacknowledge::*, remoteuereportnotification::*,
+    resumeacknowledge::*, resumenotification::*, stoppagingindication::*, suspendacknowledge::*,
+    suspendnotification::*, tracesessionactivation::*, tracesessiondeactivation::*,
+    ueactivityacknowledge::*, ueactivitynotification::*, ueregistrationqueryreq::*,
+    ueregistrationqueryresp::*, updatebearerreq::*, updatebearerresp::*,
+    updatepdnconnectionsetreq::*, updatepdnconnectionsetresp::*, versionnotsupported::*,
 };
 
 mod alertmmeacknowledge;
@@ -223,6 +223,7 @@ mod downlinkdatanotificationacknowledge;
 mod downlinkdatanotificationfailureindication;
 mod echoreq;
 mod echoresp;
+mod errorresponse;
 mod forwardaccesscontextacknowledge;
 mod forwardaccesscontextnotification;
 mod forwardrelocationcompleteacknowledge;
@@ -239,6 +240,7 @@ mod mbmssessionstopreq;
 mod mbmssessionstopresp;
 mod mbmssessionupdatereq;
 mod mbmssessionupdateresp;
+mod message;
 mod modifyaccessbearersreq;
 mod modifyaccessbearersresp;
 mod modifybearercommand;
@@ -249,6 +251,7 @@ mod pgwdownlinktriggeringacknowledge;
 mod pgwdownlinktriggeringnotification;
 mod pgwrestartnotification;
 mod pgwrestartnotificationacknowledge;
+mod piggyback;
 mod raninformationrelay;
 mod releaseaccessbearersreq;
 mod releaseaccessbearersresp;
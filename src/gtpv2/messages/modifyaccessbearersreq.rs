@@ -144,10 +144,8 @@ impl Messages for ModifyAccessBearersRequest {
                         self.recovery = Some(j.clone());
                     }
                 }
-                InformationElement::SecondaryRatUsageDataReport(j) => {
-                    if j.ins == 0 {
-                        self.secondary_rat_usage_report.push(j.clone());
-                    }
+                InformationElement::SecondaryRatUsageDataReport(j) if j.ins == 0 => {
+                    self.secondary_rat_usage_report.push(j.clone());
                 }
                 InformationElement::PSCellId(j) => {
                     if let (0, true) = (j.ins, self.pscellid.is_none()) {
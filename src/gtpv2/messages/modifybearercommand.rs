@@ -112,10 +112,8 @@ impl Messages for ModifyBearerCommand {
                         mandatory[1] = true;
                     }
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.overload_info.push(j.clone());
-                    }
+                InformationElement::OverloadControlInfo(j) if j.ins < 3 => {
+                    self.overload_info.push(j.clone());
                 }
                 InformationElement::Fteid(j) => {
                     if let (0, true) = (j.ins, self.fteid_control.is_none()) {
@@ -313,10 +313,8 @@ impl Messages for ModifyBearerRequest {
                         self.delay_dl_pnr = Some(j.clone())
                     };
                 }
-                InformationElement::BearerContext(j) => {
-                    if j.ins < 2 {
-                        self.bearer_ctxs.push(j.clone())
-                    };
+                InformationElement::BearerContext(j) if j.ins < 2 => {
+                    self.bearer_ctxs.push(j.clone())
                 }
                 InformationElement::Recovery(j) => {
                     if let (0, true) = (j.ins, self.recovery.is_none()) {
@@ -385,10 +383,8 @@ impl Messages for ModifyBearerRequest {
                         self.prai = Some(j.clone())
                     };
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.overload_info.push(j.clone())
-                    };
+                InformationElement::OverloadControlInfo(j) if j.ins < 3 => {
+                    self.overload_info.push(j.clone())
                 }
                 InformationElement::ServingPlmnRateControl(j) => {
                     if let (0, true) = (j.ins, self.srv_plmn_rate_cntrl.is_none()) {
@@ -415,10 +411,8 @@ impl Messages for ModifyBearerRequest {
                         self.wlan_loc_timestamp = Some(j.clone())
                     };
                 }
-                InformationElement::SecondaryRatUsageDataReport(j) => {
-                    if j.ins == 0 {
-                        self.secondary_rat_usage_report.push(j.clone());
-                    }
+                InformationElement::SecondaryRatUsageDataReport(j) if j.ins == 0 => {
+                    self.secondary_rat_usage_report.push(j.clone());
                 }
                 InformationElement::PSCellId(j) => {
                     if let (0, true) = (j.ins, self.pscellid.is_none()) {
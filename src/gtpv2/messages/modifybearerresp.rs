@@ -234,10 +234,8 @@ impl Messages for ModifyBearerResponse {
                         self.pco = Some(j.clone())
                     };
                 }
-                InformationElement::BearerContext(j) => {
-                    if j.ins < 2 {
-                        self.bearer_ctxs.push(j.clone())
-                    };
+                InformationElement::BearerContext(j) if j.ins < 2 => {
+                    self.bearer_ctxs.push(j.clone())
                 }
                 InformationElement::ChangeReportingAction(j) => {
                     if let (0, true) = (j.ins, self.cra.is_none()) {
@@ -295,15 +293,11 @@ impl Messages for ModifyBearerResponse {
                         self.praa = Some(j.clone())
                     };
                 }
-                InformationElement::LoadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.load_control.push(j.clone())
-                    };
+                InformationElement::LoadControlInfo(j) if j.ins < 3 => {
+                    self.load_control.push(j.clone())
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 2 {
-                        self.overload_info.push(j.clone())
-                    };
+                InformationElement::OverloadControlInfo(j) if j.ins < 2 => {
+                    self.overload_info.push(j.clone())
                 }
                 InformationElement::ChargingId(j) => {
                     if let (0, true) = (j.ins, self.charging_id.is_none()) {
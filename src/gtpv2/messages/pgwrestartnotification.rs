@@ -7,7 +7,7 @@ use crate::gtpv2::{
 
 // According to 3GPP TS 29.274 V17.10.0 (2023-12)
 
-pub const PGW_RESTART_NOTIF: u8 = 180;
+pub const PGW_RESTART_NOTIF: u8 = 179;
 
 // Definition of GTPv2-C PGW Restart Notification Message
 
@@ -102,10 +102,8 @@ impl Messages for PgwRestartNotification {
                     }
                     _ => (),
                 },
-                InformationElement::Cause(j) => {
-                    if j.ins == 0 {
-                        self.cause = Some(j.clone());
-                    };
+                InformationElement::Cause(j) if j.ins == 0 => {
+                    self.cause = Some(j.clone());
                 }
                 InformationElement::PrivateExtension(j) => self.private_ext.push(j.clone()),
                 _ => (),
@@ -123,7 +121,7 @@ impl Messages for PgwRestartNotification {
 fn test_pgw_restart_notif_unmarshal() {
     use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
     let encoded: [u8; 56] = [
-        0x48, 0xb4, 0x00, 0x34, 0x09, 0x09, 0xa4, 0x56, 0x00, 0x00, 0x2f, 0x00, 0x4a, 0x00, 0x04,
+        0x48, 0xb3, 0x00, 0x34, 0x09, 0x09, 0xa4, 0x56, 0x00, 0x00, 0x2f, 0x00, 0x4a, 0x00, 0x04,
         0x00, 0x64, 0x14, 0x14, 0x0a, 0x4a, 0x00, 0x10, 0x01, 0x00, 0xfd, 0x00, 0x00, 0x00, 0x00,
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x00, 0x10,
         0x00, 0xff, 0x00, 0x06, 0x00, 0x00, 0x00, 0x01, 0x62, 0x9c, 0xc4,
@@ -167,7 +165,7 @@ fn test_pgw_restart_notif_unmarshal() {
 fn test_pgw_restart_notif_marshal() {
     use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
     let encoded: [u8; 56] = [
-        0x48, 0xb4, 0x00, 0x34, 0x09, 0x09, 0xa4, 0x56, 0x00, 0x00, 0x2f, 0x00, 0x4a, 0x00, 0x04,
+        0x48, 0xb3, 0x00, 0x34, 0x09, 0x09, 0xa4, 0x56, 0x00, 0x00, 0x2f, 0x00, 0x4a, 0x00, 0x04,
         0x00, 0x64, 0x14, 0x14, 0x0a, 0x4a, 0x00, 0x10, 0x01, 0x00, 0xfd, 0x00, 0x00, 0x00, 0x00,
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x00, 0x10,
         0x00, 0xff, 0x00, 0x06, 0x00, 0x00, 0x00, 0x01, 0x62, 0x9c, 0xc4,
@@ -0,0 +1,260 @@
+use crate::gtpv2::{errors::*, header::*, messages::*};
+
+// According to 3GPP TS 29.274 V17.10.0 (2023-12), clause 5.5.1 and Annex F
+
+// Combinations of primary and piggybacked messages allowed in a single datagram:
+// Create Session Response with Create Bearer Request and Create Bearer Response with Modify Bearer Request (Annex F),
+// Modify Bearer Response with Create Bearer Response
+
+pub const PIGGYBACK_COMBINATIONS: [(u8, u8); 3] = [
+    (CREATE_SESSION_RESP, CREATE_BEARER_REQ),
+    (CREATE_BEARER_RESP, MODIFY_BEARER_REQ),
+    (MODIFY_BEARER_RESP, CREATE_BEARER_RESP),
+];
+
+pub fn is_piggyback_allowed(primary: u8, piggybacked: u8) -> bool {
+    PIGGYBACK_COMBINATIONS.contains(&(primary, piggybacked))
+}
+
+// Datagram carrying a message with an optional piggybacked message.
+// The P flag of the primary message header is set when a message is piggybacked,
+// while the piggybacked message never has the P flag set
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PiggybackedMessage {
+    pub primary: GTPV2Message,
+    pub piggybacked: Option<GTPV2Message>,
+}
+
+impl PiggybackedMessage {
+    pub fn marshal(&self, buffer: &mut Vec<u8>) -> Result<(), GTPV2Error> {
+        if let Some(i) = &self.piggybacked {
+            if !is_piggyback_allowed(self.primary.msg_type(), i.msg_type()) {
+                return Err(GTPV2Error::MessageInvalidMessageFormat);
+            }
+        }
+        // Each message is marshalled on its own buffer as the message length is set from the buffer size
+        let mut primary = self.primary.clone();
+        primary.header_mut().piggyback = self.piggybacked.is_some();
+        let mut buffer_msg: Vec<u8> = vec![];
+        primary.marshal(&mut buffer_msg);
+        buffer.append(&mut buffer_msg);
+        if let Some(i) = &self.piggybacked {
+            let mut piggybacked = i.clone();
+            piggybacked.header_mut().piggyback = false;
+            piggybacked.marshal(&mut buffer_msg);
+            buffer.append(&mut buffer_msg);
+        }
+        Ok(())
+    }
+
+    pub fn unmarshal(buffer: &[u8]) -> Result<Self, GTPV2Error> {
+        let header = Gtpv2Header::unmarshal(buffer)?;
+        let end = header.length as usize + MANDATORY_HDR_LENGTH;
+        if buffer.len() < end {
+            return Err(GTPV2Error::MessageInvalidLength(header.msgtype));
+        }
+        if !header.piggyback {
+            if buffer.len() > end {
+                return Err(GTPV2Error::MessageInvalidLength(header.msgtype));
+            }
+            return Ok(PiggybackedMessage {
+                primary: GTPV2Message::parse(buffer)?,
+                piggybacked: None,
+            });
+        }
+        let piggybacked_header = match Gtpv2Header::unmarshal(&buffer[end..]) {
+            Ok(i) => i,
+            Err(_) => return Err(GTPV2Error::MessageInvalidLength(header.msgtype)),
+        };
+        if buffer.len() != end + piggybacked_header.length as usize + MANDATORY_HDR_LENGTH {
+            return Err(GTPV2Error::MessageInvalidLength(piggybacked_header.msgtype));
+        }
+        if piggybacked_header.piggyback
+            || !is_piggyback_allowed(header.msgtype, piggybacked_header.msgtype)
+        {
+            return Err(GTPV2Error::MessageInvalidMessageFormat);
+        }
+        Ok(PiggybackedMessage {
+            primary: GTPV2Message::parse(&buffer[..end])?,
+            piggybacked: Some(GTPV2Message::parse(&buffer[end..])?),
+        })
+    }
+}
+
+#[cfg(test)]
+fn create_session_resp_test() -> CreateSessionResponse {
+    CreateSessionResponse {
+        header: Gtpv2Header {
+            msgtype: CREATE_SESSION_RESP,
+            teid: Some(0x0a0b0c0d),
+            sqn: 0x10,
+            ..Gtpv2Header::default()
+        },
+        cause: Cause {
+            value: CAUSE_REQUEST_ACCEPTED,
+            ..Cause::default()
+        },
+        bearer_ctxs: vec![BearerContext {
+            ebi: Ebi {
+                value: 5,
+                ..Ebi::default()
+            },
+            ..BearerContext::default()
+        }],
+        ..CreateSessionResponse::default()
+    }
+}
+
+#[cfg(test)]
+fn create_bearer_req_test() -> CreateBearerRequest {
+    let mut message = CreateBearerRequest::default();
+    message.header.teid = Some(0x0a0b0c0d);
+    message.header.sqn = 0x800011;
+    message.linked_ebi.value = 5;
+    message.bearer_ctxs = vec![BearerContext {
+        ebi: Ebi {
+            value: 6,
+            ..Ebi::default()
+        },
+        ..BearerContext::default()
+    }];
+    message
+}
+
+#[cfg(test)]
+const CREATE_SESSION_RESP_WITH_CREATE_BEARER_REQ: [u8; 53] = [
+    0x58, 0x21, 0x00, 0x17, 0x0a, 0x0b, 0x0c, 0x0d, 0x00, 0x00, 0x10, 0x00, 0x02, 0x00, 0x02, 0x00,
+    0x10, 0x00, 0x5d, 0x00, 0x05, 0x00, 0x49, 0x00, 0x01, 0x00, 0x05, 0x48, 0x5f, 0x00, 0x16, 0x0a,
+    0x0b, 0x0c, 0x0d, 0x80, 0x00, 0x11, 0x00, 0x49, 0x00, 0x01, 0x00, 0x05, 0x5d, 0x00, 0x05, 0x00,
+    0x49, 0x00, 0x01, 0x00, 0x06,
+];
+
+#[test]
+fn test_piggybacked_message_marshal() {
+    let message = PiggybackedMessage {
+        primary: GTPV2Message::CreateSessionResponse(create_session_resp_test()),
+        piggybacked: Some(GTPV2Message::CreateBearerRequest(create_bearer_req_test())),
+    };
+    let mut buffer: Vec<u8> = vec![];
+    message.marshal(&mut buffer).unwrap();
+    assert_eq!(buffer, CREATE_SESSION_RESP_WITH_CREATE_BEARER_REQ);
+}
+
+#[test]
+fn test_piggybacked_message_invalid_marshal() {
+    let mut request = ModifyBearerRequest::default();
+    request.header.sqn = 0x20;
+    let mut response = CreateBearerResponse::default();
+    response.header.sqn = 0x800011;
+    response.bearer_ctxs = vec![BearerContext::default()];
+    let mut message = PiggybackedMessage {
+        primary: GTPV2Message::ModifyBearerRequest(request),
+        piggybacked: Some(GTPV2Message::CreateBearerResponse(response)),
+    };
+    let mut buffer: Vec<u8> = vec![];
+    assert_eq!(
+        message.marshal(&mut buffer),
+        Err(GTPV2Error::MessageInvalidMessageFormat)
+    );
+    assert!(buffer.is_empty());
+    // Create Bearer Response with a piggybacked Modify Bearer Request is allowed
+    (message.primary, message.piggybacked) = (message.piggybacked.unwrap(), Some(message.primary));
+    message.marshal(&mut buffer).unwrap();
+    let decoded = PiggybackedMessage::unmarshal(&buffer).unwrap();
+    assert_eq!(decoded.primary.msg_type(), CREATE_BEARER_RESP);
+    assert_eq!(decoded.piggybacked.unwrap().msg_type(), MODIFY_BEARER_REQ);
+}
+
+#[test]
+fn test_piggybacked_modify_bearer_resp_with_create_bearer_resp() {
+    let mut primary = ModifyBearerResponse::default();
+    primary.header.sqn = 0x30;
+    let mut piggybacked = CreateBearerResponse::default();
+    piggybacked.header.sqn = 0x800012;
+    piggybacked.bearer_ctxs = vec![BearerContext::default()];
+    let message = PiggybackedMessage {
+        primary: GTPV2Message::ModifyBearerResponse(primary),
+        piggybacked: Some(GTPV2Message::CreateBearerResponse(piggybacked)),
+    };
+    let mut buffer: Vec<u8> = vec![];
+    message.marshal(&mut buffer).unwrap();
+    assert_eq!(buffer[0] & 0x10, 0x10);
+    let decoded = PiggybackedMessage::unmarshal(&buffer).unwrap();
+    assert!(decoded.primary.header().piggyback);
+    assert_eq!(decoded.primary.msg_type(), MODIFY_BEARER_RESP);
+    match decoded.piggybacked {
+        Some(GTPV2Message::CreateBearerResponse(i)) => {
+            assert!(!i.header.piggyback);
+            assert_eq!(i.header.sqn, 0x800012);
+        }
+        i => panic!("unexpected piggybacked message {:?}", i),
+    }
+}
+
+#[test]
+fn test_piggybacked_message_unmarshal() {
+    let decoded =
+        PiggybackedMessage::unmarshal(&CREATE_SESSION_RESP_WITH_CREATE_BEARER_REQ).unwrap();
+    assert!(decoded.primary.header().piggyback);
+    assert_eq!(decoded.primary.msg_type(), CREATE_SESSION_RESP);
+    match decoded.piggybacked {
+        Some(GTPV2Message::CreateBearerRequest(i)) => {
+            assert_eq!(i.header.sqn, 0x800011);
+            assert_eq!(i.bearer_ctxs[0].ebi.value, 6);
+        }
+        i => panic!("unexpected piggybacked message {:?}", i),
+    }
+}
+
+#[test]
+fn test_piggybacked_message_single_unmarshal() {
+    let decoded =
+        PiggybackedMessage::unmarshal(&CREATE_SESSION_RESP_WITH_CREATE_BEARER_REQ[27..]).unwrap();
+    assert_eq!(decoded.primary.msg_type(), CREATE_BEARER_REQ);
+    assert_eq!(decoded.piggybacked, None);
+}
+
+#[test]
+fn test_piggybacked_message_invalid_unmarshal() {
+    let mut encoded = CREATE_SESSION_RESP_WITH_CREATE_BEARER_REQ.to_vec();
+    // Create Bearer Request piggybacked on Modify Bearer Response is not allowed
+    encoded[1] = MODIFY_BEARER_RESP;
+    assert_eq!(
+        PiggybackedMessage::unmarshal(&encoded),
+        Err(GTPV2Error::MessageInvalidMessageFormat)
+    );
+    // P flag set on the piggybacked message
+    encoded[1] = CREATE_SESSION_RESP;
+    encoded[27] |= 0x10;
+    assert_eq!(
+        PiggybackedMessage::unmarshal(&encoded),
+        Err(GTPV2Error::MessageInvalidMessageFormat)
+    );
+    // P flag set without piggybacked message
+    assert_eq!(
+        PiggybackedMessage::unmarshal(&encoded[..27]),
+        Err(GTPV2Error::MessageInvalidLength(CREATE_SESSION_RESP))
+    );
+    // Trailing bytes without P flag
+    encoded[0] &= !0x10;
+    assert_eq!(
+        PiggybackedMessage::unmarshal(&encoded),
+        Err(GTPV2Error::MessageInvalidLength(CREATE_SESSION_RESP))
+    );
+}
+
+#[test]
+fn test_piggybacked_message_short_length_unmarshal() {
+    // Message length shorter than the header with the TEID
+    assert_eq!(
+        PiggybackedMessage::unmarshal(&[0x42, 0x48, 0x00, 0x04, 0xfd, 0x16, 0xc6, 0xb7]),
+        Err(GTPV2Error::MessageInvalidLength(0x48))
+    );
+    let mut encoded = CREATE_SESSION_RESP_WITH_CREATE_BEARER_REQ[..27].to_vec();
+    encoded.extend_from_slice(&[0x48, 0x5f, 0x00, 0x04, 0x0a, 0x0b, 0x0c, 0x0d]);
+    assert_eq!(
+        PiggybackedMessage::unmarshal(&encoded),
+        Err(GTPV2Error::MessageInvalidLength(CREATE_SESSION_RESP))
+    );
+}
@@ -108,10 +108,8 @@ impl Messages for ReleaseAccessBearersRequest {
     fn fromvec(&mut self, elements: Vec<InformationElement>) -> Result<bool, GTPV2Error> {
         for e in elements.iter() {
             match e {
-                InformationElement::Ebi(j) => {
-                    if j.ins == 0 {
-                        self.list_rabs.push(j.clone());
-                    }
+                InformationElement::Ebi(j) if j.ins == 0 => {
+                    self.list_rabs.push(j.clone());
                 }
                 InformationElement::NodeType(j) => {
                     if let (0, true) = (j.ins, self.orig_node.is_none()) {
@@ -123,10 +121,8 @@ impl Messages for ReleaseAccessBearersRequest {
                         self.indication = Some(j.clone());
                     }
                 }
-                InformationElement::SecondaryRatUsageDataReport(j) => {
-                    if j.ins == 0 {
-                        self.secondary_rat_usage_report.push(j.clone());
-                    }
+                InformationElement::SecondaryRatUsageDataReport(j) if j.ins == 0 => {
+                    self.secondary_rat_usage_report.push(j.clone());
                 }
                 InformationElement::PSCellId(j) => {
                     if let (0, true) = (j.ins, self.pscellid.is_none()) {
@@ -122,15 +122,11 @@ impl Messages for ReleaseAccessBearersResponse {
                         self.indication = Some(j.clone())
                     };
                 }
-                InformationElement::LoadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.load_control.push(j.clone())
-                    };
+                InformationElement::LoadControlInfo(j) if j.ins < 3 => {
+                    self.load_control.push(j.clone())
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 2 {
-                        self.overload_info.push(j.clone())
-                    };
+                InformationElement::OverloadControlInfo(j) if j.ins < 2 => {
+                    self.overload_info.push(j.clone())
                 }
                 InformationElement::PrivateExtension(j) => self.private_ext.push(j.clone()),
                 _ => (),
@@ -7,7 +7,7 @@ use crate::gtpv2::{
 
 // According to 3GPP TS 29.274 V17.10.0 (2023-12)
 
-pub const STOP_PAGING_IND: u8 = 164;
+pub const STOP_PAGING_IND: u8 = 73;
 
 // Definition of GTPv2-C Stop Paging Indication Message
 
@@ -83,10 +83,8 @@ impl Messages for StopPagingIndication {
     fn fromvec(&mut self, elements: Vec<InformationElement>) -> Result<bool, GTPV2Error> {
         for e in elements.into_iter() {
             match e {
-                InformationElement::Imsi(j) => {
-                    if j.ins == 0 {
-                        self.imsi = Some(j);
-                    }
+                InformationElement::Imsi(j) if j.ins == 0 => {
+                    self.imsi = Some(j);
                 }
                 InformationElement::PrivateExtension(j) => self.private_ext.push(j),
                 _ => (),
@@ -99,7 +97,7 @@ impl Messages for StopPagingIndication {
 #[test]
 fn test_stop_paging_indication_unmarshal() {
     let encoded: [u8; 34] = [
-        0x48, 0xa4, 0x00, 0x1e, 0xa4, 0x78, 0x95, 0x80, 0x4b, 0x29, 0x1e, 0x00, 0x01, 0x00, 0x08,
+        0x48, 0x49, 0x00, 0x1e, 0xa4, 0x78, 0x95, 0x80, 0x4b, 0x29, 0x1e, 0x00, 0x01, 0x00, 0x08,
         0x00, 0x09, 0x41, 0x50, 0x01, 0x91, 0x16, 0x78, 0xf3, 0xff, 0x00, 0x06, 0x00, 0x07, 0xdb,
         0x07, 0x00, 0x01, 0x00,
     ];
@@ -133,7 +131,7 @@ fn test_stop_paging_indication_unmarshal() {
 #[test]
 fn test_stop_paging_indication_marshal() {
     let encoded: [u8; 34] = [
-        0x48, 0xa4, 0x00, 0x1e, 0xa4, 0x78, 0x95, 0x80, 0x4b, 0x29, 0x1e, 0x00, 0x01, 0x00, 0x08,
+        0x48, 0x49, 0x00, 0x1e, 0xa4, 0x78, 0x95, 0x80, 0x4b, 0x29, 0x1e, 0x00, 0x01, 0x00, 0x08,
         0x00, 0x09, 0x41, 0x50, 0x01, 0x91, 0x16, 0x78, 0xf3, 0xff, 0x00, 0x06, 0x00, 0x07, 0xdb,
         0x07, 0x00, 0x01, 0x00,
     ];
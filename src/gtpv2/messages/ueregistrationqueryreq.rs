@@ -82,11 +82,9 @@ impl Messages for UeRegistrationQueryRequest {
         let mut mandatory = false;
         for e in elements.iter() {
             match e {
-                InformationElement::Imsi(j) => {
-                    if j.ins == 0 {
-                        mandatory = true;
-                        self.imsi = j.clone();
-                    };
+                InformationElement::Imsi(j) if j.ins == 0 => {
+                    mandatory = true;
+                    self.imsi = j.clone();
                 }
                 InformationElement::PrivateExtension(j) => self.private_ext.push(j.clone()),
                 _ => (),
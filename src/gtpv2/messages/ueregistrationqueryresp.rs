@@ -90,23 +90,17 @@ impl Messages for UeRegistrationQueryResponse {
         let mut mandatory: [bool; 3] = [false; 3];
         for e in elements.iter() {
             match e {
-                InformationElement::Cause(j) => {
-                    if j.ins == 0 && !mandatory[0] {
-                        mandatory[0] = true;
-                        self.cause = j.clone();
-                    };
+                InformationElement::Cause(j) if j.ins == 0 && !mandatory[0] => {
+                    mandatory[0] = true;
+                    self.cause = j.clone();
                 }
-                InformationElement::Imsi(j) => {
-                    if j.ins == 0 && !mandatory[1] {
-                        mandatory[1] = true;
-                        self.imsi = j.clone();
-                    };
+                InformationElement::Imsi(j) if j.ins == 0 && !mandatory[1] => {
+                    mandatory[1] = true;
+                    self.imsi = j.clone();
                 }
-                InformationElement::PlmnId(j) => {
-                    if j.ins == 0 && !mandatory[2] {
-                        mandatory[2] = true;
-                        self.selected_cnoid = j.clone();
-                    };
+                InformationElement::PlmnId(j) if j.ins == 0 && !mandatory[2] => {
+                    mandatory[2] = true;
+                    self.selected_cnoid = j.clone();
                 }
                 InformationElement::PrivateExtension(j) => self.private_ext.push(j.clone()),
                 _ => (),
@@ -166,10 +166,8 @@ impl Messages for UpdateBearerRequest {
         let mut mandatory: bool = false;
         for e in elements.iter() {
             match e {
-                InformationElement::BearerContext(j) => {
-                    if j.ins == 0 {
-                        self.bearer_ctxs.push(j.clone());
-                    }
+                InformationElement::BearerContext(j) if j.ins == 0 => {
+                    self.bearer_ctxs.push(j.clone());
                 }
                 InformationElement::Pti(j) => {
                     if let (0, true) = (j.ins, self.pti.is_none()) {
@@ -220,25 +218,19 @@ impl Messages for UpdateBearerRequest {
                     }
                 }
 
-                InformationElement::LoadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.load_control.push(j.clone());
-                    }
+                InformationElement::LoadControlInfo(j) if j.ins < 3 => {
+                    self.load_control.push(j.clone());
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 2 {
-                        self.overload_info.push(j.clone());
-                    }
+                InformationElement::OverloadControlInfo(j) if j.ins < 2 => {
+                    self.overload_info.push(j.clone());
                 }
                 InformationElement::Fcontainer(j) => {
                     if let (0, true) = (j.ins, self.nbifom.is_none()) {
                         self.nbifom = Some(j.clone());
                     }
                 }
-                InformationElement::PgwChangeInfo(j) => {
-                    if j.ins == 0 {
-                        self.pgw_change_info.push(j.clone());
-                    }
+                InformationElement::PgwChangeInfo(j) if j.ins == 0 => {
+                    self.pgw_change_info.push(j.clone());
                 }
                 InformationElement::Fteid(j) => {
                     if let (0, true) = (j.ins, self.fteid_control.is_none()) {
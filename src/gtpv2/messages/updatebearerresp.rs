@@ -191,11 +191,9 @@ impl Messages for UpdateBearerResponse {
                         (self.cause, mandatory[0]) = (j.clone(), true);
                     }
                 }
-                InformationElement::BearerContext(j) => {
-                    if j.ins == 0 {
-                        mandatory[1] = true;
-                        self.bearer_ctxs.push(j.clone());
-                    }
+                InformationElement::BearerContext(j) if j.ins == 0 => {
+                    mandatory[1] = true;
+                    self.bearer_ctxs.push(j.clone());
                 }
                 InformationElement::Pco(j) => {
                     if let (0, true) = (j.ins, self.pco.is_none()) {
@@ -245,10 +243,8 @@ impl Messages for UpdateBearerResponse {
                         _ => (),
                     }
                 }
-                InformationElement::OverloadControlInfo(j) => {
-                    if j.ins < 3 {
-                        self.overload_info.push(j.clone());
-                    }
+                InformationElement::OverloadControlInfo(j) if j.ins < 3 => {
+                    self.overload_info.push(j.clone());
                 }
                 InformationElement::PresenceReportingAreaInformation(j) => {
                     if let (0, true) = (j.ins, self.prai.is_none()) {
@@ -91,15 +91,11 @@ impl Messages for UpdatePdnConnectionSetRequest {
         for e in elements.iter() {
             match e {
                 InformationElement::Fqcsid(j) => match j.ins {
-                    0 => {
-                        if self.mme_fqcsid.is_none() {
-                            self.mme_fqcsid = Some(j.clone());
-                        }
+                    0 if self.mme_fqcsid.is_none() => {
+                        self.mme_fqcsid = Some(j.clone());
                     }
-                    1 => {
-                        if self.sgw_fqcsid.is_none() {
-                            self.sgw_fqcsid = Some(j.clone());
-                        }
+                    1 if self.sgw_fqcsid.is_none() => {
+                        self.sgw_fqcsid = Some(j.clone());
                     }
                     _ => (),
                 },
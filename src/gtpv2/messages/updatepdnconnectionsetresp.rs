@@ -94,11 +94,9 @@ impl Messages for UpdatePndConnectionSetResponse {
         let mut mandatory = false;
         for e in elements.iter() {
             match e {
-                InformationElement::Cause(j) => {
-                    if j.ins == 0 {
-                        mandatory = true;
-                        self.cause = j.clone();
-                    };
+                InformationElement::Cause(j) if j.ins == 0 => {
+                    mandatory = true;
+                    self.cause = j.clone();
                 }
                 InformationElement::Fqcsid(j) => {
                     if let (0, true) = (j.ins, self.pgw_fqcsid.is_none()) {
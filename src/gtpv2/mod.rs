@@ -1,4 +1,5 @@
 pub mod errors;
 pub mod header;
 pub mod messages;
+pub mod node;
 pub mod utils;
@@ -0,0 +1,52 @@
+use crate::gtpv2::errors::GTPV2Error;
+use std::{fmt::Display, io::ErrorKind, net::SocketAddr};
+
+// Errors of the GTPv2-C node and its transaction handling
+
+#[derive(Debug, PartialEq, Eq)]
+
+pub enum NodeError {
+    // Transport Errors
+    Io(ErrorKind),
+    // Message Errors
+    MessageDecode(GTPV2Error),
+    MessageNotInitial(u8),
+    MessageNotTriggered(u8),
+    // Transaction Errors
+    RequestTimeout { peer: SocketAddr, sqn: u32 },
+}
+
+impl std::error::Error for NodeError {}
+
+impl From<std::io::Error> for NodeError {
+    fn from(i: std::io::Error) -> Self {
+        NodeError::Io(i.kind())
+    }
+}
+
+impl From<GTPV2Error> for NodeError {
+    fn from(i: GTPV2Error) -> Self {
+        NodeError::MessageDecode(i)
+    }
+}
+
+impl Display for NodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            // Transport Errors
+            NodeError::Io(i) => write!(f, "Transport error: {}", i),
+            // Message Errors
+            NodeError::MessageDecode(i) => write!(f, "Message decoding error: {}", i),
+            NodeError::MessageNotInitial(i) => {
+                write!(f, "Message type {} is not an initial message", i)
+            }
+            NodeError::MessageNotTriggered(i) => {
+                write!(f, "Message type {} is not a triggered message", i)
+            }
+            // Transaction Errors
+            NodeError::RequestTimeout { peer, sqn } => {
+                write!(f, "No response from {} for sequence number {}", peer, sqn)
+            }
+        }
+    }
+}
@@ -0,0 +1,775 @@
+// GTPv2-C node over UDP with request/response transaction handling - according to 3GPP TS 29.274 V17.10.0 (2023-12)
+
+use crate::gtpv2::{
+    errors::GTPV2Error,
+    header::*,
+    messages::GTPV2Message,
+    node::{errors::*, paths::*, transactions::*},
+};
+use std::{
+    collections::VecDeque,
+    io::ErrorKind,
+    net::{SocketAddr, ToSocketAddrs, UdpSocket},
+    time::{Duration, Instant},
+};
+
+pub const GTPV2C_PORT: u16 = 2123;
+pub const MAX_DATAGRAM_SIZE: usize = 65535;
+
+// Events surfaced by the node to the application
+
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum NodeEvent {
+    // Initial message received from a peer, to be answered with send_response()
+    Request {
+        peer: SocketAddr,
+        message: GTPV2Message,
+    },
+    // Triggered message matching an initial message sent by the node.
+    // The triggered request answering a Command is delivered here and is to be answered with send_response().
+    Response {
+        peer: SocketAddr,
+        request: GTPV2Message,
+        response: GTPV2Message,
+    },
+    // Message that is neither an initial message nor a triggered message
+    Message {
+        peer: SocketAddr,
+        message: GTPV2Message,
+    },
+    // Initial message sent by the node that got no response after N3-REQUESTS retransmissions
+    Timeout {
+        peer: SocketAddr,
+        request: GTPV2Message,
+    },
+    // Path failure or peer restart detected by the path management
+    Path(PathEvent),
+    // Datagram, or part of a piggybacked datagram, that could not be decoded.
+    // The other messages carried by the datagram are still processed.
+    DecodeError {
+        peer: SocketAddr,
+        error: GTPV2Error,
+    },
+}
+
+// GTPv2-C node
+
+#[derive(Debug)]
+pub struct Gtpv2Node {
+    socket: UdpSocket,
+    transactions: TransactionTable,
+    sqn: u32,
+    events: VecDeque<NodeEvent>,
+    paths: Option<PathManager>,
+    buffer: Vec<u8>,
+}
+
+impl Gtpv2Node {
+    pub fn bind<A: ToSocketAddrs>(addr: A, config: TransactionConfig) -> Result<Self, NodeError> {
+        Ok(Gtpv2Node {
+            socket: UdpSocket::bind(addr)?,
+            transactions: TransactionTable::new(config),
+            sqn: 0,
+            events: VecDeque::new(),
+            paths: None,
+            buffer: vec![0; MAX_DATAGRAM_SIZE],
+        })
+    }
+
+    pub fn local_addr(&self) -> Result<SocketAddr, NodeError> {
+        Ok(self.socket.local_addr()?)
+    }
+
+    pub fn transactions(&self) -> &TransactionTable {
+        &self.transactions
+    }
+
+    // Supervise the paths to the peers added with paths_mut() and to the peers the node sends requests to:
+    // Echo Requests are sent periodically, Echo Requests received are answered automatically,
+    // path failures, restorations and peer restarts are reported as events
+
+    pub fn enable_path_management(&mut self, config: PathConfig) {
+        self.paths = Some(PathManager::new(config));
+    }
+
+    pub fn paths(&self) -> Option<&PathManager> {
+        self.paths.as_ref()
+    }
+
+    pub fn paths_mut(&mut self) -> Option<&mut PathManager> {
+        self.paths.as_mut()
+    }
+
+    // Allocate the sequence number of a new initial message, MSB is set for Command messages
+
+    pub fn next_sqn(&mut self, command: bool) -> u32 {
+        self.sqn = (self.sqn + 1) & (MAX_SQN >> 1);
+        if command {
+            self.sqn | COMMAND_SQN_FLAG
+        } else {
+            self.sqn
+        }
+    }
+
+    // Send an initial message with a newly allocated sequence number and start its transaction
+
+    pub fn send_request(
+        &mut self,
+        peer: SocketAddr,
+        message: GTPV2Message,
+    ) -> Result<u32, NodeError> {
+        let sqn = self.next_sqn(message.is_command());
+        self.send_triggered_request(peer, message, sqn)
+    }
+
+    // Send an initial message with the given sequence number, e.g. a request triggered by a Command
+
+    pub fn send_triggered_request(
+        &mut self,
+        peer: SocketAddr,
+        mut message: GTPV2Message,
+        sqn: u32,
+    ) -> Result<u32, NodeError> {
+        if !message.is_request() {
+            return Err(NodeError::MessageNotInitial(message.msg_type()));
+        }
+        message.header_mut().sqn = sqn & MAX_SQN;
+        let mut buffer: Vec<u8> = vec![];
+        message.marshal(&mut buffer);
+        self.socket.send_to(&buffer, peer)?;
+        let now = Instant::now();
+        if let Some(i) = self.paths.as_mut() {
+            i.add_peer(peer, now);
+        }
+        self.transactions.add_pending(peer, message, buffer, now);
+        Ok(sqn & MAX_SQN)
+    }
+
+    // Send a triggered message, the sequence number shall be copied from the initial message.
+    // The response is kept to answer retransmissions of the initial message.
+
+    pub fn send_response(
+        &mut self,
+        peer: SocketAddr,
+        message: GTPV2Message,
+    ) -> Result<(), NodeError> {
+        if !message.is_response() {
+            return Err(NodeError::MessageNotTriggered(message.msg_type()));
+        }
+        let mut buffer: Vec<u8> = vec![];
+        message.marshal(&mut buffer);
+        self.socket.send_to(&buffer, peer)?;
+        self.transactions
+            .cache_response(peer, message.header().sqn, buffer, Instant::now());
+        Ok(())
+    }
+
+    // Send a message outside of any transaction
+
+    pub fn send(&mut self, peer: SocketAddr, message: &GTPV2Message) -> Result<(), NodeError> {
+        let mut buffer: Vec<u8> = vec![];
+        message.marshal(&mut buffer);
+        self.socket.send_to(&buffer, peer)?;
+        Ok(())
+    }
+
+    // Receive and process messages and run the transaction timers for at most the given duration.
+    // Returns the next event, or None if nothing happened in the meantime.
+
+    pub fn poll(&mut self, timeout: Duration) -> Result<Option<NodeEvent>, NodeError> {
+        let until = Instant::now() + timeout;
+        loop {
+            self.run_timers()?;
+            if let Some(i) = self.events.pop_front() {
+                return Ok(Some(i));
+            }
+            let now = Instant::now();
+            if now >= until {
+                return Ok(None);
+            }
+            let wait = self
+                .transactions
+                .next_deadline()
+                .into_iter()
+                .chain(self.paths.as_ref().and_then(|x| x.next_deadline()))
+                .fold(until, |x, y| x.min(y))
+                .saturating_duration_since(now)
+                .max(Duration::from_millis(1));
+            self.socket.set_read_timeout(Some(wait))?;
+            match self.socket.recv_from(&mut self.buffer) {
+                Ok((len, peer)) => {
+                    // The receive buffer is reused across polls, it is taken out while the datagram is handled
+                    let buffer = std::mem::take(&mut self.buffer);
+                    let result = self.handle_datagram(&buffer[..len], peer);
+                    self.buffer = buffer;
+                    result?
+                }
+                Err(i) if matches!(i.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => (),
+                Err(i) => return Err(i.into()),
+            }
+        }
+    }
+
+    // Send an initial message and block until its triggered message is received or the transaction times out.
+    // Other events received in the meantime are kept for the next poll().
+
+    pub fn request(
+        &mut self,
+        peer: SocketAddr,
+        message: GTPV2Message,
+    ) -> Result<GTPV2Message, NodeError> {
+        let sqn = self.send_request(peer, message)?;
+        let t3 = self.transactions.config.t3_response;
+        let mut other: Vec<NodeEvent> = vec![];
+        let result = loop {
+            match self.poll(t3) {
+                Ok(Some(NodeEvent::Response {
+                    peer: p,
+                    request,
+                    response,
+                })) if p == peer && request.header().sqn == sqn => break Ok(response),
+                Ok(Some(NodeEvent::Timeout { peer: p, request }))
+                    if p == peer && request.header().sqn == sqn =>
+                {
+                    break Err(NodeError::RequestTimeout { peer, sqn })
+                }
+                Ok(Some(i)) => other.push(i),
+                Ok(None) => (),
+                Err(i) => break Err(i),
+            }
+        };
+        other
+            .into_iter()
+            .rev()
+            .for_each(|x| self.events.push_front(x));
+        result
+    }
+
+    fn run_timers(&mut self) -> Result<(), NodeError> {
+        let (retransmit, timed_out) = self.transactions.expire(Instant::now());
+        for (peer, buffer) in retransmit.iter() {
+            self.socket.send_to(buffer, peer)?;
+        }
+        for i in timed_out.into_iter() {
+            match self.paths.as_mut() {
+                Some(paths) if paths.is_echo(i.peer, i.sqn) => {
+                    if let Some(j) = paths.on_echo_timeout(i.peer) {
+                        self.events.push_back(NodeEvent::Path(j));
+                    }
+                }
+                _ => self.events.push_back(NodeEvent::Timeout {
+                    peer: i.peer,
+                    request: i.request,
+                }),
+            }
+        }
+        let (echo, due) = match self.paths.as_mut() {
+            Some(i) => (i.echo_request(), i.due_echoes(Instant::now())),
+            None => return Ok(()),
+        };
+        for peer in due.into_iter() {
+            let sqn = self.send_request(peer, GTPV2Message::EchoRequest(echo.clone()))?;
+            if let Some(i) = self.paths.as_mut() {
+                i.echo_sent(peer, sqn);
+            }
+        }
+        Ok(())
+    }
+
+    // Each message of the datagram is handled on its own: a part that cannot be decoded is reported
+    // as an event and does not prevent the handling of the other parts
+
+    fn handle_datagram(&mut self, buffer: &[u8], peer: SocketAddr) -> Result<(), NodeError> {
+        let (parts, error) = split_parts(buffer);
+        for part in parts.into_iter() {
+            match GTPV2Message::parse(part) {
+                Ok(i) => self.handle_message(i, peer)?,
+                Err(error) => self
+                    .events
+                    .push_back(NodeEvent::DecodeError { peer, error }),
+            }
+        }
+        if let Some(error) = error {
+            self.events
+                .push_back(NodeEvent::DecodeError { peer, error });
+        }
+        Ok(())
+    }
+
+    fn handle_message(&mut self, message: GTPV2Message, peer: SocketAddr) -> Result<(), NodeError> {
+        let sqn = message.header().sqn;
+        let now = Instant::now();
+        if let Some(i) = self.paths.as_mut() {
+            if let Some(j) = i.on_message(peer, &message) {
+                self.events.push_back(NodeEvent::Path(j));
+            }
+            // Echo Responses to the Echo Requests of the path management are consumed here
+            if i.is_echo(peer, sqn) && message.is_response() {
+                if let Some(j) = i.on_echo_response(peer) {
+                    self.events.push_back(NodeEvent::Path(j));
+                }
+                self.transactions.complete(peer, sqn);
+                return Ok(());
+            }
+        }
+        if message.is_response() {
+            // Late or duplicated triggered messages are silently discarded
+            if let Some(i) = self.transactions.complete(peer, sqn) {
+                self.events.push_back(NodeEvent::Response {
+                    peer,
+                    request: i.request,
+                    response: message,
+                });
+            }
+        } else if message.is_request() {
+            match self.transactions.on_request(peer, sqn, now) {
+                RequestDisposition::New => {
+                    let command = self
+                        .transactions
+                        .pending(peer, sqn)
+                        .is_some_and(|x| x.request.is_command());
+                    match command {
+                        true => {
+                            if let Some(i) = self.transactions.complete(peer, sqn) {
+                                self.events.push_back(NodeEvent::Response {
+                                    peer,
+                                    request: i.request,
+                                    response: message,
+                                });
+                            }
+                        }
+                        false => match (&self.paths, &message) {
+                            (Some(i), GTPV2Message::EchoRequest(_)) => {
+                                let response = GTPV2Message::EchoResponse(i.echo_response(sqn));
+                                self.send_response(peer, response)?;
+                            }
+                            _ => self.events.push_back(NodeEvent::Request { peer, message }),
+                        },
+                    }
+                }
+                RequestDisposition::DuplicateInProgress => (),
+                RequestDisposition::DuplicateAnswered(i) => {
+                    self.socket.send_to(&i, peer)?;
+                }
+            }
+        } else {
+            self.events.push_back(NodeEvent::Message { peer, message });
+        }
+        Ok(())
+    }
+}
+
+// Split a datagram into the messages it carries, following the piggybacking (P) flag
+
+pub fn split_datagram(buffer: &[u8]) -> Result<Vec<&[u8]>, GTPV2Error> {
+    match split_parts(buffer) {
+        (i, None) => Ok(i),
+        (_, Some(i)) => Err(i),
+    }
+}
+
+// Messages that could be delimited in a datagram, with the error that stopped the split if any
+
+fn split_parts(buffer: &[u8]) -> (Vec<&[u8]>, Option<GTPV2Error>) {
+    let mut messages: Vec<&[u8]> = vec![];
+    let mut cursor: usize = 0;
+    loop {
+        let header = match Gtpv2Header::unmarshal(&buffer[cursor..]) {
+            Ok(i) => i,
+            Err(i) => return (messages, Some(i)),
+        };
+        let end = cursor + header.length as usize + MANDATORY_HDR_LENGTH;
+        if buffer.len() < end {
+            return (
+                messages,
+                Some(GTPV2Error::MessageInvalidLength(header.msgtype)),
+            );
+        }
+        messages.push(&buffer[cursor..end]);
+        cursor = end;
+        if !header.piggyback || cursor >= buffer.len() {
+            break;
+        }
+    }
+    (messages, None)
+}
+
+#[cfg(test)]
+fn test_config() -> TransactionConfig {
+    TransactionConfig {
+        t3_response: Duration::from_millis(50),
+        n3_requests: 2,
+    }
+}
+
+#[cfg(test)]
+fn echo_response(sqn: u32) -> GTPV2Message {
+    use crate::gtpv2::messages::EchoResponse;
+    let mut message = EchoResponse::default();
+    message.header.sqn = sqn;
+    GTPV2Message::EchoResponse(message)
+}
+
+#[test]
+fn node_request_response_loopback_test() {
+    use crate::gtpv2::messages::EchoRequest;
+    let mut client = Gtpv2Node::bind("127.0.0.1:0", test_config()).unwrap();
+    let mut server = Gtpv2Node::bind("127.0.0.1:0", test_config()).unwrap();
+    let server_addr = server.local_addr().unwrap();
+    let handle = std::thread::spawn(move || loop {
+        if let Some(NodeEvent::Request { peer, message }) =
+            server.poll(Duration::from_secs(1)).unwrap()
+        {
+            server
+                .send_response(peer, echo_response(message.header().sqn))
+                .unwrap();
+            break;
+        }
+    });
+    let response = client
+        .request(
+            server_addr,
+            GTPV2Message::EchoRequest(EchoRequest::default()),
+        )
+        .unwrap();
+    handle.join().unwrap();
+    assert_eq!(response.msg_type(), crate::gtpv2::messages::ECHO_RESPONSE);
+    assert_eq!(response.header().sqn, 1);
+    assert_eq!(client.transactions().pending_len(), 0);
+}
+
+#[test]
+fn node_retransmission_test() {
+    use crate::gtpv2::messages::EchoRequest;
+    let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let peer_addr = peer.local_addr().unwrap();
+    let mut client = Gtpv2Node::bind("127.0.0.1:0", test_config()).unwrap();
+    let handle = std::thread::spawn(move || {
+        client.request(peer_addr, GTPV2Message::EchoRequest(EchoRequest::default()))
+    });
+    let mut first = [0u8; 64];
+    let mut second = [0u8; 64];
+    let (len, client_addr) = peer.recv_from(&mut first).unwrap();
+    let (len2, _) = peer.recv_from(&mut second).unwrap();
+    assert_eq!(first[..len], second[..len2]);
+    let sqn = GTPV2Message::parse(&first[..len]).unwrap().header().sqn;
+    let mut buffer: Vec<u8> = vec![];
+    echo_response(sqn).marshal(&mut buffer);
+    peer.send_to(&buffer, client_addr).unwrap();
+    let response = handle.join().unwrap().unwrap();
+    assert_eq!(response.header().sqn, sqn);
+}
+
+#[test]
+fn node_request_timeout_test() {
+    use crate::gtpv2::messages::EchoRequest;
+    let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let peer_addr = peer.local_addr().unwrap();
+    let mut client = Gtpv2Node::bind("127.0.0.1:0", test_config()).unwrap();
+    let result = client.request(peer_addr, GTPV2Message::EchoRequest(EchoRequest::default()));
+    assert_eq!(
+        result,
+        Err(NodeError::RequestTimeout {
+            peer: peer_addr,
+            sqn: 1
+        })
+    );
+    peer.set_read_timeout(Some(Duration::from_millis(50)))
+        .unwrap();
+    let mut buffer = [0u8; 64];
+    let mut copies = 0;
+    while peer.recv_from(&mut buffer).is_ok() {
+        copies += 1;
+    }
+    assert_eq!(copies, 1 + test_config().n3_requests);
+}
+
+#[test]
+fn node_duplicate_request_test() {
+    use crate::gtpv2::messages::EchoRequest;
+    let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+    peer.set_read_timeout(Some(Duration::from_secs(1))).unwrap();
+    let mut server = Gtpv2Node::bind("127.0.0.1:0", test_config()).unwrap();
+    let server_addr = server.local_addr().unwrap();
+    let mut request = EchoRequest::default();
+    request.header.sqn = 7;
+    let mut buffer: Vec<u8> = vec![];
+    GTPV2Message::EchoRequest(request).marshal(&mut buffer);
+    peer.send_to(&buffer, server_addr).unwrap();
+    match server.poll(Duration::from_secs(1)).unwrap() {
+        Some(NodeEvent::Request { peer, message }) => server
+            .send_response(peer, echo_response(message.header().sqn))
+            .unwrap(),
+        i => panic!("Unexpected event {:?}", i),
+    }
+    let mut first = [0u8; 64];
+    let (len, _) = peer.recv_from(&mut first).unwrap();
+    // Retransmitted request is answered from the cache and not delivered again
+    peer.send_to(&buffer, server_addr).unwrap();
+    assert_eq!(server.poll(Duration::from_millis(50)).unwrap(), None);
+    let mut second = [0u8; 64];
+    let (len2, _) = peer.recv_from(&mut second).unwrap();
+    assert_eq!(first[..len], second[..len2]);
+}
+
+#[test]
+fn node_piggybacked_response_test() {
+    use crate::gtpv2::messages::{
+        BearerContext, CreateBearerRequest, CreateSessionRequest, CreateSessionResponse,
+        CREATE_BEARER_REQ,
+    };
+    let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let peer_addr = peer.local_addr().unwrap();
+    let mut client = Gtpv2Node::bind("127.0.0.1:0", test_config()).unwrap();
+    let sqn = client
+        .send_request(
+            peer_addr,
+            GTPV2Message::CreateSessionRequest(CreateSessionRequest::default()),
+        )
+        .unwrap();
+    let mut buffer = [0u8; 1024];
+    let (_, client_addr) = peer.recv_from(&mut buffer).unwrap();
+    let mut response = CreateSessionResponse::default();
+    response.header.sqn = sqn;
+    response.header.piggyback = true;
+    response.bearer_ctxs = vec![BearerContext::default()];
+    let mut request = CreateBearerRequest::default();
+    request.header.sqn = 100;
+    request.bearer_ctxs = vec![BearerContext::default()];
+    let mut datagram: Vec<u8> = vec![];
+    let mut piggybacked: Vec<u8> = vec![];
+    GTPV2Message::CreateSessionResponse(response).marshal(&mut datagram);
+    GTPV2Message::CreateBearerRequest(request).marshal(&mut piggybacked);
+    datagram.append(&mut piggybacked);
+    peer.send_to(&datagram, client_addr).unwrap();
+    match client.poll(Duration::from_secs(1)).unwrap() {
+        Some(NodeEvent::Response { response, .. }) => assert_eq!(response.header().sqn, sqn),
+        i => panic!("Unexpected event {:?}", i),
+    }
+    match client.poll(Duration::from_secs(1)).unwrap() {
+        Some(NodeEvent::Request { message, .. }) => {
+            assert_eq!(message.msg_type(), CREATE_BEARER_REQ)
+        }
+        i => panic!("Unexpected event {:?}", i),
+    }
+}
+
+#[test]
+fn node_command_triggered_request_test() {
+    use crate::gtpv2::messages::{BearerContext, ModifyBearerCommand, UpdateBearerRequest};
+    let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let peer_addr = peer.local_addr().unwrap();
+    let mut client = Gtpv2Node::bind("127.0.0.1:0", test_config()).unwrap();
+    let sqn = client
+        .send_request(
+            peer_addr,
+            GTPV2Message::ModifyBearerCommand(ModifyBearerCommand::default()),
+        )
+        .unwrap();
+    assert_eq!(sqn & COMMAND_SQN_FLAG, COMMAND_SQN_FLAG);
+    let mut buffer = [0u8; 1024];
+    let (_, client_addr) = peer.recv_from(&mut buffer).unwrap();
+    let mut request = UpdateBearerRequest::default();
+    request.header.sqn = sqn;
+    request.bearer_ctxs = vec![BearerContext::default()];
+    let mut datagram: Vec<u8> = vec![];
+    GTPV2Message::UpdateBearerRequest(request).marshal(&mut datagram);
+    peer.send_to(&datagram, client_addr).unwrap();
+    match client.poll(Duration::from_secs(1)).unwrap() {
+        Some(NodeEvent::Response {
+            request, response, ..
+        }) => {
+            assert!(request.is_command());
+            assert_eq!(response.header().sqn, sqn);
+        }
+        i => panic!("Unexpected event {:?}", i),
+    }
+    assert_eq!(client.transactions().pending_len(), 0);
+}
+
+#[test]
+fn node_decode_error_test() {
+    use crate::gtpv2::messages::{EchoRequest, ECHO_REQUEST};
+    let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let peer_addr = peer.local_addr().unwrap();
+    let mut server = Gtpv2Node::bind("127.0.0.1:0", test_config()).unwrap();
+    let server_addr = server.local_addr().unwrap();
+    // Header length shorter than the header itself
+    peer.send_to(
+        &[0x48, 0x20, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00],
+        server_addr,
+    )
+    .unwrap();
+    assert_eq!(
+        server.poll(Duration::from_secs(1)).unwrap(),
+        Some(NodeEvent::DecodeError {
+            peer: peer_addr,
+            error: GTPV2Error::MessageInvalidLength(0)
+        })
+    );
+    // Valid message followed by a truncated piggybacked message: the first one is still delivered
+    let mut request = EchoRequest::default();
+    request.header.sqn = 1;
+    request.header.piggyback = true;
+    let mut datagram: Vec<u8> = vec![];
+    GTPV2Message::EchoRequest(request).marshal(&mut datagram);
+    datagram.extend_from_slice(&[0x48, 0x21, 0x00, 0x20, 0x00, 0x00]);
+    peer.send_to(&datagram, server_addr).unwrap();
+    match server.poll(Duration::from_secs(1)).unwrap() {
+        Some(NodeEvent::Request { message, .. }) => assert_eq!(message.msg_type(), ECHO_REQUEST),
+        i => panic!("Unexpected event {:?}", i),
+    }
+    assert!(matches!(
+        server.poll(Duration::from_secs(1)).unwrap(),
+        Some(NodeEvent::DecodeError { .. })
+    ));
+}
+
+#[test]
+fn split_datagram_test() {
+    let datagram: [u8; 16] = [
+        0x50, 0x01, 0x00, 0x04, 0x00, 0x00, 0x01, 0x00, 0x40, 0x02, 0x00, 0x04, 0x00, 0x00, 0x02,
+        0x00,
+    ];
+    assert_eq!(
+        split_datagram(&datagram).unwrap(),
+        vec![&datagram[..8], &datagram[8..]]
+    );
+    assert_eq!(
+        split_datagram(&datagram[8..]).unwrap(),
+        vec![&datagram[8..]]
+    );
+    assert!(split_datagram(&datagram[..12]).is_err());
+}
+
+#[test]
+fn node_path_echo_answer_and_restart_test() {
+    use crate::gtpv2::messages::EchoRequest;
+    let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+    peer.set_read_timeout(Some(Duration::from_secs(1))).unwrap();
+    let mut server = Gtpv2Node::bind("127.0.0.1:0", test_config()).unwrap();
+    server.enable_path_management(PathConfig {
+        recovery: 7,
+        ..PathConfig::default()
+    });
+    let server_addr = server.local_addr().unwrap();
+    let peer_addr = peer.local_addr().unwrap();
+    server
+        .paths_mut()
+        .unwrap()
+        .add_peer(peer_addr, Instant::now());
+    let mut request = EchoRequest::default();
+    request.header.sqn = 1;
+    request.recovery.recovery = 3;
+    let mut buffer: Vec<u8> = vec![];
+    GTPV2Message::EchoRequest(request.clone()).marshal(&mut buffer);
+    peer.send_to(&buffer, server_addr).unwrap();
+    // Echo Request is answered by the node and not delivered to the application
+    assert_eq!(server.poll(Duration::from_millis(50)).unwrap(), None);
+    let mut response = [0u8; 64];
+    let (len, _) = peer.recv_from(&mut response).unwrap();
+    match GTPV2Message::parse(&response[..len]).unwrap() {
+        GTPV2Message::EchoResponse(i) => {
+            assert_eq!(i.header.sqn, 1);
+            assert_eq!(i.recovery.recovery, 7);
+        }
+        i => panic!("Unexpected message {:?}", i),
+    }
+    request.header.sqn = 2;
+    request.recovery.recovery = 4;
+    let mut buffer: Vec<u8> = vec![];
+    GTPV2Message::EchoRequest(request).marshal(&mut buffer);
+    peer.send_to(&buffer, server_addr).unwrap();
+    assert_eq!(
+        server.poll(Duration::from_secs(1)).unwrap(),
+        Some(NodeEvent::Path(PathEvent::PeerRestart {
+            peer: peer_addr,
+            previous: 3,
+            recovery: 4
+        }))
+    );
+}
+
+#[test]
+fn node_path_failure_test() {
+    let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let peer_addr = peer.local_addr().unwrap();
+    let mut client = Gtpv2Node::bind("127.0.0.1:0", test_config()).unwrap();
+    client.enable_path_management(PathConfig {
+        echo_interval: Duration::from_millis(20),
+        ..PathConfig::default()
+    });
+    client
+        .paths_mut()
+        .unwrap()
+        .add_peer(peer_addr, Instant::now());
+    assert_eq!(
+        client.poll(Duration::from_secs(1)).unwrap(),
+        Some(NodeEvent::Path(PathEvent::PathFailure { peer: peer_addr }))
+    );
+    let mut buffer = [0u8; 64];
+    let (len, _) = peer.recv_from(&mut buffer).unwrap();
+    assert_eq!(
+        GTPV2Message::parse(&buffer[..len]).unwrap().msg_type(),
+        crate::gtpv2::messages::ECHO_REQUEST
+    );
+    assert!(client.paths().unwrap().peer(peer_addr).unwrap().failed);
+}
+
+#[test]
+fn node_path_unsupervised_sender_test() {
+    use crate::gtpv2::messages::EchoRequest;
+    let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+    peer.set_read_timeout(Some(Duration::from_secs(1))).unwrap();
+    let mut server = Gtpv2Node::bind("127.0.0.1:0", test_config()).unwrap();
+    server.enable_path_management(PathConfig::default());
+    let server_addr = server.local_addr().unwrap();
+    let mut request = EchoRequest::default();
+    request.header.sqn = 1;
+    let mut buffer: Vec<u8> = vec![];
+    GTPV2Message::EchoRequest(request).marshal(&mut buffer);
+    peer.send_to(&buffer, server_addr).unwrap();
+    // Echo Request is answered but its sender is not supervised
+    assert_eq!(server.poll(Duration::from_millis(50)).unwrap(), None);
+    let mut response = [0u8; 64];
+    assert!(peer.recv_from(&mut response).is_ok());
+    assert!(server.paths().unwrap().peers().is_empty());
+}
+
+#[test]
+fn node_path_restored_test() {
+    let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+    peer.set_read_timeout(Some(Duration::from_secs(1))).unwrap();
+    let peer_addr = peer.local_addr().unwrap();
+    let mut client = Gtpv2Node::bind("127.0.0.1:0", test_config()).unwrap();
+    client.enable_path_management(PathConfig {
+        echo_interval: Duration::from_millis(20),
+        ..PathConfig::default()
+    });
+    client
+        .paths_mut()
+        .unwrap()
+        .add_peer(peer_addr, Instant::now());
+    assert_eq!(
+        client.poll(Duration::from_secs(1)).unwrap(),
+        Some(NodeEvent::Path(PathEvent::PathFailure { peer: peer_addr }))
+    );
+    // Answer the Echo Request sent after the failure, skipping the copies of the timed out one
+    let mut buffer = [0u8; 64];
+    let (sqn, client_addr) = loop {
+        let (len, client_addr) = peer.recv_from(&mut buffer).unwrap();
+        let sqn = GTPV2Message::parse(&buffer[..len]).unwrap().header().sqn;
+        if client.paths().unwrap().is_echo(peer_addr, sqn) {
+            break (sqn, client_addr);
+        }
+    };
+    let mut response: Vec<u8> = vec![];
+    echo_response(sqn).marshal(&mut response);
+    peer.send_to(&response, client_addr).unwrap();
+    assert_eq!(
+        client.poll(Duration::from_secs(1)).unwrap(),
+        Some(NodeEvent::Path(PathEvent::PathRestored { peer: peer_addr }))
+    );
+    assert!(!client.paths().unwrap().peer(peer_addr).unwrap().failed);
+}
@@ -0,0 +1,7 @@
+pub use {errors::*, gtpv2node::*, overload::*, paths::*, selection::*, transactions::*};
+mod errors;
+mod gtpv2node;
+mod overload;
+mod paths;
+mod selection;
+mod transactions;
@@ -0,0 +1,362 @@
+// GTPv2-C overload control - according to 3GPP TS 29.274 V17.10.0 (2023-12), clause 12.3
+
+use crate::gtpv2::messages::{EpcTimer, GTPV2Message, OverloadControlInfo};
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+
+// Throttling settings: requests sent with a Message Priority up to exempt_priority are never throttled
+// (0 being the highest priority, e.g. for emergency or MPS sessions)
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OverloadConfig {
+    pub exempt_priority: u8,
+}
+
+// Latest overload information received for a peer, node level or for an APN
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverloadEntry {
+    pub sqn: u32,
+    pub metric: u8,
+    pub expiry: Option<Instant>,
+    credit: u16,
+}
+
+impl OverloadEntry {
+    pub fn is_active(&self, now: Instant) -> bool {
+        self.metric > 0 && self.expiry.is_none_or(|x| x > now)
+    }
+}
+
+// Overload information table keyed by the node which generated it and APN (None for the node level
+// overload information). Nodes are identified by their address, e.g. the PGW overload information
+// relayed by an SGW is recorded under the PGW address
+
+#[derive(Debug, Clone, Default)]
+pub struct OverloadControl {
+    pub config: OverloadConfig,
+    entries: HashMap<(SocketAddr, Option<String>), OverloadEntry>,
+}
+
+impl OverloadControl {
+    pub fn new(config: OverloadConfig) -> Self {
+        OverloadControl {
+            config,
+            ..OverloadControl::default()
+        }
+    }
+
+    // Record an Overload Control Information IE received from a peer.
+    // Information with a sequence number not higher than the one already recorded is ignored,
+    // as well as information with an out of range metric (above 100).
+    // Returns whether the information was recorded.
+
+    pub fn update(&mut self, peer: SocketAddr, info: &OverloadControlInfo, now: Instant) -> bool {
+        let metric = info.metric.metric;
+        if metric > 100 {
+            return false;
+        }
+        let expiry = validity(&info.validity).map(|x| now + x);
+        let apns: Vec<Option<String>> = match &info.list {
+            Some(i) => i.iter().map(|x| Some(x.name.to_lowercase())).collect(),
+            None => vec![None],
+        };
+        let mut recorded = false;
+        for apn in apns.into_iter() {
+            match self.entries.get_mut(&(peer, apn.clone())) {
+                Some(i) if i.sqn >= info.sqn.sqn => (),
+                Some(i) => {
+                    (i.sqn, i.metric, i.expiry) = (info.sqn.sqn, metric, expiry);
+                    recorded = true;
+                }
+                None => {
+                    self.entries.insert(
+                        (peer, apn),
+                        OverloadEntry {
+                            sqn: info.sqn.sqn,
+                            metric,
+                            expiry,
+                            credit: 0,
+                        },
+                    );
+                    recorded = true;
+                }
+            }
+        }
+        recorded
+    }
+
+    // Record the Overload Control Information IEs carried by a message. The IEs of a message are generated
+    // by different nodes told apart by their instance (e.g. in a Create Session Response, instance 0 for the PGW
+    // and instance 1 for the SGW): node returns the address of the node which generated the IEs of an instance,
+    // the IEs of the instances it returns None for are ignored
+
+    pub fn on_message<F>(&mut self, message: &GTPV2Message, node: F, now: Instant)
+    where
+        F: Fn(u8) -> Option<SocketAddr>,
+    {
+        for i in overload_infos(message) {
+            if let Some(j) = node(i.ins) {
+                self.update(j, i, now);
+            }
+        }
+    }
+
+    pub fn entry(&self, peer: SocketAddr, apn: Option<&str>) -> Option<&OverloadEntry> {
+        self.entries.get(&(peer, apn.map(|x| x.to_lowercase())))
+    }
+
+    pub fn remove_peer(&mut self, peer: SocketAddr) {
+        self.entries.retain(|(i, _), _| *i != peer);
+    }
+
+    // Remove the overload information whose validity period has expired
+
+    pub fn purge(&mut self, now: Instant) {
+        self.entries.retain(|_, i| i.expiry.is_none_or(|x| x > now));
+    }
+
+    // Throttling decision for a new session establishment request (e.g. Create Session Request) towards a peer.
+    // The highest metric among the valid node level and APN level overload information applies,
+    // and that percentage of requests is dropped.
+
+    pub fn should_send(
+        &mut self,
+        peer: SocketAddr,
+        apn: Option<&str>,
+        priority: Option<u8>,
+    ) -> bool {
+        self.should_send_at(peer, apn, priority, Instant::now())
+    }
+
+    pub fn should_send_at(
+        &mut self,
+        peer: SocketAddr,
+        apn: Option<&str>,
+        priority: Option<u8>,
+        now: Instant,
+    ) -> bool {
+        if priority.is_some_and(|x| x <= self.config.exempt_priority) {
+            return true;
+        }
+        let mut keys = vec![(peer, None)];
+        if let Some(i) = apn {
+            keys.push((peer, Some(i.to_lowercase())));
+        }
+        let entry = keys
+            .into_iter()
+            .filter(|x| self.entries.get(x).is_some_and(|y| y.is_active(now)))
+            .max_by_key(|x| self.entries[x].metric)
+            .and_then(|x| self.entries.get_mut(&x));
+        match entry {
+            Some(i) => {
+                // Drop metric requests out of every 100, spread evenly
+                i.credit += i.metric as u16;
+                if i.credit >= 100 {
+                    i.credit -= 100;
+                    false
+                } else {
+                    true
+                }
+            }
+            None => true,
+        }
+    }
+}
+
+// Validity period of the overload information, None when infinite
+
+fn validity(timer: &EpcTimer) -> Option<Duration> {
+    let unit = match timer.timer_unit {
+        0 => 2,
+        2 => 600,
+        3 => 3600,
+        4 => 36000,
+        7 => return None,
+        _ => 60,
+    };
+    Some(Duration::from_secs(unit * timer.timer_value as u64))
+}
+
+fn overload_infos(message: &GTPV2Message) -> Vec<&OverloadControlInfo> {
+    match message {
+        GTPV2Message::BearerResourceCommand(i) => i.overload_info.iter().collect(),
+        GTPV2Message::BearerResourceFailureInd(i) => i.overload_info.iter().collect(),
+        GTPV2Message::CreateBearerRequest(i) => i.overload_info.iter().collect(),
+        GTPV2Message::CreateBearerResponse(i) => i.overload_info.iter().collect(),
+        GTPV2Message::CreateSessionRequest(i) => i.overload_info.iter().collect(),
+        GTPV2Message::CreateSessionResponse(i) => i.overload_info.iter().collect(),
+        GTPV2Message::DeleteBearerCommand(i) => i.overload_info.iter().collect(),
+        GTPV2Message::DeleteBearerFailureInd(i) => i.overload_info.iter().collect(),
+        GTPV2Message::DeleteBearerRequest(i) => i.overload_info.iter().collect(),
+        GTPV2Message::DeleteBearerResponse(i) => i.overload_info.iter().collect(),
+        GTPV2Message::DeleteSessionRequest(i) => i.overload_info.iter().collect(),
+        GTPV2Message::DeleteSessionResponse(i) => i.overload_info.iter().collect(),
+        GTPV2Message::DownlinkDataNotification(i) => i.overload_info.iter().collect(),
+        GTPV2Message::ModifyAccessBearersResponse(i) => i.overload_control_info.iter().collect(),
+        GTPV2Message::ModifyBearerCommand(i) => i.overload_info.iter().collect(),
+        GTPV2Message::ModifyBearerFailureInd(i) => i.overload_info.iter().collect(),
+        GTPV2Message::ModifyBearerRequest(i) => i.overload_info.iter().collect(),
+        GTPV2Message::ModifyBearerResponse(i) => i.overload_info.iter().collect(),
+        GTPV2Message::ReleaseAccessBearersResponse(i) => i.overload_info.iter().collect(),
+        GTPV2Message::UpdateBearerRequest(i) => i.overload_info.iter().collect(),
+        GTPV2Message::UpdateBearerResponse(i) => i.overload_info.iter().collect(),
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+use crate::gtpv2::messages::{Apn, Metric, Sqn};
+
+#[cfg(test)]
+fn test_peer() -> SocketAddr {
+    "127.0.0.1:2123".parse().unwrap()
+}
+
+#[cfg(test)]
+fn test_overload_info(sqn: u32, metric: u8, apns: Option<Vec<&str>>) -> OverloadControlInfo {
+    OverloadControlInfo {
+        sqn: Sqn {
+            sqn,
+            ..Sqn::default()
+        },
+        metric: Metric {
+            metric,
+            ..Metric::default()
+        },
+        validity: EpcTimer {
+            timer_unit: 1,
+            timer_value: 10,
+            ..EpcTimer::default()
+        },
+        list: apns.map(|x| {
+            x.into_iter()
+                .map(|y| Apn {
+                    name: y.to_string(),
+                    ..Apn::default()
+                })
+                .collect()
+        }),
+        ..OverloadControlInfo::default()
+    }
+}
+
+#[test]
+fn overload_update_test() {
+    let mut overload = OverloadControl::default();
+    let now = Instant::now();
+    assert!(overload.update(test_peer(), &test_overload_info(10, 50, None), now));
+    let entry = overload.entry(test_peer(), None).unwrap();
+    assert_eq!((entry.sqn, entry.metric), (10, 50));
+    assert_eq!(entry.expiry, Some(now + Duration::from_secs(600)));
+    // Stale sequence numbers are ignored
+    assert!(!overload.update(test_peer(), &test_overload_info(10, 80, None), now));
+    assert!(!overload.update(test_peer(), &test_overload_info(9, 80, None), now));
+    assert_eq!(overload.entry(test_peer(), None).unwrap().metric, 50);
+    // APN level information is recorded apart from the node level one
+    assert!(overload.update(
+        test_peer(),
+        &test_overload_info(5, 20, Some(vec!["ims", "internet"])),
+        now
+    ));
+    assert_eq!(overload.entry(test_peer(), Some("IMS")).unwrap().metric, 20);
+    assert_eq!(
+        overload.entry(test_peer(), Some("internet")).unwrap().sqn,
+        5
+    );
+    // Out of range metric
+    assert!(!overload.update(test_peer(), &test_overload_info(12, 101, None), now));
+    assert_eq!(overload.entry(test_peer(), None).unwrap().sqn, 10);
+    assert!(overload.update(test_peer(), &test_overload_info(11, 0, None), now));
+    assert!(!overload.entry(test_peer(), None).unwrap().is_active(now));
+    overload.remove_peer(test_peer());
+    assert_eq!(overload.entry(test_peer(), Some("ims")), None);
+}
+
+#[test]
+fn overload_expiry_test() {
+    let mut overload = OverloadControl::default();
+    let now = Instant::now();
+    let mut info = test_overload_info(1, 100, None);
+    overload.update(test_peer(), &info, now);
+    assert!(!overload.should_send_at(test_peer(), None, None, now));
+    let later = now + Duration::from_secs(601);
+    assert!(overload.should_send_at(test_peer(), None, None, later));
+    overload.purge(later);
+    assert_eq!(overload.entry(test_peer(), None), None);
+    // Infinite validity
+    info.sqn.sqn = 2;
+    info.validity.timer_unit = 7;
+    overload.update(test_peer(), &info, now);
+    assert_eq!(overload.entry(test_peer(), None).unwrap().expiry, None);
+    assert!(!overload.should_send_at(test_peer(), None, None, later));
+}
+
+#[test]
+fn overload_throttling_test() {
+    let mut overload = OverloadControl::default();
+    let now = Instant::now();
+    overload.update(test_peer(), &test_overload_info(1, 30, None), now);
+    overload.update(
+        test_peer(),
+        &test_overload_info(1, 50, Some(vec!["ims"])),
+        now,
+    );
+    let sent = (0..100)
+        .filter(|_| overload.should_send_at(test_peer(), None, None, now))
+        .count();
+    assert_eq!(sent, 70);
+    let sent = (0..100)
+        .filter(|_| overload.should_send_at(test_peer(), Some("ims"), Some(5), now))
+        .count();
+    assert_eq!(sent, 50);
+    // High priority requests are exempted
+    assert!((0..100).all(|_| overload.should_send_at(test_peer(), Some("ims"), Some(0), now)));
+    let other: SocketAddr = "127.0.0.2:2123".parse().unwrap();
+    assert!(overload.should_send_at(other, Some("ims"), None, now));
+}
+
+#[test]
+fn overload_on_message_test() {
+    use crate::gtpv2::messages::CreateSessionResponse;
+    let mut overload = OverloadControl::default();
+    let (sgw, pgw): (SocketAddr, SocketAddr) = (test_peer(), "127.0.0.2:2123".parse().unwrap());
+    let mut sgw_info = test_overload_info(3, 40, None);
+    sgw_info.ins = 1;
+    let message = CreateSessionResponse {
+        overload_info: vec![test_overload_info(10, 80, None), sgw_info],
+        ..CreateSessionResponse::default()
+    };
+    // Create Session Response received from the SGW, relaying the PGW overload information
+    let node = |ins| match ins {
+        0 => Some(pgw),
+        1 => Some(sgw),
+        _ => None,
+    };
+    let now = Instant::now();
+    overload.on_message(
+        &GTPV2Message::CreateSessionResponse(message.clone()),
+        node,
+        now,
+    );
+    let entry = overload.entry(sgw, None).unwrap();
+    assert_eq!((entry.sqn, entry.metric), (3, 40));
+    let entry = overload.entry(pgw, None).unwrap();
+    assert_eq!((entry.sqn, entry.metric), (10, 80));
+    let sent = (0..100)
+        .filter(|_| overload.should_send_at(sgw, None, None, now))
+        .count();
+    assert_eq!(sent, 60);
+    // Instances the caller does not attribute to a node are ignored
+    let mut overload = OverloadControl::default();
+    overload.on_message(
+        &GTPV2Message::CreateSessionResponse(message),
+        |ins| (ins == 1).then_some(sgw),
+        now,
+    );
+    assert_eq!(overload.entry(pgw, None), None);
+    assert_eq!(overload.entry(sgw, None).unwrap().metric, 40);
+}
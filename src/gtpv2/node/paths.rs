@@ -0,0 +1,338 @@
+// GTPv2-C path management - according to 3GPP TS 29.274 V17.10.0 (2023-12), clause 7.1 and 3GPP TS 23.007
+
+use crate::{
+    commons::DEFAULT_ECHO_INTERVAL,
+    gtpv2::messages::{EchoRequest, EchoResponse, GTPV2Message, NodeFeatures, Recovery},
+};
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+
+// Each Echo Request is already retransmitted N3-REQUESTS times by the transaction handling,
+// so a single unanswered Echo Request means the path is down
+
+pub const DEFAULT_MAX_MISSED_ECHOES: u8 = 1;
+
+// Path supervision settings, with the local Recovery counter and features advertised in Echo messages
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathConfig {
+    pub echo_interval: Duration,
+    pub max_missed_echoes: u8,
+    pub recovery: u8,
+    pub node_features: Option<NodeFeatures>,
+}
+
+impl Default for PathConfig {
+    fn default() -> Self {
+        PathConfig {
+            echo_interval: DEFAULT_ECHO_INTERVAL,
+            max_missed_echoes: DEFAULT_MAX_MISSED_ECHOES,
+            recovery: 0,
+            node_features: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathEvent {
+    // Path to the peer considered down after the configured number of unanswered Echo Requests
+    PathFailure {
+        peer: SocketAddr,
+    },
+    // Path to the peer back up after a path failure, an Echo Response being received again
+    PathRestored {
+        peer: SocketAddr,
+    },
+    // Peer restart detected by a change of the Recovery counter it sends
+    PeerRestart {
+        peer: SocketAddr,
+        previous: u8,
+        recovery: u8,
+    },
+}
+
+// State of the path towards a peer
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeerPath {
+    pub recovery: Option<u8>,
+    pub missed_echoes: u8,
+    pub failed: bool,
+    pub next_echo: Instant,
+    pub echo_sqn: Option<u32>,
+}
+
+// Path table keyed by peer, scheduling the Echo Requests and tracking the Recovery counters
+
+#[derive(Debug, Clone, Default)]
+pub struct PathManager {
+    pub config: PathConfig,
+    peers: HashMap<SocketAddr, PeerPath>,
+}
+
+impl PathManager {
+    pub fn new(config: PathConfig) -> Self {
+        PathManager {
+            config,
+            ..PathManager::default()
+        }
+    }
+
+    pub fn add_peer(&mut self, peer: SocketAddr, now: Instant) {
+        let next_echo = now + self.config.echo_interval;
+        self.peers.entry(peer).or_insert(PeerPath {
+            recovery: None,
+            missed_echoes: 0,
+            failed: false,
+            next_echo,
+            echo_sqn: None,
+        });
+    }
+
+    pub fn remove_peer(&mut self, peer: SocketAddr) -> Option<PeerPath> {
+        self.peers.remove(&peer)
+    }
+
+    pub fn peer(&self, peer: SocketAddr) -> Option<&PeerPath> {
+        self.peers.get(&peer)
+    }
+
+    pub fn peers(&self) -> Vec<SocketAddr> {
+        self.peers.keys().copied().collect()
+    }
+
+    pub fn echo_request(&self) -> EchoRequest {
+        EchoRequest {
+            recovery: Recovery {
+                recovery: self.config.recovery,
+                ..Recovery::default()
+            },
+            sending_node_features: self.config.node_features.clone(),
+            ..EchoRequest::default()
+        }
+    }
+
+    // Echo Response to an Echo Request, the sequence number is copied from the request
+
+    pub fn echo_response(&self, sqn: u32) -> EchoResponse {
+        let mut message = EchoResponse {
+            recovery: Recovery {
+                recovery: self.config.recovery,
+                ..Recovery::default()
+            },
+            sending_node_features: self.config.node_features.clone(),
+            ..EchoResponse::default()
+        };
+        message.header.sqn = sqn;
+        message
+    }
+
+    // Peers to be echoed now, the next Echo Request is scheduled one interval later
+
+    pub fn due_echoes(&mut self, now: Instant) -> Vec<SocketAddr> {
+        let interval = self.config.echo_interval;
+        self.peers
+            .iter_mut()
+            .filter(|(_, i)| i.echo_sqn.is_none() && i.next_echo <= now)
+            .map(|(peer, i)| {
+                i.next_echo = now + interval;
+                *peer
+            })
+            .collect()
+    }
+
+    pub fn echo_sent(&mut self, peer: SocketAddr, sqn: u32) {
+        if let Some(i) = self.peers.get_mut(&peer) {
+            i.echo_sqn = Some(sqn);
+        }
+    }
+
+    // Whether the transaction is an Echo Request sent by the path management
+
+    pub fn is_echo(&self, peer: SocketAddr, sqn: u32) -> bool {
+        self.peers
+            .get(&peer)
+            .is_some_and(|x| x.echo_sqn == Some(sqn))
+    }
+
+    // Answered Echo Request, the path restoration is reported when the path was down
+
+    pub fn on_echo_response(&mut self, peer: SocketAddr) -> Option<PathEvent> {
+        let i = self.peers.get_mut(&peer)?;
+        i.echo_sqn = None;
+        i.missed_echoes = 0;
+        match std::mem::replace(&mut i.failed, false) {
+            true => Some(PathEvent::PathRestored { peer }),
+            false => None,
+        }
+    }
+
+    // Count an unanswered Echo Request, the path failure is reported once until the path is back
+
+    pub fn on_echo_timeout(&mut self, peer: SocketAddr) -> Option<PathEvent> {
+        let max_missed_echoes = self.config.max_missed_echoes;
+        let i = self.peers.get_mut(&peer)?;
+        i.echo_sqn = None;
+        i.missed_echoes = i.missed_echoes.saturating_add(1);
+        if i.failed || i.missed_echoes < max_missed_echoes {
+            return None;
+        }
+        i.failed = true;
+        Some(PathEvent::PathFailure { peer })
+    }
+
+    // Track the Recovery counter carried by Echo and Create Session messages received from a peer
+
+    pub fn on_message(&mut self, peer: SocketAddr, message: &GTPV2Message) -> Option<PathEvent> {
+        let recovery = match message {
+            GTPV2Message::EchoRequest(i) => i.recovery.recovery,
+            GTPV2Message::EchoResponse(i) => i.recovery.recovery,
+            GTPV2Message::CreateSessionRequest(i) => i.recovery.as_ref()?.recovery,
+            GTPV2Message::CreateSessionResponse(i) => i.recovery.as_ref()?.recovery,
+            _ => return None,
+        };
+        let i = self.peers.get_mut(&peer)?;
+        match i.recovery.replace(recovery) {
+            Some(previous) if previous != recovery => Some(PathEvent::PeerRestart {
+                peer,
+                previous,
+                recovery,
+            }),
+            _ => None,
+        }
+    }
+
+    // Earliest instant at which an Echo Request is due
+
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.peers
+            .values()
+            .filter(|x| x.echo_sqn.is_none())
+            .map(|x| x.next_echo)
+            .min()
+    }
+}
+
+#[cfg(test)]
+fn test_peer() -> SocketAddr {
+    "127.0.0.1:2123".parse().unwrap()
+}
+
+#[test]
+fn path_echo_scheduling_test() {
+    let config = PathConfig {
+        echo_interval: Duration::from_millis(100),
+        ..PathConfig::default()
+    };
+    let mut paths = PathManager::new(config);
+    let start = Instant::now();
+    paths.add_peer(test_peer(), start);
+    assert_eq!(
+        paths.next_deadline(),
+        Some(start + Duration::from_millis(100))
+    );
+    assert!(paths
+        .due_echoes(start + Duration::from_millis(50))
+        .is_empty());
+    assert_eq!(
+        paths.due_echoes(start + Duration::from_millis(100)),
+        vec![test_peer()]
+    );
+    paths.echo_sent(test_peer(), 5);
+    assert!(paths.is_echo(test_peer(), 5));
+    assert_eq!(paths.next_deadline(), None);
+    assert!(paths
+        .due_echoes(start + Duration::from_millis(300))
+        .is_empty());
+    assert_eq!(paths.on_echo_response(test_peer()), None);
+    assert!(!paths.is_echo(test_peer(), 5));
+    assert_eq!(
+        paths.next_deadline(),
+        Some(start + Duration::from_millis(200))
+    );
+}
+
+#[test]
+fn path_failure_test() {
+    let config = PathConfig {
+        max_missed_echoes: 2,
+        ..PathConfig::default()
+    };
+    let mut paths = PathManager::new(config);
+    paths.add_peer(test_peer(), Instant::now());
+    assert_eq!(paths.on_echo_timeout(test_peer()), None);
+    assert_eq!(
+        paths.on_echo_timeout(test_peer()),
+        Some(PathEvent::PathFailure { peer: test_peer() })
+    );
+    assert_eq!(paths.on_echo_timeout(test_peer()), None);
+    assert!(paths.peer(test_peer()).unwrap().failed);
+    assert_eq!(
+        paths.on_echo_response(test_peer()),
+        Some(PathEvent::PathRestored { peer: test_peer() })
+    );
+    assert_eq!(paths.on_echo_response(test_peer()), None);
+    assert_eq!(paths.peer(test_peer()).unwrap().missed_echoes, 0);
+    assert!(!paths.peer(test_peer()).unwrap().failed);
+}
+
+#[test]
+fn path_peer_restart_test() {
+    use crate::gtpv2::messages::CreateSessionRequest;
+    let mut paths = PathManager::default();
+    paths.add_peer(test_peer(), Instant::now());
+    let mut echo = EchoResponse::default();
+    echo.recovery.recovery = 10;
+    assert_eq!(
+        paths.on_message(test_peer(), &GTPV2Message::EchoResponse(echo.clone())),
+        None
+    );
+    assert_eq!(
+        paths.on_message(test_peer(), &GTPV2Message::EchoResponse(echo)),
+        None
+    );
+    let request = CreateSessionRequest {
+        recovery: Some(Recovery {
+            recovery: 11,
+            ..Recovery::default()
+        }),
+        ..CreateSessionRequest::default()
+    };
+    assert_eq!(
+        paths.on_message(test_peer(), &GTPV2Message::CreateSessionRequest(request)),
+        Some(PathEvent::PeerRestart {
+            peer: test_peer(),
+            previous: 10,
+            recovery: 11
+        })
+    );
+    assert_eq!(paths.peer(test_peer()).unwrap().recovery, Some(11));
+    assert_eq!(
+        paths.on_message(
+            test_peer(),
+            &GTPV2Message::CreateSessionRequest(CreateSessionRequest::default())
+        ),
+        None
+    );
+}
+
+#[test]
+fn path_echo_response_test() {
+    let config = PathConfig {
+        recovery: 7,
+        node_features: Some(NodeFeatures {
+            prn: true,
+            ..NodeFeatures::default()
+        }),
+        ..PathConfig::default()
+    };
+    let paths = PathManager::new(config);
+    let response = paths.echo_response(0x1234);
+    assert_eq!(response.header.sqn, 0x1234);
+    assert_eq!(response.recovery.recovery, 7);
+    assert!(response.sending_node_features.unwrap().prn);
+    assert_eq!(paths.echo_request().recovery.recovery, 7);
+}
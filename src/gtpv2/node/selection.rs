@@ -0,0 +1,374 @@
+// GTPv2-C load control aware peer selection - according to 3GPP TS 29.274 V17.10.0 (2023-12), clause 12.2
+
+use crate::gtpv2::messages::{GTPV2Message, LoadControl};
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// Latest load information received from a peer, node level or for an APN
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadEntry {
+    pub sqn: u32,
+    pub load_metric: u8,
+    pub relative_cap: Option<u8>,
+}
+
+// Weighted selection among candidate peers (e.g. PGWs or SGWs resolved through DNS).
+// The static weight of each peer is scaled by its advertised spare capacity: node level load information,
+// or APN level load information with the APN relative capacity when available for the requested APN.
+// Load information is recorded under the address of the node which generated it, e.g. the PGW load
+// information relayed by an SGW is recorded under the PGW address.
+
+#[derive(Debug, Clone)]
+pub struct PeerSelector {
+    peers: Vec<(SocketAddr, u16)>,
+    loads: HashMap<(SocketAddr, Option<String>), LoadEntry>,
+    seed: u64,
+}
+
+impl Default for PeerSelector {
+    fn default() -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |x| x.as_nanos() as u64);
+        PeerSelector::with_seed(seed)
+    }
+}
+
+impl PeerSelector {
+    pub fn new() -> Self {
+        PeerSelector::default()
+    }
+
+    // Selector with a given seed, giving a reproducible sequence of selections
+
+    pub fn with_seed(seed: u64) -> Self {
+        PeerSelector {
+            peers: vec![],
+            loads: HashMap::new(),
+            seed,
+        }
+    }
+
+    // Add a candidate peer with its static weight, or update the weight of a known peer
+
+    pub fn add_peer(&mut self, peer: SocketAddr, weight: u16) {
+        match self.peers.iter_mut().find(|(i, _)| *i == peer) {
+            Some(i) => i.1 = weight,
+            None => self.peers.push((peer, weight)),
+        }
+    }
+
+    pub fn remove_peer(&mut self, peer: SocketAddr) {
+        self.peers.retain(|(i, _)| *i != peer);
+        self.loads.retain(|(i, _), _| *i != peer);
+    }
+
+    pub fn peers(&self) -> Vec<SocketAddr> {
+        self.peers.iter().map(|(i, _)| *i).collect()
+    }
+
+    // Record a Load Control Information IE received from a peer.
+    // Information with a sequence number not higher than the one already recorded is ignored,
+    // as well as information with an out of range load metric (above 100).
+    // Returns whether the information was recorded.
+
+    pub fn update(&mut self, peer: SocketAddr, info: &LoadControl) -> bool {
+        let load_metric = info.load_metric.metric;
+        if load_metric > 100 {
+            return false;
+        }
+        let apns: Vec<(Option<String>, Option<u8>)> = match &info.list {
+            Some(i) => i
+                .iter()
+                .map(|x| (Some(x.name.to_lowercase()), Some(x.relative_cap)))
+                .collect(),
+            None => vec![(None, None)],
+        };
+        let mut recorded = false;
+        for (apn, relative_cap) in apns.into_iter() {
+            let entry = LoadEntry {
+                sqn: info.sqn.sqn,
+                load_metric,
+                relative_cap,
+            };
+            match self.loads.get_mut(&(peer, apn.clone())) {
+                Some(i) if i.sqn >= info.sqn.sqn => (),
+                Some(i) => {
+                    *i = entry;
+                    recorded = true;
+                }
+                None => {
+                    self.loads.insert((peer, apn), entry);
+                    recorded = true;
+                }
+            }
+        }
+        recorded
+    }
+
+    // Record the Load Control Information IEs carried by a message. The IEs of a message are generated
+    // by different nodes told apart by their instance (e.g. in a Create Session Response, instances 0 and 2
+    // for the PGW node and APN level load information and instance 1 for the SGW): node returns the address
+    // of the node which generated the IEs of an instance, the IEs of the instances it returns None for are ignored
+
+    pub fn on_message<F>(&mut self, message: &GTPV2Message, node: F)
+    where
+        F: Fn(u8) -> Option<SocketAddr>,
+    {
+        for i in load_infos(message) {
+            if let Some(j) = node(i.ins) {
+                self.update(j, i);
+            }
+        }
+    }
+
+    pub fn entry(&self, peer: SocketAddr, apn: Option<&str>) -> Option<&LoadEntry> {
+        self.loads.get(&(peer, apn.map(|x| x.to_lowercase())))
+    }
+
+    // Effective weight of a peer: static weight scaled by the spare capacity (100 - load metric)
+    // and, for APN level load information, by the APN relative capacity
+
+    pub fn weight(&self, peer: SocketAddr, apn: Option<&str>) -> u64 {
+        let weight = match self.peers.iter().find(|(i, _)| *i == peer) {
+            Some((_, i)) => *i as u64,
+            None => return 0,
+        };
+        let load = apn
+            .and_then(|x| self.entry(peer, Some(x)))
+            .or_else(|| self.entry(peer, None));
+        match load {
+            Some(i) => {
+                let spare = 100 - i.load_metric as u64;
+                match i.relative_cap {
+                    Some(j) => weight * spare * j as u64,
+                    None => weight * spare * 100,
+                }
+            }
+            None => weight * 100 * 100,
+        }
+    }
+
+    // Weighted random selection of a peer for a new session, optionally for an APN.
+    // When all the peers advertise a full load, the static weights alone are used.
+
+    pub fn select(&mut self, apn: Option<&str>) -> Option<SocketAddr> {
+        let mut weights: Vec<(SocketAddr, u64)> = self
+            .peers
+            .iter()
+            .map(|(i, _)| (*i, self.weight(*i, apn)))
+            .collect();
+        if weights.iter().all(|(_, i)| *i == 0) {
+            weights = self.peers.iter().map(|(i, j)| (*i, *j as u64)).collect();
+        }
+        let total: u64 = weights.iter().map(|(_, i)| *i).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut draw = self.next_random() % total;
+        for (peer, weight) in weights.into_iter() {
+            if draw < weight {
+                return Some(peer);
+            }
+            draw -= weight;
+        }
+        None
+    }
+
+    // SplitMix64 pseudo random generator
+
+    fn next_random(&mut self) -> u64 {
+        self.seed = self.seed.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+fn load_infos(message: &GTPV2Message) -> Vec<&LoadControl> {
+    match message {
+        GTPV2Message::CreateBearerRequest(i) => i.load_control.iter().collect(),
+        GTPV2Message::CreateSessionResponse(i) => i.load_control.iter().collect(),
+        GTPV2Message::DeleteBearerRequest(i) => i.load_control.iter().collect(),
+        GTPV2Message::DeleteSessionResponse(i) => i.load_control.iter().collect(),
+        GTPV2Message::DownlinkDataNotification(i) => i.load_control.iter().collect(),
+        GTPV2Message::ModifyAccessBearersResponse(i) => i.load_control_info.iter().collect(),
+        GTPV2Message::ModifyBearerResponse(i) => i.load_control.iter().collect(),
+        GTPV2Message::ReleaseAccessBearersResponse(i) => i.load_control.iter().collect(),
+        GTPV2Message::UpdateBearerRequest(i) => i.load_control.iter().collect(),
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+use crate::gtpv2::messages::{ApnRelativeCapacity, Metric, Sqn};
+
+#[cfg(test)]
+fn test_peers() -> [SocketAddr; 2] {
+    [
+        "127.0.0.1:2123".parse().unwrap(),
+        "127.0.0.2:2123".parse().unwrap(),
+    ]
+}
+
+#[cfg(test)]
+fn test_load_control(sqn: u32, load_metric: u8, apns: Option<Vec<(&str, u8)>>) -> LoadControl {
+    LoadControl {
+        sqn: Sqn {
+            sqn,
+            ..Sqn::default()
+        },
+        load_metric: Metric {
+            metric: load_metric,
+            ..Metric::default()
+        },
+        list: apns.map(|x| {
+            x.into_iter()
+                .map(|(name, relative_cap)| ApnRelativeCapacity {
+                    relative_cap,
+                    name: name.to_string(),
+                    ..ApnRelativeCapacity::default()
+                })
+                .collect()
+        }),
+        ..LoadControl::default()
+    }
+}
+
+#[cfg(test)]
+fn test_selections(selector: &mut PeerSelector, apn: Option<&str>) -> [usize; 2] {
+    let mut count = [0, 0];
+    for _ in 0..1000 {
+        let peer = selector.select(apn).unwrap();
+        count[test_peers().iter().position(|x| *x == peer).unwrap()] += 1;
+    }
+    count
+}
+
+#[test]
+fn load_update_test() {
+    let [peer, _] = test_peers();
+    let mut selector = PeerSelector::with_seed(1);
+    selector.add_peer(peer, 10);
+    assert!(selector.update(peer, &test_load_control(10, 50, None)));
+    assert!(!selector.update(peer, &test_load_control(10, 20, None)));
+    assert!(!selector.update(peer, &test_load_control(9, 20, None)));
+    assert_eq!(selector.entry(peer, None).unwrap().load_metric, 50);
+    // Out of range load metric
+    assert!(!selector.update(peer, &test_load_control(11, 101, None)));
+    assert_eq!(selector.entry(peer, None).unwrap().sqn, 10);
+    assert_eq!(selector.weight(peer, None), 10 * 50 * 100);
+    // APN level load information is recorded apart from the node level one
+    assert!(selector.update(
+        peer,
+        &test_load_control(1, 20, Some(vec![("ims", 40), ("internet", 100)]))
+    ));
+    assert_eq!(
+        selector.entry(peer, Some("IMS")),
+        Some(&LoadEntry {
+            sqn: 1,
+            load_metric: 20,
+            relative_cap: Some(40)
+        })
+    );
+    assert_eq!(selector.weight(peer, Some("ims")), 10 * 80 * 40);
+    assert_eq!(selector.weight(peer, Some("other")), 10 * 50 * 100);
+    selector.remove_peer(peer);
+    assert_eq!(selector.entry(peer, Some("ims")), None);
+    assert_eq!(selector.weight(peer, None), 0);
+}
+
+#[test]
+fn load_weighted_selection_test() {
+    let [first, second] = test_peers();
+    let mut selector = PeerSelector::with_seed(7);
+    assert_eq!(selector.select(None), None);
+    selector.add_peer(first, 1);
+    selector.add_peer(second, 1);
+    let count = test_selections(&mut selector, None);
+    assert!(count[0] > 400 && count[1] > 400);
+    // First peer loaded at 80%, second peer idle: 1 out of 6 sessions goes to the first peer
+    selector.update(first, &test_load_control(1, 80, None));
+    let count = test_selections(&mut selector, None);
+    assert!(count[0] > 100 && count[0] < 250);
+    // Fully loaded peer is not selected
+    selector.update(first, &test_load_control(2, 100, None));
+    assert_eq!(test_selections(&mut selector, None), [0, 1000]);
+    // All the peers fully loaded: static weights are used
+    selector.update(second, &test_load_control(1, 100, None));
+    selector.add_peer(second, 3);
+    let count = test_selections(&mut selector, None);
+    assert!(count[0] > 150 && count[0] < 350);
+}
+
+#[test]
+fn load_apn_selection_test() {
+    let [first, second] = test_peers();
+    let mut selector = PeerSelector::with_seed(42);
+    selector.add_peer(first, 1);
+    selector.add_peer(second, 1);
+    selector.update(first, &test_load_control(1, 100, Some(vec![("ims", 100)])));
+    assert_eq!(test_selections(&mut selector, Some("ims")), [0, 1000]);
+    let count = test_selections(&mut selector, Some("internet"));
+    assert!(count[0] > 400 && count[1] > 400);
+}
+
+#[test]
+fn load_deterministic_selection_test() {
+    let [first, second] = test_peers();
+    let mut selectors = [PeerSelector::with_seed(3), PeerSelector::with_seed(3)];
+    for i in selectors.iter_mut() {
+        i.add_peer(first, 2);
+        i.add_peer(second, 5);
+        i.update(second, &test_load_control(1, 30, None));
+    }
+    let [a, b] = &mut selectors;
+    assert!((0..100).all(|_| a.select(None) == b.select(None)));
+}
+
+#[test]
+fn load_on_message_test() {
+    use crate::gtpv2::messages::CreateSessionResponse;
+    let [sgw, pgw] = test_peers();
+    let mut selector = PeerSelector::with_seed(0);
+    let mut sgw_load = test_load_control(3, 40, None);
+    sgw_load.ins = 1;
+    let mut pgw_apn_load = test_load_control(12, 70, Some(vec![("ims", 50)]));
+    pgw_apn_load.ins = 2;
+    let message = CreateSessionResponse {
+        load_control: vec![test_load_control(12, 20, None), sgw_load, pgw_apn_load],
+        ..CreateSessionResponse::default()
+    };
+    // Create Session Response received from the SGW, relaying the PGW load information
+    selector.on_message(
+        &GTPV2Message::CreateSessionResponse(message),
+        |ins| match ins {
+            0 | 2 => Some(pgw),
+            1 => Some(sgw),
+            _ => None,
+        },
+    );
+    assert_eq!(selector.entry(sgw, None).unwrap().load_metric, 40);
+    assert_eq!(selector.entry(sgw, Some("ims")), None);
+    assert_eq!(
+        selector.entry(pgw, None),
+        Some(&LoadEntry {
+            sqn: 12,
+            load_metric: 20,
+            relative_cap: None
+        })
+    );
+    assert_eq!(
+        selector.entry(pgw, Some("ims")),
+        Some(&LoadEntry {
+            sqn: 12,
+            load_metric: 70,
+            relative_cap: Some(50)
+        })
+    );
+}
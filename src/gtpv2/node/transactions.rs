@@ -0,0 +1,266 @@
+// GTPv2-C transaction handling - according to 3GPP TS 29.274 V17.10.0 (2023-12), clause 7.6
+
+use crate::{
+    commons::{DEFAULT_N3_REQUESTS, DEFAULT_T3_RESPONSE},
+    gtpv2::messages::GTPV2Message,
+};
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+
+// Sequence number is 24 bits, Command messages and their triggered requests have the MSB set
+
+pub const MAX_SQN: u32 = 0x00ffffff;
+pub const COMMAND_SQN_FLAG: u32 = 0x00800000;
+
+// Transaction timers
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionConfig {
+    pub t3_response: Duration,
+    pub n3_requests: u8,
+}
+
+impl Default for TransactionConfig {
+    fn default() -> Self {
+        TransactionConfig {
+            t3_response: DEFAULT_T3_RESPONSE,
+            n3_requests: DEFAULT_N3_REQUESTS,
+        }
+    }
+}
+
+impl TransactionConfig {
+    // Time during which a peer may retransmit a request, so a response has to be kept for duplicates
+
+    pub fn transaction_lifetime(&self) -> Duration {
+        self.t3_response * (self.n3_requests as u32 + 1)
+    }
+}
+
+// Outstanding initial message awaiting its triggered message
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingTransaction {
+    pub peer: SocketAddr,
+    pub sqn: u32,
+    pub request: GTPV2Message,
+    pub buffer: Vec<u8>,
+    pub retransmissions: u8,
+    pub deadline: Instant,
+}
+
+// Outcome of receiving an initial message
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequestDisposition {
+    New,
+    DuplicateInProgress,
+    DuplicateAnswered(Vec<u8>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ReceivedRequest {
+    response: Option<Vec<u8>>,
+    expires: Instant,
+}
+
+// Transaction table keyed by peer and sequence number.
+// It keeps the initial messages sent and awaiting a response, and the initial messages received
+// together with the response sent, so that duplicates can be answered without reprocessing.
+
+#[derive(Debug, Clone, Default)]
+pub struct TransactionTable {
+    pub config: TransactionConfig,
+    pending: HashMap<(SocketAddr, u32), PendingTransaction>,
+    received: HashMap<(SocketAddr, u32), ReceivedRequest>,
+}
+
+impl TransactionTable {
+    pub fn new(config: TransactionConfig) -> Self {
+        TransactionTable {
+            config,
+            ..TransactionTable::default()
+        }
+    }
+
+    pub fn add_pending(
+        &mut self,
+        peer: SocketAddr,
+        request: GTPV2Message,
+        buffer: Vec<u8>,
+        now: Instant,
+    ) {
+        let sqn = request.header().sqn;
+        self.pending.insert(
+            (peer, sqn),
+            PendingTransaction {
+                peer,
+                sqn,
+                request,
+                buffer,
+                retransmissions: 0,
+                deadline: now + self.config.t3_response,
+            },
+        );
+    }
+
+    pub fn pending(&self, peer: SocketAddr, sqn: u32) -> Option<&PendingTransaction> {
+        self.pending.get(&(peer, sqn))
+    }
+
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    // Close the transaction of a sent initial message on reception of its triggered message
+
+    pub fn complete(&mut self, peer: SocketAddr, sqn: u32) -> Option<PendingTransaction> {
+        self.pending.remove(&(peer, sqn))
+    }
+
+    // Register a received initial message and detect duplicates
+
+    pub fn on_request(&mut self, peer: SocketAddr, sqn: u32, now: Instant) -> RequestDisposition {
+        match self.received.get(&(peer, sqn)) {
+            Some(ReceivedRequest {
+                response: Some(i), ..
+            }) => RequestDisposition::DuplicateAnswered(i.clone()),
+            Some(_) => RequestDisposition::DuplicateInProgress,
+            None => {
+                self.received.insert(
+                    (peer, sqn),
+                    ReceivedRequest {
+                        response: None,
+                        expires: now + self.config.transaction_lifetime(),
+                    },
+                );
+                RequestDisposition::New
+            }
+        }
+    }
+
+    // Keep the response sent to a received initial message for retransmission on duplicates
+
+    pub fn cache_response(&mut self, peer: SocketAddr, sqn: u32, buffer: Vec<u8>, now: Instant) {
+        self.received.insert(
+            (peer, sqn),
+            ReceivedRequest {
+                response: Some(buffer),
+                expires: now + self.config.transaction_lifetime(),
+            },
+        );
+    }
+
+    // Run the timers: returns the requests to be retransmitted and the transactions that timed out
+
+    pub fn expire(
+        &mut self,
+        now: Instant,
+    ) -> (Vec<(SocketAddr, Vec<u8>)>, Vec<PendingTransaction>) {
+        let mut retransmit: Vec<(SocketAddr, Vec<u8>)> = vec![];
+        let mut expired: Vec<(SocketAddr, u32)> = vec![];
+        for (key, i) in self.pending.iter_mut() {
+            if i.deadline > now {
+                continue;
+            }
+            if i.retransmissions < self.config.n3_requests {
+                i.retransmissions += 1;
+                i.deadline = now + self.config.t3_response;
+                retransmit.push((i.peer, i.buffer.clone()));
+            } else {
+                expired.push(*key);
+            }
+        }
+        let timed_out = expired
+            .iter()
+            .filter_map(|x| self.pending.remove(x))
+            .collect();
+        self.received.retain(|_, i| i.expires > now);
+        (retransmit, timed_out)
+    }
+
+    // Earliest instant at which a pending transaction needs attention
+
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending.values().map(|x| x.deadline).min()
+    }
+}
+
+#[cfg(test)]
+fn test_peer() -> SocketAddr {
+    "127.0.0.1:2123".parse().unwrap()
+}
+
+#[cfg(test)]
+fn test_request(sqn: u32) -> GTPV2Message {
+    use crate::gtpv2::messages::EchoRequest;
+    let mut message = EchoRequest::default();
+    message.header.sqn = sqn;
+    GTPV2Message::EchoRequest(message)
+}
+
+#[test]
+fn transaction_retransmission_and_timeout_test() {
+    let config = TransactionConfig {
+        t3_response: Duration::from_millis(100),
+        n3_requests: 2,
+    };
+    let mut table = TransactionTable::new(config);
+    let start = Instant::now();
+    table.add_pending(test_peer(), test_request(10), vec![0x40, 0x01], start);
+    assert_eq!(table.next_deadline(), Some(start + config.t3_response));
+    let (retransmit, timed_out) = table.expire(start + Duration::from_millis(50));
+    assert!(retransmit.is_empty() && timed_out.is_empty());
+    let (retransmit, timed_out) = table.expire(start + Duration::from_millis(100));
+    assert_eq!(retransmit, vec![(test_peer(), vec![0x40, 0x01])]);
+    assert!(timed_out.is_empty());
+    let (retransmit, _) = table.expire(start + Duration::from_millis(200));
+    assert_eq!(retransmit.len(), 1);
+    let (retransmit, timed_out) = table.expire(start + Duration::from_millis(300));
+    assert!(retransmit.is_empty());
+    assert_eq!(timed_out.len(), 1);
+    assert_eq!(timed_out[0].sqn, 10);
+    assert_eq!(timed_out[0].retransmissions, 2);
+    assert_eq!(table.pending_len(), 0);
+}
+
+#[test]
+fn transaction_complete_test() {
+    let mut table = TransactionTable::default();
+    let now = Instant::now();
+    table.add_pending(test_peer(), test_request(20), vec![], now);
+    assert!(table.complete(test_peer(), 21).is_none());
+    assert_eq!(table.complete(test_peer(), 20).unwrap().sqn, 20);
+    assert!(table.complete(test_peer(), 20).is_none());
+}
+
+#[test]
+fn transaction_duplicate_request_test() {
+    let config = TransactionConfig {
+        t3_response: Duration::from_millis(100),
+        n3_requests: 1,
+    };
+    let mut table = TransactionTable::new(config);
+    let now = Instant::now();
+    assert_eq!(
+        table.on_request(test_peer(), 30, now),
+        RequestDisposition::New
+    );
+    assert_eq!(
+        table.on_request(test_peer(), 30, now),
+        RequestDisposition::DuplicateInProgress
+    );
+    table.cache_response(test_peer(), 30, vec![0x48, 0x02], now);
+    assert_eq!(
+        table.on_request(test_peer(), 30, now),
+        RequestDisposition::DuplicateAnswered(vec![0x48, 0x02])
+    );
+    table.expire(now + config.transaction_lifetime());
+    assert_eq!(
+        table.on_request(test_peer(), 30, now + config.transaction_lifetime()),
+        RequestDisposition::New
+    );
+}
@@ -14,7 +14,7 @@ pub fn tbcd_encode(number: &str) -> Vec<u8> {
         .flat_map(|c| c.to_digit(10))
         .map(|x| x as u8)
         .collect();
-    if chr.len() % 2 != 0 {
+    if !chr.len().is_multiple_of(2) {
         chr.push(0x0f);
     }
     let tbcd: Vec<u8> = chr
@@ -102,7 +102,7 @@ fn mcc_mnc_encode_test() {
 
     // Forced 3-digit MNC (MCC=310, MNC=012)
     let test_mnc_012: u16 = 12;
-    let encoded_number_310_012: [u8; 3] = [0x13, 0x00, 0x21];
+    let encoded_number_310_012: [u8; 3] = [0x13, 0x20, 0x10];
     assert_eq!(
         mcc_mnc_encode(test_mcc_310, test_mnc_012, true),
         encoded_number_310_012
@@ -166,7 +166,7 @@ fn mcc_mnc_decode_test() {
 
     // Forced 3-digit MNC (MCC=310, MNC=012)
     let test_mnc_012: u16 = 12;
-    let encoded_number_310_012: [u8; 3] = [0x13, 0x00, 0x21];
+    let encoded_number_310_012: [u8; 3] = [0x13, 0x20, 0x10];
     assert_eq!(
         mcc_mnc_decode(&encoded_number_310_012),
         (test_mcc_310, test_mnc_012, true)
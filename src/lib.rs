@@ -1,2 +1,4 @@
+pub mod commons;
+pub mod gtpprime;
 pub mod gtpv1;
 pub mod gtpv2;